@@ -1,7 +1,11 @@
+use miden_lib::transaction::TransactionKernel;
 use miden_objects::transaction::TransactionWitness;
+use miden_proving_service_client::{
+    MAX_PROOF_VERSION_METADATA_KEY, MIN_PROOF_VERSION_METADATA_KEY, PROOF_VERSION_METADATA_KEY,
+};
 use miden_tx::{
     utils::{Deserializable, Serializable},
-    LocalTransactionProver, TransactionProver,
+    LocalTransactionProver, TransactionProver, PROOF_VERSION,
 };
 use tokio::{net::TcpListener, sync::Mutex};
 use tonic::{Request, Response, Status};
@@ -10,9 +14,9 @@ use tracing::instrument;
 use crate::{
     generated::{
         api_server::{Api as ProverApi, ApiServer},
-        ProveTransactionRequest, ProveTransactionResponse,
+        ProveTransactionRequest, ProveTransactionResponse, StatusRequest, StatusResponse,
     },
-    utils::MIDEN_PROVING_SERVICE,
+    utils::{DEFAULT_MAX_TRACE_LENGTH, MIDEN_PROVING_SERVICE},
 };
 
 pub struct RpcListener {
@@ -21,15 +25,31 @@ pub struct RpcListener {
 }
 
 impl RpcListener {
-    pub fn new(listener: TcpListener) -> Self {
-        let api_service = ApiServer::new(ProverRpcApi::default());
+    pub fn new(listener: TcpListener, max_trace_length: u64) -> Self {
+        let api_service = ApiServer::new(ProverRpcApi::new(max_trace_length));
         Self { listener, api_service }
     }
 }
 
-#[derive(Default)]
 pub struct ProverRpcApi {
     local_prover: Mutex<LocalTransactionProver>,
+    /// The maximum trace length of the transactions this worker is configured to prove.
+    max_trace_length: u64,
+}
+
+impl ProverRpcApi {
+    pub fn new(max_trace_length: u64) -> Self {
+        Self {
+            local_prover: Mutex::new(LocalTransactionProver::default()),
+            max_trace_length,
+        }
+    }
+}
+
+impl Default for ProverRpcApi {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_TRACE_LENGTH)
+    }
 }
 
 #[async_trait::async_trait]
@@ -39,13 +59,19 @@ impl ProverApi for ProverRpcApi {
         name = "prover:prove_transaction",
         skip_all,
         ret(level = "debug"),
-        fields(transaction_id = tracing::field::Empty),
+        fields(
+            transaction_id = tracing::field::Empty,
+            kernel_version = %TransactionKernel::version(),
+            kernel_root = %TransactionKernel::kernel_root(),
+        ),
         err
     )]
     async fn prove_transaction(
         &self,
         request: Request<ProveTransactionRequest>,
     ) -> Result<Response<ProveTransactionResponse>, tonic::Status> {
+        check_proof_version(&request)?;
+
         // Try to acquire a permit without waiting
         let prover = self
             .local_prover
@@ -64,11 +90,60 @@ impl ProverApi for ProverRpcApi {
 
         Ok(Response::new(ProveTransactionResponse { proven_transaction: proof.to_bytes() }))
     }
+
+    #[instrument(
+        target = MIDEN_PROVING_SERVICE,
+        name = "prover:status",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn status(
+        &self,
+        _request: Request<StatusRequest>,
+    ) -> Result<Response<StatusResponse>, tonic::Status> {
+        Ok(Response::new(StatusResponse { max_trace_length: self.max_trace_length }))
+    }
 }
 
 // UTILITIES
 // ================================================================================================
 
+/// Checks that [PROOF_VERSION] is within the range of proof versions accepted by the client.
+///
+/// Bounds which are missing from the request metadata are not enforced. If the proof version is
+/// not accepted, returns a `FAILED_PRECONDITION` status carrying [PROOF_VERSION] in its metadata
+/// under the [PROOF_VERSION_METADATA_KEY] key.
+fn check_proof_version<T>(request: &Request<T>) -> Result<(), Status> {
+    let min_version = proof_version_bound(request, MIN_PROOF_VERSION_METADATA_KEY)?.unwrap_or(0);
+    let max_version =
+        proof_version_bound(request, MAX_PROOF_VERSION_METADATA_KEY)?.unwrap_or(u32::MAX);
+
+    if (min_version..=max_version).contains(&PROOF_VERSION) {
+        return Ok(());
+    }
+
+    let mut status = Status::failed_precondition(format!(
+        "proof version {PROOF_VERSION} is not in the accepted range {min_version}..={max_version}"
+    ));
+    status.metadata_mut().insert(PROOF_VERSION_METADATA_KEY, PROOF_VERSION.into());
+
+    Err(status)
+}
+
+/// Returns the proof version bound stored in the request metadata under the provided key, if any.
+fn proof_version_bound<T>(request: &Request<T>, key: &str) -> Result<Option<u32>, Status> {
+    request
+        .metadata()
+        .get(key)
+        .map(|value| {
+            value.to_str().ok().and_then(|value| value.parse().ok()).ok_or_else(|| {
+                Status::invalid_argument(format!("invalid value of the {key} metadata entry"))
+            })
+        })
+        .transpose()
+}
+
 /// Formats an error
 fn internal_error<E: core::fmt::Debug>(err: E) -> Status {
     Status::internal(format!("{:?}", err))
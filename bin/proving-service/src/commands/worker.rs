@@ -4,7 +4,11 @@ use tokio_stream::wrappers::TcpListenerStream;
 use tonic_health::server::health_reporter;
 use tracing::{info, instrument};
 
-use crate::{api::RpcListener, generated::api_server::ApiServer, utils::MIDEN_PROVING_SERVICE};
+use crate::{
+    api::RpcListener,
+    generated::api_server::ApiServer,
+    utils::{DEFAULT_MAX_TRACE_LENGTH, MIDEN_PROVING_SERVICE},
+};
 
 /// Starts a worker.
 #[derive(Debug, Parser)]
@@ -15,6 +19,12 @@ pub struct StartWorker {
     /// The port of the worker
     #[clap(short, long, default_value = "50051")]
     port: u16,
+    /// The maximum trace length of the transactions the worker is meant to prove
+    ///
+    /// The worker exposes it through its status endpoint, so that the proxy only routes
+    /// transactions with an estimated trace length up to this value to the worker.
+    #[clap(long, default_value_t = DEFAULT_MAX_TRACE_LENGTH)]
+    max_trace_length: u64,
 }
 
 impl StartWorker {
@@ -22,7 +32,8 @@ impl StartWorker {
     ///
     /// This method receives the host and port from the CLI and starts a worker on that address.
     /// In case that one of the parameters is not provided, it will default to `0.0.0.0` for the
-    /// host and `50051` for the port.
+    /// host and `50051` for the port. The maximum trace length defaults to
+    /// [DEFAULT_MAX_TRACE_LENGTH].
     ///
     /// The worker includes a health reporter that will mark the service as serving, following the
     /// [gRPC health checking protocol](
@@ -30,12 +41,15 @@ impl StartWorker {
     #[instrument(target = MIDEN_PROVING_SERVICE, name = "worker:execute")]
     pub async fn execute(&self) -> Result<(), String> {
         let worker_addr = format!("{}:{}", self.host, self.port);
-        let rpc =
-            RpcListener::new(TcpListener::bind(&worker_addr).await.map_err(|err| err.to_string())?);
+        let rpc = RpcListener::new(
+            TcpListener::bind(&worker_addr).await.map_err(|err| err.to_string())?,
+            self.max_trace_length,
+        );
 
         info!(
-            "Server listening on {}",
-            rpc.listener.local_addr().map_err(|err| err.to_string())?
+            "Server listening on {} with a maximum trace length of {}",
+            rpc.listener.local_addr().map_err(|err| err.to_string())?,
+            self.max_trace_length
         );
 
         // Create a health reporter
@@ -29,20 +29,19 @@ mod test {
 
     use miden_lib::transaction::TransactionKernel;
     use miden_objects::{
-        asset::{Asset, FungibleAsset},
-        note::NoteType,
-        testing::{
-            account_code::DEFAULT_AUTH_SCRIPT,
-            account_id::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
-        },
+        testing::account_code::DEFAULT_AUTH_SCRIPT,
         transaction::{ProvenTransaction, TransactionScript, TransactionWitness},
     };
+    use miden_proving_service_client::{
+        MAX_PROOF_VERSION_METADATA_KEY, MIN_PROOF_VERSION_METADATA_KEY, PROOF_VERSION_METADATA_KEY,
+    };
     use miden_tx::{
-        testing::{Auth, MockChain},
+        testing::{fixtures, Auth, MockChain},
         utils::Serializable,
+        validate_proven_transaction_stateless, StatelessValidationConfig, PROOF_VERSION,
     };
     use tokio::net::TcpListener;
-    use tonic::Request;
+    use tonic::{Code, Request};
 
     use crate::{
         api::ProverRpcApi,
@@ -76,19 +75,7 @@ mod test {
         let mut mock_chain = MockChain::new();
         let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
 
-        let fungible_asset_1: Asset =
-            FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap(), 100)
-                .unwrap()
-                .into();
-        let note_1 = mock_chain
-            .add_p2id_note(
-                ACCOUNT_ID_SENDER.try_into().unwrap(),
-                account.id(),
-                &[fungible_asset_1],
-                NoteType::Private,
-                None,
-            )
-            .unwrap();
+        let note_1 = fixtures::note::p2id_100_to(account.id());
 
         let tx_script =
             TransactionScript::compile(DEFAULT_AUTH_SCRIPT, vec![], TransactionKernel::assembler())
@@ -128,7 +115,49 @@ mod test {
         let response_success = response_1.or(response_2).unwrap();
 
         // Cast into a ProvenTransaction
-        let _proven_transaction: ProvenTransaction =
+        let proven_transaction: ProvenTransaction =
             response_success.into_inner().try_into().expect("Failed to convert response");
+
+        // Check that the proven transaction passes the validation performed before it is admitted
+        // to the mempool
+        let config = StatelessValidationConfig::new([TransactionKernel::kernel_root()]);
+        validate_proven_transaction_stateless(&proven_transaction, &config).unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_prove_transaction_with_proof_version_mismatch() {
+        // Start the server in the background
+        let listener = TcpListener::bind("127.0.0.1:50053").await.unwrap();
+        let api_service = ApiServer::new(ProverRpcApi::default());
+
+        tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .accept_http1(true)
+                .add_service(tonic_web::enable(api_service))
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+                .await
+                .unwrap();
+        });
+
+        // Give the server some time to start
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        let mut client = ApiClient::connect("http://127.0.0.1:50053").await.unwrap();
+
+        // The client only accepts proofs of versions newer than the ones generated by the server
+        let mut request = Request::new(ProveTransactionRequest { transaction_witness: vec![] });
+        request
+            .metadata_mut()
+            .insert(MIN_PROOF_VERSION_METADATA_KEY, (PROOF_VERSION + 1).into());
+        request
+            .metadata_mut()
+            .insert(MAX_PROOF_VERSION_METADATA_KEY, (PROOF_VERSION + 2).into());
+
+        let status = client.prove_transaction(request).await.unwrap_err();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(
+            status.metadata().get(PROOF_VERSION_METADATA_KEY).unwrap(),
+            PROOF_VERSION.to_string().as_str()
+        );
     }
 }
@@ -13,6 +13,7 @@ use metrics::{
     REQUEST_FAILURE_COUNT, REQUEST_LATENCY, REQUEST_RETRIES, WORKER_BUSY, WORKER_COUNT,
     WORKER_REQUEST_COUNT, WORKER_UNHEALTHY,
 };
+use miden_proving_service_client::TRACE_LENGTH_ESTIMATE_METADATA_KEY;
 use pingora::{
     http::ResponseHeader,
     lb::Backend,
@@ -103,16 +104,30 @@ impl LoadBalancerState {
         })
     }
 
-    /// Gets an available worker and marks it as unavailable.
+    /// Gets an available worker which can prove a transaction with the given estimated trace
+    /// length and marks it as unavailable.
     ///
-    /// If no worker is available, it will return None.
-    pub async fn pop_available_worker(&self) -> Option<Worker> {
+    /// If no such worker is available, it will return None.
+    pub async fn pop_available_worker(&self, trace_length_estimate: Option<u64>) -> Option<Worker> {
         let mut available_workers = self.workers.write().await;
-        available_workers.iter_mut().find(|w| w.is_available()).map(|w| {
-            w.set_availability(false);
-            WORKER_BUSY.inc();
-            w.clone()
-        })
+        available_workers
+            .iter_mut()
+            .find(|w| w.is_available() && w.can_prove(trace_length_estimate))
+            .map(|w| {
+                w.set_availability(false);
+                WORKER_BUSY.inc();
+                w.clone()
+            })
+    }
+
+    /// Returns `true` if any of the current workers, available or not, can prove a transaction
+    /// with the given estimated trace length.
+    pub async fn has_capable_worker(&self, trace_length_estimate: u64) -> bool {
+        self.workers
+            .read()
+            .await
+            .iter()
+            .any(|w| w.can_prove(Some(trace_length_estimate)))
     }
 
     /// Marks the given worker as available.
@@ -350,6 +365,8 @@ pub struct RequestContext {
     parent_span: Span,
     /// Time when the request was created
     created_at: Instant,
+    /// Estimated trace length of the transaction to prove, if provided by the client
+    trace_length_estimate: Option<u64>,
 }
 
 impl RequestContext {
@@ -362,6 +379,7 @@ impl RequestContext {
             worker: None,
             parent_span: info_span!(target: MIDEN_PROVING_SERVICE, "proxy:new_request", request_id = request_id.to_string()),
             created_at: Instant::now(),
+            trace_length_estimate: None,
         }
     }
 
@@ -444,6 +462,8 @@ impl ProxyHttp for LoadBalancer {
         // Increment the request count
         REQUEST_COUNT.inc();
 
+        ctx.trace_length_estimate = get_trace_length_estimate(session);
+
         let user_id = Some(client_addr);
 
         // Retrieve the current window requests
@@ -471,14 +491,28 @@ impl ProxyHttp for LoadBalancer {
             return create_queue_full_response(session).await;
         }
 
+        // Check if any worker can prove the transaction, otherwise the request would wait in the
+        // queue forever
+        if let Some(trace_length_estimate) = ctx.trace_length_estimate {
+            if !self.0.has_capable_worker(trace_length_estimate).await {
+                let error_message = format!(
+                    "No worker can prove a transaction with an estimated trace length of {}",
+                    trace_length_estimate
+                );
+                error!("{}", error_message);
+                return create_response_with_error_message(session, error_message).await;
+            }
+        }
+
         Ok(false)
     }
 
     /// Returns [HttpPeer] corresponding to the worker that will handle the current request.
     ///
     /// Here we enqueue the request and wait for it to be at the front of the queue and a worker
-    /// becomes available, then we dequeue the request and process it. We then set the SNI,
-    /// timeouts, and enable HTTP/2.
+    /// which can prove transactions with the estimated trace length of the request becomes
+    /// available, then we dequeue the request and process it. We then set the SNI, timeouts, and
+    /// enable HTTP/2.
     ///
     /// Note that the request will be assigned a worker here, and the worker will be removed from
     /// the list of available workers once it reaches the [Self::logging] method.
@@ -501,7 +535,7 @@ impl ProxyHttp for LoadBalancer {
             }
 
             // Check if there is an available worker
-            if let Some(worker) = self.0.pop_available_worker().await {
+            if let Some(worker) = self.0.pop_available_worker(ctx.trace_length_estimate).await {
                 info!("Worker {} picked up the request with ID: {}", worker.address(), request_id);
                 ctx.set_worker(worker);
                 break;
@@ -722,6 +756,21 @@ impl ProxyHttp for LoadBalancer {
     }
 }
 
+/// Returns the estimated trace length of the transaction to prove from the request metadata.
+///
+/// If the metadata entry is missing or cannot be parsed, the request is not restricted to any
+/// worker and `None` is returned.
+fn get_trace_length_estimate(session: &Session) -> Option<u64> {
+    let header = session.req_header().headers.get(TRACE_LENGTH_ESTIMATE_METADATA_KEY)?;
+    match header.to_str().ok().and_then(|value| value.parse().ok()) {
+        Some(trace_length_estimate) => Some(trace_length_estimate),
+        None => {
+            warn!("Invalid trace length estimate: {:?}", header);
+            None
+        },
+    }
+}
+
 // PROXY HTTP DEFAULT IMPLEMENTATION
 // ================================================================================================
 
@@ -5,9 +5,13 @@ use tonic::transport::Channel;
 use tonic_health::pb::{
     health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
 };
-use tracing::error;
+use tracing::{error, warn};
 
-use crate::{error::TxProverServiceError, utils::create_health_check_client};
+use crate::{
+    error::TxProverServiceError,
+    generated::StatusRequest,
+    utils::{create_api_client, create_health_check_client, DEFAULT_MAX_TRACE_LENGTH},
+};
 
 // WORKER
 // ================================================================================================
@@ -15,17 +19,23 @@ use crate::{error::TxProverServiceError, utils::create_health_check_client};
 /// A worker used for processing of requests.
 ///
 /// A worker consists of a backend service (defined by worker address), a flag indicating wheter
-/// the worker is currently available to process new requests, and a gRPC health check client.
+/// the worker is currently available to process new requests, a gRPC health check client, and the
+/// maximum trace length of the transactions the worker is configured to prove.
 #[derive(Debug, Clone)]
 pub struct Worker {
     backend: Backend,
     health_check_client: HealthClient<Channel>,
     is_available: bool,
+    max_trace_length: u64,
 }
 
 impl Worker {
     /// Creates a new worker and a gRPC health check client for the given worker address.
     ///
+    /// The maximum trace length of the worker is requested from its status endpoint. If the status
+    /// cannot be retrieved, e.g. because the worker does not implement the endpoint yet, the worker
+    /// is assumed to accept transactions of any length, i.e. [DEFAULT_MAX_TRACE_LENGTH].
+    ///
     /// # Errors
     /// - Returns [TxProverServiceError::InvalidURI] if the worker address is invalid.
     /// - Returns [TxProverServiceError::ConnectionFailed] if the connection to the worker fails.
@@ -34,14 +44,28 @@ impl Worker {
         connection_timeout: Duration,
         total_timeout: Duration,
     ) -> Result<Self, TxProverServiceError> {
+        let address = worker.addr.to_string();
         let health_check_client =
-            create_health_check_client(worker.addr.to_string(), connection_timeout, total_timeout)
-                .await?;
+            create_health_check_client(address.clone(), connection_timeout, total_timeout).await?;
+
+        let mut api_client =
+            create_api_client(address.clone(), connection_timeout, total_timeout).await?;
+        let max_trace_length = match api_client.status(StatusRequest {}).await {
+            Ok(response) => response.into_inner().max_trace_length,
+            Err(err) => {
+                warn!(
+                    "Failed to get the status of worker ({}), assuming the default maximum trace length: {}",
+                    address, err
+                );
+                DEFAULT_MAX_TRACE_LENGTH
+            },
+        };
 
         Ok(Self {
             backend: worker,
             is_available: true,
             health_check_client,
+            max_trace_length,
         })
     }
 
@@ -70,6 +94,13 @@ impl Worker {
     pub fn set_availability(&mut self, is_available: bool) {
         self.is_available = is_available;
     }
+
+    /// Returns `true` if this worker is configured to prove a transaction with the given
+    /// estimated trace length. Transactions without an estimate can be proven by any worker.
+    pub fn can_prove(&self, trace_length_estimate: Option<u64>) -> bool {
+        trace_length_estimate
+            .is_none_or(|trace_length_estimate| trace_length_estimate <= self.max_trace_length)
+    }
 }
 
 impl PartialEq for Worker {
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use miden_objects::MAX_TX_EXECUTION_CYCLES;
 use opentelemetry::{trace::TracerProvider as _, KeyValue};
 use opentelemetry_sdk::{
     runtime,
@@ -16,7 +17,9 @@ use tonic::transport::Channel;
 use tonic_health::pb::health_client::HealthClient;
 use tracing_subscriber::{layer::SubscriberExt, Registry};
 
-use crate::{error::TxProverServiceError, proxy::metrics::QUEUE_DROP_COUNT};
+use crate::{
+    error::TxProverServiceError, generated::api_client::ApiClient, proxy::metrics::QUEUE_DROP_COUNT,
+};
 
 pub const MIDEN_PROVING_SERVICE: &str = "miden-proving-service";
 
@@ -25,6 +28,12 @@ const RESOURCE_EXHAUSTED_CODE: u16 = 8;
 /// Name of the configuration file
 pub const PROVING_SERVICE_CONFIG_FILE_NAME: &str = "miden-proving-service.toml";
 
+/// Default maximum trace length of the transactions a worker is configured to prove.
+///
+/// It corresponds to the maximum number of cycles a transaction is allowed to take, so by default
+/// a worker accepts all transactions.
+pub const DEFAULT_MAX_TRACE_LENGTH: u64 = MAX_TX_EXECUTION_CYCLES as u64;
+
 /// Initializes and configures the global tracing and telemetry system for the CLI, worker and
 /// proxy services.
 ///
@@ -175,13 +184,35 @@ pub async fn create_health_check_client(
     connection_timeout: Duration,
     total_timeout: Duration,
 ) -> Result<HealthClient<Channel>, TxProverServiceError> {
-    let channel = Channel::from_shared(format!("http://{}", address))
+    let channel = create_worker_channel(address, connection_timeout, total_timeout).await?;
+    Ok(HealthClient::new(channel))
+}
+
+/// Create a gRPC [ApiClient] for the given worker address.
+///
+/// # Errors
+/// - [TxProverServiceError::InvalidURI] if the worker address is invalid.
+/// - [TxProverServiceError::ConnectionFailed] if the connection to the worker fails.
+pub async fn create_api_client(
+    address: String,
+    connection_timeout: Duration,
+    total_timeout: Duration,
+) -> Result<ApiClient<Channel>, TxProverServiceError> {
+    let channel = create_worker_channel(address, connection_timeout, total_timeout).await?;
+    Ok(ApiClient::new(channel))
+}
+
+/// Create a gRPC [Channel] connected to the given worker address.
+async fn create_worker_channel(
+    address: String,
+    connection_timeout: Duration,
+    total_timeout: Duration,
+) -> Result<Channel, TxProverServiceError> {
+    Channel::from_shared(format!("http://{}", address))
         .map_err(|err| TxProverServiceError::InvalidURI(err, address.clone()))?
         .connect_timeout(connection_timeout)
         .timeout(total_timeout)
         .connect()
         .await
-        .map_err(|err| TxProverServiceError::ConnectionFailed(err, address))?;
-
-    Ok(HealthClient::new(channel))
+        .map_err(|err| TxProverServiceError::ConnectionFailed(err, address))
 }
@@ -79,9 +79,64 @@ fn main() -> Result<()> {
 
     generate_kernel_error_constants(&source_dir)?;
 
+    set_build_description(&crate_dir);
+
     Ok(())
 }
 
+// BUILD DESCRIPTION
+// ================================================================================================
+
+/// Sets the `MIDEN_LIB_BUILD_DESCRIPTION` environment variable for the compilation of the crate to
+/// a description of the source revision, as returned by `git describe`.
+///
+/// The description is only determined if the `MIDEN_LIB_GIT_DESCRIBE` environment variable is set,
+/// since the crate may be built from within the git checkout of another project, e.g. if it is
+/// vendored. Otherwise, or if the revision cannot be determined, the description is left empty so
+/// that the kernel version only consists of the crate version and builds remain reproducible.
+///
+/// The description is refreshed whenever `HEAD` or the branch it points to changes.
+fn set_build_description(crate_dir: &str) {
+    println!("cargo::rerun-if-env-changed=MIDEN_LIB_GIT_DESCRIBE");
+    if env::var_os("MIDEN_LIB_GIT_DESCRIBE").is_none() {
+        println!("cargo:rustc-env=MIDEN_LIB_BUILD_DESCRIPTION=");
+        return;
+    }
+
+    // re-run when another revision is checked out or the checked out branch moves
+    let mut watched_paths = vec!["HEAD".to_string()];
+    if let Some(head_ref) = run_git(crate_dir, &["symbolic-ref", "-q", "HEAD"]) {
+        watched_paths.extend([head_ref, "packed-refs".to_string()]);
+    }
+    for path in watched_paths {
+        if let Some(path) = run_git(crate_dir, &["rev-parse", "--git-path", &path]) {
+            let path = Path::new(crate_dir).join(path);
+            // cargo always re-runs the build script if a watched path does not exist
+            if path.exists() {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+    }
+
+    let description = run_git(crate_dir, &["describe", "--always", "--tags"]).unwrap_or_default();
+
+    println!("cargo:rustc-env=MIDEN_LIB_BUILD_DESCRIPTION={description}");
+}
+
+/// Runs git with the provided arguments in `dir` and returns its trimmed output, or `None` if git
+/// fails or its output is empty.
+fn run_git(dir: &str, args: &[&str]) -> Option<String> {
+    std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|output| output.trim().to_string())
+        .filter(|output| !output.is_empty())
+}
+
 // COMPILE TRANSACTION KERNEL
 // ================================================================================================
 
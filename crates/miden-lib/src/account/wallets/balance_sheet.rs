@@ -0,0 +1,354 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use miden_objects::{
+    account::{Account, AccountId},
+    asset::{Asset, NonFungibleAsset},
+    block::BlockNumber,
+    note::Note,
+    transaction::InputNote,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use crate::note::WellKnownNote;
+
+// BALANCE SHEET
+// ================================================================================================
+
+/// The balances of a wallet account, split by how certain it is that the assets can be spent.
+///
+/// The balance sheet consists of three buckets:
+/// - `available`: the assets currently stored in the vault of the account.
+/// - `incoming_authenticated`: the assets of input notes which the account can consume and whose
+///   inclusion in the chain has been proven.
+/// - `incoming_unauthenticated`: the assets of input notes which the account can consume, but whose
+///   inclusion in the chain has not been proven yet.
+///
+/// Only well-known notes whose consumption is determined by their inputs are taken into account,
+/// i.e., P2ID notes targeting the account, and P2IDR notes which either target the account or
+/// which were sent by the account and can be reclaimed at the provided block. The assets of all
+/// other notes are ignored, as it cannot be determined whether the account is able to consume them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BalanceSheet {
+    available: AssetBalances,
+    incoming_authenticated: AssetBalances,
+    incoming_unauthenticated: AssetBalances,
+}
+
+impl BalanceSheet {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the balance sheet of the provided account given the provided incoming notes.
+    ///
+    /// `block_num` is the number of the block against which the notes would be consumed, and
+    /// determines whether P2IDR notes sent by the account can be reclaimed.
+    pub fn compute(account: &Account, incoming: &[InputNote], block_num: BlockNumber) -> Self {
+        let mut balance_sheet = Self::default();
+
+        for asset in account.vault().assets() {
+            balance_sheet.available.add_asset(asset);
+        }
+
+        for input_note in incoming {
+            let note = input_note.note();
+            if !is_consumable_by(note, account.id(), block_num) {
+                continue;
+            }
+
+            let balances = match input_note {
+                InputNote::Authenticated { .. } => &mut balance_sheet.incoming_authenticated,
+                InputNote::Unauthenticated { .. } => &mut balance_sheet.incoming_unauthenticated,
+            };
+            for asset in note.assets().iter() {
+                balances.add_asset(*asset);
+            }
+        }
+
+        balance_sheet
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the balances of the assets stored in the vault of the account.
+    pub fn available(&self) -> &AssetBalances {
+        &self.available
+    }
+
+    /// Returns the balances of the assets of authenticated notes the account can consume.
+    pub fn incoming_authenticated(&self) -> &AssetBalances {
+        &self.incoming_authenticated
+    }
+
+    /// Returns the balances of the assets of unauthenticated notes the account can consume.
+    pub fn incoming_unauthenticated(&self) -> &AssetBalances {
+        &self.incoming_unauthenticated
+    }
+}
+
+// ASSET BALANCES
+// ================================================================================================
+
+/// The fungible totals per faucet and the non-fungible assets of a single bucket of a
+/// [BalanceSheet].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetBalances {
+    fungible: BTreeMap<AccountId, u64>,
+    non_fungible: BTreeSet<NonFungibleAsset>,
+}
+
+impl AssetBalances {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the total amount of the fungible asset issued by the provided faucet.
+    pub fn fungible(&self, faucet_id: AccountId) -> u64 {
+        self.fungible.get(&faucet_id).copied().unwrap_or_default()
+    }
+
+    /// Returns the total amounts of fungible assets keyed by the ID of their faucet.
+    pub fn fungible_totals(&self) -> &BTreeMap<AccountId, u64> {
+        &self.fungible
+    }
+
+    /// Returns the non-fungible assets.
+    pub fn non_fungible(&self) -> &BTreeSet<NonFungibleAsset> {
+        &self.non_fungible
+    }
+
+    /// Returns `true` if there are no assets in this bucket.
+    pub fn is_empty(&self) -> bool {
+        self.fungible.is_empty() && self.non_fungible.is_empty()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided asset to the balances.
+    ///
+    /// Fungible totals saturate at `u64::MAX`, as the amounts of the assets of several notes may
+    /// exceed the maximum amount of a single fungible asset.
+    fn add_asset(&mut self, asset: Asset) {
+        match asset {
+            Asset::Fungible(asset) => {
+                let total = self.fungible.entry(asset.faucet_id()).or_default();
+                *total = total.saturating_add(asset.amount());
+            },
+            Asset::NonFungible(asset) => {
+                self.non_fungible.insert(asset);
+            },
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns `true` if the provided note is a P2ID or P2IDR note which the account with the provided
+/// ID can consume at the provided block.
+fn is_consumable_by(note: &Note, account_id: AccountId, block_num: BlockNumber) -> bool {
+    let Some(well_known_note) = WellKnownNote::from_note(note) else {
+        return false;
+    };
+
+    let inputs = note.inputs().values();
+    if inputs.len() != well_known_note.expected_num_inputs() {
+        return false;
+    }
+
+    let is_target = WellKnownNote::target_account_id(note) == Some(account_id);
+
+    match well_known_note {
+        WellKnownNote::P2ID => is_target,
+        WellKnownNote::P2IDR => {
+            // the sender may reclaim the note at and after the reclaim block height
+            let can_reclaim = note.metadata().sender() == account_id
+                && u32::try_from(inputs[2].as_int())
+                    .is_ok_and(|reclaim_height| block_num.as_u32() >= reclaim_height);
+
+            is_target || can_reclaim
+        },
+        WellKnownNote::P2ID_TIMELOCK
+        | WellKnownNote::P2IDR_PARTIAL
+        | WellKnownNote::SWAP
+        | WellKnownNote::BURN => false,
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BalanceSheet {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.available.write_into(target);
+        self.incoming_authenticated.write_into(target);
+        self.incoming_unauthenticated.write_into(target);
+    }
+}
+
+impl Deserializable for BalanceSheet {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let available = AssetBalances::read_from(source)?;
+        let incoming_authenticated = AssetBalances::read_from(source)?;
+        let incoming_unauthenticated = AssetBalances::read_from(source)?;
+
+        Ok(Self {
+            available,
+            incoming_authenticated,
+            incoming_unauthenticated,
+        })
+    }
+}
+
+impl Serializable for AssetBalances {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.fungible.write_into(target);
+        self.non_fungible.write_into(target);
+    }
+}
+
+impl Deserializable for AssetBalances {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let fungible = BTreeMap::<AccountId, u64>::read_from(source)?;
+        let non_fungible = BTreeSet::<NonFungibleAsset>::read_from(source)?;
+
+        Ok(Self { fungible, non_fungible })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_objects::{
+        account::AccountBuilder,
+        asset::FungibleAsset,
+        crypto::{dsa::rpo_falcon512::PublicKey, merkle::MerklePath, rand::RpoRandomCoin},
+        note::{NoteInclusionProof, NoteType},
+        testing::account_id::{
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Felt, ONE, ZERO,
+    };
+
+    use super::*;
+    use crate::{
+        account::{auth::RpoFalcon512, wallets::BasicWallet},
+        note::{create_p2id_note, create_p2idr_note},
+    };
+
+    fn authenticated(note: Note) -> InputNote {
+        let proof = NoteInclusionProof::new(1.into(), 0, MerklePath::new(vec![])).unwrap();
+        InputNote::authenticated(note, proof)
+    }
+
+    #[test]
+    fn balance_sheet_of_mixed_notes() {
+        let fungible_asset = FungibleAsset::mock(100);
+        let faucet_id = FungibleAsset::mock_issuer();
+        let non_fungible_asset = NonFungibleAsset::mock(&[1, 2, 3]);
+
+        let account = AccountBuilder::new([7; 32])
+            .with_component(RpoFalcon512::new(PublicKey::new([ONE; 4])))
+            .with_component(BasicWallet)
+            .with_assets([fungible_asset, non_fungible_asset])
+            .build_existing()
+            .unwrap();
+        let account_id = account.id();
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let other =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let mut rng = RpoRandomCoin::new([Felt::new(3); 4]);
+
+        let asset = |amount| FungibleAsset::new(faucet_id, amount).unwrap().into();
+        let p2id = |sender, target, assets, rng: &mut RpoRandomCoin| {
+            create_p2id_note(sender, target, assets, NoteType::Public, ZERO, rng).unwrap()
+        };
+        let p2idr = |sender, target, assets, reclaim_height: u32, rng: &mut RpoRandomCoin| {
+            create_p2idr_note(
+                sender,
+                target,
+                assets,
+                NoteType::Public,
+                ZERO,
+                reclaim_height.into(),
+                rng,
+            )
+            .unwrap()
+        };
+
+        let incoming = [
+            // a P2ID note for the account
+            authenticated(p2id(sender, account_id, vec![asset(10), non_fungible_asset], &mut rng)),
+            // an unauthenticated P2ID note for the account
+            InputNote::unauthenticated(p2id(sender, account_id, vec![asset(20)], &mut rng)),
+            // a P2ID note addressed to another account
+            authenticated(p2id(sender, other, vec![asset(40)], &mut rng)),
+            // a P2IDR note for the account whose reclaim height has not been reached
+            InputNote::unauthenticated(p2idr(sender, account_id, vec![asset(80)], 100, &mut rng)),
+            // a P2IDR note sent by the account which it can reclaim
+            authenticated(p2idr(account_id, other, vec![asset(160)], 5, &mut rng)),
+            // a P2IDR note sent by the account whose reclaim height has not been reached
+            authenticated(p2idr(account_id, other, vec![asset(320)], 11, &mut rng)),
+            // a reclaimable P2IDR note sent by another account
+            authenticated(p2idr(sender, other, vec![asset(640)], 5, &mut rng)),
+        ];
+
+        let balance_sheet = BalanceSheet::compute(&account, &incoming, 10.into());
+
+        let available = balance_sheet.available();
+        assert_eq!(available.fungible(faucet_id), 100);
+        assert_eq!(available.fungible_totals().len(), 1);
+        assert_eq!(
+            available.non_fungible().iter().copied().map(Asset::from).collect::<Vec<_>>(),
+            vec![non_fungible_asset]
+        );
+
+        let authenticated_balances = balance_sheet.incoming_authenticated();
+        assert_eq!(authenticated_balances.fungible(faucet_id), 10 + 160);
+        assert_eq!(authenticated_balances.non_fungible().len(), 1);
+
+        let unauthenticated_balances = balance_sheet.incoming_unauthenticated();
+        assert_eq!(unauthenticated_balances.fungible(faucet_id), 20 + 80);
+        assert!(unauthenticated_balances.non_fungible().is_empty());
+
+        // at the later block, the second P2IDR note sent by the account can be reclaimed as well
+        let balance_sheet = BalanceSheet::compute(&account, &incoming, 11.into());
+        assert_eq!(balance_sheet.incoming_authenticated().fungible(faucet_id), 10 + 160 + 320);
+
+        // without incoming notes, only the vault of the account is accounted for
+        let balance_sheet = BalanceSheet::compute(&account, &[], 10.into());
+        assert_eq!(balance_sheet.available(), available);
+        assert!(balance_sheet.incoming_authenticated().is_empty());
+        assert!(balance_sheet.incoming_unauthenticated().is_empty());
+    }
+
+    #[test]
+    fn balance_sheet_serialization() {
+        let account = AccountBuilder::new([7; 32])
+            .with_component(RpoFalcon512::new(PublicKey::new([ONE; 4])))
+            .with_component(BasicWallet)
+            .with_assets([FungibleAsset::mock(100), NonFungibleAsset::mock(&[1, 2, 3])])
+            .build_existing()
+            .unwrap();
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let mut rng = RpoRandomCoin::new([Felt::new(3); 4]);
+        let note = create_p2id_note(
+            sender,
+            account.id(),
+            vec![FungibleAsset::mock(50)],
+            NoteType::Private,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+
+        let balance_sheet =
+            BalanceSheet::compute(&account, &[InputNote::unauthenticated(note)], 1.into());
+        let bytes = balance_sheet.to_bytes();
+        assert_eq!(BalanceSheet::read_from_bytes(&bytes).unwrap(), balance_sheet);
+    }
+}
@@ -10,6 +10,9 @@ use miden_objects::{
 use super::AuthScheme;
 use crate::account::{auth::RpoFalcon512, components::basic_wallet_library};
 
+mod balance_sheet;
+pub use balance_sheet::{AssetBalances, BalanceSheet};
+
 // BASIC WALLET
 // ================================================================================================
 
@@ -21,6 +21,7 @@ pub const ERR_ACCOUNT_INVALID_STORAGE_OFFSET_FOR_SIZE: u32 = 0x00020013;
 pub const ERR_ACCOUNT_IS_NOT_NATIVE: u32 = 0x00020030;
 pub const ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE: u32 = 0x00020028;
 pub const ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32: u32 = 0x00020004;
+pub const ERR_ACCOUNT_NONCE_MUST_STRICTLY_INCREASE: u32 = 0x0002005C;
 pub const ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS: u32 = 0x0002000C;
 pub const ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE: u32 = 0x0002000B;
 pub const ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT: u32 = 0x00020002;
@@ -66,19 +67,28 @@ pub const ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID: u32 = 0x0002004D
 pub const ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT: u32 = 0x00020032;
 pub const ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT: u32 = 0x00020033;
 pub const ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT: u32 = 0x00020031;
+pub const ERR_NOTE_ATTEMPT_TO_FORWARD_ASSET_FROM_INCORRECT_CONTEXT: u32 = 0x00020061;
 pub const ERR_NOTE_DATA_DOES_NOT_MATCH_COMMITMENT: u32 = 0x0002004E;
+pub const ERR_NOTE_FORWARD_ASSET_TO_NOTE_NOT_CREATED_BY_CURRENT_NOTE: u32 = 0x00020065;
 pub const ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED: u32 = 0x00020046;
 pub const ERR_NOTE_INVALID_INDEX: u32 = 0x00020048;
 pub const ERR_NOTE_INVALID_NOTE_TYPE_FOR_NOTE_TAG_PREFIX: u32 = 0x00020044;
 pub const ERR_NOTE_INVALID_TYPE: u32 = 0x00020043;
-pub const ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT: u32 = 0x0002002F;
+pub const ERR_NOTE_NUM_OF_ASSETS_MUST_BE_U32: u32 = 0x0002002F;
 pub const ERR_NOTE_TAG_MUST_BE_U32: u32 = 0x00020045;
+pub const ERR_NOTE_TOO_MANY_ASSETS: u32 = 0x0002005D;
 
+pub const ERR_P2IDR_PARTIAL_ASSET_NOT_FUNGIBLE: u32 = 0x00020060;
+pub const ERR_P2IDR_PARTIAL_WRONG_NUMBER_OF_ASSETS: u32 = 0x0002005F;
+pub const ERR_P2IDR_PARTIAL_WRONG_NUMBER_OF_INPUTS: u32 = 0x0002005E;
 pub const ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER: u32 = 0x00020053;
 pub const ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED: u32 = 0x00020054;
 pub const ERR_P2IDR_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020052;
 
 pub const ERR_P2ID_TARGET_ACCT_MISMATCH: u32 = 0x00020051;
+pub const ERR_P2ID_TIMELOCK_TARGET_ACCT_MISMATCH: u32 = 0x00020063;
+pub const ERR_P2ID_TIMELOCK_UNLOCK_TIMESTAMP_NOT_REACHED: u32 = 0x00020064;
+pub const ERR_P2ID_TIMELOCK_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020062;
 pub const ERR_P2ID_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020050;
 
 pub const ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE: u32 = 0x0002003B;
@@ -115,7 +125,7 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x0002001C;
 pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: u32 = 0x0002001F;
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: u32 = 0x0002001E;
 
-pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
+pub const TX_KERNEL_ERRORS: [(u32, &str); 100] = [
     (ERR_ACCOUNT_ANCHOR_BLOCK_HASH_MUST_NOT_BE_EMPTY, "Anchor block hash must not be empty"),
     (ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH, "Computed account code commitment does not match recorded account code commitment"),
     (ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, "Account code must be updatable for it to be possible to set new code"),
@@ -127,6 +137,7 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_ACCOUNT_IS_NOT_NATIVE, "The current account is not native"),
     (ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE, "Account nonce did not increase after a state changing transaction"),
     (ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32, "Account nonce cannot be increased by a greater than u32 value"),
+    (ERR_ACCOUNT_NONCE_MUST_STRICTLY_INCREASE, "Account nonce must strictly increase and must not wrap around the field modulus"),
     (ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS, "Provided procedure index is out of bounds"),
     (ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE, "Account procedure is not part of the account code"),
     (ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT, "Failed to read an account map item from a non-map storage slot"),
@@ -172,19 +183,28 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT, "Attempted to access note assets from incorrect context"),
     (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT, "Attempted to access note inputs from incorrect context"),
     (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT, "Attempted to access note sender from incorrect context"),
+    (ERR_NOTE_ATTEMPT_TO_FORWARD_ASSET_FROM_INCORRECT_CONTEXT, "Attempted to forward an asset from incorrect context"),
     (ERR_NOTE_DATA_DOES_NOT_MATCH_COMMITMENT, "Note data does not match the commitment"),
+    (ERR_NOTE_FORWARD_ASSET_TO_NOTE_NOT_CREATED_BY_CURRENT_NOTE, "Assets can only be forwarded to output notes created by the note currently being processed"),
     (ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED, "Adding a fungible asset to a note cannot exceed the max_amount of 9223372036854775807"),
     (ERR_NOTE_INVALID_INDEX, "Failed to find note at the given index; index must be within [0, num_of_notes]"),
     (ERR_NOTE_INVALID_NOTE_TYPE_FOR_NOTE_TAG_PREFIX, "Invalid note type for the given note tag prefix"),
     (ERR_NOTE_INVALID_TYPE, "Invalid note type"),
-    (ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT, "Number of assets in a note exceed 255"),
+    (ERR_NOTE_NUM_OF_ASSETS_MUST_BE_U32, "Number of assets in an output note must fit into a u32"),
     (ERR_NOTE_TAG_MUST_BE_U32, "The note's tag must fit into a u32 so the 32 most significant bits must be zero."),
+    (ERR_NOTE_TOO_MANY_ASSETS, "Note contains a number of assets which exceeds the maximum of 255"),
 
+    (ERR_P2IDR_PARTIAL_ASSET_NOT_FUNGIBLE, "P2IDR_PARTIAL script requires the note asset to be fungible"),
+    (ERR_P2IDR_PARTIAL_WRONG_NUMBER_OF_ASSETS, "P2IDR_PARTIAL script requires exactly 1 note asset"),
+    (ERR_P2IDR_PARTIAL_WRONG_NUMBER_OF_INPUTS, "P2IDR_PARTIAL script expects exactly 11 note inputs"),
     (ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, "P2IDR's reclaimer is not the original sender"),
     (ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED, "P2IDR can not be reclaimed as the transaction's reference block is lower than the reclaim height"),
     (ERR_P2IDR_WRONG_NUMBER_OF_INPUTS, "P2IDR scripts expect exactly 3 note inputs"),
 
     (ERR_P2ID_TARGET_ACCT_MISMATCH, "P2ID's target account address and transaction address do not match"),
+    (ERR_P2ID_TIMELOCK_TARGET_ACCT_MISMATCH, "P2ID_TIMELOCK's target account address and transaction address do not match"),
+    (ERR_P2ID_TIMELOCK_UNLOCK_TIMESTAMP_NOT_REACHED, "P2ID_TIMELOCK can not be consumed as the timestamp of the transaction's reference block is lower than the unlock timestamp"),
+    (ERR_P2ID_TIMELOCK_WRONG_NUMBER_OF_INPUTS, "P2ID_TIMELOCK script expects exactly 3 note inputs"),
     (ERR_P2ID_WRONG_NUMBER_OF_INPUTS, "P2ID script expects exactly 2 note inputs"),
 
     (ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE, "Existing accounts must have a non-zero nonce"),
@@ -207,7 +227,7 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS, "Provided storage slot index is out of bounds"),
 
     (ERR_SWAP_WRONG_NUMBER_OF_ASSETS, "SWAP script requires exactly 1 note asset"),
-    (ERR_SWAP_WRONG_NUMBER_OF_INPUTS, "SWAP script expects exactly 10 note inputs"),
+    (ERR_SWAP_WRONG_NUMBER_OF_INPUTS, "SWAP script expects exactly 11 note inputs"),
 
     (ERR_TX_INVALID_EXPIRATION_DELTA, "Transaction expiration block delta must be within 0x1 and 0xFFFF."),
     (ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT, "Number of output notes in the transaction exceeds the maximum limit of 1024"),
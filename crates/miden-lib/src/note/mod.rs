@@ -1,21 +1,24 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    account::AccountId,
-    asset::Asset,
+    account::{AccountId, AccountType},
+    asset::{Asset, FungibleAsset},
     block::BlockNumber,
     crypto::rand::FeltRng,
     note::{
         Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteInputs,
         NoteMetadata, NoteRecipient, NoteTag, NoteType,
     },
-    Felt, NoteError, Word,
+    Felt, NoteError, Word, ZERO,
 };
 use utils::build_swap_tag;
 
 pub mod scripts;
 pub mod utils;
 
+mod well_known_note;
+pub use well_known_note::WellKnownNote;
+
 // STANDARDIZED SCRIPTS
 // ================================================================================================
 
@@ -48,6 +51,40 @@ pub fn create_p2id_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates a P2ID_TIMELOCK note - pay to id which cannot be consumed before a certain time.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account
+/// by specifying the target's account ID, like a P2ID note. Additionally, the note can only be
+/// consumed in transactions whose reference block has a timestamp of at least `unlock_timestamp`,
+/// in seconds since the UNIX epoch.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2ID_TIMELOCK` script fails.
+pub fn create_p2id_timelock_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    unlock_timestamp: u32,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let note_script = scripts::p2id_timelock();
+
+    let inputs =
+        NoteInputs::new(vec![target.suffix(), target.prefix().as_felt(), unlock_timestamp.into()])?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+
+    let vault = NoteAssets::new(assets)?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
 ///
 /// This script enables the transfer of assets from the sender `sender` account to the `target`
@@ -69,17 +106,90 @@ pub fn create_p2idr_note<R: FeltRng>(
     recall_height: BlockNumber,
     rng: &mut R,
 ) -> Result<Note, NoteError> {
-    let note_script = scripts::p2idr();
+    let serial_num = rng.draw_word();
+    let recipient = utils::build_p2idr_recipient(target, recall_height, serial_num)?;
 
-    let inputs =
-        NoteInputs::new(vec![target.suffix(), target.prefix().as_felt(), recall_height.into()])?;
     let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
-    let serial_num = rng.draw_word();
 
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
     let vault = NoteAssets::new(assets)?;
+
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+/// Generates a P2IDR_PARTIAL note - pay to id with a claim cap and recall after a certain block
+/// height - and returns the note as well as [NoteDetails] for the change note, if any.
+///
+/// This script enables the transfer of up to `max_claim` units of a fungible `asset` from the
+/// `sender` account to the `target` account. When the target consumes the note, it keeps at most
+/// `max_claim` units and creates a private P2ID change note with `sender` as target, containing
+/// the rest of the asset. Like for P2IDR notes, the sender may reclaim the whole asset if the note
+/// has not been consumed by the target before `recall_height`.
+///
+/// The passed-in `rng` is used to generate the serial numbers of the change note and of the note,
+/// in this order. The returned note's tag is set to the target's account ID and the tag of the
+/// change note is set to the sender's account ID. No change note details are returned if
+/// `max_claim` is not smaller than the amount of `asset`, as the target then claims the whole
+/// asset.
+///
+/// # Errors
+/// Returns an error if:
+/// - the tags of the note or the change note are invalid.
+/// - deserialization or compilation of the `P2IDR_PARTIAL` script fails.
+#[allow(clippy::too_many_arguments)]
+pub fn create_p2idr_partial_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    asset: FungibleAsset,
+    max_claim: u64,
+    note_type: NoteType,
+    aux: Felt,
+    recall_height: BlockNumber,
+    rng: &mut R,
+) -> Result<(Note, Option<NoteDetails>), NoteError> {
+    let note_script = scripts::p2idr_partial();
+
+    // a claim cap above the amount of the asset is equivalent to no cap at all
+    let max_claim = max_claim.min(asset.amount());
+
+    let change_serial_num = rng.draw_word();
+    let change_recipient = utils::build_p2id_recipient(sender, change_serial_num)?;
+    let change_recipient_word: Word = change_recipient.digest().into();
+    let change_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+    change_tag.validate(NoteType::Private)?;
+    let change_execution_hint = NoteExecutionHint::always();
+
+    let inputs = NoteInputs::new(vec![
+        target.suffix(),
+        target.prefix().as_felt(),
+        recall_height.into(),
+        Felt::new(max_claim),
+        change_recipient_word[0],
+        change_recipient_word[1],
+        change_recipient_word[2],
+        change_recipient_word[3],
+        change_tag.inner().into(),
+        change_execution_hint.into(),
+        ZERO,
+    ])?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+
+    let vault = NoteAssets::new(vec![asset.into()])?;
     let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
     let recipient = NoteRecipient::new(serial_num, note_script, inputs);
-    Ok(Note::new(vault, metadata, recipient))
+    let note = Note::new(vault, metadata, recipient);
+
+    let change_note = if max_claim < asset.amount() {
+        let change_asset = FungibleAsset::new(asset.faucet_id(), asset.amount() - max_claim)
+            .expect("change amount should be smaller than the amount of a valid asset");
+        let change_assets = NoteAssets::new(vec![change_asset.into()])?;
+        Some(NoteDetails::new(change_assets, change_recipient))
+    } else {
+        None
+    };
+
+    Ok((note, change_note))
 }
 
 /// Generates a SWAP note - swap of assets between two accounts - and returns the note as well as
@@ -89,6 +199,9 @@ pub fn create_p2idr_note<R: FeltRng>(
 /// is willing to consume the note. The consumer will receive the `offered_asset` and will create a
 /// new P2ID note with `sender` as target, containing the `requested_asset`.
 ///
+/// The note is built with the defaults of [SwapNoteBuilder] except for the note type and aux
+/// value. Use [SwapNoteBuilder] to control the metadata of the SWAP and payback notes.
+///
 /// # Errors
 /// Returns an error if deserialization or compilation of the `SWAP` script fails.
 pub fn create_swap_note<R: FeltRng>(
@@ -99,41 +212,212 @@ pub fn create_swap_note<R: FeltRng>(
     aux: Felt,
     rng: &mut R,
 ) -> Result<(Note, NoteDetails), NoteError> {
-    let note_script = scripts::swap();
+    SwapNoteBuilder::new(sender, offered_asset, requested_asset)
+        .note_type(note_type)
+        .aux(aux)
+        .build(rng)
+}
 
-    let payback_serial_num = rng.draw_word();
-    let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
+/// Generates a BURN note - a note which burns its assets in the faucet which issued them.
+///
+/// This script enables the `sender` account to return assets to the `faucet_id` faucet, which
+/// burns all of them when it consumes the note and thereby decreases its total issuance. The note
+/// does not create any output notes.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the faucet's account ID.
+///
+/// # Errors
+/// Returns an error if:
+/// - `faucet_id` is not the ID of a fungible faucet, as only fungible assets can be burned.
+/// - any of the `assets` was not issued by the `faucet_id` faucet.
+/// - the note assets, tag or metadata are invalid.
+pub fn create_burn_note<R: FeltRng>(
+    sender: AccountId,
+    faucet_id: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    if faucet_id.account_type() != AccountType::FungibleFaucet {
+        return Err(NoteError::NotAFungibleFaucet(faucet_id));
+    }
 
-    let payback_recipient_word: Word = payback_recipient.digest().into();
-    let requested_asset_word: Word = requested_asset.into();
-    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+    for asset in assets.iter() {
+        let is_issued_by_faucet = match asset {
+            Asset::Fungible(asset) => asset.faucet_id() == faucet_id,
+            Asset::NonFungible(_) => false,
+        };
+        if !is_issued_by_faucet {
+            return Err(NoteError::AssetNotIssuedByFaucet { faucet_id, asset: *asset });
+        }
+    }
 
-    let inputs = NoteInputs::new(vec![
-        payback_recipient_word[0],
-        payback_recipient_word[1],
-        payback_recipient_word[2],
-        payback_recipient_word[3],
-        requested_asset_word[0],
-        requested_asset_word[1],
-        requested_asset_word[2],
-        requested_asset_word[3],
-        payback_tag.inner().into(),
-        NoteExecutionHint::always().into(),
-    ])?;
+    let note_script = scripts::burn();
 
-    // build the tag for the SWAP use case
-    let tag = build_swap_tag(note_type, &offered_asset, &requested_asset)?;
+    let inputs = NoteInputs::new(vec![])?;
+    let tag = NoteTag::from_account_id(faucet_id, NoteExecutionMode::Local)?;
     let serial_num = rng.draw_word();
 
-    // build the outgoing note
+    let vault = NoteAssets::new(assets)?;
     let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
-    let assets = NoteAssets::new(vec![offered_asset])?;
     let recipient = NoteRecipient::new(serial_num, note_script, inputs);
-    let note = Note::new(assets, metadata, recipient);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+// SWAP NOTE BUILDER
+// ================================================================================================
+
+/// A builder for SWAP notes, which allows to control the metadata of both the SWAP note and the
+/// payback note created by the consumer of the SWAP note.
+///
+/// The payback note is always a private P2ID note with the sender of the SWAP note as target. Its
+/// tag, aux value and execution hint are committed to by the inputs of the SWAP note.
+///
+/// By default, the builder is initialized with:
+/// - The `note_type` of the SWAP note set to [NoteType::Private].
+/// - The `tag` of the SWAP note set to the SWAP tag for the offered and requested assets, see
+///   [build_swap_tag].
+/// - The `payback_tag` set to the local execution tag of the sender.
+/// - All execution hints set to [NoteExecutionHint::always].
+/// - All aux values set to [ZERO].
+#[derive(Debug, Clone)]
+pub struct SwapNoteBuilder {
+    sender: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    note_type: NoteType,
+    tag: Option<NoteTag>,
+    aux: Felt,
+    execution_hint: NoteExecutionHint,
+    payback_tag: Option<NoteTag>,
+    payback_aux: Felt,
+    payback_execution_hint: NoteExecutionHint,
+}
+
+impl SwapNoteBuilder {
+    /// Creates a new builder for a SWAP note through which the `sender` offers `offered_asset` in
+    /// exchange for `requested_asset`.
+    pub fn new(sender: AccountId, offered_asset: Asset, requested_asset: Asset) -> Self {
+        Self {
+            sender,
+            offered_asset,
+            requested_asset,
+            note_type: NoteType::Private,
+            tag: None,
+            aux: ZERO,
+            execution_hint: NoteExecutionHint::always(),
+            payback_tag: None,
+            payback_aux: ZERO,
+            payback_execution_hint: NoteExecutionHint::always(),
+        }
+    }
+
+    /// Sets the [NoteType] of the SWAP note.
+    pub fn note_type(mut self, note_type: NoteType) -> Self {
+        self.note_type = note_type;
+        self
+    }
+
+    /// Sets the [NoteTag] of the SWAP note.
+    pub fn tag(mut self, tag: NoteTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the aux value of the SWAP note.
+    pub fn aux(mut self, aux: Felt) -> Self {
+        self.aux = aux;
+        self
+    }
+
+    /// Sets the [NoteExecutionHint] of the SWAP note.
+    pub fn execution_hint(mut self, execution_hint: NoteExecutionHint) -> Self {
+        self.execution_hint = execution_hint;
+        self
+    }
+
+    /// Sets the [NoteTag] of the payback note.
+    pub fn payback_tag(mut self, tag: NoteTag) -> Self {
+        self.payback_tag = Some(tag);
+        self
+    }
+
+    /// Sets the aux value of the payback note.
+    pub fn payback_aux(mut self, aux: Felt) -> Self {
+        self.payback_aux = aux;
+        self
+    }
+
+    /// Sets the [NoteExecutionHint] of the payback note.
+    pub fn payback_execution_hint(mut self, execution_hint: NoteExecutionHint) -> Self {
+        self.payback_execution_hint = execution_hint;
+        self
+    }
+
+    /// Builds the SWAP note and returns it together with the [NoteDetails] of the payback note.
+    ///
+    /// The passed-in `rng` is used to generate the serial number of the payback note first and the
+    /// serial number of the SWAP note second.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the tag of the SWAP note is inconsistent with its note type.
+    /// - the tag of the payback note does not allow private notes.
+    /// - deserialization or compilation of the `SWAP` script fails.
+    pub fn build<R: FeltRng>(self, rng: &mut R) -> Result<(Note, NoteDetails), NoteError> {
+        let note_script = scripts::swap();
+
+        let payback_serial_num = rng.draw_word();
+        let payback_recipient = utils::build_p2id_recipient(self.sender, payback_serial_num)?;
+
+        let payback_recipient_word: Word = payback_recipient.digest().into();
+        let requested_asset_word: Word = self.requested_asset.into();
+
+        // the payback note is always created as a private note by the SWAP script
+        let payback_tag = match self.payback_tag {
+            Some(tag) => tag,
+            None => NoteTag::from_account_id(self.sender, NoteExecutionMode::Local)?,
+        };
+        payback_tag.validate(NoteType::Private)?;
+
+        let inputs = NoteInputs::new(vec![
+            payback_recipient_word[0],
+            payback_recipient_word[1],
+            payback_recipient_word[2],
+            payback_recipient_word[3],
+            requested_asset_word[0],
+            requested_asset_word[1],
+            requested_asset_word[2],
+            requested_asset_word[3],
+            payback_tag.inner().into(),
+            self.payback_execution_hint.into(),
+            self.payback_aux,
+        ])?;
+
+        // build the tag for the SWAP use case
+        let tag = match self.tag {
+            Some(tag) => tag,
+            None => build_swap_tag(self.note_type, &self.offered_asset, &self.requested_asset)?,
+        };
+        let serial_num = rng.draw_word();
+
+        // build the outgoing note
+        let metadata = NoteMetadata::builder(self.sender)
+            .note_type(self.note_type)
+            .tag(tag)
+            .execution_hint(self.execution_hint)
+            .aux(self.aux)
+            .build()?;
+        let assets = NoteAssets::new(vec![self.offered_asset])?;
+        let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+        let note = Note::new(assets, metadata, recipient);
 
-    // build the payback note details
-    let payback_assets = NoteAssets::new(vec![requested_asset])?;
-    let payback_note = NoteDetails::new(payback_assets, payback_recipient);
+        // build the payback note details
+        let payback_assets = NoteAssets::new(vec![self.requested_asset])?;
+        let payback_note = NoteDetails::new(payback_assets, payback_recipient);
 
-    Ok((note, payback_note))
+        Ok((note, payback_note))
+    }
 }
@@ -11,6 +11,14 @@ static P2ID_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the P2ID_TIMELOCK note script only once
+static P2ID_TIMELOCK_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID_TIMELOCK.masb"));
+    let program =
+        Program::read_from_bytes(bytes).expect("Shipped P2ID_TIMELOCK script is well-formed");
+    NoteScript::new(program)
+});
+
 // Initialize the P2IDR note script only once
 static P2IDR_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
@@ -18,6 +26,14 @@ static P2IDR_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the P2IDR_PARTIAL note script only once
+static P2IDR_PARTIAL_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR_PARTIAL.masb"));
+    let program =
+        Program::read_from_bytes(bytes).expect("Shipped P2IDR_PARTIAL script is well-formed");
+    NoteScript::new(program)
+});
+
 // Initialize the SWAP note script only once
 static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
@@ -25,17 +41,39 @@ static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the BURN note script only once
+static BURN_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BURN.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped BURN script is well-formed");
+    NoteScript::new(program)
+});
+
 /// Returns the P2ID (Pay-to-ID) note script.
 pub fn p2id() -> NoteScript {
     P2ID_SCRIPT.clone()
 }
 
+/// Returns the P2ID_TIMELOCK (Pay-to-ID with time lock) note script.
+pub fn p2id_timelock() -> NoteScript {
+    P2ID_TIMELOCK_SCRIPT.clone()
+}
+
 /// Returns the P2IDR (Pay-to-ID with recall) note script.
 pub fn p2idr() -> NoteScript {
     P2IDR_SCRIPT.clone()
 }
 
+/// Returns the P2IDR_PARTIAL (Pay-to-ID with recall and partial claim) note script.
+pub fn p2idr_partial() -> NoteScript {
+    P2IDR_PARTIAL_SCRIPT.clone()
+}
+
 /// Returns the SWAP (Swap note) note script.
 pub fn swap() -> NoteScript {
     SWAP_SCRIPT.clone()
 }
+
+/// Returns the BURN note script.
+pub fn burn() -> NoteScript {
+    BURN_SCRIPT.clone()
+}
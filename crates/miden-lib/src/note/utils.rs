@@ -1,7 +1,8 @@
 use miden_objects::{
     account::AccountId,
     asset::Asset,
-    note::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteTag, NoteType},
+    block::BlockNumber,
+    note::{NoteInputs, NoteRecipient, NoteTag, NoteType},
     NoteError, Word,
 };
 
@@ -21,12 +22,28 @@ pub fn build_p2id_recipient(
     Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
 }
 
-/// Returns a note tag for a swap note with the specified parameters.
+/// Creates a [NoteRecipient] for the P2IDR note.
 ///
-/// Use case ID for the returned tag is set to 0.
+/// Notes created with this recipient will be P2IDR notes consumable by the specified target
+/// account, or by the sender once the chain has reached `recall_height`.
+pub fn build_p2idr_recipient(
+    target: AccountId,
+    recall_height: BlockNumber,
+    serial_num: Word,
+) -> Result<NoteRecipient, NoteError> {
+    let note_script = scripts::p2idr();
+    let note_inputs =
+        NoteInputs::new(vec![target.suffix(), target.prefix().as_felt(), recall_height.into()])?;
+
+    Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
+}
+
+/// Returns a note tag for a swap note with the specified parameters.
 ///
-/// Tag payload is constructed by taking asset tags (8 bits of each faucet ID) and concatenating
-/// them together as offered_asset_tag + requested_asset tag.
+/// The tag follows the SWAP tag convention described in [`NoteTag::for_swap`]: use case ID is set
+/// to [`NoteTag::SWAP_USE_CASE_ID`] and the payload is constructed by concatenating the 8 most
+/// significant bits of the offered and requested faucet IDs. Public notes get a public use case
+/// tag (see [`NoteTag::for_public_swap`]), all other notes get a local use case tag.
 ///
 /// Network execution hint for the returned tag is set to `Local`.
 pub fn build_swap_tag(
@@ -34,22 +51,15 @@ pub fn build_swap_tag(
     offered_asset: &Asset,
     requested_asset: &Asset,
 ) -> Result<NoteTag, NoteError> {
-    const SWAP_USE_CASE_ID: u16 = 0;
-
-    // Get bits 0..8 from the faucet IDs of both assets which will form the tag payload.
-    let offered_asset_id: u64 = offered_asset.faucet_id_prefix().into();
-    let offered_asset_tag = (offered_asset_id >> 56) as u8;
-
-    let requested_asset_id: u64 = requested_asset.faucet_id_prefix().into();
-    let requested_asset_tag = (requested_asset_id >> 56) as u8;
+    let offered_faucet = offered_asset.faucet_id_prefix();
+    let requested_faucet = requested_asset.faucet_id_prefix();
 
-    let payload = ((offered_asset_tag as u16) << 8) | (requested_asset_tag as u16);
+    let tag = match note_type {
+        NoteType::Public => NoteTag::for_public_swap(offered_faucet, requested_faucet),
+        _ => NoteTag::for_swap(offered_faucet, requested_faucet),
+    };
 
-    let execution = NoteExecutionMode::Local;
-    match note_type {
-        NoteType::Public => NoteTag::for_public_use_case(SWAP_USE_CASE_ID, payload, execution),
-        _ => NoteTag::for_local_use_case(SWAP_USE_CASE_ID, payload),
-    }
+    tag.validate(note_type)
 }
 
 #[cfg(test)]
@@ -58,9 +68,14 @@ mod tests {
         self,
         account::{AccountIdVersion, AccountStorageMode, AccountType},
         asset::{FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        crypto::rand::RpoRandomCoin,
+        note::NoteExecutionMode,
+        testing::account_id::ACCOUNT_ID_SENDER,
+        ZERO,
     };
 
     use super::*;
+    use crate::note::create_swap_note;
 
     #[test]
     fn swap_tag() {
@@ -118,5 +133,41 @@ mod tests {
                 .unwrap();
 
         assert_eq!(actual_tag, expected_tag);
+        assert!(actual_tag.matches_swap_pair(
+            offered_asset.faucet_id_prefix(),
+            requested_asset.faucet_id_prefix()
+        ));
+
+        let private_tag =
+            build_swap_tag(NoteType::Private, &offered_asset, &requested_asset).unwrap();
+        assert_eq!(private_tag, NoteTag::for_local_use_case(0, expected_tag_payload).unwrap());
+        assert!(private_tag.matches_swap_pair(
+            offered_asset.faucet_id_prefix(),
+            requested_asset.faucet_id_prefix()
+        ));
+
+        // Notes created by `create_swap_note` follow the SWAP tag convention.
+        for note_type in [NoteType::Public, NoteType::Private] {
+            let (note, _) = create_swap_note(
+                ACCOUNT_ID_SENDER.try_into().unwrap(),
+                offered_asset,
+                requested_asset,
+                note_type,
+                ZERO,
+                &mut RpoRandomCoin::new(Word::default()),
+            )
+            .unwrap();
+            let tag = note.metadata().tag();
+
+            assert_eq!(tag, build_swap_tag(note_type, &offered_asset, &requested_asset).unwrap());
+            assert!(tag.matches_swap_pair(
+                offered_asset.faucet_id_prefix(),
+                requested_asset.faucet_id_prefix()
+            ));
+            assert!(!tag.matches_swap_pair(
+                requested_asset.faucet_id_prefix(),
+                offered_asset.faucet_id_prefix()
+            ));
+        }
     }
 }
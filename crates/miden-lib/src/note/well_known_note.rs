@@ -0,0 +1,351 @@
+use miden_objects::{
+    account::AccountId,
+    note::{Note, NoteScript},
+    utils::sync::LazyLock,
+    Digest,
+};
+
+use crate::note::scripts;
+
+// Initialize the script roots of the well-known notes only once
+static P2ID_SCRIPT_ROOT: LazyLock<Digest> = LazyLock::new(|| scripts::p2id().hash());
+static P2ID_TIMELOCK_SCRIPT_ROOT: LazyLock<Digest> =
+    LazyLock::new(|| scripts::p2id_timelock().hash());
+static P2IDR_SCRIPT_ROOT: LazyLock<Digest> = LazyLock::new(|| scripts::p2idr().hash());
+static P2IDR_PARTIAL_SCRIPT_ROOT: LazyLock<Digest> =
+    LazyLock::new(|| scripts::p2idr_partial().hash());
+static SWAP_SCRIPT_ROOT: LazyLock<Digest> = LazyLock::new(|| scripts::swap().hash());
+static BURN_SCRIPT_ROOT: LazyLock<Digest> = LazyLock::new(|| scripts::burn().hash());
+
+// WELL KNOWN NOTE
+// ================================================================================================
+
+/// The standard notes whose scripts are shipped with this library.
+///
+/// A note is recognized as a well-known note if its script root is equal to the root of one of the
+/// bundled scripts. The roots are computed from the compiled scripts on first use, so they always
+/// match the MASM sources this library was built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum WellKnownNote {
+    P2ID,
+    P2ID_TIMELOCK,
+    P2IDR,
+    P2IDR_PARTIAL,
+    SWAP,
+    BURN,
+}
+
+impl WellKnownNote {
+    /// All well-known notes.
+    pub const ALL: [Self; 6] = [
+        Self::P2ID,
+        Self::P2ID_TIMELOCK,
+        Self::P2IDR,
+        Self::P2IDR_PARTIAL,
+        Self::SWAP,
+        Self::BURN,
+    ];
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the well-known note with the provided script root, or `None` if the root does not
+    /// belong to any of the bundled note scripts.
+    pub fn from_script_root(script_root: Digest) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|well_known_note| well_known_note.script_root() == script_root)
+    }
+
+    /// Returns the well-known note the provided note is an instance of, or `None` if the script of
+    /// the note is not one of the bundled note scripts.
+    ///
+    /// Only the script of the note is inspected, so the returned kind does not imply that the note
+    /// has the number of inputs expected by its script.
+    pub fn from_note(note: &Note) -> Option<Self> {
+        Self::from_script_root(note.script().hash())
+    }
+
+    /// Returns the ID of the account targeted by the provided note, or `None` if the note is not a
+    /// P2ID, P2ID_TIMELOCK, P2IDR or P2IDR_PARTIAL note with the number of inputs expected by its
+    /// script.
+    ///
+    /// The target account ID is stored as `[suffix, prefix]` in the first two inputs of these
+    /// notes. Note that the sender of a P2IDR or P2IDR_PARTIAL note may also consume it once the
+    /// note can be reclaimed.
+    pub fn target_account_id(note: &Note) -> Option<AccountId> {
+        let well_known_note = Self::from_note(note)?;
+        if matches!(well_known_note, Self::SWAP | Self::BURN) {
+            return None;
+        }
+
+        let inputs = note.inputs().values();
+        if inputs.len() != well_known_note.expected_num_inputs() {
+            return None;
+        }
+
+        AccountId::try_from([inputs[1], inputs[0]]).ok()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the script of this note.
+    pub fn script_root(&self) -> Digest {
+        match self {
+            Self::P2ID => *P2ID_SCRIPT_ROOT,
+            Self::P2ID_TIMELOCK => *P2ID_TIMELOCK_SCRIPT_ROOT,
+            Self::P2IDR => *P2IDR_SCRIPT_ROOT,
+            Self::P2IDR_PARTIAL => *P2IDR_PARTIAL_SCRIPT_ROOT,
+            Self::SWAP => *SWAP_SCRIPT_ROOT,
+            Self::BURN => *BURN_SCRIPT_ROOT,
+        }
+    }
+
+    /// Returns the script of this note.
+    pub fn script(&self) -> NoteScript {
+        match self {
+            Self::P2ID => scripts::p2id(),
+            Self::P2ID_TIMELOCK => scripts::p2id_timelock(),
+            Self::P2IDR => scripts::p2idr(),
+            Self::P2IDR_PARTIAL => scripts::p2idr_partial(),
+            Self::SWAP => scripts::swap(),
+            Self::BURN => scripts::burn(),
+        }
+    }
+
+    /// Returns the number of inputs the script of this note expects.
+    pub fn expected_num_inputs(&self) -> usize {
+        match self {
+            // the target account ID
+            Self::P2ID => 2,
+            // the target account ID and the unlock timestamp
+            Self::P2ID_TIMELOCK => 3,
+            // the target account ID and the reclaim block height
+            Self::P2IDR => 3,
+            // the target account ID, the reclaim block height, the claim cap and the change
+            // recipient, tag, execution hint and aux
+            Self::P2IDR_PARTIAL => 11,
+            // the payback recipient, the requested asset and the payback note tag, execution hint
+            // and aux
+            Self::SWAP => 11,
+            // no inputs, the assets are burned by the faucet consuming the note
+            Self::BURN => 0,
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        account::AccountId,
+        asset::{Asset, FungibleAsset, NonFungibleAsset},
+        crypto::rand::RpoRandomCoin,
+        note::{NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteType, ScriptInfo},
+        testing::account_id::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Felt, ZERO,
+    };
+
+    use super::*;
+    use crate::{
+        note::{
+            create_burn_note, create_p2id_note, create_p2id_timelock_note, create_p2idr_note,
+            create_p2idr_partial_note, create_swap_note,
+        },
+        transaction::TransactionKernel,
+    };
+
+    #[test]
+    fn well_known_note_from_note() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let asset: Asset = fungible_asset.into();
+        let requested_asset = NonFungibleAsset::mock(&[1, 2, 3]);
+        let mut rng = RpoRandomCoin::new([Felt::new(1); 4]);
+
+        let notes = [
+            (
+                create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, &mut rng)
+                    .unwrap(),
+                WellKnownNote::P2ID,
+            ),
+            (
+                create_p2id_timelock_note(
+                    sender,
+                    target,
+                    vec![asset],
+                    NoteType::Public,
+                    ZERO,
+                    1_700_000_000,
+                    &mut rng,
+                )
+                .unwrap(),
+                WellKnownNote::P2ID_TIMELOCK,
+            ),
+            (
+                create_p2idr_note(
+                    sender,
+                    target,
+                    vec![asset],
+                    NoteType::Public,
+                    ZERO,
+                    10.into(),
+                    &mut rng,
+                )
+                .unwrap(),
+                WellKnownNote::P2IDR,
+            ),
+            (
+                create_p2idr_partial_note(
+                    sender,
+                    target,
+                    fungible_asset,
+                    40,
+                    NoteType::Public,
+                    ZERO,
+                    10.into(),
+                    &mut rng,
+                )
+                .unwrap()
+                .0,
+                WellKnownNote::P2IDR_PARTIAL,
+            ),
+            (
+                create_swap_note(sender, asset, requested_asset, NoteType::Public, ZERO, &mut rng)
+                    .unwrap()
+                    .0,
+                WellKnownNote::SWAP,
+            ),
+            (
+                create_burn_note(sender, faucet_id, vec![asset], NoteType::Public, ZERO, &mut rng)
+                    .unwrap(),
+                WellKnownNote::BURN,
+            ),
+        ];
+
+        for (note, well_known_note) in notes {
+            assert_eq!(WellKnownNote::from_note(&note), Some(well_known_note));
+            assert_eq!(note.inputs().num_values() as usize, well_known_note.expected_num_inputs());
+        }
+
+        // a note with a custom script is not a well-known note
+        let custom_script =
+            NoteScript::compile("begin push.1 drop end", TransactionKernel::assembler()).unwrap();
+        let metadata = NoteMetadata::builder(sender).build().unwrap();
+        let recipient = NoteRecipient::new(
+            [ZERO; 4],
+            custom_script,
+            NoteInputs::new(vec![target.suffix(), target.prefix().as_felt()]).unwrap(),
+        );
+        let custom_note = Note::new(NoteAssets::default(), metadata, recipient);
+        assert_eq!(WellKnownNote::from_note(&custom_note), None);
+    }
+
+    #[test]
+    fn well_known_note_target_account_id() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let mut rng = RpoRandomCoin::new([Felt::new(1); 4]);
+
+        let p2idr_note = create_p2idr_note(
+            sender,
+            target,
+            vec![asset],
+            NoteType::Public,
+            ZERO,
+            10.into(),
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(WellKnownNote::target_account_id(&p2idr_note), Some(target));
+
+        let burn_note =
+            create_burn_note(sender, faucet_id, vec![asset], NoteType::Public, ZERO, &mut rng)
+                .unwrap();
+        assert_eq!(WellKnownNote::target_account_id(&burn_note), None);
+
+        // a P2ID note without the expected number of inputs has no target
+        let recipient = NoteRecipient::new(
+            [ZERO; 4],
+            WellKnownNote::P2ID.script(),
+            NoteInputs::new(vec![target.suffix()]).unwrap(),
+        );
+        let metadata = NoteMetadata::builder(sender).build().unwrap();
+        let malformed_note = Note::new(NoteAssets::default(), metadata, recipient);
+        assert_eq!(WellKnownNote::from_note(&malformed_note), Some(WellKnownNote::P2ID));
+        assert_eq!(WellKnownNote::target_account_id(&malformed_note), None);
+    }
+
+    #[test]
+    fn well_known_note_detection_ignores_script_info() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let requested_asset = NonFungibleAsset::mock(&[1, 2, 3]);
+        let mut rng = RpoRandomCoin::new([Felt::new(1); 4]);
+
+        let p2id_note =
+            create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, &mut rng)
+                .unwrap();
+        let (swap_note, _) =
+            create_swap_note(sender, asset, requested_asset, NoteType::Public, ZERO, &mut rng)
+                .unwrap();
+
+        for (note, well_known_note) in
+            [(p2id_note, WellKnownNote::P2ID), (swap_note, WellKnownNote::SWAP)]
+        {
+            for info in [ScriptInfo::new("wallet::pay"), ScriptInfo::new("explorer::renamed")] {
+                let script = note.script().clone().with_info(info.clone());
+                assert_eq!(script.info(), Some(&info));
+
+                let recipient =
+                    NoteRecipient::new(note.serial_num(), script, note.inputs().clone());
+                let note_with_info = Note::new(note.assets().clone(), *note.metadata(), recipient);
+
+                assert_eq!(note_with_info.id(), note.id());
+                assert_eq!(WellKnownNote::from_note(&note_with_info), Some(well_known_note));
+            }
+        }
+    }
+
+    #[test]
+    fn well_known_note_entrypoint_of_compiled_script() {
+        let source = include_str!("../../asm/note_scripts/P2ID.masm");
+        let script = NoteScript::compile(source, TransactionKernel::assembler()).unwrap();
+        let recompiled_script =
+            NoteScript::compile(source, TransactionKernel::assembler()).unwrap();
+
+        assert_eq!(script.entrypoint(), recompiled_script.entrypoint());
+        assert_eq!(script.entrypoint(), script.hash());
+        assert_eq!(script.mast_forest(), recompiled_script.mast_forest());
+
+        // a script compiled by a client is recognized as the bundled P2ID script
+        assert_eq!(WellKnownNote::from_script_root(script.entrypoint()), Some(WellKnownNote::P2ID));
+    }
+
+    #[test]
+    fn well_known_note_script_roots() {
+        for well_known_note in WellKnownNote::ALL {
+            assert_eq!(well_known_note.script_root(), well_known_note.script().hash());
+            assert_eq!(
+                WellKnownNote::from_script_root(well_known_note.script_root()),
+                Some(well_known_note)
+            );
+        }
+    }
+}
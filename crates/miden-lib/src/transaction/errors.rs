@@ -1,7 +1,7 @@
 use alloc::{boxed::Box, vec::Vec};
 use core::error::Error;
 
-use miden_objects::{note::NoteMetadata, AccountDeltaError, AssetError, Digest, Felt, NoteError};
+use miden_objects::{AccountDeltaError, AssetError, Digest, Felt, NoteError};
 use thiserror::Error;
 
 // TRANSACTION KERNEL ERROR
@@ -41,8 +41,6 @@ pub enum TransactionKernelError {
     MalformedRecipientData(Vec<Felt>),
     #[error("cannot add asset to note with index {0}, note does not exist in the advice provider")]
     MissingNote(u64),
-    #[error("public note with metadata {0:?} and recipient digest {1} is missing details in the advice provider")]
-    PublicNoteMissingDetails(NoteMetadata, Digest),
     #[error("public note has incomplete inputs in the advice provider")]
     MissingNoteInputs,
     #[error("note input data in advice provider contains fewer elements ({actual}) than specified ({specified}) by its inputs length")]
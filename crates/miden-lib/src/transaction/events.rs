@@ -31,6 +31,9 @@ const NOTE_AFTER_CREATED: u32 = 0x2_000c; // 131084
 const NOTE_BEFORE_ADD_ASSET: u32 = 0x2_000d; // 131085
 const NOTE_AFTER_ADD_ASSET: u32 = 0x2_000e; // 131086
 
+const NOTE_BEFORE_FORWARD_ASSET: u32 = 0x2_000f; // 131087
+const NOTE_AFTER_FORWARD_ASSET: u32 = 0x2_0010; // 131088
+
 /// Events which may be emitted by a transaction kernel.
 ///
 /// The events are emitted via the `emit.<event_id>` instruction. The event ID is a 32-bit
@@ -63,6 +66,9 @@ pub enum TransactionEvent {
 
     NoteBeforeAddAsset = NOTE_BEFORE_ADD_ASSET,
     NoteAfterAddAsset = NOTE_AFTER_ADD_ASSET,
+
+    NoteBeforeForwardAsset = NOTE_BEFORE_FORWARD_ASSET,
+    NoteAfterForwardAsset = NOTE_AFTER_FORWARD_ASSET,
 }
 
 impl TransactionEvent {
@@ -114,6 +120,9 @@ impl TryFrom<u32> for TransactionEvent {
             NOTE_BEFORE_ADD_ASSET => Ok(TransactionEvent::NoteBeforeAddAsset),
             NOTE_AFTER_ADD_ASSET => Ok(TransactionEvent::NoteAfterAddAsset),
 
+            NOTE_BEFORE_FORWARD_ASSET => Ok(TransactionEvent::NoteBeforeForwardAsset),
+            NOTE_AFTER_FORWARD_ASSET => Ok(TransactionEvent::NoteAfterForwardAsset),
+
             _ => Err(TransactionEventError::InvalidTransactionEvent(value)),
         }
     }
@@ -16,11 +16,11 @@ pub type StorageSlot = u8;
 //
 // | Section           | Start address, pointer (word pointer) | End address, pointer (word pointer) | Comment                                     |
 // | ----------------- | :-----------------------------------: | :---------------------------------: | ------------------------------------------- |
-// | Bookkeeping       | 0 (0)                                 | 31 (7)                              |                                             |
+// | Bookkeeping       | 0 (0)                                 | 43 (10)                             |                                             |
 // | Global inputs     | 400 (100)                             | 423 (105)                           |                                             |
 // | Block header      | 800 (200)                             | 835 (208)                           |                                             |
 // | Chain MMR         | 1_200 (300)                           | 1_331? (332?)                       |                                             |
-// | Kernel data       | 1_600 (400)                           | 1_739 (434)                         | 34 procedures in total, 4 elements each     |
+// | Kernel data       | 1_600 (400)                           | 1_747 (436)                         | 36 procedures in total, 4 elements each     |
 // | Accounts data     | 8_192 (2048)                          | 532_479 (133_119)                   | 64 foreign accounts max, 8192 elements each |
 // | Input notes       | 4_194_304 (1_048_576)                 | ?                                   |                                             |
 // | Output notes      | 16_777_216 (4_194_304)                | ?                                   |                                             |
@@ -80,6 +80,18 @@ pub const NEW_CODE_ROOT_PTR: MemoryAddress = 24;
 /// The memory address at which the transaction expiration block number is stored.
 pub const TX_EXPIRATION_BLOCK_NUM_PTR: MemoryAddress = 28;
 
+/// The memory address at which the root of the vault holding the assets of the input note being
+/// executed which have not been forwarded yet is stored.
+pub const FORWARDABLE_VAULT_ROOT_PTR: MemoryAddress = 32;
+
+/// The memory address at which a pointer to the input note whose assets are held by the
+/// forwardable vault is stored.
+pub const FORWARDABLE_VAULT_NOTE_PTR: MemoryAddress = 36;
+
+/// The memory address at which the number of output notes at the start of the execution of the
+/// current input note is stored. Only output notes created by this note can receive its assets.
+pub const FORWARDABLE_OUTPUT_NOTES_START_PTR: MemoryAddress = 40;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -2,11 +2,12 @@ use alloc::{string::ToString, sync::Arc, vec::Vec};
 
 use miden_objects::{
     account::{AccountCode, AccountHeader, AccountId, AccountStorageHeader},
-    assembly::{Assembler, DefaultSourceManager, KernelLibrary},
+    assembly::{Assembler, DefaultSourceManager, KernelLibrary, Library},
     block::BlockNumber,
     crypto::merkle::{MerkleError, MerklePath},
     transaction::{
-        OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
+        ForeignAccountInputs, OutputNote, OutputNotes, TransactionArgs, TransactionInputs,
+        TransactionOutputs,
     },
     utils::serde::Deserializable,
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
@@ -34,6 +35,9 @@ pub use errors::{TransactionEventError, TransactionKernelError, TransactionTrace
 
 mod procedures;
 
+mod version;
+pub use version::{KernelCommitments, KernelVersion};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -71,6 +75,33 @@ impl TransactionKernel {
             .expect("failed to deserialize transaction kernel runtime")
     }
 
+    // KERNEL IDENTIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of the build which produced the transaction kernel.
+    ///
+    /// The version consists of the semantic version of this crate and, if requested via the
+    /// `MIDEN_LIB_GIT_DESCRIBE` environment variable at build time, a description of the source
+    /// revision, see [KernelVersion::build]. It is intended for identifying the kernel in logs and
+    /// diagnostics only and is not committed to by proofs.
+    pub fn version() -> KernelVersion {
+        KernelVersion::new(env!("CARGO_PKG_VERSION"), env!("MIDEN_LIB_BUILD_DESCRIPTION"))
+    }
+
+    /// Returns the commitments to the code of the transaction kernel.
+    ///
+    /// # Panics
+    /// Panics if the transaction kernel source is not well-formed.
+    pub fn commitments() -> KernelCommitments {
+        let kernel = Self::kernel();
+        let kernel_library: &Library = kernel.as_ref();
+
+        KernelCommitments {
+            kernel_root: Self::kernel_root(),
+            library_root: *kernel_library.digest(),
+        }
+    }
+
     /// Returns [ProgramInfo] for the transaction kernel executable program.
     ///
     /// # Panics
@@ -201,6 +232,34 @@ impl TransactionKernel {
         Ok(())
     }
 
+    /// Extends the advice inputs with the data required to invoke procedures of the foreign
+    /// account described by the provided [ForeignAccountInputs].
+    ///
+    /// In addition to the data added by [Self::extend_advice_inputs_for_account], this adds the
+    /// Merkle paths and leaves of the storage map entries contained in the foreign account inputs.
+    pub fn extend_advice_inputs_for_foreign_account(
+        advice_inputs: &mut AdviceInputs,
+        foreign_account: &ForeignAccountInputs,
+    ) -> Result<(), MerkleError> {
+        Self::extend_advice_inputs_for_account(
+            advice_inputs,
+            foreign_account.account_header(),
+            foreign_account.account_code(),
+            foreign_account.storage_header(),
+            foreign_account.account_witness(),
+        )?;
+
+        for proof in foreign_account.storage_map_proofs() {
+            let leaf = proof.leaf();
+            advice_inputs
+                .extend_merkle_store(proof.path().inner_nodes(leaf.index().value(), leaf.hash())?);
+            // populate advice map with the Sparse Merkle Tree leaf node
+            advice_inputs.extend_map([(leaf.hash(), leaf.to_elements())]);
+        }
+
+        Ok(())
+    }
+
     /// Builds the stack for expected transaction execution outputs.
     /// The transaction kernel's output stack is formed like so:
     ///
@@ -302,6 +361,13 @@ impl TransactionKernel {
     ///
     /// The actual data describing the new account state and output notes is expected to be located
     /// in the provided advice map under keys CNC and FAH.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The output stack is invalid or the final account data is missing from the advice map.
+    /// - A public output note is not provided in full, i.e., its details were not supplied via the
+    ///   transaction arguments.
+    /// - The commitment to the output notes does not match the one produced by the kernel.
     pub fn from_transaction_parts(
         stack: &StackOutputs,
         adv_map: &AdviceMap,
@@ -313,12 +379,18 @@ impl TransactionKernel {
         // parse final account state
         let final_account_data = adv_map
             .get(&final_acct_hash)
-            .ok_or(TransactionOutputError::FinalAccountHashMissingInAdviceMap)?;
+            .ok_or(TransactionOutputError::MissingAdviceMapEntry { key: final_acct_hash })?;
         let account = parse_final_account_header(final_account_data)
             .map_err(TransactionOutputError::FinalAccountHeaderParseFailure)?;
 
         // validate output notes
         let output_notes = OutputNotes::new(output_notes)?;
+        if let Some(note) = output_notes
+            .iter()
+            .find(|note| !note.metadata().is_private() && !matches!(note, OutputNote::Full(_)))
+        {
+            return Err(TransactionOutputError::MissingOutputNoteDetails { note_id: note.id() });
+        }
         if output_notes_hash != output_notes.commitment() {
             return Err(TransactionOutputError::OutputNotesCommitmentInconsistent {
                 actual: output_notes.commitment(),
@@ -6,7 +6,7 @@ use miden_objects::{digest, Digest};
 // ================================================================================================
 
 /// Hashes of all dynamically executed procedures from the kernel 0.
-pub const KERNEL0_PROCEDURES: [Digest; 35] = [
+pub const KERNEL0_PROCEDURES: [Digest; 37] = [
     // account_get_initial_hash
     digest!("0x920898348bacd6d98a399301eb308478fd32b32eab019a5a6ef7a6b44abb61f6"),
     // account_get_current_hash
@@ -16,7 +16,7 @@ pub const KERNEL0_PROCEDURES: [Digest; 35] = [
     // account_get_nonce
     digest!("0x0db82eda6e834383e79ee83751434aabadbf71996ce8897562a77a34b43f9a31"),
     // account_incr_nonce
-    digest!("0xe33735d9c594a0515ee30348a4e988a10b941792a4823c69435a37310ec1fd5f"),
+    digest!("0x24512f579f0e3c98fbd16754625c4e4c0b84226db0dbae478c3d99d3f39bcc95"),
     // account_get_code_commitment
     digest!("0xf998788832427ab137eaee1c4d99aaea435754b861fcb1d00b1640345eb9afe7"),
     // account_get_storage_commitment
@@ -77,4 +77,8 @@ pub const KERNEL0_PROCEDURES: [Digest; 35] = [
     digest!("0x4c3ca7bb7dac8ae20aefe6ebe582499730cd5ffa3d2592ac88b83a4d72873089"),
     // tx_update_expiration_block_num
     digest!("0xa7b1045569f0905558f38454bfc4b6bbbd07648e34248161a4bb44cfb557043d"),
+    // note_forward_asset
+    digest!("0x762ea64f82d82642389d5b751a10f9562bdc61a16cec014a34ab40323debac77"),
+    // tx_get_block_timestamp
+    digest!("0x786863e6dbcd5026619afd3831b7dcbf824cda54950b0e0724ebf9d9370ec723"),
 ];
@@ -1,14 +1,40 @@
-use alloc::vec::Vec;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use kernel_v0::KERNEL0_PROCEDURES;
-use miden_objects::{Digest, Felt, Hasher};
+use miden_objects::{
+    assembly::{
+        mast::{MastForest, MastNode, MastNodeId},
+        Assembler, Compile, Library,
+    },
+    transaction::TransactionScript,
+    utils::sync::LazyLock,
+    Digest, Felt, Hasher, TransactionScriptError, Word,
+};
+use miden_stdlib::StdLibrary;
 
 use super::TransactionKernel;
+use crate::MidenLib;
 
 // Include kernel v0 procedure hashes generated in build.rs
 #[rustfmt::skip]
 mod kernel_v0;
 
+/// The registry returned by [TransactionKernel::state_mutating_procedures], which is built only
+/// once since it requires traversing the MAST of all exported miden-lib procedures.
+static STATE_MUTATING_PROCEDURE_REGISTRY: LazyLock<BTreeMap<Digest, String>> =
+    LazyLock::new(build_state_mutating_procedures);
+
+/// The allowlist returned by [TransactionKernel::readonly_procedures], which is built only once
+/// since it requires traversing the MAST of all exported standard library and miden-lib
+/// procedures.
+static READONLY_PROCEDURE_REGISTRY: LazyLock<BTreeSet<Digest>> =
+    LazyLock::new(build_readonly_procedures);
+
 // TRANSACTION KERNEL
 // ================================================================================================
 
@@ -22,6 +48,21 @@ impl TransactionKernel {
     /// Array of all available kernels.
     pub const PROCEDURES: [&'static [Digest]; Self::NUM_VERSIONS] = [&KERNEL0_PROCEDURES];
 
+    /// Names of the miden-lib procedures which directly change the state of the account against
+    /// which the transaction is executed.
+    ///
+    /// Other miden-lib procedures, e.g. the procedures of the basic wallet, change the state of the
+    /// account by invoking these procedures, see [Self::state_mutating_procedures].
+    pub const STATE_MUTATING_PROCEDURES: [&'static str; 7] = [
+        "miden::account::incr_nonce",
+        "miden::account::set_item",
+        "miden::account::set_map_item",
+        "miden::account::add_asset",
+        "miden::account::remove_asset",
+        "miden::faucet::mint",
+        "miden::faucet::burn",
+    ];
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -41,8 +82,187 @@ impl TransactionKernel {
         Hasher::hash_elements(&Self::procedures_as_elements(kernel_version))
     }
 
+    /// Returns the MAST roots of the miden-lib procedures which change the state of the account
+    /// mapped to their names.
+    ///
+    /// These are the [Self::STATE_MUTATING_PROCEDURES] and all exported miden-lib procedures whose
+    /// MAST invokes any of them, e.g. `miden::contracts::wallets::basic::receive_asset`.
+    ///
+    /// This is the registry against which read-only transaction scripts are checked, see
+    /// [Self::compile_readonly_script].
+    pub fn state_mutating_procedures() -> &'static BTreeMap<Digest, String> {
+        &STATE_MUTATING_PROCEDURE_REGISTRY
+    }
+
+    /// Returns the MAST roots of the exported procedures of the Miden standard library and
+    /// miden-lib which cannot change the state of the account.
+    ///
+    /// These are the procedures whose MAST neither invokes any of the
+    /// [Self::state_mutating_procedures], nor contains `call`, `dyncall` or `dynexec` instructions,
+    /// nor references external procedures which are not read-only themselves. Syscalls are allowed.
+    ///
+    /// This is the allowlist of the external procedures a read-only transaction script may invoke,
+    /// see [Self::compile_readonly_script].
+    pub fn readonly_procedures() -> &'static BTreeSet<Digest> {
+        &READONLY_PROCEDURE_REGISTRY
+    }
+
+    /// Returns a new read-only [TransactionScript] compiled from the provided source code and
+    /// inputs using the specified assembler.
+    ///
+    /// This is [TransactionScript::compile_readonly] checked against the
+    /// [Self::state_mutating_procedures] and the [Self::readonly_procedures].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The compilation of the provided source code fails.
+    /// - The compiled script is not read-only, see [TransactionScript::verify_readonly].
+    pub fn compile_readonly_script(
+        source_code: impl Compile,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+        assembler: Assembler,
+    ) -> Result<TransactionScript, TransactionScriptError> {
+        TransactionScript::compile_readonly(
+            source_code,
+            inputs,
+            assembler,
+            Self::state_mutating_procedures(),
+            Self::readonly_procedures(),
+        )
+    }
+
     /// Computes a hash from all kernel hashes.
     pub fn kernel_root() -> Digest {
         Hasher::hash_elements(&[Self::kernel_hash(0).as_elements()].concat())
     }
 }
+
+// HELPERS
+// ================================================================================================
+
+/// Builds the registry returned by [TransactionKernel::state_mutating_procedures].
+fn build_state_mutating_procedures() -> BTreeMap<Digest, String> {
+    let miden_lib = MidenLib::default();
+    let library: &Library = miden_lib.as_ref();
+    let mast_forest = library.mast_forest();
+
+    let mutating_roots: BTreeSet<Digest> = library
+        .exports()
+        .filter(|export| {
+            TransactionKernel::STATE_MUTATING_PROCEDURES.contains(&export.to_string().as_str())
+        })
+        .map(|export| mast_forest[library.get_export_node_id(export)].digest())
+        .collect();
+
+    library
+        .exports()
+        .filter_map(|export| {
+            let node_id = library.get_export_node_id(export);
+            reaches_any_root(mast_forest, node_id, &mutating_roots)
+                .then(|| (mast_forest[node_id].digest(), export.to_string()))
+        })
+        .collect()
+}
+
+/// Builds the allowlist returned by [TransactionKernel::readonly_procedures].
+fn build_readonly_procedures() -> BTreeSet<Digest> {
+    let mutating_roots: BTreeSet<Digest> =
+        TransactionKernel::state_mutating_procedures().keys().copied().collect();
+
+    let std_lib = StdLibrary::default();
+    let miden_lib = MidenLib::default();
+
+    let mut readonly_roots = BTreeSet::new();
+    for library in [std_lib.as_ref(), miden_lib.as_ref()] {
+        let library: &Library = library;
+        let mast_forest = library.mast_forest();
+
+        let library_roots: Vec<Digest> = library
+            .exports()
+            .map(|export| library.get_export_node_id(export))
+            .filter(|&node_id| is_readonly(mast_forest, node_id, &mutating_roots, &readonly_roots))
+            .map(|node_id| mast_forest[node_id].digest())
+            .collect();
+        readonly_roots.extend(library_roots);
+    }
+
+    readonly_roots
+}
+
+/// Returns `true` if the MAST subtree rooted at `node_id` contains a node with any of the provided
+/// `roots`, including the node itself.
+fn reaches_any_root(
+    mast_forest: &MastForest,
+    node_id: MastNodeId,
+    roots: &BTreeSet<Digest>,
+) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut to_visit = vec![node_id];
+
+    while let Some(node_id) = to_visit.pop() {
+        if !visited.insert(node_id) {
+            continue;
+        }
+
+        let node = &mast_forest[node_id];
+        if roots.contains(&node.digest()) {
+            return true;
+        }
+
+        match node {
+            MastNode::Join(join_node) => to_visit.extend([join_node.first(), join_node.second()]),
+            MastNode::Split(split_node) => {
+                to_visit.extend([split_node.on_true(), split_node.on_false()])
+            },
+            MastNode::Loop(loop_node) => to_visit.push(loop_node.body()),
+            MastNode::Call(call_node) => to_visit.push(call_node.callee()),
+            MastNode::Block(_) | MastNode::Dyn(_) | MastNode::External(_) => (),
+        }
+    }
+
+    false
+}
+
+/// Returns `true` if the MAST subtree rooted at `node_id` cannot change the state of the account,
+/// i.e. if it does not contain a node with any of the `mutating_roots`, a `call` or dynamic call,
+/// nor an external node which is not among the `readonly_roots`.
+///
+/// The callees of syscalls are not visited.
+fn is_readonly(
+    mast_forest: &MastForest,
+    node_id: MastNodeId,
+    mutating_roots: &BTreeSet<Digest>,
+    readonly_roots: &BTreeSet<Digest>,
+) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut to_visit = vec![node_id];
+
+    while let Some(node_id) = to_visit.pop() {
+        if !visited.insert(node_id) {
+            continue;
+        }
+
+        let node = &mast_forest[node_id];
+        if mutating_roots.contains(&node.digest()) {
+            return false;
+        }
+
+        match node {
+            MastNode::Join(join_node) => to_visit.extend([join_node.first(), join_node.second()]),
+            MastNode::Split(split_node) => {
+                to_visit.extend([split_node.on_true(), split_node.on_false()])
+            },
+            MastNode::Loop(loop_node) => to_visit.push(loop_node.body()),
+            MastNode::Call(call_node) if call_node.is_syscall() => (),
+            MastNode::Call(_) | MastNode::Dyn(_) => return false,
+            MastNode::External(external_node) => {
+                if !readonly_roots.contains(&external_node.digest()) {
+                    return false;
+                }
+            },
+            MastNode::Block(_) => (),
+        }
+    }
+
+    true
+}
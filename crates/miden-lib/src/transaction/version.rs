@@ -0,0 +1,105 @@
+use core::fmt;
+
+use miden_objects::Digest;
+
+// KERNEL VERSION
+// ================================================================================================
+
+/// Identifies the build of the transaction kernel, see
+/// [`TransactionKernel::version`](super::TransactionKernel::version).
+///
+/// This is not to be confused with the kernel version passed to
+/// [`TransactionKernel::kernel_hash`](super::TransactionKernel::kernel_hash), which identifies a
+/// set of kernel procedures rather than the build they were compiled by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelVersion {
+    semver: &'static str,
+    build: &'static str,
+}
+
+impl KernelVersion {
+    /// Returns a new [KernelVersion] instantiated from the provided semantic version and build
+    /// description.
+    pub(super) const fn new(semver: &'static str, build: &'static str) -> Self {
+        Self { semver, build }
+    }
+
+    /// Returns the semantic version of the `miden-lib` crate which contains the kernel.
+    pub fn semver(&self) -> &'static str {
+        self.semver
+    }
+
+    /// Returns a description of the source revision the kernel was built from, as returned by
+    /// `git describe`.
+    ///
+    /// The description is only available if the `MIDEN_LIB_GIT_DESCRIBE` environment variable was
+    /// set when building this crate from a git checkout.
+    pub fn build(&self) -> Option<&'static str> {
+        Some(self.build).filter(|build| !build.is_empty())
+    }
+}
+
+impl fmt::Display for KernelVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.build() {
+            Some(build) => write!(f, "{}+{build}", self.semver),
+            None => f.write_str(self.semver),
+        }
+    }
+}
+
+// KERNEL COMMITMENTS
+// ================================================================================================
+
+/// Commitments to the code of the transaction kernel, see
+/// [`TransactionKernel::commitments`](super::TransactionKernel::commitments).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelCommitments {
+    /// The root of all kernel versions, as committed to by block headers.
+    pub kernel_root: Digest,
+    /// The commitment to the compiled kernel library.
+    pub library_root: Digest,
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::KernelVersion;
+    use crate::transaction::TransactionKernel;
+
+    #[test]
+    fn kernel_version_is_stable() {
+        let version = TransactionKernel::version();
+        assert_eq!(version.semver(), env!("CARGO_PKG_VERSION"));
+        match version.build() {
+            Some(build) => assert_eq!(version.to_string(), format!("{}+{build}", version.semver())),
+            None => assert_eq!(version.to_string(), version.semver()),
+        }
+
+        assert_eq!(TransactionKernel::version(), version);
+    }
+
+    #[test]
+    fn kernel_version_without_build_description() {
+        let version = KernelVersion::new("0.7.0", "");
+        assert_eq!(version.build(), None);
+        assert_eq!(version.to_string(), "0.7.0");
+
+        let version = KernelVersion::new("0.7.0", "v0.7.0-3-g1234567");
+        assert_eq!(version.build(), Some("v0.7.0-3-g1234567"));
+        assert_eq!(version.to_string(), "0.7.0+v0.7.0-3-g1234567");
+    }
+
+    #[test]
+    fn kernel_commitments() {
+        let commitments = TransactionKernel::commitments();
+        assert_eq!(commitments.kernel_root, TransactionKernel::kernel_root());
+        assert_ne!(commitments.library_root, commitments.kernel_root);
+
+        assert_eq!(TransactionKernel::commitments(), commitments);
+    }
+}
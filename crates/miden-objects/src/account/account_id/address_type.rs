@@ -0,0 +1,31 @@
+use crate::errors::Bech32Error;
+
+// ADDRESS TYPE
+// ================================================================================================
+
+/// The type of the data encoded in a bech32 address, which is stored in the first byte of the
+/// data part of the address.
+///
+/// The first character of the data part encodes the five most significant bits of this byte, so
+/// the discriminants are chosen such that these bits differ between address types. This allows
+/// telling addresses of different types apart by the first character after the separator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AddressType {
+    /// The address encodes an [`AccountId`](super::AccountId).
+    AccountId = 0,
+    /// The address encodes a [`NoteId`](crate::note::NoteId).
+    NoteId = 8,
+}
+
+impl TryFrom<u8> for AddressType {
+    type Error = Bech32Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            val if val == AddressType::AccountId as u8 => Ok(AddressType::AccountId),
+            val if val == AddressType::NoteId as u8 => Ok(AddressType::NoteId),
+            _ => Err(Bech32Error::UnknownAddressType(value)),
+        }
+    }
+}
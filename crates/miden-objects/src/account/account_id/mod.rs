@@ -14,9 +14,13 @@ pub use account_type::AccountType;
 mod storage_mode;
 pub use storage_mode::AccountStorageMode;
 mod id_version;
+mod network_id;
+pub use network_id::{CustomNetworkId, NetworkId};
+mod address_type;
 use alloc::string::{String, ToString};
-use core::fmt;
+use core::{fmt, ops::ControlFlow};
 
+pub use address_type::AddressType;
 pub use id_version::AccountIdVersion;
 use miden_crypto::{merkle::LeafIndex, utils::hex_to_bytes};
 use vm_core::{
@@ -25,7 +29,12 @@ use vm_core::{
 };
 use vm_processor::{DeserializationError, Digest};
 
-use crate::{errors::AccountIdError, AccountError, ACCOUNT_TREE_DEPTH};
+pub use crate::bech32::Bech32Variant;
+use crate::{
+    base58, bech32,
+    errors::{AccountIdError, Base58Error, Bech32Error},
+    AccountError, ACCOUNT_TREE_DEPTH,
+};
 
 /// The identifier of an [`Account`](crate::account::Account).
 ///
@@ -121,6 +130,10 @@ impl AccountId {
     /// The serialized size of an [`AccountId`] in bytes.
     pub const SERIALIZED_SIZE: usize = 15;
 
+    /// The length of the data part of a bech32-encoded ID, i.e. the address type byte followed by
+    /// the 15 bytes of the ID.
+    const BECH32_DATA_LENGTH: usize = 1 + Self::SERIALIZED_SIZE;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -205,6 +218,51 @@ impl AccountId {
         }
     }
 
+    /// Grinds `n` account IDs with random account types, storage modes and initial seeds and
+    /// asserts that no two of them share the same [`AccountIdPrefix`].
+    ///
+    /// The prefix of an account ID is guaranteed to be unique, e.g. it alone identifies the faucet
+    /// of a non-fungible asset. This function exercises that guarantee by deriving the IDs through
+    /// [`AccountId::compute_account_seed`], rather than sampling them directly like
+    /// [`AccountId::dummy`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if two of the generated IDs share the same prefix, or if any ID cannot be derived
+    /// from its seed.
+    #[cfg(any(feature = "testing", test))]
+    pub fn assert_no_prefix_collisions<R: rand::Rng + ?Sized>(n: usize, rng: &mut R) {
+        let mut ids_by_prefix = alloc::collections::BTreeMap::<u64, AccountId>::new();
+        let anchor = AccountIdAnchor::new_unchecked(0, Digest::default());
+        let code_commitment = Digest::default();
+        let storage_commitment = Digest::default();
+
+        for _ in 0..n {
+            let seed = Self::compute_account_seed(
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                AccountIdVersion::Version0,
+                code_commitment,
+                storage_commitment,
+                anchor.block_hash(),
+            )
+            .expect("account seed should be computable");
+            let account_id = Self::new(
+                seed,
+                anchor,
+                AccountIdVersion::Version0,
+                code_commitment,
+                storage_commitment,
+            )
+            .expect("account ID should be derivable from the computed seed");
+
+            if let Some(other_id) = ids_by_prefix.insert(account_id.prefix().as_u64(), account_id) {
+                panic!("account IDs {other_id} and {account_id} share the same prefix");
+            }
+        }
+    }
+
     /// Grinds an account seed until its hash matches the given `account_type`, `storage_mode` and
     /// `version` and returns it as a [`Word`]. The input to the hash function next to the seed are
     /// the `code_commitment`, `storage_commitment` and `anchor_block_hash`.
@@ -233,6 +291,37 @@ impl AccountId {
         }
     }
 
+    /// Grinds an account seed like [`AccountId::compute_account_seed`], but allows observing the
+    /// progress of the grinding and cancelling it.
+    ///
+    /// The `control` closure is invoked with the number of attempts made so far before the first
+    /// attempt and then every 10,000 attempts. If it returns [`ControlFlow::Break`], the grinding
+    /// stops and `Ok(None)` is returned.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compute_account_seed_with_control(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        version: AccountIdVersion,
+        code_commitment: Digest,
+        storage_commitment: Digest,
+        anchor_block_hash: Digest,
+        control: &mut dyn FnMut(u64) -> ControlFlow<()>,
+    ) -> Result<Option<Word>, AccountError> {
+        match version {
+            AccountIdVersion::Version0 => AccountIdV0::compute_account_seed_with_control(
+                init_seed,
+                account_type,
+                storage_mode,
+                version,
+                code_commitment,
+                storage_commitment,
+                anchor_block_hash,
+                control,
+            ),
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -298,6 +387,120 @@ impl AccountId {
         }
     }
 
+    /// Encodes this ID into a [bech32m](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)
+    /// string with the provided [`NetworkId`] as the human-readable part.
+    ///
+    /// The data part consists of the [`AddressType::AccountId`] byte followed by the 15 bytes of
+    /// the ID. The returned string is always lowercase.
+    pub fn to_bech32(&self, network_id: NetworkId) -> String {
+        self.to_bech32_with(network_id, Bech32Variant::Bech32m)
+    }
+
+    /// Encodes this ID into a bech32 string with the provided [`NetworkId`] as the human-readable
+    /// part and the checksum of the provided [`Bech32Variant`].
+    ///
+    /// The legacy [`Bech32Variant::Bech32`] checksum is only meant for interoperability with
+    /// systems which do not support bech32m. Such strings can only be decoded via
+    /// [`AccountId::from_bech32_allow_legacy`].
+    pub fn to_bech32_with(&self, network_id: NetworkId, variant: Bech32Variant) -> String {
+        let mut data = [0; Self::BECH32_DATA_LENGTH];
+        data[0] = AddressType::AccountId as u8;
+        data[1..].copy_from_slice(&<[u8; Self::SERIALIZED_SIZE]>::from(*self));
+
+        bech32::encode_with_variant(network_id.as_str(), &data, variant)
+    }
+
+    /// Decodes a bech32m string into the [`NetworkId`] and the [`AccountId`] it encodes.
+    ///
+    /// The string may be all lowercase or all uppercase, but must not mix the two.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the string is not a valid bech32m string, which includes strings containing both uppercase
+    ///   and lowercase characters and strings with a legacy bech32 checksum.
+    /// - the address type of the string is not [`AddressType::AccountId`].
+    /// - the data part does not consist of the address type and 15 bytes.
+    /// - the decoded bytes are not a valid [`AccountId`].
+    pub fn from_bech32(bech32_string: &str) -> Result<(NetworkId, Self), AccountIdError> {
+        let (hrp, data) =
+            bech32::decode(bech32_string).map_err(AccountIdError::Bech32DecodeError)?;
+
+        Self::from_bech32_parts(&hrp, &data)
+    }
+
+    /// Decodes a bech32m or legacy bech32 string into the [`NetworkId`] and the [`AccountId`] it
+    /// encodes, and returns the [`Bech32Variant`] of its checksum.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`AccountId::from_bech32`], except that strings with a legacy
+    /// bech32 checksum are accepted.
+    pub fn from_bech32_allow_legacy(
+        bech32_string: &str,
+    ) -> Result<(NetworkId, Self, Bech32Variant), AccountIdError> {
+        let (hrp, data, variant) = bech32::decode_with_variant(bech32_string)
+            .map_err(AccountIdError::Bech32DecodeError)?;
+
+        Self::from_bech32_parts(&hrp, &data)
+            .map(|(network_id, account_id)| (network_id, account_id, variant))
+    }
+
+    /// Returns the [`NetworkId`] and the [`AccountId`] encoded by the human-readable part and the
+    /// data of a decoded bech32 string.
+    fn from_bech32_parts(hrp: &str, data: &[u8]) -> Result<(NetworkId, Self), AccountIdError> {
+        let network_id = NetworkId::new(hrp).map_err(AccountIdError::Bech32DecodeError)?;
+
+        let address_type = data
+            .first()
+            .ok_or(Bech32Error::MissingAddressType)
+            .and_then(|address_type| AddressType::try_from(*address_type))
+            .map_err(AccountIdError::Bech32DecodeError)?;
+        if address_type != AddressType::AccountId {
+            return Err(AccountIdError::Bech32DecodeError(Bech32Error::UnexpectedAddressType {
+                expected: AddressType::AccountId,
+                actual: address_type,
+            }));
+        }
+
+        let id_bytes: [u8; 15] = data[1..].try_into().map_err(|_| {
+            AccountIdError::Bech32DecodeError(Bech32Error::InvalidDataLength {
+                expected: Self::BECH32_DATA_LENGTH,
+                actual: data.len(),
+            })
+        })?;
+
+        AccountId::try_from(id_bytes).map(|account_id| (network_id, account_id))
+    }
+
+    /// Encodes this ID into a compact, URL-safe base58 string.
+    ///
+    /// The string encodes the 15 bytes of the ID followed by a 4-byte checksum, so it is shorter
+    /// than the hex representation. Unlike [`AccountId::to_bech32`], it does not contain a
+    /// [`NetworkId`].
+    pub fn to_compact_string(&self) -> String {
+        base58::encode_check(&<[u8; Self::SERIALIZED_SIZE]>::from(*self))
+    }
+
+    /// Decodes a string created by [`AccountId::to_compact_string`] into an [`AccountId`].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the string is not a valid base58 string or its checksum does not match.
+    /// - the decoded data does not consist of 15 bytes.
+    /// - the decoded bytes are not a valid [`AccountId`].
+    pub fn from_compact_string(compact_string: &str) -> Result<Self, AccountIdError> {
+        let data = base58::decode_check(compact_string)
+            .map_err(AccountIdError::CompactStringDecodeError)?;
+
+        let id_bytes: [u8; Self::SERIALIZED_SIZE] = data.as_slice().try_into().map_err(|_| {
+            AccountIdError::CompactStringDecodeError(Base58Error::InvalidDataLength {
+                expected: Self::SERIALIZED_SIZE,
+                actual: data.len(),
+            })
+        })?;
+
+        AccountId::try_from(id_bytes)
+    }
+
     /// Returns the [`AccountIdPrefix`] of this ID.
     ///
     /// The prefix of an account ID is guaranteed to be unique.
@@ -471,6 +674,11 @@ impl Deserializable for AccountId {
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+
+    use assert_matches::assert_matches;
+    use rand::SeedableRng;
+
     use super::*;
     use crate::testing::account_id::{
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
@@ -498,4 +706,208 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn account_id_prefixes_do_not_collide() {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(515);
+        AccountId::assert_no_prefix_collisions(32, &mut rng);
+    }
+
+    #[test]
+    fn bech32_encode_decode_roundtrip() {
+        let network_ids = [
+            NetworkId::Mainnet,
+            NetworkId::Testnet,
+            NetworkId::Devnet,
+            NetworkId::new("mcst").unwrap(),
+        ];
+
+        for account_id in [
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_OFF_CHAIN_SENDER,
+        ] {
+            let account_id = AccountId::try_from(account_id).unwrap();
+
+            for network_id in network_ids.clone() {
+                let bech32_string = account_id.to_bech32(network_id.clone());
+                assert!(bech32_string.starts_with(&format!("{network_id}1")));
+
+                let (decoded_network_id, decoded_account_id) =
+                    AccountId::from_bech32(&bech32_string).unwrap();
+                assert_eq!(decoded_network_id, network_id);
+                assert_eq!(decoded_account_id, account_id);
+            }
+        }
+    }
+
+    #[test]
+    fn bech32_variant_roundtrip() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+
+        for variant in [Bech32Variant::Bech32, Bech32Variant::Bech32m] {
+            let bech32_string = account_id.to_bech32_with(NetworkId::Testnet, variant);
+            let (network_id, decoded_account_id, decoded_variant) =
+                AccountId::from_bech32_allow_legacy(&bech32_string).unwrap();
+            assert_eq!(network_id, NetworkId::Testnet);
+            assert_eq!(decoded_account_id, account_id);
+            assert_eq!(decoded_variant, variant);
+        }
+
+        // bech32m is the default
+        assert_eq!(
+            account_id.to_bech32(NetworkId::Testnet),
+            account_id.to_bech32_with(NetworkId::Testnet, Bech32Variant::Bech32m)
+        );
+    }
+
+    #[test]
+    fn bech32_rejects_legacy_checksum_by_default() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let legacy = account_id.to_bech32_with(NetworkId::Testnet, Bech32Variant::Bech32);
+
+        assert_matches!(
+            AccountId::from_bech32(&legacy),
+            Err(AccountIdError::Bech32DecodeError(Bech32Error::LegacyChecksum))
+        );
+    }
+
+    #[test]
+    fn bech32_decode_case_handling() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let lowercase = account_id.to_bech32(NetworkId::Testnet);
+        assert_eq!(lowercase, lowercase.to_ascii_lowercase());
+
+        let (network_id, decoded_account_id) = AccountId::from_bech32(&lowercase).unwrap();
+        assert_eq!(network_id, NetworkId::Testnet);
+        assert_eq!(decoded_account_id, account_id);
+
+        // all-uppercase strings are valid and decode into the same network ID and account ID
+        let uppercase = lowercase.to_ascii_uppercase();
+        let (network_id, decoded_account_id) = AccountId::from_bech32(&uppercase).unwrap();
+        assert_eq!(network_id, NetworkId::Testnet);
+        assert_eq!(decoded_account_id, account_id);
+
+        // mixing cases in either the human-readable or the data part is rejected
+        let mixed_hrp = format!("MTST{}", &lowercase[4..]);
+        let mixed_data = format!("{}{}", &uppercase[..10], &lowercase[10..]);
+        for mixed_case in [mixed_hrp, mixed_data] {
+            assert_matches!(
+                AccountId::from_bech32(&mixed_case),
+                Err(AccountIdError::Bech32DecodeError(Bech32Error::MixedCase))
+            );
+        }
+    }
+
+    #[test]
+    fn bech32_invalid_data() {
+        // unknown address type
+        let mut data = [0; AccountId::BECH32_DATA_LENGTH];
+        data[0] = 1;
+        data[1..].copy_from_slice(&<[u8; 15]>::from(
+            AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap(),
+        ));
+        assert_matches!(
+            AccountId::from_bech32(&bech32::encode("mtst", &data)),
+            Err(AccountIdError::Bech32DecodeError(Bech32Error::UnknownAddressType(1)))
+        );
+
+        // invalid data length
+        assert_matches!(
+            AccountId::from_bech32(&bech32::encode("mtst", &data[..1])),
+            Err(AccountIdError::Bech32DecodeError(Bech32Error::UnknownAddressType(1)))
+        );
+        data[0] = AddressType::AccountId as u8;
+        assert_matches!(
+            AccountId::from_bech32(&bech32::encode("mtst", &data[..10])),
+            Err(AccountIdError::Bech32DecodeError(Bech32Error::InvalidDataLength {
+                expected: 16,
+                actual: 10
+            }))
+        );
+        assert_matches!(
+            AccountId::from_bech32(&bech32::encode("mtst", &[])),
+            Err(AccountIdError::Bech32DecodeError(Bech32Error::MissingAddressType))
+        );
+
+        // note addresses are rejected
+        let note_id = crate::note::NoteId::from(Digest::default());
+        assert_matches!(
+            AccountId::from_bech32(&note_id.to_bech32(NetworkId::Testnet)),
+            Err(AccountIdError::Bech32DecodeError(Bech32Error::UnexpectedAddressType {
+                expected: AddressType::AccountId,
+                actual: AddressType::NoteId
+            }))
+        );
+    }
+
+    #[test]
+    fn compact_string_roundtrip() {
+        // the lower 8 bits of the suffix are always zero, and the last ID has a suffix of zero
+        let ids_with_trailing_zeros = [
+            AccountId::dummy(
+                [0; 15],
+                AccountIdVersion::Version0,
+                AccountType::RegularAccountImmutableCode,
+                AccountStorageMode::Private,
+            ),
+            AccountId::dummy(
+                [0xff; 15],
+                AccountIdVersion::Version0,
+                AccountType::FungibleFaucet,
+                AccountStorageMode::Public,
+            ),
+            AccountId::dummy(
+                [1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0],
+                AccountIdVersion::Version0,
+                AccountType::NonFungibleFaucet,
+                AccountStorageMode::Private,
+            ),
+        ];
+
+        for account_id in [
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_OFF_CHAIN_SENDER,
+        ]
+        .into_iter()
+        .map(|account_id| AccountId::try_from(account_id).unwrap())
+        .chain(ids_with_trailing_zeros)
+        {
+            let compact_string = account_id.to_compact_string();
+            assert!(compact_string.len() < account_id.to_hex().len());
+            assert_eq!(AccountId::from_compact_string(&compact_string).unwrap(), account_id);
+        }
+    }
+
+    #[test]
+    fn compact_string_invalid_data() {
+        // a checksummed string of the wrong length
+        assert_matches!(
+            AccountId::from_compact_string(&base58::encode_check(&[1; 14])),
+            Err(AccountIdError::CompactStringDecodeError(Base58Error::InvalidDataLength {
+                expected: 15,
+                actual: 14
+            }))
+        );
+
+        // a hex string is not a valid compact string
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        assert_matches!(
+            AccountId::from_compact_string(&account_id.to_hex()),
+            Err(AccountIdError::CompactStringDecodeError(Base58Error::InvalidCharacter('0')))
+        );
+
+        // a string with a corrupted checksum
+        let compact_string = account_id.to_compact_string();
+        let corrupted = format!("{}{}", &compact_string[1..], &compact_string[..1]);
+        assert_matches!(
+            AccountId::from_compact_string(&corrupted),
+            Err(AccountIdError::CompactStringDecodeError(_))
+        );
+    }
 }
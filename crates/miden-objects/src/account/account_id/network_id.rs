@@ -0,0 +1,115 @@
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+use crate::{bech32, errors::Bech32Error};
+
+// NETWORK ID
+// ================================================================================================
+
+/// The identifier of a Miden network, which is used as the human-readable part of bech32-encoded
+/// addresses, e.g. [`AccountId::to_bech32`](super::AccountId::to_bech32).
+///
+/// The human-readable part is always lowercase. It is normalized to lowercase when constructed from
+/// an all-uppercase string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NetworkId {
+    Mainnet,
+    Testnet,
+    Devnet,
+    /// A network with a custom human-readable part.
+    Custom(CustomNetworkId),
+}
+
+impl NetworkId {
+    const MAINNET: &'static str = "mm";
+    const TESTNET: &'static str = "mtst";
+    const DEVNET: &'static str = "mdev";
+
+    /// Constructs a new [`NetworkId`] from a string.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the string contains both uppercase and lowercase characters.
+    /// - the string is not a valid bech32 human-readable part.
+    pub fn new(string: &str) -> Result<Self, Bech32Error> {
+        let has_lowercase = string.chars().any(|c| c.is_ascii_lowercase());
+        let has_uppercase = string.chars().any(|c| c.is_ascii_uppercase());
+        if has_lowercase && has_uppercase {
+            return Err(Bech32Error::MixedCase);
+        }
+        let string = string.to_ascii_lowercase();
+        bech32::validate_hrp(&string)?;
+
+        Ok(match string.as_str() {
+            Self::MAINNET => NetworkId::Mainnet,
+            Self::TESTNET => NetworkId::Testnet,
+            Self::DEVNET => NetworkId::Devnet,
+            _ => NetworkId::Custom(CustomNetworkId(string)),
+        })
+    }
+
+    /// Returns the human-readable part of this network ID.
+    pub fn as_str(&self) -> &str {
+        match self {
+            NetworkId::Mainnet => Self::MAINNET,
+            NetworkId::Testnet => Self::TESTNET,
+            NetworkId::Devnet => Self::DEVNET,
+            NetworkId::Custom(custom) => custom.as_str(),
+        }
+    }
+
+    /// Returns `true` if this is the mainnet network ID, `false` otherwise.
+    pub fn is_mainnet(&self) -> bool {
+        matches!(self, NetworkId::Mainnet)
+    }
+
+    /// Returns `true` if this is the testnet network ID, `false` otherwise.
+    pub fn is_testnet(&self) -> bool {
+        matches!(self, NetworkId::Testnet)
+    }
+
+    /// Returns `true` if this is the devnet network ID, `false` otherwise.
+    pub fn is_devnet(&self) -> bool {
+        matches!(self, NetworkId::Devnet)
+    }
+}
+
+impl FromStr for NetworkId {
+    type Err = Bech32Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::new(string)
+    }
+}
+
+impl fmt::Display for NetworkId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// CUSTOM NETWORK ID
+// ================================================================================================
+
+/// A valid, lowercase bech32 human-readable part which is not one of the well-known network IDs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomNetworkId(String);
+
+impl CustomNetworkId {
+    /// Returns the human-readable part of this network ID.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CustomNetworkId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<CustomNetworkId> for String {
+    fn from(custom: CustomNetworkId) -> Self {
+        custom.0
+    }
+}
@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::ops::ControlFlow;
 
 use vm_core::{Felt, Word};
 use vm_processor::Digest;
@@ -14,6 +15,10 @@ use crate::{
     AccountError,
 };
 
+/// The number of grinding attempts between two invocations of the control closure passed to
+/// [compute_account_seed_with_control].
+pub(super) const SEED_CONTROL_INTERVAL: u64 = 10_000;
+
 /// Finds and returns a seed suitable for creating an account ID for the specified account type
 /// using the provided initial seed as a starting point.
 ///
@@ -29,7 +34,7 @@ pub(super) fn compute_account_seed(
     storage_commitment: Digest,
     anchor_block_hash: Digest,
 ) -> Result<Word, AccountError> {
-    compute_account_seed_single(
+    compute_account_seed_with_control(
         init_seed,
         account_type,
         storage_mode,
@@ -37,10 +42,19 @@ pub(super) fn compute_account_seed(
         code_commitment,
         storage_commitment,
         anchor_block_hash,
+        &mut |_| ControlFlow::Continue(()),
     )
+    .map(|seed| seed.expect("grinding is never cancelled by a no-op control closure"))
 }
 
-fn compute_account_seed_single(
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// using the provided initial seed as a starting point, or `None` if the grinding was cancelled.
+///
+/// The `control` closure is invoked with the number of attempts made so far before the first
+/// attempt and then every [SEED_CONTROL_INTERVAL] attempts. The grinding stops as soon as the
+/// closure returns [ControlFlow::Break].
+#[allow(clippy::too_many_arguments)]
+pub(super) fn compute_account_seed_with_control(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_mode: AccountStorageMode,
@@ -48,7 +62,8 @@ fn compute_account_seed_single(
     code_commitment: Digest,
     storage_commitment: Digest,
     anchor_block_hash: Digest,
-) -> Result<Word, AccountError> {
+    control: &mut dyn FnMut(u64) -> ControlFlow<()>,
+) -> Result<Option<Word>, AccountError> {
     let init_seed: Vec<[u8; 8]> =
         init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
     let mut current_seed: Word = [
@@ -64,7 +79,13 @@ fn compute_account_seed_single(
     let mut log = log::Log::start(current_digest, current_seed, account_type, storage_mode);
 
     // loop until we have a seed that satisfies the specified account type.
+    let mut attempts: u64 = 0;
     loop {
+        if attempts % SEED_CONTROL_INTERVAL == 0 && control(attempts).is_break() {
+            return Ok(None);
+        }
+        attempts += 1;
+
         #[cfg(feature = "log")]
         log.iteration(current_digest, current_seed);
 
@@ -80,7 +101,7 @@ fn compute_account_seed_single(
                 #[cfg(feature = "log")]
                 log.done(current_digest, current_seed);
 
-                return Ok(current_seed);
+                return Ok(Some(current_seed));
             };
         }
 
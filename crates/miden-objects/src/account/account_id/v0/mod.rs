@@ -3,7 +3,7 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
-use core::fmt;
+use core::{fmt, ops::ControlFlow};
 
 use miden_crypto::{merkle::LeafIndex, utils::hex_to_bytes};
 pub use prefix::AccountIdPrefixV0;
@@ -165,6 +165,31 @@ impl AccountIdV0 {
         )
     }
 
+    /// See [`AccountId::compute_account_seed_with_control`](super::AccountId::compute_account_seed_with_control)
+    /// for details.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compute_account_seed_with_control(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        version: AccountIdVersion,
+        code_commitment: Digest,
+        storage_commitment: Digest,
+        anchor_block_hash: Digest,
+        control: &mut dyn FnMut(u64) -> ControlFlow<()>,
+    ) -> Result<Option<Word>, AccountError> {
+        crate::account::account_id::seed::compute_account_seed_with_control(
+            init_seed,
+            account_type,
+            storage_mode,
+            version,
+            code_commitment,
+            storage_commitment,
+            anchor_block_hash,
+            control,
+        )
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -536,6 +561,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_account_seed_with_control() {
+        let compute_seed = |control: &mut dyn FnMut(u64) -> ControlFlow<()>| {
+            AccountIdV0::compute_account_seed_with_control(
+                [10; 32],
+                AccountType::FungibleFaucet,
+                AccountStorageMode::Public,
+                AccountIdVersion::Version0,
+                Digest::default(),
+                Digest::default(),
+                Digest::default(),
+                control,
+            )
+            .unwrap()
+        };
+
+        // a control closure which never cancels yields the same seed as the plain grinding
+        let mut invocations = Vec::new();
+        let seed = compute_seed(&mut |attempts| {
+            invocations.push(attempts);
+            ControlFlow::Continue(())
+        });
+        let expected_seed = AccountIdV0::compute_account_seed(
+            [10; 32],
+            AccountType::FungibleFaucet,
+            AccountStorageMode::Public,
+            AccountIdVersion::Version0,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+        )
+        .unwrap();
+        assert_eq!(seed, Some(expected_seed));
+        assert_eq!(invocations.first(), Some(&0));
+
+        // cancelling stops the grinding before a seed is found
+        assert_eq!(compute_seed(&mut |_| ControlFlow::Break(())), None);
+    }
+
     #[test]
     fn account_id_from_felts_with_high_pop_count() {
         let valid_suffix = Felt::try_from(0xfffe_ffff_ffff_ff00u64).unwrap();
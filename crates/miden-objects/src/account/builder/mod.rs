@@ -1,4 +1,4 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
 
 use vm_core::FieldElement;
 use vm_processor::Digest;
@@ -6,7 +6,7 @@ use vm_processor::Digest;
 use crate::{
     account::{
         Account, AccountCode, AccountComponent, AccountId, AccountIdAnchor, AccountIdV0,
-        AccountIdVersion, AccountStorage, AccountStorageMode, AccountType,
+        AccountIdVersion, AccountStorage, AccountStorageMode, AccountType, StorageValueType,
     },
     asset::AssetVault,
     AccountError, Felt, Word,
@@ -101,8 +101,15 @@ impl AccountBuilder {
         self
     }
 
+    /// Returns the [`StorageValueType`]s declared by the components.
+    fn value_slot_types(&self) -> BTreeMap<u8, StorageValueType> {
+        Account::value_slot_types_from_components(self.account_type, &self.components)
+    }
+
     /// Builds the common parts of testing and non-testing code.
     fn build_inner(&self) -> Result<(AssetVault, AccountCode, AccountStorage), AccountError> {
+        self.validate_faucet_components()?;
+
         #[cfg(any(feature = "testing", test))]
         let vault = AssetVault::new(&self.assets).map_err(|err| {
             AccountError::BuildError(format!("asset vault failed to build: {err}"), None)
@@ -124,6 +131,28 @@ impl AccountBuilder {
         Ok((vault, code, storage))
     }
 
+    /// Checks that faucet accounts have exactly one faucet component (see
+    /// [`AccountComponent::is_faucet_component`]).
+    fn validate_faucet_components(&self) -> Result<(), AccountError> {
+        if !self.account_type.is_faucet() {
+            return Ok(());
+        }
+
+        let num_faucet_components = self
+            .components
+            .iter()
+            .filter(|component| component.is_faucet_component())
+            .count();
+        if num_faucet_components != 1 {
+            return Err(AccountError::InvalidFaucetComponentCount {
+                account_type: self.account_type,
+                num_faucet_components,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Grinds a new [`AccountId`] using the `init_seed` as a starting point.
     fn grind_account_id(
         &self,
@@ -155,6 +184,8 @@ impl AccountBuilder {
     ///
     /// Returns an error if:
     /// - The init seed is not set.
+    /// - The account is a faucet and it does not have exactly one faucet component, i.e. one which
+    ///   only supports faucet account types.
     /// - Any of the components does not support the set account type.
     /// - The number of procedures in all merged components is 0 or exceeds
     ///   [`AccountCode::MAX_NUM_PROCEDURES`](crate::account::AccountCode::MAX_NUM_PROCEDURES).
@@ -198,7 +229,8 @@ impl AccountBuilder {
         debug_assert_eq!(account_id.account_type(), self.account_type);
         debug_assert_eq!(account_id.storage_mode(), self.storage_mode);
 
-        let account = Account::from_parts(account_id, vault, storage, code, Felt::ZERO);
+        let account = Account::from_parts(account_id, vault, storage, code, Felt::ZERO)
+            .with_value_slot_types(self.value_slot_types());
 
         Ok((account, seed))
     }
@@ -234,7 +266,8 @@ impl AccountBuilder {
             )
         };
 
-        Ok(Account::from_parts(account_id, vault, storage, code, Felt::ONE))
+        Ok(Account::from_parts(account_id, vault, storage, code, Felt::ONE)
+            .with_value_slot_types(self.value_slot_types()))
     }
 }
 
@@ -400,5 +433,55 @@ mod tests {
         assert_matches!(build_error, AccountError::BuildError(msg, _) if msg == "account asset vault must be empty on new accounts")
     }
 
+    #[test]
+    fn account_builder_faucet_requires_exactly_one_faucet_component() {
+        let faucet_component = |library: &Library| {
+            AccountComponent::new(library.clone(), vec![])
+                .expect("component should be valid")
+                .with_supported_type(AccountType::FungibleFaucet)
+        };
+
+        // a faucet without a faucet component
+        let build_error = Account::builder([0xff; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(CustomComponent1 { slot0: 25 })
+            .build_existing()
+            .unwrap_err();
+        assert_matches!(
+            build_error,
+            AccountError::InvalidFaucetComponentCount {
+                account_type: AccountType::FungibleFaucet,
+                num_faucet_components: 0
+            }
+        );
+
+        // a faucet with two faucet components
+        let build_error = Account::builder([0xff; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(faucet_component(&CUSTOM_LIBRARY1))
+            .with_component(faucet_component(&CUSTOM_LIBRARY2))
+            .build_existing()
+            .unwrap_err();
+        assert_matches!(
+            build_error,
+            AccountError::InvalidFaucetComponentCount { num_faucet_components: 2, .. }
+        );
+
+        let account = Account::builder([0xff; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(faucet_component(&CUSTOM_LIBRARY1))
+            .with_component(CustomComponent2 { slot0: 1, slot1: 2 })
+            .build_existing()
+            .unwrap();
+        assert!(account.is_faucet());
+
+        // components supporting all account types are not faucet components and may be used by
+        // regular accounts without any faucet component
+        Account::builder([0xff; 32])
+            .with_component(CustomComponent1 { slot0: 25 })
+            .build_existing()
+            .unwrap();
+    }
+
     // TODO: Test that a BlockHeader with a number which is not a multiple of 2^16 returns an error.
 }
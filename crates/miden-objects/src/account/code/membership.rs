@@ -0,0 +1,220 @@
+use alloc::{format, vec::Vec};
+
+use super::{
+    build_procedure_commitment, AccountProcedureInfo, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Digest, Serializable,
+};
+
+// PROCEDURE MEMBERSHIP PROOF
+// ================================================================================================
+
+/// A proof that a procedure with a given MAST root, storage offset and storage size is part of the
+/// public interface of an account, i.e. of an [`AccountCode`](super::AccountCode) with a given
+/// commitment.
+///
+/// The commitment to an account's code is a sequential hash of the information of all of its
+/// procedures. Thus, the proof consists of the [`AccountProcedureInfo`]s of all procedures and the
+/// index of the proven procedure among them, but not of the MAST of the code. This makes the proof
+/// much smaller than the full code, while its size is still bounded by
+/// [`AccountCode::MAX_NUM_PROCEDURES`](super::AccountCode::MAX_NUM_PROCEDURES) procedures.
+///
+/// Proofs are created via [`AccountCode::open_procedure`](super::AccountCode::open_procedure).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureMembershipProof {
+    procedures: Vec<AccountProcedureInfo>,
+    index: u8,
+}
+
+impl ProcedureMembershipProof {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new proof for the procedure at `index` among the provided `procedures`.
+    ///
+    /// The caller must ensure that the number of procedures is between 1 and 256 and that `index`
+    /// is smaller than the number of procedures.
+    pub(super) fn new(procedures: Vec<AccountProcedureInfo>, index: u8) -> Self {
+        debug_assert!((index as usize) < procedures.len(), "procedure index out of bounds");
+
+        Self { procedures, index }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the information of the proven procedure.
+    pub fn procedure(&self) -> &AccountProcedureInfo {
+        &self.procedures[self.index as usize]
+    }
+
+    /// Returns the index of the proven procedure in the account code.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the commitment to the account code this proof was opened against.
+    pub fn code_commitment(&self) -> Digest {
+        build_procedure_commitment(&self.procedures)
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `true` if this proof shows that a procedure with the provided MAST root, storage
+    /// offset and storage size is part of the account code with the provided commitment, `false`
+    /// otherwise.
+    pub fn verify(
+        &self,
+        code_commitment: Digest,
+        procedure_root: Digest,
+        storage_offset: u8,
+        storage_size: u8,
+    ) -> bool {
+        let procedure = self.procedure();
+
+        *procedure.mast_root() == procedure_root
+            && procedure.storage_offset() == storage_offset
+            && procedure.storage_size() == storage_size
+            && self.code_commitment() == code_commitment
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProcedureMembershipProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // the number of procedures is between 1 and 256, so it is stored as a single byte after
+        // subtracting 1, like in the serialization of the account code
+        target.write_u8((self.procedures.len() - 1) as u8);
+        target.write_many(&self.procedures);
+        target.write_u8(self.index);
+    }
+}
+
+impl Deserializable for ProcedureMembershipProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_procedures = (source.read_u8()? as usize) + 1;
+        let procedures = source.read_many::<AccountProcedureInfo>(num_procedures)?;
+        let index = source.read_u8()?;
+
+        if index as usize >= num_procedures {
+            return Err(DeserializationError::InvalidValue(format!(
+                "procedure index {index} is out of bounds for {num_procedures} procedures"
+            )));
+        }
+
+        Ok(Self::new(procedures, index))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assembly::Assembler;
+    use vm_core::Word;
+
+    use super::{Deserializable, ProcedureMembershipProof, Serializable};
+    use crate::account::{AccountCode, AccountComponent, AccountType, StorageSlot};
+
+    /// Returns account code with a component without storage followed by a component with two
+    /// storage slots, so that the procedures of the second component have a non-zero offset.
+    fn code_with_storage_offsets() -> AccountCode {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default()
+            .assemble_library(["export.bar sub end export.baz mul end"])
+            .unwrap();
+
+        let component1 = AccountComponent::new(library1, vec![StorageSlot::Value(Word::default())])
+            .unwrap()
+            .with_supports_all_types();
+        let component2 =
+            AccountComponent::new(library2, vec![StorageSlot::Value(Word::default()); 2])
+                .unwrap()
+                .with_supports_all_types();
+
+        AccountCode::from_components(
+            &[component1, component2],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn procedure_membership_proof() {
+        let code = AccountCode::mock();
+
+        for procedure in code.procedures() {
+            let proof = code.open_procedure(*procedure.mast_root()).unwrap();
+            assert_eq!(proof.procedure(), procedure);
+            assert_eq!(proof.code_commitment(), code.commitment());
+            assert!(proof.verify(
+                code.commitment(),
+                *procedure.mast_root(),
+                procedure.storage_offset(),
+                procedure.storage_size()
+            ));
+
+            let deserialized =
+                ProcedureMembershipProof::read_from_bytes(&proof.to_bytes()).unwrap();
+            assert_eq!(deserialized, proof);
+        }
+    }
+
+    #[test]
+    fn procedure_membership_proof_rejects_forged_storage_layout() {
+        let code = code_with_storage_offsets();
+        let procedure = code.get_procedure_by_index(2);
+        assert_eq!((procedure.storage_offset(), procedure.storage_size()), (1, 2));
+
+        let proof = code.open_procedure(*procedure.mast_root()).unwrap();
+        let verify = |storage_offset, storage_size| {
+            proof.verify(code.commitment(), *procedure.mast_root(), storage_offset, storage_size)
+        };
+
+        assert!(verify(1, 2));
+        assert!(!verify(0, 2));
+        assert!(!verify(2, 2));
+        assert!(!verify(1, 1));
+    }
+
+    #[test]
+    fn procedure_membership_proof_rejects_other_code() {
+        let code = AccountCode::mock();
+        let other_code = code_with_storage_offsets();
+
+        // a procedure of another account code cannot be proven against this code
+        let other_procedure = other_code.get_procedure_by_index(0);
+        assert!(code.open_procedure(*other_procedure.mast_root()).is_none());
+
+        let other_proof = other_code.open_procedure(*other_procedure.mast_root()).unwrap();
+        assert!(!other_proof.verify(
+            code.commitment(),
+            *other_procedure.mast_root(),
+            other_procedure.storage_offset(),
+            other_procedure.storage_size()
+        ));
+
+        // nor can a procedure of this code be proven with a proof for another procedure
+        let procedure = code.get_procedure_by_index(0);
+        let proof = code.open_procedure(*code.get_procedure_by_index(1).mast_root()).unwrap();
+        assert!(!proof.verify(
+            code.commitment(),
+            *procedure.mast_root(),
+            procedure.storage_offset(),
+            procedure.storage_size()
+        ));
+    }
+
+    #[test]
+    fn procedure_membership_proof_with_invalid_index() {
+        let code = AccountCode::mock();
+        let proof = code.open_procedure(*code.get_procedure_by_index(0).mast_root()).unwrap();
+
+        let mut bytes = proof.to_bytes();
+        *bytes.last_mut().unwrap() = code.num_procedures() as u8;
+        assert!(ProcedureMembershipProof::read_from_bytes(&bytes).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
+use alloc::{collections::BTreeSet, format, sync::Arc, vec::Vec};
 
 use vm_core::mast::MastForest;
 
@@ -8,6 +8,9 @@ use super::{
 };
 use crate::account::{AccountComponent, AccountType};
 
+mod membership;
+pub use membership::ProcedureMembershipProof;
+
 pub mod procedure;
 use procedure::AccountProcedureInfo;
 
@@ -51,6 +54,7 @@ impl AccountCode {
         account_type: AccountType,
     ) -> Result<Self, AccountError> {
         super::validate_components_support_account_type(components, account_type)?;
+        super::validate_component_requirements(components)?;
         Self::from_components_unchecked(components, account_type)
     }
 
@@ -66,6 +70,7 @@ impl AccountCode {
     /// - The number of procedures in all merged libraries is 0 or exceeds
     ///   [`AccountCode::MAX_NUM_PROCEDURES`].
     /// - Two or more libraries export a procedure with the same MAST root.
+    /// - Two or more libraries export a procedure with the same fully-qualified name.
     /// - The number of [`StorageSlot`](crate::account::StorageSlot)s of a component or of all
     ///   components exceeds 255.
     /// - [`MastForest::merge`] fails on all libraries.
@@ -79,6 +84,7 @@ impl AccountCode {
 
         let mut procedures = Vec::new();
         let mut proc_root_set = BTreeSet::new();
+        let mut proc_name_set = BTreeSet::new();
 
         // Slot 0 is globally reserved for faucet accounts so the accessible slots begin at 1 if
         // there is a faucet component present.
@@ -88,7 +94,16 @@ impl AccountCode {
             let component_storage_size = component.storage_size();
 
             for module in component.library().module_infos() {
-                for proc_mast_root in module.procedure_digests() {
+                for (_, proc_info) in module.procedures() {
+                    let proc_mast_root = proc_info.digest;
+
+                    // A procedure name exported by multiple components would be ambiguous, as
+                    // only one of the procedures could be referenced by it.
+                    let proc_name = format!("{}::{}", module.path(), proc_info.name);
+                    if !proc_name_set.insert(proc_name.clone()) {
+                        return Err(AccountError::DuplicateProcedureName { name: proc_name });
+                    }
+
                     // We cannot support procedures from multiple components with the same MAST root
                     // since storage offsets/sizes are set per MAST root. Setting them again for
                     // procedures where the offset has already been inserted would cause that
@@ -210,6 +225,16 @@ impl AccountCode {
             .position(|r| r == &root)
     }
 
+    /// Returns a proof that the procedure with the specified MAST root is part of this
+    /// [AccountCode], or None if such procedure is not defined in this [AccountCode].
+    ///
+    /// The proof can be verified against the commitment to this [AccountCode] without access to
+    /// the code itself.
+    pub fn open_procedure(&self, root: Digest) -> Option<ProcedureMembershipProof> {
+        self.get_procedure_index_by_root(root)
+            .map(|index| ProcedureMembershipProof::new(self.procedures.clone(), index as u8))
+    }
+
     /// Converts procedure information in this [AccountCode] into a vector of field elements.
     ///
     /// This is done by first converting each procedure into 8 field elements as follows:
@@ -1,13 +1,21 @@
-use alloc::{collections::BTreeSet, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
 
 use assembly::{Assembler, Compile, Library};
+use semver::Version;
 use vm_processor::MastForest;
 
+mod requirement;
+pub use requirement::{ComponentRequirement, ComponentRequirementError};
+
 mod template;
 pub use template::{
     AccountComponentMetadata, AccountComponentTemplate, FeltRepresentation, InitStorageData,
     MapRepresentation, PlaceholderType, StorageEntry, StoragePlaceholder, StorageValue,
-    WordRepresentation,
+    StorageValueType, WordRepresentation,
 };
 
 use crate::{
@@ -30,11 +38,23 @@ use crate::{
 /// would only specify support for [`AccountType::FungibleFaucet`]. Using it to instantiate a
 /// regular account would fail. By default, the set of supported types is empty, so each component
 /// is forced to explicitly define what it supports.
+///
+/// Components can optionally be identified by a name and a version, and can declare
+/// [`ComponentRequirement`]s on other components. When components are combined into an account,
+/// each requirement must be satisfied by one of the provided components, regardless of the order
+/// in which they are provided.
+///
+/// Components can also declare the [`StorageValueType`]s of their value slots. These declarations
+/// are not part of the account's commitments, but are kept on the built
+/// [`Account`](crate::account::Account) to validate storage updates against them.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountComponent {
     pub(super) library: Library,
     pub(super) storage_slots: Vec<StorageSlot>,
     pub(super) supported_types: BTreeSet<AccountType>,
+    pub(super) identity: Option<(String, Version)>,
+    pub(super) requirements: Vec<ComponentRequirement>,
+    pub(super) value_slot_types: BTreeMap<u8, StorageValueType>,
 }
 
 impl AccountComponent {
@@ -64,6 +84,9 @@ impl AccountComponent {
             library: code,
             storage_slots,
             supported_types: BTreeSet::new(),
+            identity: None,
+            requirements: Vec::new(),
+            value_slot_types: BTreeMap::new(),
         })
     }
 
@@ -96,6 +119,9 @@ impl AccountComponent {
     /// mapping [storage placeholders](StoragePlaceholder) to [values](StorageValue) through the
     /// `init_storage_data` parameter.
     ///
+    /// The name, version, requirements and value slot types of the instantiated component are
+    /// taken from the template's metadata.
+    ///
     /// # Errors
     ///
     /// - If any of the component's storage entries cannot be transformed into a valid storage slot.
@@ -113,8 +139,18 @@ impl AccountComponent {
             storage_slots.extend(entry_storage_slots);
         }
 
+        let metadata = template.metadata();
+        let value_slot_types = metadata.storage_entries().iter().filter_map(|storage_entry| {
+            storage_entry
+                .value_type()
+                .map(|value_type| (storage_entry.slot_indices()[0], value_type))
+        });
+
         Ok(AccountComponent::new(template.library().clone(), storage_slots)?
-            .with_supported_types(template.metadata().targets().clone()))
+            .with_supported_types(metadata.targets().clone())
+            .with_identity(metadata.name(), metadata.version().clone())
+            .with_requirements(metadata.requirements().iter().cloned())
+            .with_value_slot_types(value_slot_types))
     }
 
     // ACCESSORS
@@ -151,6 +187,43 @@ impl AccountComponent {
         self.supported_types.contains(&account_type)
     }
 
+    /// Returns `true` if this component is a faucet component, i.e. if it only supports faucet
+    /// account types, `false` otherwise.
+    pub fn is_faucet_component(&self) -> bool {
+        !self.supported_types.is_empty()
+            && self.supported_types.iter().all(|account_type| account_type.is_faucet())
+    }
+
+    /// Returns the name of this component, if it has been set.
+    pub fn name(&self) -> Option<&str> {
+        self.identity.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the version of this component, if it has been set.
+    pub fn version(&self) -> Option<&Version> {
+        self.identity.as_ref().map(|(_, version)| version)
+    }
+
+    /// Returns the requirements this component has on other components.
+    pub fn requirements(&self) -> &[ComponentRequirement] {
+        &self.requirements
+    }
+
+    /// Returns the declared [`StorageValueType`]s of this component's value slots, keyed by the
+    /// slot index relative to this component.
+    pub fn value_slot_types(&self) -> &BTreeMap<u8, StorageValueType> {
+        &self.value_slot_types
+    }
+
+    /// Returns `true` if this component satisfies the given `requirement`, `false` otherwise.
+    ///
+    /// A component without a name and version never satisfies a requirement.
+    pub fn satisfies(&self, requirement: &ComponentRequirement) -> bool {
+        self.identity
+            .as_ref()
+            .is_some_and(|(name, version)| requirement.is_satisfied_by(name, version))
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -172,6 +245,48 @@ impl AccountComponent {
         self
     }
 
+    /// Sets the name and version by which this component can be referenced in the
+    /// [`ComponentRequirement`]s of other components.
+    pub fn with_identity(mut self, name: impl Into<String>, version: Version) -> Self {
+        self.identity = Some((name.into(), version));
+        self
+    }
+
+    /// Adds `requirement` to the requirements of this component.
+    pub fn with_requirement(mut self, requirement: ComponentRequirement) -> Self {
+        self.requirements.push(requirement);
+        self
+    }
+
+    /// Adds all provided `requirements` to the requirements of this component.
+    pub fn with_requirements(
+        mut self,
+        requirements: impl IntoIterator<Item = ComponentRequirement>,
+    ) -> Self {
+        self.requirements.extend(requirements);
+        self
+    }
+
+    /// Declares that the value slot at index `slot` of this component stores values of type
+    /// `value_type`, overwriting any previous declaration for that slot.
+    ///
+    /// Declarations for slots which are not value slots of this component are ignored when the
+    /// component is used to build an account.
+    pub fn with_value_slot_type(mut self, slot: u8, value_type: StorageValueType) -> Self {
+        self.value_slot_types.insert(slot, value_type);
+        self
+    }
+
+    /// Declares the [`StorageValueType`]s of all provided `(slot, value_type)` pairs, see
+    /// [`Self::with_value_slot_type`].
+    pub fn with_value_slot_types(
+        mut self,
+        value_slot_types: impl IntoIterator<Item = (u8, StorageValueType)>,
+    ) -> Self {
+        self.value_slot_types.extend(value_slot_types);
+        self
+    }
+
     /// Sets the [`AccountType`]s supported by this component to all account types.
     pub fn with_supports_all_types(mut self) -> Self {
         self.supported_types.extend([
@@ -0,0 +1,201 @@
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
+
+use semver::{Version, VersionReq};
+use thiserror::Error;
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::DeserializationError;
+
+// COMPONENT REQUIREMENT
+// ================================================================================================
+
+/// A dependency of an [`AccountComponent`](super::AccountComponent) on another component.
+///
+/// A requirement references another component by its name and a range of acceptable versions,
+/// following semantic versioning rules. Requirements are written as `name@version_req`, for example
+/// `miden::basic-wallet@^1`. The version range may be omitted (e.g. `miden::basic-wallet`), in
+/// which case any version of the named component satisfies the requirement.
+///
+/// A requirement is satisfied by a component whose name is equal to the requirement's name and
+/// whose version matches the requirement's version range.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentRequirement {
+    name: String,
+    version_req: VersionReq,
+}
+
+impl ComponentRequirement {
+    /// Separates the component name from the version range in the string representation.
+    const SEPARATOR: char = '@';
+
+    /// Returns a new [`ComponentRequirement`] on the component with the provided `name` and a
+    /// version matching `version_req`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is empty or contains the `@` separator.
+    pub fn new(
+        name: impl Into<String>,
+        version_req: VersionReq,
+    ) -> Result<Self, ComponentRequirementError> {
+        let name = name.into();
+        Self::validate_name(&name)?;
+        Ok(Self { name, version_req })
+    }
+
+    /// Returns the name of the required component.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the range of versions of the required component satisfying this requirement.
+    pub fn version_req(&self) -> &VersionReq {
+        &self.version_req
+    }
+
+    /// Returns `true` if a component with the provided `name` and `version` satisfies this
+    /// requirement, `false` otherwise.
+    pub fn is_satisfied_by(&self, name: &str, version: &Version) -> bool {
+        self.name == name && self.version_req.matches(version)
+    }
+
+    /// Checks that the provided string is a valid component name.
+    fn validate_name(name: &str) -> Result<(), ComponentRequirementError> {
+        if name.is_empty() {
+            return Err(ComponentRequirementError::EmptyName);
+        }
+
+        if name.contains(Self::SEPARATOR) {
+            return Err(ComponentRequirementError::InvalidName(name.into()));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ComponentRequirement {
+    type Err = ComponentRequirementError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (name, version_req) = match value.split_once(Self::SEPARATOR) {
+            Some((name, version_req)) => {
+                let version_req = VersionReq::parse(version_req).map_err(|err| {
+                    ComponentRequirementError::InvalidVersionReq(value.into(), err.to_string())
+                })?;
+                (name, version_req)
+            },
+            None => (value, VersionReq::STAR),
+        };
+
+        Self::new(name, version_req)
+    }
+}
+
+impl TryFrom<&str> for ComponentRequirement {
+    type Error = ComponentRequirementError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for ComponentRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.name, Self::SEPARATOR, self.version_req)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ComponentRequirementError {
+    #[error("component requirement name cannot be empty")]
+    EmptyName,
+    #[error("component requirement name `{0}` must not contain the `@` separator")]
+    InvalidName(String),
+    #[error("component requirement `{0}` has an invalid version range: {1}")]
+    InvalidVersionReq(String, String),
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ComponentRequirement {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.to_string());
+    }
+}
+
+impl Deserializable for ComponentRequirement {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let requirement: String = source.read()?;
+        requirement.parse().map_err(|err: ComponentRequirementError| {
+            DeserializationError::InvalidValue(err.to_string())
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use assert_matches::assert_matches;
+    use semver::{Version, VersionReq};
+    use vm_core::utils::{Deserializable, Serializable};
+
+    use super::{ComponentRequirement, ComponentRequirementError};
+
+    #[test]
+    fn parse_component_requirement() {
+        let requirement: ComponentRequirement = "miden::basic-wallet@^1".parse().unwrap();
+        assert_eq!(requirement.name(), "miden::basic-wallet");
+        assert_eq!(requirement.version_req(), &VersionReq::parse("^1").unwrap());
+
+        let requirement: ComponentRequirement = "miden::basic-wallet".parse().unwrap();
+        assert_eq!(requirement.name(), "miden::basic-wallet");
+        assert_eq!(requirement.version_req(), &VersionReq::STAR);
+
+        assert_matches!(
+            "".parse::<ComponentRequirement>(),
+            Err(ComponentRequirementError::EmptyName)
+        );
+        assert_matches!(
+            "@^1".parse::<ComponentRequirement>(),
+            Err(ComponentRequirementError::EmptyName)
+        );
+        assert_matches!(
+            "miden::basic-wallet@not-a-version".parse::<ComponentRequirement>(),
+            Err(ComponentRequirementError::InvalidVersionReq(..))
+        );
+        assert_matches!(
+            "miden::basic-wallet@1@2".parse::<ComponentRequirement>(),
+            Err(ComponentRequirementError::InvalidVersionReq(..))
+        );
+    }
+
+    #[test]
+    fn component_requirement_matching() {
+        let requirement: ComponentRequirement = "miden::basic-wallet@^1.2".parse().unwrap();
+
+        assert!(requirement.is_satisfied_by("miden::basic-wallet", &Version::new(1, 2, 0)));
+        assert!(requirement.is_satisfied_by("miden::basic-wallet", &Version::new(1, 9, 3)));
+        assert!(!requirement.is_satisfied_by("miden::basic-wallet", &Version::new(1, 1, 0)));
+        assert!(!requirement.is_satisfied_by("miden::basic-wallet", &Version::new(2, 0, 0)));
+        assert!(!requirement.is_satisfied_by("miden::auth", &Version::new(1, 2, 0)));
+    }
+
+    #[test]
+    fn component_requirement_serde() {
+        for requirement in ["miden::basic-wallet@^1", "miden::basic-wallet", "auth@>=0.1, <0.3"] {
+            let requirement: ComponentRequirement = requirement.parse().unwrap();
+
+            let display_roundtrip: ComponentRequirement = requirement.to_string().parse().unwrap();
+            assert_eq!(display_roundtrip, requirement);
+
+            let deserialized =
+                ComponentRequirement::read_from_bytes(&requirement.to_bytes()).unwrap();
+            assert_eq!(deserialized, requirement);
+        }
+    }
+}
@@ -10,7 +10,7 @@ use semver::Version;
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::AccountType;
+use super::{AccountType, ComponentRequirement};
 use crate::errors::AccountComponentTemplateError;
 
 mod storage;
@@ -163,6 +163,11 @@ pub struct AccountComponentMetadata {
     /// A set of supported target account types for this component.
     targets: BTreeSet<AccountType>,
 
+    /// A list of requirements on other components which must be present in any account this
+    /// component is used in, e.g. `requires = ["miden::basic-wallet@^1"]`.
+    #[cfg_attr(feature = "std", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    requires: Vec<ComponentRequirement>,
+
     /// A list of storage entries defining the component's storage layout and initialization
     /// values.
     storage: Vec<StorageEntry>,
@@ -188,6 +193,7 @@ impl AccountComponentMetadata {
             description,
             version,
             targets,
+            requires: Vec::new(),
             storage,
         };
         component.validate()?;
@@ -235,11 +241,26 @@ impl AccountComponentMetadata {
         &self.targets
     }
 
+    /// Returns the requirements the component has on other components.
+    pub fn requirements(&self) -> &[ComponentRequirement] {
+        &self.requires
+    }
+
     /// Returns the list of storage entries of the component.
     pub fn storage_entries(&self) -> &Vec<StorageEntry> {
         &self.storage
     }
 
+    /// Sets the requirements the component has on other components, replacing any previously set
+    /// requirements.
+    pub fn with_requirements(
+        mut self,
+        requirements: impl IntoIterator<Item = ComponentRequirement>,
+    ) -> Self {
+        self.requires = requirements.into_iter().collect();
+        self
+    }
+
     /// Validate the [AccountComponentMetadata].
     ///
     /// # Errors
@@ -316,6 +337,7 @@ impl Serializable for AccountComponentMetadata {
         self.description.write_into(target);
         self.version.to_string().write_into(target);
         self.targets.write_into(target);
+        self.requires.write_into(target);
         self.storage.write_into(target);
     }
 }
@@ -329,6 +351,7 @@ impl Deserializable for AccountComponentMetadata {
                 |err: semver::Error| DeserializationError::InvalidValue(err.to_string()),
             )?,
             targets: BTreeSet::<AccountType>::read_from(source)?,
+            requires: Vec::<ComponentRequirement>::read_from(source)?,
             storage: Vec::<StorageEntry>::read_from(source)?,
         })
     }
@@ -355,6 +378,7 @@ mod tests {
                 description: None,
                 slot: 0,
                 value: WordRepresentation::Value(Default::default()),
+                value_type: None,
             },
             StorageEntry::MultiSlot {
                 name: "slot1".into(),
@@ -390,12 +414,14 @@ mod tests {
                 description: None,
                 slot: 0,
                 value: Default::default(),
+                value_type: None,
             },
             StorageEntry::Value {
                 name: "slot2".into(),
                 description: None,
                 slot: 2,
                 value: Default::default(),
+                value_type: None,
             },
         ];
 
@@ -426,6 +452,7 @@ mod tests {
                 description: None,
                 slot: 0,
                 value: WordRepresentation::Value(Default::default()),
+                value_type: None,
             },
         ];
 
@@ -516,4 +543,83 @@ mod tests {
         ]);
         AccountComponent::from_template(&template, &valid_init_storage_data).unwrap();
     }
+
+    #[test]
+    fn component_requirements_from_toml() {
+        let toml_text = r#"
+            name = "miden::vesting"
+            description = "A vesting component"
+            version = "0.2.0"
+            targets = ["RegularAccountUpdatableCode"]
+            requires = ["miden::basic-wallet@^1", "miden::auth"]
+
+            [[storage]]
+            name = "schedule"
+            slot = 0
+            value = "0x1"
+        "#;
+
+        let metadata = AccountComponentMetadata::from_toml(toml_text).unwrap();
+        let requirements: Vec<_> =
+            metadata.requirements().iter().map(ToString::to_string).collect();
+        assert_eq!(requirements, ["miden::basic-wallet@^1", "miden::auth@*"]);
+
+        // requirements survive TOML and binary roundtrips
+        let toml_roundtrip =
+            AccountComponentMetadata::from_toml(&metadata.as_toml().unwrap()).unwrap();
+        assert_eq!(toml_roundtrip, metadata);
+        assert_eq!(
+            AccountComponentMetadata::read_from_bytes(&metadata.to_bytes()).unwrap(),
+            metadata
+        );
+
+        // instantiated components carry the identity and requirements of the template
+        let library = Assembler::default().assemble_library([CODE]).unwrap();
+        let template = AccountComponentTemplate::new(metadata, library);
+        let component =
+            AccountComponent::from_template(&template, &InitStorageData::default()).unwrap();
+        assert_eq!(component.name(), Some("miden::vesting"));
+        assert_eq!(component.version(), Some(&Version::new(0, 2, 0)));
+        assert_eq!(component.requirements(), template.metadata().requirements());
+
+        let invalid_toml = toml_text.replace("^1", "not-a-version");
+        assert_matches!(
+            AccountComponentMetadata::from_toml(&invalid_toml),
+            Err(AccountComponentTemplateError::DeserializationError(_))
+        );
+    }
+
+    #[test]
+    fn component_requirements_binary_layout() {
+        let requirements: Vec<ComponentRequirement> =
+            vec!["miden::basic-wallet@^1".parse().unwrap(), "miden::auth".parse().unwrap()];
+        let metadata = AccountComponentMetadata::new(
+            "miden::vesting".into(),
+            "desc".into(),
+            Version::new(0, 2, 0),
+            BTreeSet::from([AccountType::RegularAccountUpdatableCode]),
+            vec![],
+        )
+        .unwrap()
+        .with_requirements(requirements.clone());
+
+        // the requirements are serialized between the targets and the storage entries
+        let mut expected = Vec::new();
+        String::from("miden::vesting").write_into(&mut expected);
+        String::from("desc").write_into(&mut expected);
+        String::from("0.2.0").write_into(&mut expected);
+        BTreeSet::from([AccountType::RegularAccountUpdatableCode]).write_into(&mut expected);
+        requirements.write_into(&mut expected);
+        Vec::<StorageEntry>::new().write_into(&mut expected);
+        assert_eq!(metadata.to_bytes(), expected);
+
+        // metadata serialized before requirements were introduced is not deserializable
+        let mut legacy = Vec::new();
+        String::from("miden::vesting").write_into(&mut legacy);
+        String::from("desc").write_into(&mut legacy);
+        String::from("0.2.0").write_into(&mut legacy);
+        BTreeSet::from([AccountType::RegularAccountUpdatableCode]).write_into(&mut legacy);
+        Vec::<StorageEntry>::new().write_into(&mut legacy);
+        assert!(AccountComponentMetadata::read_from_bytes(&legacy).is_err());
+    }
 }
@@ -6,7 +6,9 @@ use vm_core::{
 };
 use vm_processor::{DeserializationError, Digest};
 
-use super::{placeholder::PlaceholderType, InitStorageData, MapEntry, StoragePlaceholder};
+use super::{
+    placeholder::PlaceholderType, InitStorageData, MapEntry, StoragePlaceholder, StorageValue,
+};
 use crate::account::{component::template::AccountComponentTemplateError, StorageMap};
 
 // WORDS
@@ -322,8 +324,13 @@ impl MapRepresentation {
     /// Attempts to convert the [MapRepresentation] into a [StorageMap].
     ///
     /// If the representation is a template, the value is retrieved from
-    /// `init_storage_data`, identified by its key. If any of the inner elements
+    /// `init_storage_data`, identified by its key. The value can either be a [StorageMap], or a
+    /// list of key-value pairs from which the map is built. If any of the inner elements
     /// within the value are a template, they are retrieved in the same way.
+    ///
+    /// # Errors
+    /// Returns an error if a value is not provided or has an unexpected type, or if the resulting
+    /// list of entries contains the same key more than once.
     pub fn try_build_map(
         &self,
         init_storage_data: &InitStorageData,
@@ -339,27 +346,20 @@ impl MapRepresentation {
                     })
                     .collect::<Result<Vec<(Digest, Word)>, _>>()?;
 
-                // validate that no key appears multiple times
-                let mut seen_keys = BTreeSet::new();
-                for (map_key, _map_value) in entries.iter() {
-                    if !seen_keys.insert(map_key) {
-                        return Err(AccountComponentTemplateError::StorageMapHasDuplicateKeys(
-                            map_key.to_hex(),
-                        ));
-                    }
-                }
-
-                StorageMap::with_entries(entries)
+                build_storage_map(&entries)?
             },
-            MapRepresentation::Template(storage_placeholder) => init_storage_data
-                .get(storage_placeholder)
-                .ok_or_else(|| {
+            MapRepresentation::Template(storage_placeholder) => {
+                let value = init_storage_data.get(storage_placeholder).ok_or_else(|| {
                     AccountComponentTemplateError::PlaceholderValueNotProvided(
                         storage_placeholder.clone(),
                     )
-                })?
-                .as_map()
-                .cloned()?,
+                })?;
+
+                match value {
+                    StorageValue::MapEntries(entries) => build_storage_map(entries)?,
+                    value => value.as_map().cloned()?,
+                }
+            },
         };
 
         Ok(map)
@@ -387,6 +387,22 @@ impl MapRepresentation {
     }
 }
 
+/// Builds a [StorageMap] from the provided entries, validating that no key appears multiple times.
+fn build_storage_map(
+    entries: &[(Digest, Word)],
+) -> Result<StorageMap, AccountComponentTemplateError> {
+    let mut seen_keys = BTreeSet::new();
+    for (map_key, _map_value) in entries.iter() {
+        if !seen_keys.insert(map_key) {
+            return Err(AccountComponentTemplateError::StorageMapHasDuplicateKeys(
+                map_key.to_hex(),
+            ));
+        }
+    }
+
+    Ok(StorageMap::with_entries(entries.iter().copied()))
+}
+
 impl Serializable for MapRepresentation {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         match self {
@@ -12,6 +12,9 @@ use crate::account::StorageSlot;
 mod placeholder;
 pub use placeholder::{PlaceholderType, StoragePlaceholder, StorageValue};
 
+mod value_type;
+pub use value_type::StorageValueType;
+
 mod init_storage_data;
 pub use init_storage_data::InitStorageData;
 
@@ -40,6 +43,8 @@ pub enum StorageEntry {
         slot: u8,
         /// The initial value for this slot.
         value: WordRepresentation,
+        /// The optional semantic type of the values stored in this slot.
+        value_type: Option<StorageValueType>,
     },
 
     /// A map slot, containing multiple key-value pairs. Keys and values are hex-encoded strings.
@@ -80,6 +85,25 @@ impl StorageEntry {
             description: description.map(Into::<String>::into),
             slot,
             value: value.into(),
+            value_type: None,
+        }
+    }
+
+    /// Creates a new [`StorageEntry::Value`] variant whose values must be of the provided
+    /// [`StorageValueType`].
+    pub fn new_typed_value(
+        name: impl Into<String>,
+        description: Option<impl Into<String>>,
+        slot: u8,
+        value: impl Into<WordRepresentation>,
+        value_type: StorageValueType,
+    ) -> Self {
+        StorageEntry::Value {
+            name: name.into(),
+            description: description.map(Into::<String>::into),
+            slot,
+            value: value.into(),
+            value_type: Some(value_type),
         }
     }
 
@@ -146,6 +170,16 @@ impl StorageEntry {
         }
     }
 
+    /// Returns the declared [`StorageValueType`] of the storage entry, if any.
+    ///
+    /// Only [`StorageEntry::Value`] entries can declare a type.
+    pub fn value_type(&self) -> Option<StorageValueType> {
+        match self {
+            StorageEntry::Value { value_type, .. } => *value_type,
+            StorageEntry::Map { .. } | StorageEntry::MultiSlot { .. } => None,
+        }
+    }
+
     /// Returns all the `WordRepresentation` values covered by this entry.
     /// For `Value` entries, this returns a single-element slice.
     /// For `MultiSlot` entries, this returns all values.
@@ -179,15 +213,25 @@ impl StorageEntry {
     /// - StorageEntry::Map would convert to a [StorageSlot::Map]
     ///
     /// Each of the entry's values could be templated. These values are replaced for values found
-    /// in `init_storage_data`, identified by its key.
+    /// in `init_storage_data`, identified by its key. If a value entry declares a
+    /// [`StorageValueType`], the resulting word must be a valid value of that type.
     pub fn try_build_storage_slots(
         &self,
         init_storage_data: &InitStorageData,
     ) -> Result<Vec<StorageSlot>, AccountComponentTemplateError> {
         match self {
-            StorageEntry::Value { value, .. } => {
-                let slot = value.try_build_word(init_storage_data)?;
-                Ok(vec![StorageSlot::Value(slot)])
+            StorageEntry::Value { slot, value, value_type, .. } => {
+                let word = value.try_build_word(init_storage_data)?;
+                if let Some(expected_type) = value_type {
+                    if !expected_type.is_valid_value(&word) {
+                        return Err(AccountComponentTemplateError::SlotTypeViolation {
+                            slot: *slot,
+                            expected_type: *expected_type,
+                        });
+                    }
+                }
+
+                Ok(vec![StorageSlot::Value(word)])
             },
             StorageEntry::Map { map: values, .. } => {
                 let storage_map = values.try_build_map(init_storage_data)?;
@@ -237,12 +281,19 @@ impl StorageEntry {
 impl Serializable for StorageEntry {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         match self {
-            StorageEntry::Value { name, description, slot, value } => {
+            StorageEntry::Value {
+                name,
+                description,
+                slot,
+                value,
+                value_type,
+            } => {
                 target.write_u8(0u8);
                 target.write(name);
                 target.write(description);
                 target.write_u8(*slot);
                 target.write(value);
+                target.write(value_type);
             },
             StorageEntry::Map { name, description, slot, map: values } => {
                 target.write_u8(1u8);
@@ -273,8 +324,15 @@ impl Deserializable for StorageEntry {
             0 => {
                 let slot = source.read_u8()?;
                 let value: WordRepresentation = source.read()?;
+                let value_type: Option<StorageValueType> = source.read()?;
 
-                Ok(StorageEntry::Value { name, description, slot, value })
+                Ok(StorageEntry::Value {
+                    name,
+                    description,
+                    slot,
+                    value,
+                    value_type,
+                })
             },
 
             // Map
@@ -365,12 +423,13 @@ impl Deserializable for MapEntry {
 #[cfg(test)]
 mod tests {
     use core::panic;
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeMap, BTreeSet};
 
     use assembly::Assembler;
     use assert_matches::assert_matches;
     use semver::Version;
-    use vm_core::{Felt, FieldElement};
+    use vm_core::{Felt, FieldElement, Word};
+    use vm_processor::Digest;
 
     use super::*;
     use crate::{
@@ -397,6 +456,7 @@ mod tests {
                 description: Some("First slot".into()),
                 slot: 0,
                 value: WordRepresentation::Value(digest!("0x333123").into()),
+                value_type: None,
             },
             StorageEntry::Map {
                 name: "map".into(),
@@ -438,6 +498,7 @@ mod tests {
                 value: WordRepresentation::Template(
                     StoragePlaceholder::new("single-slot-key").unwrap(),
                 ),
+                value_type: None,
             },
         ];
 
@@ -446,6 +507,7 @@ mod tests {
             description: "This is a test component".into(),
             version: Version::parse("1.0.0").unwrap(),
             targets: BTreeSet::from([AccountType::FungibleFaucet]),
+            requires: vec![],
             storage,
         };
 
@@ -567,6 +629,64 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn map_template_with_dynamic_entries() {
+        let toml_text = r#"
+            name = "Allow-list"
+            description = "A component with an allow-list of account IDs"
+            version = "1.0.0"
+            targets = ["FungibleFaucet"]
+
+            [[storage]]
+            name = "allowed_accounts"
+            description = "account IDs which are allowed to receive assets"
+            slot = 0
+            values = "{{allowed.accounts}}"
+        "#;
+
+        let component_metadata = AccountComponentMetadata::from_toml(toml_text).unwrap();
+        let library = Assembler::default().assemble_library([CODE]).unwrap();
+        let template = AccountComponentTemplate::new(component_metadata, library);
+
+        let entries: Vec<(Digest, Word)> = (0..100u64)
+            .map(|i| {
+                let key = Digest::new([Felt::new(i), Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+                (key, [Felt::ONE, Felt::ZERO, Felt::ZERO, Felt::new(i)])
+            })
+            .collect();
+        let init_storage_data = |entries: Vec<(Digest, Word)>| {
+            InitStorageData::new([(
+                StoragePlaceholder::new("allowed.accounts").unwrap(),
+                StorageValue::MapEntries(entries),
+            )])
+        };
+
+        let component =
+            AccountComponent::from_template(&template, &init_storage_data(entries.clone()))
+                .unwrap();
+        let expected_map = StorageMap::with_entries(entries.clone());
+        match component.storage_slots().first().unwrap() {
+            StorageSlot::Map(storage_map) => {
+                assert_eq!(storage_map.entries().count(), 100);
+                assert_eq!(storage_map.root(), expected_map.root());
+            },
+            _ => panic!("should be map"),
+        }
+
+        // providing the same key twice is rejected
+        let duplicate_key = entries[42].0;
+        let mut duplicate_entries = entries;
+        duplicate_entries.push((duplicate_key, Word::default()));
+        let err = AccountComponent::from_template(&template, &init_storage_data(duplicate_entries))
+            .unwrap_err();
+        assert_matches!(
+            err,
+            AccountError::AccountComponentTemplateInstantiationError(
+                AccountComponentTemplateError::StorageMapHasDuplicateKeys(key)
+            ) if key == duplicate_key.to_hex()
+        );
+    }
+
     #[test]
     pub fn fail_placeholder_type_mismatch() {
         let toml_text = r#"
@@ -594,4 +714,99 @@ mod tests {
             Err(AccountComponentTemplateError::StoragePlaceholderTypeMismatch(_, _, _))
         );
     }
+
+    #[test]
+    pub fn typed_value_slots() {
+        let toml_text = r#"
+            name = "Vesting"
+            description = "A component with typed value slots"
+            version = "1.0.0"
+            targets = ["RegularAccountImmutableCode"]
+
+            [[storage]]
+            name = "unlock_block"
+            slot = 0
+            value = ["{{unlock.block}}", "0", "0", "0"]
+            type = "block_number"
+
+            [[storage]]
+            name = "beneficiary"
+            slot = 1
+            value = "{{beneficiary}}"
+        "#;
+
+        let component_metadata = AccountComponentMetadata::from_toml(toml_text).unwrap();
+        assert_eq!(
+            component_metadata.storage_entries()[0].value_type(),
+            Some(StorageValueType::BlockNumber)
+        );
+        assert_eq!(component_metadata.storage_entries()[1].value_type(), None);
+        assert_eq!(
+            AccountComponentMetadata::from_toml(&component_metadata.as_toml().unwrap()).unwrap(),
+            component_metadata
+        );
+
+        let library = Assembler::default().assemble_library([CODE]).unwrap();
+        let template = AccountComponentTemplate::new(component_metadata, library);
+        assert_eq!(
+            AccountComponentTemplate::read_from_bytes(&template.to_bytes()).unwrap(),
+            template
+        );
+
+        let init_storage_data = |unlock_block: Felt| {
+            InitStorageData::new([
+                (
+                    StoragePlaceholder::new("unlock.block").unwrap(),
+                    StorageValue::Felt(unlock_block),
+                ),
+                (
+                    StoragePlaceholder::new("beneficiary").unwrap(),
+                    StorageValue::Word([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+                ),
+            ])
+        };
+
+        let component =
+            AccountComponent::from_template(&template, &init_storage_data(Felt::new(100))).unwrap();
+        assert_eq!(
+            component.value_slot_types(),
+            &BTreeMap::from([(0, StorageValueType::BlockNumber)])
+        );
+
+        // the initial value must be a valid value of the declared type
+        let err = AccountComponent::from_template(
+            &template,
+            &init_storage_data(Felt::new(u32::MAX as u64 + 1)),
+        )
+        .unwrap_err();
+        assert_matches!(
+            err,
+            AccountError::AccountComponentTemplateInstantiationError(
+                AccountComponentTemplateError::SlotTypeViolation {
+                    slot: 0,
+                    expected_type: StorageValueType::BlockNumber
+                }
+            )
+        );
+    }
+
+    #[test]
+    pub fn fail_type_on_map_entry() {
+        let toml_text = r#"
+            name = "Test Component"
+            description = "This is a test component"
+            version = "1.0.1"
+            targets = ["FungibleFaucet"]
+
+            [[storage]]
+            name = "map"
+            slot = 0
+            values = [{ key = "0x1", value = "0x2" }]
+            type = "block_number"
+        "#;
+        assert_matches!(
+            AccountComponentMetadata::from_toml(toml_text),
+            Err(AccountComponentTemplateError::DeserializationError(_))
+        );
+    }
 }
@@ -1,11 +1,14 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use thiserror::Error;
 use vm_core::{
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
     Felt, Word,
 };
-use vm_processor::DeserializationError;
+use vm_processor::{DeserializationError, Digest};
 
 use crate::account::{component::template::AccountComponentTemplateError, StorageMap};
 
@@ -162,6 +165,8 @@ impl Deserializable for StoragePlaceholder {
 /// - `Felt(Felt)`: a single [Felt] value
 /// - `Word(Word)`: a single [Word] value
 /// - `Map(StorageMap)`: a storage map
+/// - `MapEntries(Vec<(Digest, Word)>)`: a list of key-value pairs from which a storage map is
+///   built, e.g. when the number of entries is only known at instantiation
 ///
 /// These values are used to resolve dynamic placeholders at component instantiation.
 #[derive(Clone, Debug)]
@@ -169,6 +174,7 @@ pub enum StorageValue {
     Felt(Felt),
     Word(Word),
     Map(StorageMap),
+    MapEntries(Vec<(Digest, Word)>),
 }
 
 impl StorageValue {
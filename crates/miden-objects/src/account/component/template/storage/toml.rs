@@ -12,8 +12,9 @@ use super::{
     FeltRepresentation, MapRepresentation, StorageEntry, StoragePlaceholder, WordRepresentation,
 };
 use crate::{
-    account::AccountComponentMetadata, errors::AccountComponentTemplateError,
-    utils::parse_hex_string_as_word,
+    account::{AccountComponentMetadata, ComponentRequirement, StorageValueType},
+    errors::AccountComponentTemplateError,
+    utils::parse_hex_word,
 };
 
 // ACCOUNT COMPONENT METADATA TOML FROM/TO
@@ -94,7 +95,7 @@ impl<'de> serde::Deserialize<'de> for WordRepresentation {
                 }
 
                 // try hex parsing otherwise
-                let word = parse_hex_string_as_word(value).map_err(|_err| {
+                let word = parse_hex_word(value).map_err(|_err| {
                     E::invalid_value(
                         serde::de::Unexpected::Str(value),
                         &"a valid hexadecimal string or storage placeholder (in '{{key}}' format)",
@@ -199,6 +200,50 @@ impl<'de> serde::Deserialize<'de> for StoragePlaceholder {
     }
 }
 
+// COMPONENT REQUIREMENT SERIALIZATION
+// ================================================================================================
+
+impl serde::Serialize for ComponentRequirement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ComponentRequirement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// VALUE SLOT TYPE SERIALIZATION
+// ================================================================================================
+
+impl serde::Serialize for StorageValueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for StorageValueType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 // STORAGE VALUES
 // ================================================================================================
 
@@ -254,16 +299,25 @@ struct RawStorageEntry {
     slots: Option<Vec<u8>>,
     value: Option<WordRepresentation>,
     values: Option<StorageValues>,
+    #[serde(rename = "type")]
+    value_type: Option<StorageValueType>,
 }
 
 impl From<StorageEntry> for RawStorageEntry {
     fn from(entry: StorageEntry) -> Self {
         match entry {
-            StorageEntry::Value { name, description, slot, value } => RawStorageEntry {
+            StorageEntry::Value {
+                name,
+                description,
+                slot,
+                value,
+                value_type,
+            } => RawStorageEntry {
                 name,
                 description,
                 slot: Some(slot),
                 value: Some(value),
+                value_type,
                 ..Default::default()
             },
             StorageEntry::Map { name, description, slot, map: values } => RawStorageEntry {
@@ -305,6 +359,12 @@ impl<'de> Deserialize<'de> for StorageEntry {
         let slot_present = raw.slot.is_some();
         let value_present = raw.value.is_some();
 
+        if raw.value_type.is_some() && (raw.slots.is_some() || raw.values.is_some()) {
+            return Err(D::Error::custom(
+                "field 'type' is only supported for single-slot value entries",
+            ));
+        }
+
         // Use a match on the combination of presence flags to choose variant
         match (raw.slots, raw.values) {
             (None, None) => {
@@ -319,6 +379,7 @@ impl<'de> Deserialize<'de> for StorageEntry {
                     value: raw
                         .value
                         .ok_or(D::Error::custom("missing 'value' field for single-slot entry"))?,
+                    value_type: raw.value_type,
                 })
             },
             (Some(_), None) => {
@@ -0,0 +1,160 @@
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+use vm_core::{
+    utils::{ByteReader, ByteWriter, Deserializable, Serializable},
+    Felt, Word, ZERO,
+};
+use vm_processor::DeserializationError;
+
+use crate::account::AccountId;
+
+// STORAGE VALUE TYPE
+// ================================================================================================
+
+/// The semantic type of the word stored in a
+/// [`StorageSlot::Value`](crate::account::StorageSlot::Value).
+///
+/// Value types are declared by value entries of an account component's storage layout (see
+/// [`StorageEntry::Value`](super::StorageEntry::Value)) and are persisted on the built
+/// [`Account`](crate::account::Account) outside of any commitment. They allow detecting writes
+/// which do not represent a value of the declared type, e.g. a full random word written to a slot
+/// which is expected to store a block number.
+///
+/// All types store their value in the first elements of the word and require the remaining
+/// elements to be zero:
+///
+/// ```text
+/// BlockNumber: [block_num, 0, 0, 0] with block_num <= u32::MAX
+/// Timestamp:   [timestamp, 0, 0, 0] with timestamp <= u32::MAX
+/// AccountId:   [suffix, prefix, 0, 0] where (prefix, suffix) is a valid account ID
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageValueType {
+    /// A block number.
+    BlockNumber,
+    /// A timestamp in seconds since the UNIX epoch, as used in block headers.
+    Timestamp,
+    /// An account ID.
+    AccountId,
+}
+
+impl StorageValueType {
+    const BLOCK_NUMBER: &'static str = "block_number";
+    const TIMESTAMP: &'static str = "timestamp";
+    const ACCOUNT_ID: &'static str = "account_id";
+
+    /// Returns `true` if the provided word is a valid value of this type, `false` otherwise.
+    pub fn is_valid_value(&self, value: &Word) -> bool {
+        match self {
+            StorageValueType::BlockNumber | StorageValueType::Timestamp => {
+                value[0].as_int() <= u32::MAX as u64 && value[1..].iter().all(|felt| *felt == ZERO)
+            },
+            StorageValueType::AccountId => {
+                let [suffix, prefix, zero0, zero1]: [Felt; 4] = *value;
+                zero0 == ZERO && zero1 == ZERO && AccountId::try_from([prefix, suffix]).is_ok()
+            },
+        }
+    }
+}
+
+impl fmt::Display for StorageValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageValueType::BlockNumber => f.write_str(Self::BLOCK_NUMBER),
+            StorageValueType::Timestamp => f.write_str(Self::TIMESTAMP),
+            StorageValueType::AccountId => f.write_str(Self::ACCOUNT_ID),
+        }
+    }
+}
+
+impl FromStr for StorageValueType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            Self::BLOCK_NUMBER => Ok(StorageValueType::BlockNumber),
+            Self::TIMESTAMP => Ok(StorageValueType::Timestamp),
+            Self::ACCOUNT_ID => Ok(StorageValueType::AccountId),
+            _ => Err(format!("unknown value slot type `{value}`")),
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageValueType {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            StorageValueType::BlockNumber => target.write_u8(0),
+            StorageValueType::Timestamp => target.write_u8(1),
+            StorageValueType::AccountId => target.write_u8(2),
+        }
+    }
+
+    fn get_size_hint(&self) -> usize {
+        0u8.get_size_hint()
+    }
+}
+
+impl Deserializable for StorageValueType {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value_slot_type = source.read_u8()?;
+        match value_slot_type {
+            0 => Ok(StorageValueType::BlockNumber),
+            1 => Ok(StorageValueType::Timestamp),
+            2 => Ok(StorageValueType::AccountId),
+            _ => Err(DeserializationError::InvalidValue(format!(
+                "unknown value slot type `{value_slot_type}`"
+            ))),
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use vm_core::{utils::Serializable, Felt, ONE};
+
+    use super::*;
+    use crate::testing::account_id::ACCOUNT_ID_OFF_CHAIN_SENDER;
+
+    #[test]
+    fn value_slot_type_validation() {
+        let block_num = [Felt::from(u32::MAX), ZERO, ZERO, ZERO];
+        let too_large = [Felt::new(u32::MAX as u64 + 1), ZERO, ZERO, ZERO];
+        let full_word = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        for value_type in [StorageValueType::BlockNumber, StorageValueType::Timestamp] {
+            assert!(value_type.is_valid_value(&block_num));
+            assert!(!value_type.is_valid_value(&too_large));
+            assert!(!value_type.is_valid_value(&full_word));
+        }
+
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let account_id_word = [account_id.suffix(), account_id.prefix().as_felt(), ZERO, ZERO];
+        assert!(StorageValueType::AccountId.is_valid_value(&account_id_word));
+        assert!(!StorageValueType::AccountId.is_valid_value(&full_word));
+        assert!(!StorageValueType::AccountId.is_valid_value(&block_num));
+    }
+
+    #[test]
+    fn value_slot_type_serde() {
+        for value_type in [
+            StorageValueType::BlockNumber,
+            StorageValueType::Timestamp,
+            StorageValueType::AccountId,
+        ] {
+            assert_eq!(
+                value_type,
+                StorageValueType::read_from_bytes(&value_type.to_bytes()).unwrap()
+            );
+            assert_eq!(value_type, value_type.to_string().parse().unwrap());
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use alloc::string::ToString;
+use core::fmt;
 
 use super::{
     Account, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable,
@@ -17,6 +18,16 @@ pub use vault::{
 // ACCOUNT DELTA
 // ================================================================================================
 
+/// The weight of an updated storage value slot, which consists of a single word.
+const STORAGE_VALUE_WEIGHT: usize = 1;
+
+/// The weight of an updated storage map entry, which consists of a key and a value word.
+const STORAGE_MAP_ENTRY_WEIGHT: usize = 2;
+
+/// The weight of a changed fungible asset balance or an added or removed non-fungible asset, each
+/// of which is represented by a single word.
+const ASSET_WEIGHT: usize = 1;
+
 /// [AccountDelta] stores the differences between two account states.
 ///
 /// The differences are represented as follows:
@@ -53,6 +64,20 @@ impl AccountDelta {
     }
 
     /// Merge another [AccountDelta] into this one.
+    ///
+    /// The merged delta has the same effect on an account as applying this delta followed by
+    /// `other`:
+    /// - storage updates of `other` overwrite the updates of this delta per slot and per map key.
+    /// - fungible asset changes are summed, and a non-fungible asset added by one of the deltas and
+    ///   removed by the other cancels out.
+    /// - the nonce of `other` becomes the nonce of the merged delta, if it was updated.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - both deltas update the nonce and the nonce of `other` is not larger than the nonce of this
+    ///   delta.
+    /// - the merged storage or vault delta is invalid, e.g. because the same non-fungible asset is
+    ///   added by both deltas.
     pub fn merge(&mut self, other: Self) -> Result<(), AccountDeltaError> {
         match (&mut self.nonce, other.nonce) {
             (Some(old), Some(new)) if new.as_int() <= old.as_int() => {
@@ -90,12 +115,108 @@ impl AccountDelta {
         self.nonce
     }
 
+    /// Returns a summary of the number of updates contained in this account delta.
+    pub fn summary(&self) -> AccountDeltaSummary {
+        let num_fungible_assets = |added: bool| {
+            self.vault.fungible().iter().filter(|(_, &diff)| (diff >= 0) == added).count()
+        };
+        let num_non_fungible_assets = |action: NonFungibleDeltaAction| {
+            self.vault.non_fungible().iter().filter(|(_, &a)| a == action).count()
+        };
+
+        AccountDeltaSummary {
+            nonce: self.nonce,
+            num_updated_values: self.storage.values().len(),
+            num_updated_map_entries: self
+                .storage
+                .maps()
+                .values()
+                .map(|map_delta| map_delta.leaves().len())
+                .sum(),
+            num_added_assets: num_fungible_assets(true)
+                + num_non_fungible_assets(NonFungibleDeltaAction::Add),
+            num_removed_assets: num_fungible_assets(false)
+                + num_non_fungible_assets(NonFungibleDeltaAction::Remove),
+        }
+    }
+
+    /// Returns the weight of this account delta, which approximates its contribution to the size
+    /// of a block in words.
+    ///
+    /// The weight is the sum of:
+    /// - 1 for each updated storage value slot.
+    /// - 2 for each updated storage map entry, accounting for its key and value.
+    /// - 1 for each fungible asset whose balance changed and for each added or removed non-fungible
+    ///   asset.
+    ///
+    /// Account deltas do not track code updates yet, so code changes do not contribute to the
+    /// weight.
+    pub fn weight(&self) -> usize {
+        let num_map_entries: usize =
+            self.storage.maps().values().map(|map_delta| map_delta.leaves().len()).sum();
+        let num_assets =
+            self.vault.fungible().num_assets() + self.vault.non_fungible().num_assets();
+
+        self.storage.values().len() * STORAGE_VALUE_WEIGHT
+            + num_map_entries * STORAGE_MAP_ENTRY_WEIGHT
+            + num_assets * ASSET_WEIGHT
+    }
+
     /// Converts this storage delta into individual delta components.
     pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Option<Felt>) {
         (self.storage, self.vault, self.nonce)
     }
 }
 
+impl fmt::Display for AccountDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.summary().fmt(f)
+    }
+}
+
+// ACCOUNT DELTA SUMMARY
+// ================================================================================================
+
+/// The number of updates contained in an [AccountDelta].
+///
+/// The summary is meant for logging and debugging, where printing the full delta would be too
+/// verbose. It can be obtained via [AccountDelta::summary].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AccountDeltaSummary {
+    /// The new nonce, if the nonce was changed.
+    pub nonce: Option<Felt>,
+    /// The number of storage value slots that were updated.
+    pub num_updated_values: usize,
+    /// The number of storage map entries that were updated, across all storage maps.
+    pub num_updated_map_entries: usize,
+    /// The number of assets that were added to the vault.
+    pub num_added_assets: usize,
+    /// The number of assets that were removed from the vault.
+    pub num_removed_assets: usize,
+}
+
+impl fmt::Display for AccountDeltaSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.nonce {
+            Some(nonce) => write!(f, "nonce: {nonce}")?,
+            None => write!(f, "nonce: unchanged")?,
+        }
+
+        write!(
+            f,
+            ", storage values updated: {}, storage map entries updated: {}, assets added: {}, \
+             assets removed: {}",
+            self.num_updated_values,
+            self.num_updated_map_entries,
+            self.num_added_assets,
+            self.num_removed_assets
+        )
+    }
+}
+
+// ACCOUNT UPDATE DETAILS
+// ================================================================================================
+
 /// Describes the details of an account state transition resulting from applying a transaction to
 /// the account.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -279,10 +400,12 @@ fn validate_nonce(
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
 
+    use assert_matches::assert_matches;
     use vm_core::{utils::Serializable, Felt, FieldElement};
 
-    use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+    use super::{AccountDelta, AccountDeltaSummary, AccountStorageDelta, AccountVaultDelta};
     use crate::{
         account::{
             delta::AccountUpdateDetails, Account, AccountCode, AccountId, AccountStorage,
@@ -292,7 +415,7 @@ mod tests {
         testing::account_id::{
             AccountIdBuilder, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
-        ONE, ZERO,
+        AccountDeltaError, ONE, ZERO,
     };
 
     #[test]
@@ -312,6 +435,92 @@ mod tests {
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_ok());
     }
 
+    #[test]
+    fn account_delta_merge_rejects_non_increasing_nonce() {
+        let storage_delta = AccountStorageDelta::from_iters([1], [], []);
+        let delta = |nonce: u64| {
+            AccountDelta::new(
+                storage_delta.clone(),
+                AccountVaultDelta::default(),
+                Some(Felt::new(nonce)),
+            )
+            .unwrap()
+        };
+
+        for new_nonce in [2, 3] {
+            let mut merged = delta(3);
+            assert_matches!(
+                merged.merge(delta(new_nonce)).unwrap_err(),
+                AccountDeltaError::InconsistentNonceUpdate(_)
+            );
+
+            let update = AccountUpdateDetails::Delta(delta(3));
+            assert_matches!(
+                update.merge(AccountUpdateDetails::Delta(delta(new_nonce))).unwrap_err(),
+                AccountDeltaError::InconsistentNonceUpdate(_)
+            );
+        }
+
+        let mut merged = delta(3);
+        merged.merge(delta(4)).unwrap();
+        assert_eq!(merged.nonce(), Some(Felt::new(4)));
+    }
+
+    #[test]
+    fn account_delta_summary() {
+        let map_delta = StorageMapDelta::from_iters([[ONE; 4]], [([ZERO; 4], [ONE; 4])]);
+        let storage_delta = AccountStorageDelta::from_iters([1], [(2, [ONE; 4])], [(3, map_delta)]);
+        let vault_delta = AccountVaultDelta::from_iters(
+            [FungibleAsset::mock(10)],
+            [NonFungibleAsset::mock(&[1, 2, 3])],
+        );
+        let delta = AccountDelta::new(storage_delta, vault_delta, Some(ONE)).unwrap();
+
+        let summary = delta.summary();
+        assert_eq!(
+            summary,
+            AccountDeltaSummary {
+                nonce: Some(ONE),
+                num_updated_values: 2,
+                num_updated_map_entries: 2,
+                num_added_assets: 1,
+                num_removed_assets: 1,
+            }
+        );
+        assert_eq!(
+            delta.to_string(),
+            "nonce: 1, storage values updated: 2, storage map entries updated: 2, assets added: 1, \
+             assets removed: 1"
+        );
+
+        assert_eq!(AccountDelta::default().summary(), AccountDeltaSummary::default());
+        assert!(AccountDelta::default().to_string().starts_with("nonce: unchanged,"));
+    }
+
+    #[test]
+    fn account_delta_weight() {
+        assert_eq!(AccountDelta::default().weight(), 0);
+
+        let storage_delta = AccountStorageDelta::from_iters([1], [], []);
+        let delta =
+            AccountDelta::new(storage_delta, AccountVaultDelta::default(), Some(ONE)).unwrap();
+        assert_eq!(delta.weight(), 1);
+
+        let map_delta = StorageMapDelta::from_iters([], [([ONE; 4], [ONE; 4])]);
+        let storage_delta = AccountStorageDelta::from_iters([1], [], [(2, map_delta)]);
+        let delta =
+            AccountDelta::new(storage_delta.clone(), AccountVaultDelta::default(), Some(ONE))
+                .unwrap();
+        assert_eq!(delta.weight(), 3);
+
+        let vault_delta = AccountVaultDelta::from_iters(
+            [FungibleAsset::mock(10), NonFungibleAsset::mock(&[1, 2, 3])],
+            [],
+        );
+        let delta = AccountDelta::new(storage_delta, vault_delta, Some(ONE)).unwrap();
+        assert_eq!(delta.weight(), 5);
+    }
+
     #[test]
     fn account_update_details_size_hint() {
         // AccountDelta
@@ -1,13 +1,17 @@
+use alloc::{boxed::Box, collections::BTreeMap};
+
+use miden_crypto::utils::SliceReader;
+
 use crate::{
     asset::AssetVault,
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    AccountError, Digest, Felt, Hasher, Word, ZERO,
+    AccountDeltaError, AccountError, Digest, Felt, Hasher, Word, ZERO,
 };
 
 mod account_id;
 pub use account_id::{
     AccountId, AccountIdAnchor, AccountIdPrefix, AccountIdPrefixV0, AccountIdV0, AccountIdVersion,
-    AccountStorageMode, AccountType,
+    AccountStorageMode, AccountType, AddressType, Bech32Variant, CustomNetworkId, NetworkId,
 };
 
 pub mod auth;
@@ -18,23 +22,27 @@ mod builder;
 pub use builder::AccountBuilder;
 
 pub mod code;
-pub use code::{procedure::AccountProcedureInfo, AccountCode};
+pub use code::{procedure::AccountProcedureInfo, AccountCode, ProcedureMembershipProof};
 
 mod component;
 pub use component::{
-    AccountComponent, AccountComponentMetadata, AccountComponentTemplate, FeltRepresentation,
-    InitStorageData, MapRepresentation, PlaceholderType, StorageEntry, StoragePlaceholder,
-    StorageValue, WordRepresentation,
+    AccountComponent, AccountComponentMetadata, AccountComponentTemplate, ComponentRequirement,
+    ComponentRequirementError, FeltRepresentation, InitStorageData, MapRepresentation,
+    PlaceholderType, StorageEntry, StoragePlaceholder, StorageValue, StorageValueType,
+    WordRepresentation,
 };
 
 pub mod delta;
 pub use delta::{
-    AccountDelta, AccountStorageDelta, AccountVaultDelta, FungibleAssetDelta,
+    AccountDelta, AccountDeltaSummary, AccountStorageDelta, AccountVaultDelta, FungibleAssetDelta,
     NonFungibleAssetDelta, NonFungibleDeltaAction, StorageMapDelta,
 };
 
 mod storage;
-pub use storage::{AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotType};
+pub use storage::{
+    AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotProof,
+    StorageSlotType,
+};
 
 mod header;
 pub use header::AccountHeader;
@@ -63,13 +71,19 @@ pub use data::AccountData;
 ///
 /// The recommended way to build an account is through an [`AccountBuilder`], which can be
 /// instantiated through [`Account::builder`]. See the type's documentation for details.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// An account may additionally carry the [`StorageValueType`]s declared by its components for its
+/// value slots. These declarations are not part of the account's commitments and are ignored when
+/// comparing accounts for equality. If they are available, storage updates applied through
+/// [`Account::apply_delta`] are validated against them.
+#[derive(Debug, Clone)]
 pub struct Account {
     id: AccountId,
     vault: AssetVault,
     storage: AccountStorage,
     code: AccountCode,
     nonce: Felt,
+    value_slot_types: BTreeMap<u8, StorageValueType>,
 }
 
 impl Account {
@@ -77,6 +91,9 @@ impl Account {
     // --------------------------------------------------------------------------------------------
 
     /// Returns an [Account] instantiated with the provided components.
+    ///
+    /// The returned account does not have any [`StorageValueType`] declarations, see
+    /// [`Account::with_value_slot_types`].
     pub fn from_parts(
         id: AccountId,
         vault: AssetVault,
@@ -84,7 +101,24 @@ impl Account {
         code: AccountCode,
         nonce: Felt,
     ) -> Self {
-        Self { id, vault, storage, code, nonce }
+        Self {
+            id,
+            vault,
+            storage,
+            code,
+            nonce,
+            value_slot_types: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the [`StorageValueType`]s of this account's value slots, keyed by the storage slot
+    /// index, overwriting any previous declarations.
+    pub fn with_value_slot_types(
+        mut self,
+        value_slot_types: BTreeMap<u8, StorageValueType>,
+    ) -> Self {
+        self.value_slot_types = value_slot_types;
+        self
     }
 
     /// Creates an account's [`AccountCode`] and [`AccountStorage`] from the provided components.
@@ -120,7 +154,9 @@ impl Account {
     /// # Errors
     ///
     /// Returns an error if:
+    /// - Any of the components does not support any account type.
     /// - Any of the components does not support `account_type`.
+    /// - Any of the components has a requirement which is not satisfied by the provided components.
     /// - The number of procedures in all merged libraries is 0 or exceeds
     ///   [`AccountCode::MAX_NUM_PROCEDURES`].
     /// - Two or more libraries export a procedure with the same MAST root.
@@ -131,6 +167,7 @@ impl Account {
         components: &[AccountComponent],
     ) -> Result<(AccountCode, AccountStorage), AccountError> {
         validate_components_support_account_type(components, account_type)?;
+        validate_component_requirements(components)?;
 
         let code = AccountCode::from_components_unchecked(components, account_type)?;
         let storage = AccountStorage::from_components(components, account_type)?;
@@ -138,6 +175,31 @@ impl Account {
         Ok((code, storage))
     }
 
+    /// Returns the [`StorageValueType`]s declared by the provided components, keyed by the index of
+    /// the slot in the [`AccountStorage`] created by [`Account::initialize_from_components`].
+    ///
+    /// Declarations for slots which are out of bounds of their component's storage or which are not
+    /// value slots are ignored.
+    pub(super) fn value_slot_types_from_components(
+        account_type: AccountType,
+        components: &[AccountComponent],
+    ) -> BTreeMap<u8, StorageValueType> {
+        // faucet accounts have a reserved slot at index 0
+        let mut offset = if account_type.is_faucet() { 1 } else { 0 };
+        let mut value_slot_types = BTreeMap::new();
+
+        for component in components {
+            for (slot, value_type) in component.value_slot_types() {
+                if let Some(StorageSlot::Value(_)) = component.storage_slots().get(*slot as usize) {
+                    value_slot_types.insert(offset + slot, *value_type);
+                }
+            }
+            offset += component.storage_size();
+        }
+
+        value_slot_types
+    }
+
     /// Creates a new [`AccountBuilder`] for an account and sets the initial seed from which the
     /// grinding process for that account's [`AccountId`] will start.
     ///
@@ -211,6 +273,15 @@ impl Account {
         self.nonce
     }
 
+    /// Returns the declared [`StorageValueType`]s of this account's value slots, keyed by the
+    /// storage slot index.
+    ///
+    /// The map is empty if the account has no layout information, e.g. because its components did
+    /// not declare any types or it was constructed through [`Account::from_parts`].
+    pub fn value_slot_types(&self) -> &BTreeMap<u8, StorageValueType> {
+        &self.value_slot_types
+    }
+
     /// Returns true if this account can issue assets.
     pub fn is_faucet(&self) -> bool {
         self.id.is_faucet()
@@ -242,13 +313,38 @@ impl Account {
     /// Applies the provided delta to this account. This updates account vault, storage, and nonce
     /// to the values specified by the delta.
     ///
+    /// If this account has [`StorageValueType`] declarations, the value slot updates of the delta
+    /// are validated against them before the delta is applied. Slots without a declaration are
+    /// not validated.
+    ///
+    /// The nonce of the delta is validated before any changes are made, so the account is left
+    /// unchanged if the nonce does not increase.
+    ///
     /// # Errors
     /// Returns an error if:
-    /// - Applying vault sub-delta to the vault of this account fails.
-    /// - Applying storage sub-delta to the storage of this account fails.
     /// - The nonce specified in the provided delta smaller than or equal to the current account
     ///   nonce.
+    /// - A value slot update of the delta is not a valid value of the slot's declared type.
+    /// - Applying vault sub-delta to the vault of this account fails.
+    /// - Applying storage sub-delta to the storage of this account fails.
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
+        if let Some(nonce) = delta.nonce() {
+            self.validate_nonce(nonce)?;
+        }
+
+        for (slot, value) in delta.storage().values() {
+            if let Some(expected_type) = self.value_slot_types.get(slot) {
+                if !expected_type.is_valid_value(value) {
+                    return Err(AccountError::StorageSlotTypeViolation(Box::new(
+                        AccountDeltaError::SlotTypeViolation {
+                            slot: *slot,
+                            expected_type: *expected_type,
+                        },
+                    )));
+                }
+            }
+        }
+
         // update vault; we don't check vault delta validity here because `AccountDelta` can contain
         // only valid vault deltas
         self.vault
@@ -273,6 +369,14 @@ impl Account {
     /// - The new nonce is smaller than the actual account nonce
     /// - The new nonce is equal to the actual account nonce
     pub fn set_nonce(&mut self, nonce: Felt) -> Result<(), AccountError> {
+        self.validate_nonce(nonce)?;
+        self.nonce = nonce;
+
+        Ok(())
+    }
+
+    /// Checks that the specified nonce value is strictly greater than the actual account nonce.
+    fn validate_nonce(&self, nonce: Felt) -> Result<(), AccountError> {
         if self.nonce.as_int() >= nonce.as_int() {
             return Err(AccountError::NonceNotMonotonicallyIncreasing {
                 current: self.nonce.as_int(),
@@ -280,8 +384,6 @@ impl Account {
             });
         }
 
-        self.nonce = nonce;
-
         Ok(())
     }
 
@@ -304,15 +406,42 @@ impl Account {
 // SERIALIZATION
 // ================================================================================================
 
+/// The declared [`StorageValueType`]s of the account's value slots are serialized after the nonce.
+///
+/// This is a breaking change of the wire format of [`Account`] and of every type which serializes
+/// an account, i.e., [`TransactionInputs`](crate::transaction::TransactionInputs) and
+/// [`TransactionWitness`](crate::transaction::TransactionWitness). Data serialized before the
+/// declarations were introduced can no longer be deserialized.
+impl PartialEq for Account {
+    fn eq(&self, other: &Self) -> bool {
+        // the value slot type declarations are not part of the account state
+        self.id == other.id
+            && self.vault == other.vault
+            && self.storage == other.storage
+            && self.code == other.code
+            && self.nonce == other.nonce
+    }
+}
+
+impl Eq for Account {}
+
 impl Serializable for Account {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        let Account { id, vault, storage, code, nonce } = self;
+        let Account {
+            id,
+            vault,
+            storage,
+            code,
+            nonce,
+            value_slot_types,
+        } = self;
 
         id.write_into(target);
         vault.write_into(target);
         storage.write_into(target);
         code.write_into(target);
         nonce.write_into(target);
+        value_slot_types.write_into(target);
     }
 
     fn get_size_hint(&self) -> usize {
@@ -321,18 +450,56 @@ impl Serializable for Account {
             + self.storage.get_size_hint()
             + self.code.get_size_hint()
             + self.nonce.get_size_hint()
+            + self.value_slot_types.get_size_hint()
     }
 }
 
-impl Deserializable for Account {
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+impl Account {
+    /// Reads an account from the source, using the provided closure to read the value slot type
+    /// declarations which follow the nonce.
+    fn read_with_value_slot_types<R, F>(
+        source: &mut R,
+        read_value_slot_types: F,
+    ) -> Result<Self, DeserializationError>
+    where
+        R: ByteReader,
+        F: FnOnce(&mut R) -> Result<BTreeMap<u8, StorageValueType>, DeserializationError>,
+    {
         let id = AccountId::read_from(source)?;
         let vault = AssetVault::read_from(source)?;
         let storage = AccountStorage::read_from(source)?;
         let code = AccountCode::read_from(source)?;
         let nonce = Felt::read_from(source)?;
+        let value_slot_types = read_value_slot_types(source)?;
 
-        Ok(Self::from_parts(id, vault, storage, code, nonce))
+        Ok(Self::from_parts(id, vault, storage, code, nonce)
+            .with_value_slot_types(value_slot_types))
+    }
+}
+
+impl Deserializable for Account {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_with_value_slot_types(source, BTreeMap::<u8, StorageValueType>::read_from)
+    }
+
+    fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+
+        // the value slot type declarations were added after the nonce, so accounts serialized
+        // without them end right after the nonce
+        let account = Self::read_with_value_slot_types(&mut source, |source| {
+            if source.has_more_bytes() {
+                BTreeMap::<u8, StorageValueType>::read_from(source)
+            } else {
+                Ok(BTreeMap::new())
+            }
+        })?;
+
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Ok(account)
     }
 }
 
@@ -362,11 +529,18 @@ pub fn hash_account(
 }
 
 /// Validates that all `components` support the given `account_type`.
+///
+/// Components which do not support any account type are reported separately, since they can never
+/// be used to build an account.
 fn validate_components_support_account_type(
     components: &[AccountComponent],
     account_type: AccountType,
 ) -> Result<(), AccountError> {
     for (component_index, component) in components.iter().enumerate() {
+        if component.supported_types().is_empty() {
+            return Err(AccountError::NoSupportedTypes { component_index });
+        }
+
         if !component.supports_type(account_type) {
             return Err(AccountError::UnsupportedComponentForAccountType {
                 account_type,
@@ -378,12 +552,29 @@ fn validate_components_support_account_type(
     Ok(())
 }
 
+/// Validates that the requirements of all provided components are satisfied by at least one of the
+/// provided components.
+fn validate_component_requirements(components: &[AccountComponent]) -> Result<(), AccountError> {
+    for (component_index, component) in components.iter().enumerate() {
+        for requirement in component.requirements() {
+            if !components.iter().any(|other| other.satisfies(requirement)) {
+                return Err(AccountError::MissingComponentDependency {
+                    component_index,
+                    requirement: requirement.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
     use assembly::Assembler;
     use assert_matches::assert_matches;
@@ -391,6 +582,7 @@ mod tests {
         utils::{Deserializable, Serializable},
         Felt, Word,
     };
+    use rand::Rng;
     use vm_processor::Digest;
 
     use super::{
@@ -399,14 +591,20 @@ mod tests {
     };
     use crate::{
         account::{
-            Account, AccountComponent, AccountType, StorageMap, StorageMapDelta, StorageSlot,
+            Account, AccountComponent, AccountType, ComponentRequirement, StorageMap,
+            StorageMapDelta, StorageSlot, StorageValueType,
         },
         asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
         testing::{
-            account_id::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            account_id::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            },
+            asset::NonFungibleAssetBuilder,
             storage::AccountStorageDeltaBuilder,
         },
-        AccountError,
+        AccountDeltaError, AccountError, ZERO,
     };
 
     #[test]
@@ -508,6 +706,97 @@ mod tests {
         assert_eq!(account, final_account);
     }
 
+    #[test]
+    fn merged_account_delta_is_equivalent_to_sequential_deltas() {
+        let fungible_asset = FungibleAsset::mock(100);
+        let non_fungible_asset_0 = NonFungibleAsset::mock(&[1, 2, 3]);
+        let non_fungible_asset_1 = NonFungibleAsset::mock(&[4, 5, 6]);
+        let map_key_0 =
+            Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]);
+        let map_key_1 =
+            Digest::new([Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)]);
+
+        let account = build_account(
+            vec![fungible_asset, non_fungible_asset_0],
+            Felt::new(1),
+            vec![
+                StorageSlot::Value([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+                StorageSlot::Map(StorageMap::with_entries([(
+                    map_key_0,
+                    [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+                )])),
+            ],
+        );
+
+        // the first transaction spends part of the fungible asset, receives a non-fungible asset
+        // and updates both storage slots
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_updated_values([(0_u8, [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)])])
+            .add_updated_maps([(
+                1_u8,
+                StorageMapDelta::from_iters(
+                    [],
+                    [(
+                        map_key_1.into(),
+                        [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)],
+                    )],
+                ),
+            )])
+            .build()
+            .unwrap();
+        let delta_a = build_account_delta(
+            vec![non_fungible_asset_1],
+            vec![FungibleAsset::mock(30)],
+            Felt::new(2),
+            storage_delta,
+        );
+
+        // the second transaction receives the fungible asset back, sends both non-fungible assets
+        // and overwrites the storage updates of the first transaction
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_updated_values([(
+                0_u8,
+                [Felt::new(13), Felt::new(14), Felt::new(15), Felt::new(16)],
+            )])
+            .add_updated_maps([(
+                1_u8,
+                StorageMapDelta::from_iters([map_key_0.into(), map_key_1.into()], []),
+            )])
+            .build()
+            .unwrap();
+        let delta_b = build_account_delta(
+            vec![FungibleAsset::mock(30)],
+            vec![non_fungible_asset_0, non_fungible_asset_1],
+            Felt::new(5),
+            storage_delta,
+        );
+
+        let mut sequential_account = account.clone();
+        sequential_account.apply_delta(&delta_a).unwrap();
+        sequential_account.apply_delta(&delta_b).unwrap();
+
+        let mut merged_delta = delta_a.clone();
+        merged_delta.merge(delta_b.clone()).unwrap();
+        let mut merged_account = account.clone();
+        merged_account.apply_delta(&merged_delta).unwrap();
+
+        assert_eq!(merged_account, sequential_account);
+        assert_eq!(merged_delta.nonce(), Some(Felt::new(5)));
+
+        // the fungible changes and the addition and removal of the same asset cancel out
+        assert_eq!(
+            merged_delta.vault(),
+            &AccountVaultDelta::from_iters([], [non_fungible_asset_0])
+        );
+
+        // the deltas cannot be merged in the reverse order
+        let mut reversed_delta = delta_b;
+        assert_matches!(
+            reversed_delta.merge(delta_a),
+            Err(AccountDeltaError::InconsistentNonceUpdate(_))
+        );
+    }
+
     #[test]
     #[should_panic]
     fn valid_account_delta_with_unchanged_nonce() {
@@ -551,6 +840,34 @@ mod tests {
         account.apply_delta(&account_delta).unwrap()
     }
 
+    #[test]
+    fn account_delta_with_non_increasing_nonce_leaves_account_unchanged() {
+        let init_nonce = Felt::new(5);
+        let asset = FungibleAsset::mock(100);
+        let account =
+            build_account(vec![asset], init_nonce, vec![StorageSlot::Value(Word::default())]);
+
+        for final_nonce in [Felt::new(4), init_nonce] {
+            let storage_delta = AccountStorageDeltaBuilder::default()
+                .add_updated_values([(
+                    0_u8,
+                    [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+                )])
+                .build()
+                .unwrap();
+            let account_delta =
+                build_account_delta(vec![], vec![asset], final_nonce, storage_delta);
+
+            let mut updated_account = account.clone();
+            assert_matches!(
+                updated_account.apply_delta(&account_delta).unwrap_err(),
+                AccountError::NonceNotMonotonicallyIncreasing { current: 5, new }
+                    if new == final_nonce.as_int()
+            );
+            assert_eq!(updated_account, account);
+        }
+    }
+
     #[test]
     fn empty_account_delta_with_incremented_nonce() {
         // build account
@@ -572,6 +889,145 @@ mod tests {
         account.apply_delta(&account_delta).unwrap()
     }
 
+    #[test]
+    fn account_delta_is_validated_against_value_slot_types() {
+        let library = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let component = AccountComponent::new(
+            library,
+            vec![StorageSlot::Value(Word::default()), StorageSlot::Value(Word::default())],
+        )
+        .unwrap()
+        .with_supports_all_types()
+        .with_value_slot_type(1, StorageValueType::BlockNumber);
+
+        let mut account =
+            Account::builder([5; 32]).with_component(component).build_existing().unwrap();
+        assert_eq!(
+            account.value_slot_types(),
+            &BTreeMap::from([(1, StorageValueType::BlockNumber)])
+        );
+
+        // a block number in the u32 range is accepted
+        let block_num = [Felt::from(u32::MAX), ZERO, ZERO, ZERO];
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_updated_values([(1, block_num)])
+            .build()
+            .unwrap();
+        account
+            .apply_delta(&build_account_delta(vec![], vec![], Felt::new(2), storage_delta))
+            .unwrap();
+        assert_eq!(account.storage().get_item(1).unwrap(), block_num.into());
+
+        // a full word is rejected and the account is not modified
+        let full_word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_updated_values([(0, full_word), (1, full_word)])
+            .build()
+            .unwrap();
+        let err = account
+            .apply_delta(&build_account_delta(vec![], vec![], Felt::new(3), storage_delta))
+            .unwrap_err();
+        assert_matches!(
+            err,
+            AccountError::StorageSlotTypeViolation(err) if matches!(
+                *err,
+                AccountDeltaError::SlotTypeViolation {
+                    slot: 1,
+                    expected_type: StorageValueType::BlockNumber
+                }
+            )
+        );
+        assert_eq!(account.nonce(), Felt::new(2));
+        assert_eq!(account.storage().get_item(0).unwrap(), Word::default().into());
+        assert_eq!(account.storage().get_item(1).unwrap(), block_num.into());
+
+        // the declarations survive serialization
+        let deserialized = Account::read_from_bytes(&account.to_bytes()).unwrap();
+        assert_eq!(deserialized, account);
+        assert_eq!(deserialized.value_slot_types(), account.value_slot_types());
+
+        // the declarations are not part of the account state
+        let undeclared = account.clone().with_value_slot_types(BTreeMap::new());
+        assert_eq!(undeclared, account);
+        assert_eq!(undeclared.hash(), account.hash());
+    }
+
+    #[test]
+    fn account_serialized_without_value_slot_types_still_parses() {
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let account = build_account(vec![], Felt::new(1), vec![StorageSlot::Value(word)])
+            .with_value_slot_types(BTreeMap::from([(0, StorageValueType::BlockNumber)]));
+
+        // accounts serialized before the declarations were added end right after the nonce
+        let mut legacy_bytes = account.id().to_bytes();
+        legacy_bytes.extend(account.vault().to_bytes());
+        legacy_bytes.extend(account.storage().to_bytes());
+        legacy_bytes.extend(account.code().to_bytes());
+        legacy_bytes.extend(account.nonce().to_bytes());
+
+        let deserialized = Account::read_from_bytes(&legacy_bytes).unwrap();
+        assert_eq!(deserialized, account);
+        assert!(deserialized.value_slot_types().is_empty());
+    }
+
+    #[test]
+    fn account_delta_without_value_slot_types_is_not_validated() {
+        let mut account =
+            build_account(vec![], Felt::new(1), vec![StorageSlot::Value(Word::default())]);
+        assert!(account.value_slot_types().is_empty());
+
+        let full_word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_updated_values([(0, full_word)])
+            .build()
+            .unwrap();
+        account
+            .apply_delta(&build_account_delta(vec![], vec![], Felt::new(2), storage_delta))
+            .unwrap();
+        assert_eq!(account.storage().get_item(0).unwrap(), full_word.into());
+    }
+
+    #[test]
+    fn account_and_vault_apply_vault_delta_identically() {
+        let mut rng = rand::thread_rng();
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let nft_faucet = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let mut nft_builder = NonFungibleAssetBuilder::new(nft_faucet.prefix(), &mut rng).unwrap();
+        let existing_nft: Asset = nft_builder.build().unwrap().into();
+        let added_nft: Asset = nft_builder.build().unwrap().into();
+
+        let initial_amount = rng.gen_range(1..=1000);
+        let account = build_account(
+            vec![FungibleAsset::new(faucet_0, initial_amount).unwrap().into(), existing_nft],
+            Felt::new(1),
+            vec![],
+        );
+
+        let vault_delta = AccountVaultDelta::from_iters(
+            [FungibleAsset::new(faucet_1, rng.gen_range(1..=1000)).unwrap().into(), added_nft],
+            [
+                FungibleAsset::new(faucet_0, rng.gen_range(1..=initial_amount)).unwrap().into(),
+                existing_nft,
+            ],
+        );
+        let delta =
+            AccountDelta::new(AccountStorageDelta::default(), vault_delta, Some(Felt::new(2)))
+                .unwrap();
+
+        let mut vault = account.vault().clone();
+        vault.apply_delta(delta.vault()).unwrap();
+
+        let mut account = account;
+        account.apply_delta(&delta).unwrap();
+
+        assert_eq!(account.vault(), &vault);
+        assert_eq!(
+            account.vault().assets().collect::<Vec<_>>(),
+            vault.assets().collect::<Vec<_>>()
+        );
+    }
+
     pub fn build_account_delta(
         added_assets: Vec<Asset>,
         removed_assets: Vec<Asset>,
@@ -622,6 +1078,25 @@ mod tests {
         ))
     }
 
+    /// Tests that initializing code and storage from a component which does not support any
+    /// account type returns an error.
+    #[test]
+    fn test_account_component_without_supported_types() {
+        let library = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let component = AccountComponent::new(library, vec![]).unwrap();
+        assert!(component.supported_types().is_empty());
+
+        for account_type in [AccountType::FungibleFaucet, AccountType::RegularAccountUpdatableCode]
+        {
+            let err = Account::initialize_from_components(account_type, &[component.clone()])
+                .unwrap_err();
+            assert_matches!(err, AccountError::NoSupportedTypes { component_index: 0 });
+
+            let err = AccountCode::from_components(&[component.clone()], account_type).unwrap_err();
+            assert_matches!(err, AccountError::NoSupportedTypes { component_index: 0 });
+        }
+    }
+
     /// Two components who export a procedure with the same MAST root should fail to convert into
     /// code and storage.
     #[test]
@@ -643,4 +1118,118 @@ mod tests {
 
         assert_matches!(err, AccountError::AccountComponentDuplicateProcedureRoot(_))
     }
+
+    /// Two components who export a procedure with the same name should fail to convert into code
+    /// and storage, even if the procedures have different MAST roots.
+    #[test]
+    fn test_account_duplicate_exported_procedure_name() {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default().assemble_library(["export.foo mul end"]).unwrap();
+        let module_path = library1.module_infos().next().unwrap().path().clone();
+        let name = format!("{module_path}::foo");
+
+        let component1 = AccountComponent::new(library1, vec![]).unwrap().with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![]).unwrap().with_supports_all_types();
+
+        let err = AccountCode::from_components(
+            &[component1.clone(), component2.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap_err();
+        assert_matches!(err, AccountError::DuplicateProcedureName { name: err_name } if err_name == name);
+
+        let err = Account::initialize_from_components(
+            AccountType::RegularAccountUpdatableCode,
+            &[component1, component2],
+        )
+        .unwrap_err();
+        assert_matches!(err, AccountError::DuplicateProcedureName { .. });
+    }
+
+    fn wallet_and_vesting_components(
+        wallet_version: semver::Version,
+        vesting_requirement: &str,
+    ) -> (AccountComponent, AccountComponent) {
+        let wallet_library = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let vesting_library =
+            Assembler::default().assemble_library(["export.bar mul end"]).unwrap();
+
+        let wallet = AccountComponent::new(wallet_library, vec![])
+            .unwrap()
+            .with_supports_all_types()
+            .with_identity("miden::basic-wallet", wallet_version);
+        let vesting = AccountComponent::new(vesting_library, vec![])
+            .unwrap()
+            .with_supports_all_types()
+            .with_requirement(vesting_requirement.parse::<ComponentRequirement>().unwrap());
+
+        (wallet, vesting)
+    }
+
+    /// A component whose requirement is not provided should fail to convert into code and
+    /// storage.
+    #[test]
+    fn test_account_missing_component_dependency() {
+        let (_, vesting) =
+            wallet_and_vesting_components(semver::Version::new(1, 0, 0), "miden::basic-wallet@^1");
+
+        let err = Account::initialize_from_components(
+            AccountType::RegularAccountUpdatableCode,
+            &[vesting.clone()],
+        )
+        .unwrap_err();
+        assert_matches!(
+            err,
+            AccountError::MissingComponentDependency { component_index, requirement }
+                if component_index == 0 && requirement.name() == "miden::basic-wallet"
+        );
+
+        let err =
+            AccountCode::from_components(&[vesting], AccountType::RegularAccountUpdatableCode)
+                .unwrap_err();
+        assert_matches!(err, AccountError::MissingComponentDependency { .. });
+    }
+
+    /// A component whose requirement is provided should convert into code and storage regardless
+    /// of the order of the components.
+    #[test]
+    fn test_account_satisfied_component_dependency() {
+        let (wallet, vesting) =
+            wallet_and_vesting_components(semver::Version::new(1, 4, 2), "miden::basic-wallet@^1");
+
+        Account::initialize_from_components(
+            AccountType::RegularAccountUpdatableCode,
+            &[wallet.clone(), vesting.clone()],
+        )
+        .unwrap();
+        Account::initialize_from_components(
+            AccountType::RegularAccountUpdatableCode,
+            &[vesting.clone(), wallet.clone()],
+        )
+        .unwrap();
+        AccountCode::from_components(&[vesting, wallet], AccountType::RegularAccountUpdatableCode)
+            .unwrap();
+    }
+
+    /// A component whose requirement is provided in a version outside of the required range should
+    /// fail to convert into code and storage.
+    #[test]
+    fn test_account_component_dependency_version_mismatch() {
+        let (wallet, vesting) =
+            wallet_and_vesting_components(semver::Version::new(0, 9, 0), "miden::basic-wallet@^1");
+
+        let err = Account::initialize_from_components(
+            AccountType::RegularAccountUpdatableCode,
+            &[
+                wallet.with_identity("miden::basic-wallet", semver::Version::new(0, 9, 0)),
+                vesting,
+            ],
+        )
+        .unwrap_err();
+        assert_matches!(
+            err,
+            AccountError::MissingComponentDependency { component_index, requirement }
+                if component_index == 1 && requirement.to_string() == "miden::basic-wallet@^1"
+        );
+    }
 }
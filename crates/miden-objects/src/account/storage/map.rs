@@ -75,10 +75,28 @@ impl StorageMap {
         self.map.get_value(key) // Delegate to Smt's get_value method
     }
 
+    /// Returns an opening of the leaf associated with `key`.
+    ///
+    /// The opening proves either the value of `key` in this map or, if the map does not contain
+    /// `key`, its absence. It can be verified against the root of this map using
+    /// [Self::verify_opening].
     pub fn open(&self, key: &RpoDigest) -> SmtProof {
         self.map.open(key) // Delegate to Smt's open method
     }
 
+    // PROOF VERIFIER
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value of `key` proven by `proof` for a storage map with the provided `root`.
+    ///
+    /// If the proof shows that `key` is not contained in the map, [Self::EMPTY_VALUE] is returned.
+    /// Returns `None` if `proof` is not an opening of the leaf associated with `key`, or if it
+    /// does not resolve to `root`.
+    pub fn verify_opening(root: Digest, key: &RpoDigest, proof: &SmtProof) -> Option<Word> {
+        let value = proof.get(key)?;
+        proof.verify_membership(key, &value, &root).then_some(value)
+    }
+
     // ITERATORS
     // --------------------------------------------------------------------------------------------
     pub fn leaves(&self) -> impl Iterator<Item = (LeafIndex<SMT_DEPTH>, &SmtLeaf)> {
@@ -166,6 +184,50 @@ mod tests {
         assert_eq!(storage_map, StorageMap::read_from_bytes(&bytes).unwrap());
     }
 
+    #[test]
+    fn storage_map_opening_of_present_key() {
+        let key = RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]);
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let storage_map = StorageMap::with_entries([
+            (key, value),
+            (
+                RpoDigest::new([Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)]),
+                [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)],
+            ),
+        ]);
+
+        let proof = storage_map.open(&key);
+        assert_eq!(StorageMap::verify_opening(storage_map.root(), &key, &proof), Some(value));
+
+        // the proof does not resolve to a different root
+        assert_eq!(StorageMap::verify_opening(EMPTY_STORAGE_MAP_ROOT, &key, &proof), None);
+
+        // the proof is not an opening of a key associated with a different leaf
+        let other_key = RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        assert_eq!(StorageMap::verify_opening(storage_map.root(), &other_key, &proof), None);
+    }
+
+    #[test]
+    fn storage_map_opening_of_absent_key() {
+        let storage_map = StorageMap::with_entries([(
+            RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]),
+            [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        )]);
+
+        // a key associated with the same leaf as the present key, and a key associated with an
+        // empty leaf
+        for absent_key in [
+            RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(104)]),
+            RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        ] {
+            let proof = storage_map.open(&absent_key);
+            assert_eq!(
+                StorageMap::verify_opening(storage_map.root(), &absent_key, &proof),
+                Some(StorageMap::EMPTY_VALUE)
+            );
+        }
+    }
+
     #[test]
     fn test_empty_storage_map_constants() {
         // If these values don't match, update the constants.
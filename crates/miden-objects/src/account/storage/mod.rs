@@ -1,5 +1,7 @@
 use alloc::{string::ToString, vec::Vec};
 
+use miden_crypto::merkle::SmtProof;
+
 use super::{
     AccountError, AccountStorageDelta, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Digest, Felt, Hasher, Serializable, Word,
@@ -15,6 +17,9 @@ pub use map::StorageMap;
 mod header;
 pub use header::{AccountStorageHeader, StorageSlotHeader};
 
+mod slot_proof;
+pub use slot_proof::StorageSlotProof;
+
 // ACCOUNT STORAGE
 // ================================================================================================
 
@@ -27,7 +32,9 @@ pub use header::{AccountStorageHeader, StorageSlotHeader};
 /// - [StorageSlot::Map]: contains a [StorageMap] which is a key-value map where both keys and
 ///   values are [Word]s. The value of a storage slot containing a map is the commitment to the
 ///   underlying map.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Two storages are equal if their commitments are equal.
+#[derive(Debug, Clone)]
 pub struct AccountStorage {
     slots: Vec<StorageSlot>,
 }
@@ -137,6 +144,32 @@ impl AccountStorage {
         }
     }
 
+    /// Returns a map item from a map located in storage at the specified index together with an
+    /// opening of the item against the root of the map.
+    ///
+    /// If the map does not contain `key`, [StorageMap::EMPTY_VALUE] is returned together with a
+    /// proof of its absence. The proof can be verified via [StorageMap::verify_opening].
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    pub fn get_map_item_with_proof(
+        &self,
+        index: u8,
+        key: Word,
+    ) -> Result<(Word, SmtProof), AccountError> {
+        match self.slots.get(index as usize).ok_or(AccountError::StorageIndexOutOfBounds {
+            slots_len: self.slots.len() as u8,
+            index,
+        })? {
+            StorageSlot::Map(ref map) => {
+                let key = Digest::from(key);
+                Ok((map.get_value(&key), map.open(&key)))
+            },
+            _ => Err(AccountError::StorageSlotNotMap(index)),
+        }
+    }
+
     /// Returns an [AccountStorageHeader] for this account storage.
     pub fn get_header(&self) -> AccountStorageHeader {
         AccountStorageHeader::new(
@@ -144,6 +177,25 @@ impl AccountStorage {
         )
     }
 
+    /// Returns a proof of the type and value of the slot at the specified index against the
+    /// commitment of this storage.
+    ///
+    /// For map slots, the proven value is the root of the map. Individual map items can be proven
+    /// by combining the returned proof with an opening created via [StorageMap::open].
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    pub fn open(&self, index: u8) -> Result<StorageSlotProof, AccountError> {
+        if index as usize >= self.slots.len() {
+            return Err(AccountError::StorageIndexOutOfBounds {
+                slots_len: self.slots.len() as u8,
+                index,
+            });
+        }
+
+        Ok(StorageSlotProof::new(self.get_header(), index))
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -247,6 +299,19 @@ impl AccountStorage {
     }
 }
 
+// EQUALITY
+// ================================================================================================
+
+impl PartialEq for AccountStorage {
+    /// Returns true if both storages have the same commitment, i.e., the same slot types and
+    /// values.
+    fn eq(&self, other: &Self) -> bool {
+        self.commitment() == other.commitment()
+    }
+}
+
+impl Eq for AccountStorage {}
+
 // ITERATORS
 // ================================================================================================
 
@@ -312,10 +377,16 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
+
     use super::{
-        build_slots_commitment, AccountStorage, Deserializable, Serializable, StorageMap, Word,
+        build_slots_commitment, AccountError, AccountStorage, Deserializable, Digest, Felt,
+        Serializable, StorageMap, Word,
+    };
+    use crate::{
+        account::StorageSlot,
+        testing::storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2},
     };
-    use crate::account::StorageSlot;
 
     #[test]
     fn test_serde_account_storage() {
@@ -334,6 +405,34 @@ mod tests {
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
 
+    #[test]
+    fn get_map_item_with_proof() {
+        let storage = AccountStorage::mock();
+        let map_root: Digest = storage.get_item(STORAGE_INDEX_2).unwrap();
+
+        for (key, expected_value) in STORAGE_LEAVES_2 {
+            let (value, proof) =
+                storage.get_map_item_with_proof(STORAGE_INDEX_2, key.into()).unwrap();
+            assert_eq!(value, expected_value);
+            assert_eq!(StorageMap::verify_opening(map_root, &key, &proof), Some(value));
+            assert!(proof.verify_membership(&key, &value, &map_root));
+        }
+
+        // a key which is not contained in the map is proven to be absent
+        let absent_key = [Felt::new(9); 4];
+        let (value, proof) = storage.get_map_item_with_proof(STORAGE_INDEX_2, absent_key).unwrap();
+        assert_eq!(value, StorageMap::EMPTY_VALUE);
+        assert_eq!(
+            StorageMap::verify_opening(map_root, &absent_key.into(), &proof),
+            Some(StorageMap::EMPTY_VALUE)
+        );
+
+        assert_matches!(
+            storage.get_map_item_with_proof(STORAGE_INDEX_0, absent_key),
+            Err(AccountError::StorageSlotNotMap(STORAGE_INDEX_0))
+        );
+    }
+
     #[test]
     fn test_account_storage_slots_commitment() {
         let storage = AccountStorage::mock();
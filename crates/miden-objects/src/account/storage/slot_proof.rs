@@ -0,0 +1,210 @@
+use alloc::format;
+
+use miden_crypto::merkle::SmtProof;
+
+use super::{
+    AccountStorageHeader, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Hasher, Serializable, StorageMap, StorageSlotType, Word,
+};
+
+// STORAGE SLOT PROOF
+// ================================================================================================
+
+/// A proof that the storage slot at a given index has a given type and value in an account
+/// storage with a given commitment.
+///
+/// The commitment to an account's storage is a sequential hash of the headers of all of its
+/// slots. Thus, the proof consists of the [AccountStorageHeader] of the storage and the index of
+/// the proven slot, but not of the contents of storage maps. For a map slot, the proven value is
+/// the root of the map, against which openings of individual keys created via [StorageMap::open]
+/// can be verified with [StorageSlotProof::verify_map_item].
+///
+/// Proofs are created via [`AccountStorage::open`](super::AccountStorage::open).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotProof {
+    header: AccountStorageHeader,
+    index: u8,
+}
+
+impl StorageSlotProof {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new proof for the slot at `index` of the storage with the provided header.
+    ///
+    /// The caller must ensure that `index` is smaller than the number of slots in the header.
+    pub(super) fn new(header: AccountStorageHeader, index: u8) -> Self {
+        debug_assert!((index as usize) < header.num_slots(), "slot index out of bounds");
+
+        Self { header, index }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the proven slot.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the type of the proven slot.
+    pub fn slot_type(&self) -> StorageSlotType {
+        self.slot().0
+    }
+
+    /// Returns the value of the proven slot, which is the root of the map for map slots.
+    pub fn value(&self) -> Word {
+        self.slot().1
+    }
+
+    /// Returns the commitment to the account storage this proof was opened against.
+    pub fn storage_commitment(&self) -> Digest {
+        Hasher::hash_elements(&self.header.as_elements())
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `true` if this proof shows that the proven slot is part of the account storage with
+    /// the provided commitment, `false` otherwise.
+    pub fn verify(&self, commitment: Digest) -> bool {
+        self.storage_commitment() == commitment
+    }
+
+    /// Returns the value of `key` in the map of the proven slot, as proven by the provided opening
+    /// of the map.
+    ///
+    /// Returns `None` if this proof does not verify against the provided storage commitment, if
+    /// the proven slot is not a map slot, or if `map_proof` is not a valid opening of `key` against
+    /// the root of the map. See [StorageMap::verify_opening] for details.
+    pub fn verify_map_item(
+        &self,
+        commitment: Digest,
+        key: &Digest,
+        map_proof: &SmtProof,
+    ) -> Option<Word> {
+        if !self.verify(commitment) || self.slot_type() != StorageSlotType::Map {
+            return None;
+        }
+
+        StorageMap::verify_opening(self.value().into(), key, map_proof)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn slot(&self) -> &(StorageSlotType, Word) {
+        self.header
+            .slot(self.index as usize)
+            .expect("slot index should be smaller than the number of slots")
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageSlotProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.header.write_into(target);
+        target.write_u8(self.index);
+    }
+}
+
+impl Deserializable for StorageSlotProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = AccountStorageHeader::read_from(source)?;
+        let index = source.read_u8()?;
+
+        if index as usize >= header.num_slots() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "slot index {index} is out of bounds for {} slots",
+                header.num_slots()
+            )));
+        }
+
+        Ok(Self::new(header, index))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use vm_core::Felt;
+
+    use super::{Deserializable, Digest, Serializable, StorageSlotProof, StorageSlotType, Word};
+    use crate::{
+        account::{AccountStorage, StorageMap},
+        testing::storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2, STORAGE_VALUE_0},
+        AccountError,
+    };
+
+    #[test]
+    fn storage_slot_proof_of_value_slot() {
+        let storage = AccountStorage::mock();
+
+        let proof = storage.open(STORAGE_INDEX_0).unwrap();
+        assert_eq!(proof.index(), STORAGE_INDEX_0);
+        assert_eq!(proof.slot_type(), StorageSlotType::Value);
+        assert_eq!(proof.value(), STORAGE_VALUE_0);
+        assert!(proof.verify(storage.commitment()));
+        assert!(!proof.verify(Digest::default()));
+
+        // a value slot has no map items
+        let key = STORAGE_LEAVES_2[0].0;
+        let map_proof = AccountStorage::mock_map().open(&key);
+        assert_eq!(proof.verify_map_item(storage.commitment(), &key, &map_proof), None);
+
+        let deserialized = StorageSlotProof::read_from_bytes(&proof.to_bytes()).unwrap();
+        assert_eq!(deserialized, proof);
+    }
+
+    #[test]
+    fn storage_slot_proof_of_map_slot() {
+        let storage = AccountStorage::mock();
+        let map = AccountStorage::mock_map();
+
+        let proof = storage.open(STORAGE_INDEX_2).unwrap();
+        assert_eq!(proof.slot_type(), StorageSlotType::Map);
+        assert_eq!(proof.value(), Word::from(map.root()));
+        assert!(proof.verify(storage.commitment()));
+
+        // a key which exists in the map
+        let (key, value) = STORAGE_LEAVES_2[0];
+        let map_proof = map.open(&key);
+        assert_eq!(proof.verify_map_item(storage.commitment(), &key, &map_proof), Some(value));
+        assert_eq!(proof.verify_map_item(Digest::default(), &key, &map_proof), None);
+
+        // a key which does not exist in the map
+        let absent_key = Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let map_proof = map.open(&absent_key);
+        assert_eq!(
+            proof.verify_map_item(storage.commitment(), &absent_key, &map_proof),
+            Some(StorageMap::EMPTY_VALUE)
+        );
+
+        // the opening of the absent key does not prove the value of another key
+        assert_eq!(proof.verify_map_item(storage.commitment(), &key, &map_proof), None);
+
+        let deserialized = StorageSlotProof::read_from_bytes(&proof.to_bytes()).unwrap();
+        assert_eq!(deserialized, proof);
+    }
+
+    #[test]
+    fn storage_slot_proof_with_invalid_index() {
+        let storage = AccountStorage::mock();
+        let num_slots = storage.slots().len() as u8;
+
+        assert_matches!(
+            storage.open(num_slots),
+            Err(AccountError::StorageIndexOutOfBounds { slots_len, index })
+                if slots_len == num_slots && index == num_slots
+        );
+
+        let mut bytes = storage.open(0).unwrap().to_bytes();
+        *bytes.last_mut().unwrap() = num_slots;
+        assert!(StorageSlotProof::read_from_bytes(&bytes).is_err());
+    }
+}
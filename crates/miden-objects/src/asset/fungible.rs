@@ -84,12 +84,39 @@ impl FungibleAsset {
 
     /// Adds two fungible assets together and returns the result.
     ///
+    /// This is equivalent to [Self::checked_add].
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The assets were not issued by the same faucet.
     /// - The total value of assets is greater than or equal to 2^63.
     #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: Self) -> Result<Self, AssetError> {
+        self.checked_add(&other)
+    }
+
+    /// Subtracts the specified amount from this asset and returns the resulting asset.
+    ///
+    /// # Errors
+    /// Returns an error if this asset's amount is smaller than the requested amount.
+    pub fn sub(&mut self, amount: u64) -> Result<Self, AssetError> {
+        self.amount = self.amount.checked_sub(amount).ok_or(
+            AssetError::FungibleAssetAmountNotSufficient {
+                minuend: self.amount,
+                subtrahend: amount,
+            },
+        )?;
+
+        Ok(FungibleAsset { faucet_id: self.faucet_id, amount })
+    }
+
+    /// Returns a new asset whose amount is the sum of the amounts of this and the other asset.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The assets were not issued by the same faucet.
+    /// - The resulting amount is greater than [Self::MAX_AMOUNT].
+    pub fn checked_add(&self, other: &FungibleAsset) -> Result<FungibleAsset, AssetError> {
         if self.faucet_id != other.faucet_id {
             return Err(AssetError::FungibleAssetInconsistentFaucetIds {
                 original_issuer: self.faucet_id,
@@ -108,19 +135,31 @@ impl FungibleAsset {
         Ok(Self { faucet_id: self.faucet_id, amount })
     }
 
-    /// Subtracts the specified amount from this asset and returns the resulting asset.
+    /// Returns a new asset whose amount is the amount of this asset minus the amount of the other
+    /// asset.
+    ///
+    /// Unlike [Self::sub], this leaves both operands untouched.
     ///
     /// # Errors
-    /// Returns an error if this asset's amount is smaller than the requested amount.
-    pub fn sub(&mut self, amount: u64) -> Result<Self, AssetError> {
-        self.amount = self.amount.checked_sub(amount).ok_or(
-            AssetError::FungibleAssetAmountNotSufficient {
+    /// Returns an error if:
+    /// - The assets were not issued by the same faucet.
+    /// - The amount of the other asset is greater than the amount of this asset.
+    pub fn checked_sub(&self, other: &FungibleAsset) -> Result<FungibleAsset, AssetError> {
+        if self.faucet_id != other.faucet_id {
+            return Err(AssetError::FungibleAssetInconsistentFaucetIds {
+                original_issuer: self.faucet_id,
+                other_issuer: other.faucet_id,
+            });
+        }
+
+        let amount = self.amount.checked_sub(other.amount).ok_or(
+            AssetError::FungibleAssetAmountUnderflow {
                 minuend: self.amount,
-                subtrahend: amount,
+                subtrahend: other.amount,
             },
         )?;
 
-        Ok(FungibleAsset { faucet_id: self.faucet_id, amount })
+        Ok(Self { faucet_id: self.faucet_id, amount })
     }
 
     // HELPER FUNCTIONS
@@ -284,4 +323,66 @@ mod tests {
         let err = FungibleAsset::read_from_bytes(&asset_bytes).unwrap_err();
         assert!(matches!(err, DeserializationError::InvalidValue(_)));
     }
+
+    #[test]
+    fn fungible_asset_checked_add() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset_1 = FungibleAsset::new(faucet_id, 10).unwrap();
+        let asset_2 = FungibleAsset::new(faucet_id, 32).unwrap();
+
+        assert_eq!(
+            asset_1.checked_add(&asset_2).unwrap(),
+            FungibleAsset::new(faucet_id, 42).unwrap()
+        );
+        // the operands are left untouched
+        assert_eq!(asset_1.amount(), 10);
+        assert_eq!(asset_2.amount(), 32);
+
+        let max_asset = FungibleAsset::new(faucet_id, FungibleAsset::MAX_AMOUNT).unwrap();
+        let zero_asset = FungibleAsset::new(faucet_id, 0).unwrap();
+        let one_asset = FungibleAsset::new(faucet_id, 1).unwrap();
+        assert_eq!(max_asset.checked_add(&zero_asset).unwrap(), max_asset);
+        assert!(matches!(
+            max_asset.checked_add(&one_asset),
+            Err(AssetError::FungibleAssetAmountTooBig(amount)) if amount == FungibleAsset::MAX_AMOUNT + 1
+        ));
+        assert!(matches!(
+            max_asset.checked_add(&max_asset),
+            Err(AssetError::FungibleAssetAmountTooBig(_))
+        ));
+    }
+
+    #[test]
+    fn fungible_asset_checked_sub() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset_1 = FungibleAsset::new(faucet_id, 42).unwrap();
+        let asset_2 = FungibleAsset::new(faucet_id, 10).unwrap();
+
+        assert_eq!(
+            asset_1.checked_sub(&asset_2).unwrap(),
+            FungibleAsset::new(faucet_id, 32).unwrap()
+        );
+        assert_eq!(asset_1.checked_sub(&asset_1).unwrap().amount(), 0);
+        assert!(matches!(
+            asset_2.checked_sub(&asset_1),
+            Err(AssetError::FungibleAssetAmountUnderflow { minuend: 10, subtrahend: 42 })
+        ));
+    }
+
+    #[test]
+    fn fungible_asset_checked_arithmetic_rejects_other_faucet() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 10).unwrap();
+        let other_asset = FungibleAsset::new(other_faucet_id, 10).unwrap();
+
+        assert!(matches!(
+            asset.checked_add(&other_asset),
+            Err(AssetError::FungibleAssetInconsistentFaucetIds { .. })
+        ));
+        assert!(matches!(
+            asset.checked_sub(&other_asset),
+            Err(AssetError::FungibleAssetInconsistentFaucetIds { .. })
+        ));
+    }
 }
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use super::{
     account::AccountType,
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
@@ -152,6 +154,77 @@ impl Asset {
             Asset::NonFungible(asset) => *asset,
         }
     }
+
+    /// Returns the fixed-size 32-byte encoding of this asset.
+    ///
+    /// Unlike the [Serializable] encoding, whose size depends on the type of the asset, this
+    /// encoding always consists of 32 bytes, which makes it suitable e.g. as a database key. It
+    /// consists of the elements of the asset's word in reverse order, each encoded as 8
+    /// little-endian bytes:
+    /// - Fungible assets: `[faucet_id_prefix, faucet_id_suffix, 0, amount]`.
+    /// - Non-fungible assets: `[faucet_id_prefix, hash2, hash1, hash0]`.
+    ///
+    /// The first byte is thus the least significant byte of the faucet ID prefix, which holds the
+    /// metadata of the faucet ID. It serves as the discriminant of the two variants, since the
+    /// account type encoded in it is [`AccountType::FungibleFaucet`] for fungible assets and
+    /// [`AccountType::NonFungibleFaucet`] for non-fungible assets. A non-fungible asset occupies
+    /// all 32 bytes, so there is no room for a discriminant byte in addition to the metadata.
+    pub fn to_bytes32(&self) -> [u8; 32] {
+        let word = Word::from(self);
+
+        let mut bytes = [0; 32];
+        for (chunk, element) in bytes.chunks_exact_mut(8).zip(word.iter().rev()) {
+            chunk.copy_from_slice(&element.as_int().to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// Returns the asset encoded in the provided 32 bytes, as returned by [`Self::to_bytes32`].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the four 8-byte chunks is not a valid field element.
+    /// - The first chunk is not a valid faucet ID prefix.
+    /// - The bytes do not encode a valid fungible or non-fungible asset.
+    pub fn from_bytes32(bytes: [u8; 32]) -> Result<Self, AssetError> {
+        let mut word = [ZERO; 4];
+        for (element, chunk) in word.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+            let value = u64::from_le_bytes(chunk.try_into().expect("chunk should have 8 bytes"));
+            *element = Felt::try_from(value).map_err(|_| AssetError::InvalidFieldElement(value))?;
+        }
+
+        // make sure the word contains a valid faucet ID prefix before determining the asset type
+        AccountIdPrefix::try_from(word[3])
+            .map_err(|err| AssetError::InvalidFaucetAccountId(Box::new(err)))?;
+
+        Asset::try_from(word)
+    }
+}
+
+impl PartialOrd for Asset {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Asset {
+    /// Compares two assets according to the canonical ordering of assets.
+    ///
+    /// Fungible assets are ordered before non-fungible assets. Fungible assets are ordered by their
+    /// faucet ID and then by their amount, while non-fungible assets are ordered by their
+    /// [`NonFungibleAsset`] ordering.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        use core::cmp::Ordering;
+        match (self, other) {
+            (Asset::Fungible(l), Asset::Fungible(r)) => {
+                l.faucet_id().cmp(&r.faucet_id()).then_with(|| l.amount().cmp(&r.amount()))
+            },
+            (Asset::NonFungible(l), Asset::NonFungible(r)) => l.cmp(r),
+            (Asset::Fungible(_), Asset::NonFungible(_)) => Ordering::Less,
+            (Asset::NonFungible(_), Asset::Fungible(_)) => Ordering::Greater,
+        }
+    }
 }
 
 impl From<Asset> for Word {
@@ -257,12 +330,13 @@ fn is_not_a_non_fungible_asset(asset: Word) -> bool {
 #[cfg(test)]
 mod tests {
 
+    use assert_matches::assert_matches;
     use miden_crypto::{
         utils::{Deserializable, Serializable},
         Word,
     };
 
-    use super::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+    use super::{Asset, AssetError, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
     use crate::{
         account::{AccountId, AccountIdPrefix},
         testing::account_id::{
@@ -328,6 +402,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_asset_bytes32_roundtrip() {
+        let fungible_asset = FungibleAsset::mock(300);
+        let non_fungible_asset = NonFungibleAsset::mock(&[0xaa, 0xbb]);
+
+        for asset in [fungible_asset, non_fungible_asset] {
+            let bytes = asset.to_bytes32();
+            assert_eq!(Asset::from_bytes32(bytes).unwrap(), asset);
+
+            // the first 8 bytes encode the faucet ID prefix
+            assert_eq!(bytes[..8], asset.faucet_id_prefix().as_u64().to_le_bytes());
+        }
+
+        // the first byte distinguishes the variants
+        assert_ne!(fungible_asset.to_bytes32()[0], non_fungible_asset.to_bytes32()[0]);
+
+        // the remaining bytes of a fungible asset are its faucet ID suffix, zero and its amount
+        let bytes = fungible_asset.to_bytes32();
+        assert_eq!(bytes[16..24], [0; 8]);
+        assert_eq!(bytes[24..], 300u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_asset_from_invalid_bytes32() {
+        let mut bytes = FungibleAsset::mock(300).to_bytes32();
+
+        // the amount is not a valid field element
+        bytes[24..].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_matches!(Asset::from_bytes32(bytes), Err(AssetError::InvalidFieldElement(u64::MAX)));
+
+        // the first element is not a valid faucet ID prefix due to an unknown storage mode
+        let mut bytes = [0; 32];
+        bytes[0] = 0b0100_0000;
+        assert_matches!(Asset::from_bytes32(bytes), Err(AssetError::InvalidFaucetAccountId(_)));
+
+        // the first element is a valid prefix, but not the prefix of a faucet ID
+        assert_matches!(
+            Asset::from_bytes32([0; 32]),
+            Err(AssetError::NonFungibleFaucetIdTypeMismatch(_))
+        );
+    }
+
     /// This test asserts that account ID's prefix is serialized in the first felt of assets.
     /// Asset deserialization relies on that fact and if this changes the serialization must
     /// be updated.
@@ -1,27 +1,65 @@
 use alloc::string::String;
 
 use super::{AssetError, Felt};
-
+use crate::errors::TokenSymbolError;
+
+/// The symbol of a fungible token, e.g. `ETH` or `USDT`.
+///
+/// A token symbol consists of 1 to [`TokenSymbol::MAX_SYMBOL_LENGTH`] ASCII letters and is
+/// encoded into a single [Felt] by interpreting the letters as digits in base 26, with `A` being
+/// the digit 0.
+///
+/// Symbols are normalized to uppercase, i.e. `usdt`, `Usdt` and `USDT` all result in the same
+/// token symbol. Since `A` is encoded as 0, leading `A`s do not change the encoded value, and the
+/// decoded symbol is always padded with leading `A`s to [`TokenSymbol::MAX_SYMBOL_LENGTH`]
+/// characters.
 #[derive(Clone, Copy, Debug)]
-pub struct TokenSymbol(Felt);
+pub struct TokenSymbol {
+    value: Felt,
+    /// The decoded symbol, which is kept so that it can be borrowed as a string.
+    symbol: [u8; 6],
+}
 
 impl TokenSymbol {
     pub const MAX_SYMBOL_LENGTH: usize = 6;
     pub const MAX_ENCODED_VALUE: u64 = 26u64.pow(TokenSymbol::MAX_SYMBOL_LENGTH as u32);
 
+    /// Returns a new [TokenSymbol] instantiated from the provided string.
+    ///
+    /// Lowercase ASCII letters are converted to uppercase.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The symbol is empty or longer than [`TokenSymbol::MAX_SYMBOL_LENGTH`] characters.
+    /// - The symbol contains a character which is not an ASCII letter.
     pub fn new(symbol: &str) -> Result<Self, AssetError> {
-        let felt = encode_symbol_to_felt(symbol)?;
-        Ok(Self(felt))
+        let felt = encode_symbol_to_felt(symbol).map_err(AssetError::TokenSymbolError)?;
+        Ok(Self::from_felt_unchecked(felt))
+    }
+
+    /// Returns the decoded symbol as a string slice.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.symbol).expect("token symbol should consist of ASCII letters")
     }
 
+    /// Returns the decoded symbol as a [String].
     pub fn to_str(&self) -> String {
-        decode_felt_to_symbol(self.0)
+        self.as_str().into()
+    }
+
+    /// Returns a new [TokenSymbol] from the provided encoded value without checking that it is
+    /// smaller than [`TokenSymbol::MAX_ENCODED_VALUE`].
+    fn from_felt_unchecked(value: Felt) -> Self {
+        Self {
+            value,
+            symbol: decode_felt_to_symbol(value),
+        }
     }
 }
 
 impl From<TokenSymbol> for Felt {
     fn from(symbol: TokenSymbol) -> Self {
-        symbol.0
+        symbol.value
     }
 }
 
@@ -39,13 +77,11 @@ impl TryFrom<Felt> for TokenSymbol {
     fn try_from(felt: Felt) -> Result<Self, Self::Error> {
         // Check if the felt value is within the valid range
         if felt.as_int() >= TokenSymbol::MAX_ENCODED_VALUE {
-            return Err(AssetError::TokenSymbolError(format!(
-                "token symbol value {} cannot exceed {}",
+            return Err(AssetError::TokenSymbolError(TokenSymbolError::ValueTooLarge(
                 felt.as_int(),
-                TokenSymbol::MAX_ENCODED_VALUE
             )));
         }
-        Ok(TokenSymbol(felt))
+        Ok(TokenSymbol::from_felt_unchecked(felt))
     }
 }
 
@@ -53,44 +89,38 @@ impl TryFrom<Felt> for TokenSymbol {
 // ================================================================================================
 // Utils to encode and decode the token symbol as a Felt. Token Symbols can consists of up to 6
 // characters , e.g., A = 0, ...
-fn encode_symbol_to_felt(s: &str) -> Result<Felt, AssetError> {
-    if s.is_empty() || s.len() > TokenSymbol::MAX_SYMBOL_LENGTH {
-        return Err(AssetError::TokenSymbolError(format!(
-            "token symbol of length {} is not between 1 and 6 characters long",
-            s.len()
-        )));
-    } else if s.chars().any(|c| !c.is_ascii_uppercase()) {
-        return Err(AssetError::TokenSymbolError(format!(
-            "token symbol {} contains characters that are not uppercase ASCII",
-            s
-        )));
+fn encode_symbol_to_felt(s: &str) -> Result<Felt, TokenSymbolError> {
+    let len = s.chars().count();
+    if len == 0 || len > TokenSymbol::MAX_SYMBOL_LENGTH {
+        return Err(TokenSymbolError::InvalidLength(len));
     }
 
     let mut encoded_value = 0;
-    for char in s.chars() {
-        let digit = char as u64 - b'A' as u64;
-        assert!(digit < 26);
+    for (position, char) in s.chars().enumerate() {
+        if !char.is_ascii_alphabetic() {
+            return Err(TokenSymbolError::InvalidCharacter { char, position });
+        }
+
+        let digit = char.to_ascii_uppercase() as u64 - b'A' as u64;
         encoded_value = encoded_value * 26 + digit;
     }
 
     Ok(Felt::new(encoded_value))
 }
 
-fn decode_felt_to_symbol(encoded_felt: Felt) -> String {
+fn decode_felt_to_symbol(encoded_felt: Felt) -> [u8; TokenSymbol::MAX_SYMBOL_LENGTH] {
     let encoded_value = encoded_felt.as_int();
-    assert!(encoded_value < 26u64.pow(TokenSymbol::MAX_SYMBOL_LENGTH as u32));
+    assert!(encoded_value < TokenSymbol::MAX_ENCODED_VALUE);
 
-    let mut decoded_string = String::new();
+    let mut decoded_symbol = [b'A'; TokenSymbol::MAX_SYMBOL_LENGTH];
     let mut remaining_value = encoded_value;
 
-    for _ in 0..6 {
-        let digit = (remaining_value % 26) as u8;
-        let char = (digit + b'A') as char;
-        decoded_string.insert(0, char);
+    for char in decoded_symbol.iter_mut().rev() {
+        *char = (remaining_value % 26) as u8 + b'A';
         remaining_value /= 26;
     }
 
-    decoded_string
+    decoded_symbol
 }
 
 // TESTS
@@ -122,3 +152,74 @@ fn test_token_symbol_decoding_encoding() {
     let token_symbol_felt: Felt = token_symbol.unwrap().into();
     assert_eq!(token_symbol_felt, encode_symbol_to_felt(symbol).unwrap());
 }
+
+#[test]
+fn test_token_symbol_normalization() {
+    for symbol in ["usdt", "Usdt", "uSdT"] {
+        let token_symbol = TokenSymbol::new(symbol).unwrap();
+        assert_eq!(Felt::from(token_symbol), Felt::from(TokenSymbol::new("USDT").unwrap()));
+        assert_eq!(token_symbol.as_str(), "AAUSDT");
+        assert_eq!(token_symbol.to_str(), token_symbol.as_str());
+    }
+
+    let token_symbol = TokenSymbol::new("zzzzzz").unwrap();
+    assert_eq!(token_symbol.as_str(), "ZZZZZZ");
+    assert_eq!(Felt::from(token_symbol).as_int(), TokenSymbol::MAX_ENCODED_VALUE - 1);
+}
+
+#[test]
+fn test_token_symbol_invalid_characters() {
+    use assert_matches::assert_matches;
+
+    for (symbol, invalid_char, invalid_position) in [
+        ("$$$", '$', 0),
+        ("US-D", '-', 2),
+        ("ETH1", '1', 3),
+        ("BTC ", ' ', 3),
+        ("ÄBC", 'Ä', 0),
+    ] {
+        assert_matches!(
+            TokenSymbol::new(symbol),
+            Err(AssetError::TokenSymbolError(TokenSymbolError::InvalidCharacter {
+                char,
+                position
+            })) if char == invalid_char && position == invalid_position
+        );
+    }
+}
+
+#[test]
+fn test_token_symbol_length_boundaries() {
+    use assert_matches::assert_matches;
+
+    assert_matches!(
+        TokenSymbol::new(""),
+        Err(AssetError::TokenSymbolError(TokenSymbolError::InvalidLength(0)))
+    );
+    assert_eq!(TokenSymbol::new("B").unwrap().as_str(), "AAAAAB");
+    assert_eq!(TokenSymbol::new("BCDEFG").unwrap().as_str(), "BCDEFG");
+    assert_matches!(
+        TokenSymbol::new("BCDEFGH"),
+        Err(AssetError::TokenSymbolError(TokenSymbolError::InvalidLength(7)))
+    );
+
+    // the length is counted in characters rather than bytes
+    assert_matches!(
+        TokenSymbol::new("ÄÄÄÄ"),
+        Err(AssetError::TokenSymbolError(TokenSymbolError::InvalidCharacter {
+            char: 'Ä',
+            ..
+        }))
+    );
+
+    assert_matches!(
+        TokenSymbol::try_from(Felt::new(TokenSymbol::MAX_ENCODED_VALUE)),
+        Err(AssetError::TokenSymbolError(TokenSymbolError::ValueTooLarge(_)))
+    );
+    assert_eq!(
+        TokenSymbol::try_from(Felt::new(TokenSymbol::MAX_ENCODED_VALUE - 1))
+            .unwrap()
+            .as_str(),
+        "ZZZZZZ"
+    );
+}
@@ -23,7 +23,9 @@ use crate::{
 ///   value of the node.
 ///
 /// An asset vault can be reduced to a single hash which is the root of the Sparse Merkle Tree.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Two vaults are equal if their commitments are equal, regardless of the order in which their
+/// assets were added.
+#[derive(Debug, Clone, Default)]
 pub struct AssetVault {
     asset_tree: Smt,
 }
@@ -51,11 +53,15 @@ impl AssetVault {
 
     /// Returns true if the specified non-fungible asset is stored in this vault.
     pub fn has_non_fungible_asset(&self, asset: NonFungibleAsset) -> Result<bool, AssetVaultError> {
-        // check if the asset is stored in the vault
-        match self.asset_tree.get_value(&asset.vault_key().into()) {
-            asset if asset == Smt::EMPTY_VALUE => Ok(false),
-            _ => Ok(true),
-        }
+        Ok(self.contains_non_fungible(&asset))
+    }
+
+    /// Returns true if the specified non-fungible asset is stored in this vault.
+    ///
+    /// The asset is looked up by its vault key in the underlying Sparse Merkle Tree, so the vault
+    /// is not scanned.
+    pub fn contains_non_fungible(&self, asset: &NonFungibleAsset) -> bool {
+        self.asset_tree.get_value(&asset.vault_key().into()) != Smt::EMPTY_VALUE
     }
 
     /// Returns the balance of the asset issued by the specified faucet. If the vault does not
@@ -83,6 +89,22 @@ impl AssetVault {
         self.asset_tree.entries().map(|x| Asset::new_unchecked(x.1))
     }
 
+    /// Returns an iterator over the fungible assets stored in the vault.
+    pub fn fungible_assets(&self) -> impl Iterator<Item = FungibleAsset> + '_ {
+        self.assets().filter_map(|asset| match asset {
+            Asset::Fungible(asset) => Some(asset),
+            Asset::NonFungible(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the non-fungible assets stored in the vault.
+    pub fn non_fungible_assets(&self) -> impl Iterator<Item = NonFungibleAsset> + '_ {
+        self.assets().filter_map(|asset| match asset {
+            Asset::Fungible(_) => None,
+            Asset::NonFungible(asset) => Some(asset),
+        })
+    }
+
     /// Returns a reference to the Sparse Merkle Tree underling this asset vault.
     pub fn asset_tree(&self) -> &Smt {
         &self.asset_tree
@@ -93,11 +115,35 @@ impl AssetVault {
         self.asset_tree.is_empty()
     }
 
+    /// Returns a new [AssetVault] containing only the assets of this vault for which the provided
+    /// predicate returns `true`.
+    ///
+    /// The commitment of the returned vault is recomputed from the retained assets, while this
+    /// vault is left unchanged.
+    pub fn retain(&self, pred: impl Fn(&Asset) -> bool) -> AssetVault {
+        let asset_tree = Smt::with_entries(
+            self.asset_tree
+                .entries()
+                .filter(|(_, value)| pred(&Asset::new_unchecked(*value)))
+                .copied(),
+        )
+        .expect("entries of an existing vault should be unique");
+
+        Self { asset_tree }
+    }
+
     // PUBLIC MODIFIERS
     // --------------------------------------------------------------------------------------------
 
     /// Applies the specified delta to the asset vault.
     ///
+    /// Fungible asset deltas are merged with the amounts stored in the vault, and non-fungible
+    /// assets are added or removed according to their delta action. This is the same logic used
+    /// by [`Account::apply_delta`](crate::account::Account::apply_delta).
+    ///
+    /// Applying a delta is atomic: if any of the changes cannot be applied, an error is returned
+    /// and the vault is left unchanged.
+    ///
     /// # Errors
     /// Returns an error:
     /// - If the total value of assets is greater than or equal to 2^63.
@@ -106,20 +152,26 @@ impl AssetVault {
     /// - If the delta contains a non-fungible asset removal that is not stored in the vault.
     /// - If the delta contains a non-fungible asset addition that is already stored in the vault.
     pub fn apply_delta(&mut self, delta: &AccountVaultDelta) -> Result<(), AssetVaultError> {
+        // validate all changes before applying any of them, so that a failing change does not
+        // leave the vault partially updated
+        self.validate_delta(delta)?;
+
         for (&faucet_id, &delta) in delta.fungible().iter() {
             let asset = FungibleAsset::new(faucet_id, delta.unsigned_abs())
                 .expect("Not a fungible faucet ID or delta is too large");
             match delta >= 0 {
                 true => self.add_fungible_asset(asset),
                 false => self.remove_fungible_asset(asset),
-            }?;
+            }
+            .expect("fungible asset delta should have been validated");
         }
 
         for (&asset, &action) in delta.non_fungible().iter() {
             match action {
                 NonFungibleDeltaAction::Add => self.add_non_fungible_asset(asset),
                 NonFungibleDeltaAction::Remove => self.remove_non_fungible_asset(asset),
-            }?;
+            }
+            .expect("non-fungible asset delta should have been validated");
         }
 
         Ok(())
@@ -129,37 +181,44 @@ impl AssetVault {
     // --------------------------------------------------------------------------------------------
     /// Add the specified asset to the vault.
     ///
+    /// For fungible assets, the returned asset holds the new total balance of the faucet. For
+    /// non-fungible assets, the added asset is returned.
+    ///
     /// # Errors
     /// - If the total value of two fungible assets is greater than or equal to 2^63.
     /// - If the vault already contains the same non-fungible asset.
     pub fn add_asset(&mut self, asset: Asset) -> Result<Asset, AssetVaultError> {
         Ok(match asset {
-            Asset::Fungible(asset) => Asset::Fungible(self.add_fungible_asset(asset)?),
+            Asset::Fungible(asset) => {
+                let balance = self.add_fungible_asset(asset)?;
+                Asset::Fungible(
+                    FungibleAsset::new(asset.faucet_id(), balance)
+                        .expect("balance of a valid fungible asset should be valid"),
+                )
+            },
             Asset::NonFungible(asset) => Asset::NonFungible(self.add_non_fungible_asset(asset)?),
         })
     }
 
-    /// Add the specified fungible asset to the vault. If the vault already contains an asset
-    /// issued by the same faucet, the amounts are added together.
+    /// Add the specified fungible asset to the vault and returns the new balance of the asset
+    /// issued by the same faucet. If the vault already contains an asset issued by the same
+    /// faucet, the amounts are added together via [FungibleAsset::checked_add].
     ///
     /// # Errors
-    /// - If the total value of assets is greater than or equal to 2^63.
-    fn add_fungible_asset(
-        &mut self,
-        asset: FungibleAsset,
-    ) -> Result<FungibleAsset, AssetVaultError> {
+    /// - If the new balance is greater than [FungibleAsset::MAX_AMOUNT]. In this case, the vault is
+    ///   left unchanged.
+    pub fn add_fungible_asset(&mut self, asset: FungibleAsset) -> Result<u64, AssetVaultError> {
         // fetch current asset value from the tree and add the new asset to it.
         let new: FungibleAsset = match self.asset_tree.get_value(&asset.vault_key().into()) {
             current if current == Smt::EMPTY_VALUE => asset,
-            current => {
-                let current = FungibleAsset::new_unchecked(current);
-                current.add(asset).map_err(AssetVaultError::AddFungibleAssetBalanceError)?
-            },
+            current => FungibleAsset::new_unchecked(current)
+                .checked_add(&asset)
+                .map_err(AssetVaultError::FungibleAssetOverflow)?,
         };
         self.asset_tree.insert(new.vault_key().into(), new.into());
 
-        // return the new asset
-        Ok(new)
+        // return the new balance
+        Ok(new.amount())
     }
 
     /// Add the specified non-fungible asset to the vault.
@@ -183,7 +242,9 @@ impl AssetVault {
 
     // REMOVE ASSET
     // --------------------------------------------------------------------------------------------
-    /// Remove the specified asset from the vault.
+    /// Remove the specified asset from the vault and returns the removed asset.
+    ///
+    /// Use [Self::remove_fungible_asset] to obtain the remaining balance of a fungible asset.
     ///
     /// # Errors
     /// - The fungible asset is not found in the vault.
@@ -192,7 +253,7 @@ impl AssetVault {
     pub fn remove_asset(&mut self, asset: Asset) -> Result<Asset, AssetVaultError> {
         match asset {
             Asset::Fungible(asset) => {
-                let asset = self.remove_fungible_asset(asset)?;
+                self.remove_fungible_asset(asset)?;
                 Ok(Asset::Fungible(asset))
             },
             Asset::NonFungible(asset) => {
@@ -202,17 +263,18 @@ impl AssetVault {
         }
     }
 
-    /// Remove the specified fungible asset from the vault.
+    /// Remove the specified fungible asset from the vault and returns the remaining balance of the
+    /// asset issued by the same faucet. The amounts are subtracted via
+    /// [FungibleAsset::checked_sub].
     ///
     /// # Errors
     /// - The asset is not found in the vault.
     /// - The amount of the asset in the vault is less than the amount to be removed.
-    fn remove_fungible_asset(
-        &mut self,
-        asset: FungibleAsset,
-    ) -> Result<FungibleAsset, AssetVaultError> {
+    ///
+    /// In both cases, the vault is left unchanged.
+    pub fn remove_fungible_asset(&mut self, asset: FungibleAsset) -> Result<u64, AssetVaultError> {
         // fetch the asset from the vault.
-        let mut current = match self.asset_tree.get_value(&asset.vault_key().into()) {
+        let current = match self.asset_tree.get_value(&asset.vault_key().into()) {
             current if current == Smt::EMPTY_VALUE => {
                 return Err(AssetVaultError::FungibleAssetNotFound(asset))
             },
@@ -220,19 +282,21 @@ impl AssetVault {
         };
 
         // subtract the amount of the asset to be removed from the current amount.
-        current
-            .sub(asset.amount())
-            .map_err(AssetVaultError::SubtractFungibleAssetBalanceError)?;
+        let remaining =
+            current.checked_sub(&asset).map_err(|_| AssetVaultError::InsufficientBalance {
+                requested: asset.amount(),
+                available: current.amount(),
+            })?;
 
         // if the amount of the asset is zero, remove the asset from the vault.
-        let new = match current.amount() {
+        let new = match remaining.amount() {
             0 => Smt::EMPTY_VALUE,
-            _ => current.into(),
+            _ => remaining.into(),
         };
         self.asset_tree.insert(asset.vault_key().into(), new);
 
-        // return the asset that was removed.
-        Ok(asset)
+        // return the remaining balance
+        Ok(remaining.amount())
     }
 
     /// Remove the specified non-fungible asset from the vault.
@@ -254,8 +318,75 @@ impl AssetVault {
         // return the asset that was removed.
         Ok(asset)
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that all changes of the specified delta can be applied to the current contents of
+    /// the vault.
+    ///
+    /// Every asset is changed at most once by a delta, so the changes can be checked
+    /// independently of each other.
+    ///
+    /// # Errors
+    /// Returns the error which applying the first invalid change would return, see
+    /// [Self::apply_delta].
+    fn validate_delta(&self, delta: &AccountVaultDelta) -> Result<(), AssetVaultError> {
+        for (&faucet_id, &delta) in delta.fungible().iter() {
+            let asset = FungibleAsset::new(faucet_id, delta.unsigned_abs())
+                .expect("Not a fungible faucet ID or delta is too large");
+            let current = self.asset_tree.get_value(&asset.vault_key().into());
+
+            if delta >= 0 {
+                if current != Smt::EMPTY_VALUE {
+                    FungibleAsset::new_unchecked(current)
+                        .checked_add(&asset)
+                        .map_err(AssetVaultError::FungibleAssetOverflow)?;
+                }
+            } else {
+                if current == Smt::EMPTY_VALUE {
+                    return Err(AssetVaultError::FungibleAssetNotFound(asset));
+                }
+
+                let current = FungibleAsset::new_unchecked(current);
+                current.checked_sub(&asset).map_err(|_| AssetVaultError::InsufficientBalance {
+                    requested: asset.amount(),
+                    available: current.amount(),
+                })?;
+            }
+        }
+
+        for (&asset, &action) in delta.non_fungible().iter() {
+            let is_stored =
+                self.asset_tree.get_value(&asset.vault_key().into()) != Smt::EMPTY_VALUE;
+
+            match action {
+                NonFungibleDeltaAction::Add if is_stored => {
+                    return Err(AssetVaultError::DuplicateNonFungibleAsset(asset));
+                },
+                NonFungibleDeltaAction::Remove if !is_stored => {
+                    return Err(AssetVaultError::NonFungibleAssetNotFound(asset));
+                },
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
 }
 
+// EQUALITY
+// ================================================================================================
+
+impl PartialEq for AssetVault {
+    /// Returns true if both vaults have the same commitment, i.e., contain the same assets.
+    fn eq(&self, other: &Self) -> bool {
+        self.commitment() == other.commitment()
+    }
+}
+
+impl Eq for AssetVault {}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -290,3 +421,250 @@ impl Deserializable for AssetVault {
         Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::{AccountId, Asset, AssetVault, AssetVaultError, FungibleAsset, NonFungibleAsset};
+    use crate::{
+        account::AccountVaultDelta,
+        asset::NonFungibleAssetDetails,
+        testing::account_id::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        },
+    };
+
+    fn non_fungible_asset(faucet_id: u128, data: &[u8]) -> NonFungibleAsset {
+        let faucet_id = AccountId::try_from(faucet_id).unwrap().prefix();
+        let details = NonFungibleAssetDetails::new(faucet_id, data.to_vec()).unwrap();
+        NonFungibleAsset::new(&details).unwrap()
+    }
+
+    #[test]
+    fn retain_removes_assets_of_one_faucet() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let banned_faucet = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible_0: Asset = FungibleAsset::new(faucet_0, 100).unwrap().into();
+        let fungible_1: Asset = FungibleAsset::new(faucet_1, 200).unwrap().into();
+        let banned_nft_0 = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1, 2, 3]);
+        let banned_nft_1 = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[4, 5, 6]);
+        let other_nft = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1, &[1, 2, 3]);
+
+        let vault = AssetVault::new(&[
+            fungible_0,
+            fungible_1,
+            banned_nft_0.into(),
+            banned_nft_1.into(),
+            other_nft.into(),
+        ])
+        .unwrap();
+
+        let retained = vault.retain(|asset| asset.faucet_id_prefix() != banned_faucet.prefix());
+
+        assert_eq!(retained.get_balance(faucet_0).unwrap(), 100);
+        assert_eq!(retained.get_balance(faucet_1).unwrap(), 200);
+        assert!(!retained.has_non_fungible_asset(banned_nft_0).unwrap());
+        assert!(!retained.has_non_fungible_asset(banned_nft_1).unwrap());
+        assert!(retained.has_non_fungible_asset(other_nft).unwrap());
+        assert_eq!(
+            retained.commitment(),
+            AssetVault::new(&[fungible_0, fungible_1, other_nft.into()])
+                .unwrap()
+                .commitment()
+        );
+
+        // the original vault is left unchanged
+        assert_eq!(vault.assets().count(), 5);
+        assert!(vault.has_non_fungible_asset(banned_nft_0).unwrap());
+    }
+
+    #[test]
+    fn split_iterators_and_non_fungible_membership() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+
+        let fungible_0 = FungibleAsset::new(faucet_0, 100).unwrap();
+        let fungible_1 = FungibleAsset::new(faucet_1, 200).unwrap();
+        let nft_0 = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1, 2, 3]);
+        let nft_1 = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1, &[1, 2, 3]);
+        let missing_nft = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[4, 5, 6]);
+
+        let vault =
+            AssetVault::new(&[fungible_0.into(), nft_0.into(), fungible_1.into(), nft_1.into()])
+                .unwrap();
+
+        let mut fungible_assets: Vec<_> = vault.fungible_assets().collect();
+        fungible_assets.sort_by_key(FungibleAsset::amount);
+        assert_eq!(fungible_assets, [fungible_0, fungible_1]);
+
+        let non_fungible_assets: Vec<_> = vault.non_fungible_assets().collect();
+        assert_eq!(non_fungible_assets.len(), 2);
+        assert!(non_fungible_assets.contains(&nft_0));
+        assert!(non_fungible_assets.contains(&nft_1));
+
+        assert!(vault.contains_non_fungible(&nft_0));
+        assert!(vault.contains_non_fungible(&nft_1));
+        assert!(!vault.contains_non_fungible(&missing_nft));
+
+        // an empty vault yields no assets
+        let vault = AssetVault::default();
+        assert_eq!(vault.fungible_assets().count(), 0);
+        assert_eq!(vault.non_fungible_assets().count(), 0);
+        assert!(!vault.contains_non_fungible(&nft_0));
+    }
+
+    #[test]
+    fn retain_all_and_none() {
+        let vault = AssetVault::mock();
+
+        assert_eq!(vault.retain(|_| true), vault);
+        assert_eq!(vault.retain(|_| false), AssetVault::default());
+        assert_eq!(
+            vault.retain(Asset::is_fungible).commitment(),
+            AssetVault::new(&vault.assets().filter(Asset::is_fungible).collect::<Vec<_>>())
+                .unwrap()
+                .commitment()
+        );
+    }
+
+    #[test]
+    fn vault_equality_ignores_insertion_order() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+
+        let fungible_0: Asset = FungibleAsset::new(faucet_0, 100).unwrap().into();
+        let fungible_1: Asset = FungibleAsset::new(faucet_1, 200).unwrap().into();
+        let nft: Asset =
+            non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1, 2, 3]).into();
+
+        let mut vault_0 = AssetVault::default();
+        for asset in [fungible_0, fungible_1, nft] {
+            vault_0.add_asset(asset).unwrap();
+        }
+
+        let mut vault_1 = AssetVault::default();
+        for asset in [nft, fungible_1, fungible_0] {
+            vault_1.add_asset(asset).unwrap();
+        }
+
+        assert_eq!(vault_0, vault_1);
+        assert_eq!(vault_0, AssetVault::new(&[fungible_1, nft, fungible_0]).unwrap());
+
+        vault_1.remove_asset(nft).unwrap();
+        assert_ne!(vault_0, vault_1);
+    }
+
+    #[test]
+    fn add_fungible_asset_up_to_max_amount() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible = |amount| FungibleAsset::new(faucet_id, amount).unwrap();
+        let max_amount = FungibleAsset::MAX_AMOUNT;
+
+        for initial_amount in [2, 1000, max_amount / 2, max_amount - 1, max_amount] {
+            let mut vault = AssetVault::new(&[fungible(initial_amount).into()]).unwrap();
+
+            // adding up to the max amount succeeds and returns the new balance
+            assert_eq!(
+                vault.add_fungible_asset(fungible(max_amount - initial_amount)).unwrap(),
+                max_amount
+            );
+            assert_eq!(vault.get_balance(faucet_id).unwrap(), max_amount);
+
+            // adding a single unit beyond the max amount fails and leaves the vault unchanged
+            let original = vault.clone();
+            assert_matches!(
+                vault.add_fungible_asset(fungible(1)),
+                Err(AssetVaultError::FungibleAssetOverflow(_))
+            );
+            assert_matches!(
+                vault.add_asset(fungible(1).into()),
+                Err(AssetVaultError::FungibleAssetOverflow(_))
+            );
+            assert_eq!(vault, original);
+
+            // removing more than the balance fails and leaves the vault unchanged
+            let mut vault = AssetVault::new(&[fungible(initial_amount - 1).into()]).unwrap();
+            let original = vault.clone();
+            assert_matches!(
+                vault.remove_fungible_asset(fungible(initial_amount)),
+                Err(AssetVaultError::InsufficientBalance { requested, available })
+                    if requested == initial_amount && available == initial_amount - 1
+            );
+            assert_eq!(vault, original);
+        }
+    }
+
+    #[test]
+    fn remove_then_add_restores_vault() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let nft = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1, 2, 3]);
+        let vault =
+            AssetVault::new(&[FungibleAsset::new(faucet_id, 100).unwrap().into(), nft.into()])
+                .unwrap();
+
+        for amount in [1, 50, 99, 100] {
+            let asset = FungibleAsset::new(faucet_id, amount).unwrap();
+            let mut updated = vault.clone();
+
+            assert_eq!(updated.remove_fungible_asset(asset).unwrap(), 100 - amount);
+            assert_ne!(updated.commitment(), vault.commitment());
+
+            assert_eq!(updated.add_fungible_asset(asset).unwrap(), 100);
+            assert_eq!(updated.commitment(), vault.commitment());
+        }
+
+        // the same holds for the removal of the non-fungible asset
+        let mut updated = vault.clone();
+        assert_eq!(updated.remove_asset(nft.into()).unwrap(), Asset::from(nft));
+        assert_eq!(updated.add_asset(nft.into()).unwrap(), Asset::from(nft));
+        assert_eq!(updated.commitment(), vault.commitment());
+    }
+
+    #[test]
+    fn apply_delta_is_atomic() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible_0: Asset = FungibleAsset::new(faucet_0, 100).unwrap().into();
+        let nft_0 = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1, 2, 3]);
+        let nft_1 = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[4, 5, 6]);
+        let missing_nft = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1, &[7, 8, 9]);
+
+        let mut vault = AssetVault::new(&[fungible_0, nft_0.into()]).unwrap();
+        let original = vault.clone();
+
+        // fungible changes are applied before non-fungible ones, so the removal of the missing
+        // asset fails after the vault has already been partially updated
+        let delta = AccountVaultDelta::from_iters(
+            [FungibleAsset::new(faucet_0, 50).unwrap().into(), nft_1.into()],
+            [missing_nft.into()],
+        );
+        assert_matches!(
+            vault.apply_delta(&delta),
+            Err(AssetVaultError::NonFungibleAssetNotFound(asset)) if asset == missing_nft
+        );
+
+        assert_eq!(vault, original);
+        assert_eq!(vault.get_balance(faucet_0).unwrap(), 100);
+        assert!(!vault.contains_non_fungible(&nft_1));
+
+        // the same delta without the failing change is applied in full
+        let delta = AccountVaultDelta::from_iters(
+            [FungibleAsset::new(faucet_0, 50).unwrap().into(), nft_1.into()],
+            [nft_0.into()],
+        );
+        vault.apply_delta(&delta).unwrap();
+
+        assert_eq!(vault.get_balance(faucet_0).unwrap(), 150);
+        assert!(vault.contains_non_fungible(&nft_1));
+        assert!(!vault.contains_non_fungible(&nft_0));
+    }
+}
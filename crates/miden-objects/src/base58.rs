@@ -0,0 +1,117 @@
+//! Base58 encoding with a checksum, as used for compact, URL-safe identifiers such as
+//! [`AccountId`](crate::account::AccountId) compact strings.
+//!
+//! The alphabet is the one used by Bitcoin, which excludes the visually ambiguous characters `0`,
+//! `O`, `I` and `l`. The encoding itself is implemented by the [`bs58`](::bs58) crate. Unlike
+//! Bitcoin's base58check, the checksum is derived from an RPO hash of the data.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{errors::Base58Error, Hasher};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of checksum bytes appended to the data before encoding.
+const CHECKSUM_LENGTH: usize = 4;
+
+// ENCODING
+// ================================================================================================
+
+/// Encodes `data` followed by its checksum into a base58 string.
+pub(crate) fn encode_check(data: &[u8]) -> String {
+    let mut bytes = Vec::with_capacity(data.len() + CHECKSUM_LENGTH);
+    bytes.extend_from_slice(data);
+    bytes.extend_from_slice(&checksum(data));
+
+    bs58::encode(bytes).into_string()
+}
+
+// DECODING
+// ================================================================================================
+
+/// Decodes a base58 string created by [`encode_check`] and returns the data without the checksum.
+///
+/// # Errors
+/// Returns an error if:
+/// - the string contains characters outside of the base58 alphabet.
+/// - the decoded bytes are too short to contain a checksum.
+/// - the checksum does not match the data.
+pub(crate) fn decode_check(base58_string: &str) -> Result<Vec<u8>, Base58Error> {
+    let mut bytes = bs58::decode(base58_string).into_vec().map_err(|err| match err {
+        bs58::decode::Error::InvalidCharacter { character, .. } => {
+            Base58Error::InvalidCharacter(character)
+        },
+        bs58::decode::Error::NonAsciiCharacter { index } => Base58Error::InvalidCharacter(
+            base58_string[index..]
+                .chars()
+                .next()
+                .expect("index should point to a character"),
+        ),
+        err => unreachable!("decoding into a vector should not fail with {err}"),
+    })?;
+    if bytes.len() < CHECKSUM_LENGTH {
+        return Err(Base58Error::InvalidLength(bytes.len()));
+    }
+
+    let expected_checksum = bytes.split_off(bytes.len() - CHECKSUM_LENGTH);
+    if expected_checksum != checksum(&bytes) {
+        return Err(Base58Error::InvalidChecksum);
+    }
+
+    Ok(bytes)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the first bytes of the RPO hash of `data`, which are used as its checksum.
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let mut checksum = [0; CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&Hasher::hash(data).as_bytes()[..CHECKSUM_LENGTH]);
+
+    checksum
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    /// The alphabet used to encode base58 digits.
+    const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    #[test]
+    fn encode_decode_check_roundtrip() {
+        for data in [
+            &[][..],
+            &[0],
+            &[0, 0, 1],
+            &[1, 0, 0],
+            &[0xff; 15],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+        ] {
+            let encoded = encode_check(data);
+            assert!(encoded.bytes().all(|byte| ALPHABET.contains(&byte)));
+            assert_eq!(decode_check(&encoded).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn decode_check_invalid_strings() {
+        let encoded = encode_check(&[1, 2, 3]);
+
+        // changing any character invalidates the checksum
+        let mut tampered = String::from(&encoded[..encoded.len() - 1]);
+        tampered.push(if encoded.ends_with('2') { '3' } else { '2' });
+        assert_matches!(decode_check(&tampered), Err(Base58Error::InvalidChecksum));
+
+        assert_matches!(decode_check("0OIl"), Err(Base58Error::InvalidCharacter('0')));
+        assert_matches!(decode_check("2gé"), Err(Base58Error::InvalidCharacter('é')));
+        assert_matches!(decode_check("2g"), Err(Base58Error::InvalidLength(1)));
+    }
+}
@@ -0,0 +1,262 @@
+//! Encoding and decoding of [bech32m](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)
+//! strings, as used for human-readable identifiers such as
+//! [`AccountId`](crate::account::AccountId) addresses.
+//!
+//! The legacy [bech32](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki) checksum
+//! is supported as well for interoperability with systems which do not support bech32m yet.
+//!
+//! The encoding itself is implemented by the [`bech32`](::bech32) crate. This module restricts it
+//! to the rules of miden addresses, e.g. the maximum length of 90 characters and lowercase
+//! human-readable parts, and reports failures as [`Bech32Error`]s.
+
+use alloc::{string::String, vec::Vec};
+
+use ::bech32::{
+    primitives::decode::{CharError, ChecksumError, UncheckedHrpstring, UncheckedHrpstringError},
+    Bech32, Bech32m, Hrp,
+};
+
+use crate::errors::Bech32Error;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Separates the human-readable part from the data part. The last occurrence of the separator in a
+/// string is used, since the human-readable part may contain it as well.
+const SEPARATOR: char = '1';
+
+/// The maximum length of a bech32 string.
+const MAX_LENGTH: usize = 90;
+
+// BECH32 VARIANT
+// ================================================================================================
+
+/// The checksum algorithm of a bech32 string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bech32Variant {
+    /// The legacy checksum defined in BIP-173.
+    Bech32,
+    /// The checksum defined in BIP-350, which should be used by new systems.
+    Bech32m,
+}
+
+// ENCODING
+// ================================================================================================
+
+/// Encodes `data` into a lowercase bech32m string with the human-readable part `hrp`.
+///
+/// The caller must ensure `hrp` is valid, see [`validate_hrp`].
+pub(crate) fn encode(hrp: &str, data: &[u8]) -> String {
+    encode_with_variant(hrp, data, Bech32Variant::Bech32m)
+}
+
+/// Encodes `data` into a lowercase string with the human-readable part `hrp` and the checksum of
+/// the provided `variant`.
+///
+/// The caller must ensure `hrp` is valid, see [`validate_hrp`].
+pub(crate) fn encode_with_variant(hrp: &str, data: &[u8], variant: Bech32Variant) -> String {
+    debug_assert!(validate_hrp(hrp).is_ok(), "human-readable part should be valid");
+    let hrp = Hrp::parse(hrp).expect("human-readable part should be valid");
+
+    match variant {
+        Bech32Variant::Bech32 => ::bech32::encode_lower::<Bech32>(hrp, data),
+        Bech32Variant::Bech32m => ::bech32::encode_lower::<Bech32m>(hrp, data),
+    }
+    .expect("encoded data should not exceed the maximum length of the checksum")
+}
+
+// DECODING
+// ================================================================================================
+
+/// Decodes a bech32m string into its lowercase human-readable part and its data.
+///
+/// As defined by the specification, the string may be all lowercase or all uppercase, but must not
+/// mix the two.
+///
+/// # Errors
+/// Returns an error if:
+/// - the string is longer than 90 characters.
+/// - the string contains both uppercase and lowercase characters.
+/// - the string does not contain the separator or the human-readable part is invalid.
+/// - the data part contains characters outside of the bech32 alphabet or is too short to contain a
+///   checksum.
+/// - the checksum is not a valid bech32m checksum, including when it is a valid legacy bech32
+///   checksum.
+/// - the data part has invalid padding.
+pub(crate) fn decode(bech32_string: &str) -> Result<(String, Vec<u8>), Bech32Error> {
+    let (hrp, data, variant) = decode_with_variant(bech32_string)?;
+    if variant != Bech32Variant::Bech32m {
+        return Err(Bech32Error::LegacyChecksum);
+    }
+
+    Ok((hrp, data))
+}
+
+/// Decodes a bech32m or legacy bech32 string into its lowercase human-readable part, its data and
+/// the variant of its checksum.
+///
+/// # Errors
+/// Returns the same errors as [`decode`], except that a valid legacy bech32 checksum is accepted.
+pub(crate) fn decode_with_variant(
+    bech32_string: &str,
+) -> Result<(String, Vec<u8>, Bech32Variant), Bech32Error> {
+    if bech32_string.len() > MAX_LENGTH {
+        return Err(Bech32Error::InvalidLength(bech32_string.len()));
+    }
+
+    let unchecked = UncheckedHrpstring::new(bech32_string).map_err(|err| match err {
+        UncheckedHrpstringError::Char(CharError::MixedCase) => Bech32Error::MixedCase,
+        UncheckedHrpstringError::Char(CharError::MissingSeparator) => Bech32Error::MissingSeparator,
+        UncheckedHrpstringError::Char(CharError::InvalidChar(c)) => {
+            Bech32Error::InvalidCharacter(c)
+        },
+        UncheckedHrpstringError::Hrp(_) => {
+            let (hrp, _) = bech32_string
+                .rsplit_once(SEPARATOR)
+                .expect("human-readable part should only be parsed after the separator is found");
+            Bech32Error::InvalidHrp(hrp.into())
+        },
+        _ => Bech32Error::InvalidLength(bech32_string.len()),
+    })?;
+
+    let (checked, variant) = if unchecked.has_valid_checksum::<Bech32m>() {
+        (unchecked.remove_checksum::<Bech32m>(), Bech32Variant::Bech32m)
+    } else if unchecked.has_valid_checksum::<Bech32>() {
+        (unchecked.remove_checksum::<Bech32>(), Bech32Variant::Bech32)
+    } else {
+        return Err(match unchecked.validate_checksum::<Bech32m>() {
+            Err(ChecksumError::InvalidLength) => Bech32Error::InvalidLength(bech32_string.len()),
+            _ => Bech32Error::InvalidChecksum,
+        });
+    };
+
+    // the padding rules of BIP-173 apply to all data, not just to segwit addresses
+    checked.validate_segwit_padding().map_err(|_| Bech32Error::InvalidPadding)?;
+
+    Ok((checked.hrp().to_lowercase(), checked.byte_iter().collect(), variant))
+}
+
+/// Checks that `hrp` is a valid human-readable part, i.e. that it consists of 1 to 83 printable
+/// ASCII characters, excluding uppercase characters.
+pub(crate) fn validate_hrp(hrp: &str) -> Result<(), Bech32Error> {
+    if hrp.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        return Err(Bech32Error::InvalidHrp(hrp.into()));
+    }
+    Hrp::parse(hrp).map_err(|_| Bech32Error::InvalidHrp(hrp.into()))?;
+
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    /// Valid bech32m test vectors from BIP-350 whose data can be regrouped into bytes.
+    const VALID_BECH32M: [&str; 6] = [
+        "A1LQFN3A",
+        "a1lqfn3a",
+        "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+        "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+        "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+        "?1v759aa",
+    ];
+
+    #[test]
+    fn decode_bip_350_vectors() {
+        for bech32_string in VALID_BECH32M {
+            let (hrp, _) = decode(bech32_string).unwrap();
+            let separator_position = bech32_string.rfind(SEPARATOR).unwrap();
+            assert_eq!(hrp, bech32_string[..separator_position].to_ascii_lowercase());
+        }
+
+        // the checksum of this vector is valid, but its 410 data bits cannot be regrouped into
+        // bytes
+        assert_matches!(
+            decode("11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8"),
+            Err(Bech32Error::InvalidPadding)
+        );
+    }
+
+    #[test]
+    fn decode_invalid_bip_350_vectors() {
+        assert_matches!(decode("an84characterslonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11d6pts4"), Err(Bech32Error::InvalidLength(91)));
+        assert_matches!(decode("qyrz8wqd2c9m"), Err(Bech32Error::MissingSeparator));
+        assert_matches!(decode("1qyrz8wqd2c9m"), Err(Bech32Error::InvalidHrp(_)));
+        assert_matches!(decode("y1b0jsk6g"), Err(Bech32Error::InvalidCharacter('b')));
+        assert_matches!(decode("lt1igcx5c0"), Err(Bech32Error::InvalidCharacter('i')));
+        assert_matches!(decode("in1muywd"), Err(Bech32Error::InvalidLength(_)));
+        assert_matches!(decode("mm1crxm3i"), Err(Bech32Error::InvalidCharacter('i')));
+        assert_matches!(decode("au1s5cgom"), Err(Bech32Error::InvalidCharacter('o')));
+        assert_matches!(decode("M1VUXWEZ"), Err(Bech32Error::InvalidChecksum));
+        assert_matches!(decode("16plkw9"), Err(Bech32Error::InvalidHrp(_)));
+        assert_matches!(decode("1p2gdwpf"), Err(Bech32Error::InvalidHrp(_)));
+        assert_matches!(decode("A1LQfN3A"), Err(Bech32Error::MixedCase));
+    }
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        for data in [
+            &[][..],
+            &[0],
+            &[0xff; 15],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+        ] {
+            let encoded = encode("mtst", data);
+            assert_eq!(encoded, encoded.to_ascii_lowercase());
+            assert_eq!(decode(&encoded).unwrap(), (String::from("mtst"), data.to_vec()));
+            assert_eq!(
+                decode(&encoded.to_ascii_uppercase()).unwrap(),
+                (String::from("mtst"), data.to_vec())
+            );
+        }
+
+        // the BIP-350 vector "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx" encodes all 5-bit
+        // values in order, i.e. its data is the 8-bit regrouping of the values 31, 30, ...,
+        // 0
+        let (hrp, data) = decode("abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx").unwrap();
+        assert_eq!(encode(&hrp, &data), "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx");
+    }
+
+    #[test]
+    fn decode_bip_173_vectors() {
+        for bech32_string in [
+            "A12UEL5L",
+            "a12uel5l",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "?1ezyfcl",
+        ] {
+            let (hrp, data, variant) = decode_with_variant(bech32_string).unwrap();
+            assert_eq!(variant, Bech32Variant::Bech32);
+            assert_eq!(
+                encode_with_variant(&hrp, &data, variant),
+                bech32_string.to_ascii_lowercase()
+            );
+
+            // legacy strings are rejected unless explicitly accepted
+            assert_matches!(decode(bech32_string), Err(Bech32Error::LegacyChecksum));
+        }
+    }
+
+    #[test]
+    fn encode_decode_variant_roundtrip() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        for variant in [Bech32Variant::Bech32, Bech32Variant::Bech32m] {
+            let encoded = encode_with_variant("mtst", &data, variant);
+            assert_eq!(
+                decode_with_variant(&encoded).unwrap(),
+                (String::from("mtst"), data.to_vec(), variant)
+            );
+        }
+
+        // the checksums of the two variants differ for the same data
+        assert_ne!(
+            encode_with_variant("mtst", &data, Bech32Variant::Bech32),
+            encode_with_variant("mtst", &data, Bech32Variant::Bech32m)
+        );
+    }
+}
@@ -236,6 +236,20 @@ impl Serializable for BlockHeader {
         self.proof_hash.write_into(target);
         self.timestamp.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.version.get_size_hint()
+            + self.prev_hash.get_size_hint()
+            + self.block_num.get_size_hint()
+            + self.chain_root.get_size_hint()
+            + self.account_root.get_size_hint()
+            + self.nullifier_root.get_size_hint()
+            + self.note_root.get_size_hint()
+            + self.tx_hash.get_size_hint()
+            + self.kernel_root.get_size_hint()
+            + self.proof_hash.get_size_hint()
+            + self.timestamp.get_size_hint()
+    }
 }
 
 impl Deserializable for BlockHeader {
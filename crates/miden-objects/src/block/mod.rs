@@ -203,6 +203,13 @@ impl Serializable for Block {
         self.output_note_batches.write_into(target);
         self.nullifiers.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.header.get_size_hint()
+            + self.updated_accounts.get_size_hint()
+            + self.output_note_batches.get_size_hint()
+            + self.nullifiers.get_size_hint()
+    }
 }
 
 impl Deserializable for Block {
@@ -313,6 +320,16 @@ impl Serializable for BlockAccountUpdate {
         self.details.write_into(target);
         self.transactions.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        let transactions_size = self.transactions.len().get_size_hint()
+            + self.transactions.iter().map(TransactionId::get_size_hint).sum::<usize>();
+
+        self.account_id.get_size_hint()
+            + self.new_state_hash.get_size_hint()
+            + self.details.get_size_hint()
+            + transactions_size
+    }
 }
 
 impl Deserializable for BlockAccountUpdate {
@@ -325,3 +342,212 @@ impl Deserializable for BlockAccountUpdate {
         })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::{
+            delta::{AccountStorageDelta, AccountVaultDelta},
+            Account, AccountCode, AccountDelta, AccountStorage,
+        },
+        asset::{AssetVault, FungibleAsset, NonFungibleAsset},
+        note::{
+            Note, NoteAssets, NoteExecutionHint, NoteHeader, NoteInputs, NoteMetadata,
+            NoteRecipient, NoteScript, NoteTag, NoteType, PartialNote,
+        },
+        testing::account_id::{
+            ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_SENDER,
+        },
+        ONE,
+    };
+
+    fn mock_note(seed: u64) -> Note {
+        let metadata = NoteMetadata::new(
+            FungibleAsset::mock_issuer(),
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::None,
+            ZERO,
+        )
+        .unwrap();
+        let inputs = NoteInputs::new(vec![Felt::new(seed)]).unwrap();
+        let recipient = NoteRecipient::new([Felt::new(seed); 4], NoteScript::mock(), inputs);
+
+        Note::new(NoteAssets::new(vec![FungibleAsset::mock(seed)]).unwrap(), metadata, recipient)
+    }
+
+    fn mock_tx_id(seed: u64) -> TransactionId {
+        TransactionId::from(Digest::new([Felt::new(seed); 4]))
+    }
+
+    /// Returns a block header whose fields are all derived from `block_num`, so that its
+    /// serialization is stable across test runs.
+    fn deterministic_header(block_num: u32) -> BlockHeader {
+        let digest = |field: u64| Digest::new([Felt::new(block_num as u64 * 16 + field); 4]);
+
+        BlockHeader::new(
+            0,
+            digest(1),
+            BlockNumber::from(block_num),
+            digest(2),
+            digest(3),
+            digest(4),
+            digest(5),
+            digest(6),
+            digest(7),
+            digest(8),
+            1_700_000_000 + block_num,
+        )
+    }
+
+    fn empty_block() -> Block {
+        Block::new(deterministic_header(0), vec![], vec![], vec![]).unwrap()
+    }
+
+    /// Returns a block containing all kinds of account updates and output notes, as well as an
+    /// empty note batch.
+    fn maximal_block() -> Block {
+        let header = deterministic_header(7);
+
+        let new_account = Account::from_parts(
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap(),
+            AssetVault::new(&[FungibleAsset::mock(100)]).unwrap(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            ONE,
+        );
+        let delta = AccountDelta::new(
+            AccountStorageDelta::default(),
+            AccountVaultDelta::from_iters(
+                [FungibleAsset::mock(10)],
+                [NonFungibleAsset::mock(&[1, 2, 3])],
+            ),
+            Some(Felt::new(2)),
+        )
+        .unwrap();
+
+        let updated_accounts = vec![
+            BlockAccountUpdate::new(
+                ACCOUNT_ID_OFF_CHAIN_SENDER.try_into().unwrap(),
+                Digest::new([ONE; 4]),
+                AccountUpdateDetails::Private,
+                vec![mock_tx_id(1)],
+            ),
+            BlockAccountUpdate::new(
+                new_account.id(),
+                new_account.hash(),
+                AccountUpdateDetails::New(new_account),
+                vec![mock_tx_id(2)],
+            ),
+            BlockAccountUpdate::new(
+                ACCOUNT_ID_SENDER.try_into().unwrap(),
+                Digest::new([Felt::new(2); 4]),
+                AccountUpdateDetails::Delta(delta),
+                vec![mock_tx_id(3), mock_tx_id(4)],
+            ),
+        ];
+
+        let output_note_batches = vec![
+            vec![
+                OutputNote::Full(mock_note(1)),
+                OutputNote::Header(NoteHeader::from(&mock_note(2))),
+            ],
+            vec![],
+            vec![OutputNote::Partial(PartialNote::from(mock_note(3)))],
+        ];
+
+        let nullifiers = vec![mock_note(4).nullifier(), mock_note(5).nullifier()];
+
+        Block::new(header, updated_accounts, output_note_batches, nullifiers).unwrap()
+    }
+
+    #[test]
+    fn block_serialization_roundtrip() {
+        for block in [empty_block(), maximal_block()] {
+            let bytes = block.to_bytes();
+            let deserialized = Block::read_from_bytes(&bytes).unwrap();
+
+            assert_eq!(deserialized.header(), block.header());
+            assert_eq!(deserialized.updated_accounts(), block.updated_accounts());
+            assert_eq!(deserialized.output_note_batches(), block.output_note_batches());
+            assert_eq!(deserialized.nullifiers(), block.nullifiers());
+            assert_eq!(deserialized.to_bytes(), bytes);
+        }
+    }
+
+    /// Checks the serialized blocks against the golden fixtures in `src/block/fixtures`, so that
+    /// any change to the binary format of blocks is noticed.
+    ///
+    /// If the format changes intentionally, the fixtures are regenerated by running this test
+    /// with the `MIDEN_REGENERATE_FIXTURES` environment variable set:
+    ///
+    /// ```sh
+    /// MIDEN_REGENERATE_FIXTURES=1 cargo test -p miden-objects block_golden_bytes
+    /// ```
+    #[cfg(feature = "std")]
+    #[test]
+    fn block_golden_bytes() {
+        use std::{env, fs, path::Path};
+
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/block/fixtures");
+        for (name, block) in
+            [("empty_block.bin", empty_block()), ("maximal_block.bin", maximal_block())]
+        {
+            let path = fixtures.join(name);
+            let bytes = block.to_bytes();
+
+            if env::var_os("MIDEN_REGENERATE_FIXTURES").is_some() {
+                fs::write(&path, &bytes).unwrap();
+            }
+
+            let expected = fs::read(&path).unwrap();
+            assert_eq!(bytes, expected, "serialized block does not match the fixture {name}");
+
+            let deserialized = Block::read_from_bytes(&expected).unwrap();
+            assert_eq!(deserialized.hash(), block.hash());
+        }
+    }
+
+    #[test]
+    fn block_size_hints_match_serialized_sizes() {
+        let block = maximal_block();
+        assert_eq!(block.get_size_hint(), block.to_bytes().len());
+
+        let header = block.header();
+        assert_eq!(header.get_size_hint(), header.to_bytes().len());
+
+        for update in block.updated_accounts() {
+            assert_eq!(update.get_size_hint(), update.to_bytes().len());
+        }
+
+        for nullifier in block.nullifiers() {
+            assert_eq!(nullifier.get_size_hint(), nullifier.to_bytes().len());
+        }
+
+        for note in block.output_note_batches().iter().flatten() {
+            assert_eq!(note.get_size_hint(), note.to_bytes().len());
+        }
+
+        assert_eq!(empty_block().get_size_hint(), empty_block().to_bytes().len());
+    }
+
+    #[test]
+    fn truncated_block_fails_to_deserialize() {
+        for block in [empty_block(), maximal_block()] {
+            let bytes = block.to_bytes();
+
+            for len in (0..bytes.len()).step_by(64) {
+                assert!(
+                    Block::read_from_bytes(&bytes[..len]).is_err(),
+                    "block truncated to {len} of {} bytes was deserialized",
+                    bytes.len()
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use alloc::{boxed::Box, string::String};
-use core::error::Error;
+use core::{error::Error, num::ParseIntError};
 
 use assembly::{diagnostics::reporting::PrintDiagnostic, Report};
 use miden_crypto::utils::HexParseError;
@@ -9,7 +9,7 @@ use vm_processor::DeserializationError;
 
 use super::{
     account::AccountId,
-    asset::{FungibleAsset, NonFungibleAsset},
+    asset::{Asset, FungibleAsset, NonFungibleAsset, TokenSymbol},
     crypto::merkle::MerkleError,
     note::NoteId,
     Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
@@ -17,12 +17,12 @@ use super::{
 };
 use crate::{
     account::{
-        AccountCode, AccountIdPrefix, AccountStorage, AccountType, PlaceholderType,
-        StoragePlaceholder,
+        AccountCode, AccountIdPrefix, AccountStorage, AccountType, AddressType,
+        ComponentRequirement, PlaceholderType, StoragePlaceholder, StorageValueType,
     },
     block::BlockNumber,
     note::{NoteAssets, NoteExecutionHint, NoteTag, NoteType, Nullifier},
-    ACCOUNT_UPDATE_MAX_SIZE, MAX_INPUTS_PER_NOTE, MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX,
+    ACCOUNT_UPDATE_MAX_SIZE, MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX,
 };
 
 // ACCOUNT COMPONENT TEMPLATE ERROR
@@ -53,6 +53,11 @@ pub enum AccountComponentTemplateError {
         "storage placeholder `{0}` appears more than once representing different types `{0}` and `{1}`"
     )]
     StoragePlaceholderTypeMismatch(StoragePlaceholder, PlaceholderType, PlaceholderType),
+    #[error("value of storage slot {slot} is not a valid value of type `{expected_type}`")]
+    SlotTypeViolation {
+        slot: u8,
+        expected_type: StorageValueType,
+    },
 }
 
 // ACCOUNT ERROR
@@ -78,8 +83,12 @@ pub enum AccountError {
     AccountComponentMastForestMergeError(#[source] MastForestError),
     #[error("procedure with MAST root {0} is present in multiple account components")]
     AccountComponentDuplicateProcedureRoot(Digest),
+    #[error("procedure with name {name} is exported by multiple account components")]
+    DuplicateProcedureName { name: String },
     #[error("failed to create account component")]
     AccountComponentTemplateInstantiationError(#[source] AccountComponentTemplateError),
+    #[error("account delta does not match the declared storage slot types")]
+    StorageSlotTypeViolation(#[source] Box<AccountDeltaError>),
     #[error("failed to update asset vault")]
     AssetVaultUpdateError(#[source] AssetVaultError),
     #[error("account build error: {0}")]
@@ -110,11 +119,23 @@ pub enum AccountError {
         "procedure which does not access storage (storage size = 0) has non-zero storage offset"
     )]
     PureProcedureWithStorageOffset,
+    #[error("account component at index {component_index} requires `{requirement}` but none of the provided components satisfies it")]
+    MissingComponentDependency {
+        component_index: usize,
+        requirement: ComponentRequirement,
+    },
+    #[error("account component at index {component_index} does not support any account type")]
+    NoSupportedTypes { component_index: usize },
     #[error("account component at index {component_index} is incompatible with account of type {account_type}")]
     UnsupportedComponentForAccountType {
         account_type: AccountType,
         component_index: usize,
     },
+    #[error("faucet account of type {account_type} must have exactly one faucet component but has {num_faucet_components}")]
+    InvalidFaucetComponentCount {
+        account_type: AccountType,
+        num_faucet_components: usize,
+    },
     #[error("failed to parse account ID from final account header")]
     FinalAccountHeaderIdParsingFailed(#[source] AccountIdError),
     /// This variant can be used by methods that are not inherent to the account but want to return
@@ -149,6 +170,59 @@ pub enum AccountIdError {
         BlockNumber::EPOCH_LENGTH_EXPONENT
     )]
     AnchorBlockMustBeEpochBlock,
+    #[error("failed to decode bech32 string into account ID")]
+    Bech32DecodeError(#[source] Bech32Error),
+    #[error("failed to decode compact string into account ID")]
+    CompactStringDecodeError(#[source] Base58Error),
+}
+
+// BECH32 ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum Bech32Error {
+    #[error("bech32 string contains both uppercase and lowercase characters")]
+    MixedCase,
+    #[error("bech32 string has invalid length {0}")]
+    InvalidLength(usize),
+    #[error("bech32 string does not contain the `1` separator")]
+    MissingSeparator,
+    #[error("`{0}` is not a valid bech32 human-readable part")]
+    InvalidHrp(Box<str>),
+    #[error("bech32 string contains invalid character `{0}`")]
+    InvalidCharacter(char),
+    #[error("bech32 string has neither a valid bech32m nor a valid legacy bech32 checksum")]
+    InvalidChecksum,
+    #[error("bech32 string has a legacy bech32 checksum, but a bech32m checksum is required")]
+    LegacyChecksum,
+    #[error("bech32 data has invalid padding")]
+    InvalidPadding,
+    #[error("bech32 data is empty and does not contain an address type")]
+    MissingAddressType,
+    #[error("`{0}` is not a known address type")]
+    UnknownAddressType(u8),
+    #[error("expected address type {expected:?}, found {actual:?}")]
+    UnexpectedAddressType {
+        expected: AddressType,
+        actual: AddressType,
+    },
+    #[error("expected bech32 data to be of length {expected}, found {actual}")]
+    InvalidDataLength { expected: usize, actual: usize },
+}
+
+// BASE58 ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum Base58Error {
+    #[error("base58 string contains invalid character `{0}`")]
+    InvalidCharacter(char),
+    #[error("base58 data has invalid length {0} and does not contain a checksum")]
+    InvalidLength(usize),
+    #[error("base58 string has an invalid checksum")]
+    InvalidChecksum,
+    #[error("expected base58 data to be of length {expected}, found {actual}")]
+    InvalidDataLength { expected: usize, actual: usize },
 }
 
 // ACCOUNT DELTA ERROR
@@ -180,6 +254,11 @@ pub enum AccountDeltaError {
     InconsistentNonceUpdate(String),
     #[error("account ID {0} in fungible asset delta is not of type fungible faucet")]
     NotAFungibleFaucetId(AccountId),
+    #[error("storage slot {slot} is updated to a value which is not a valid value of type `{expected_type}`")]
+    SlotTypeViolation {
+        slot: u8,
+        expected_type: StorageValueType,
+    },
 }
 
 // ASSET ERROR
@@ -194,17 +273,21 @@ pub enum AssetError {
     FungibleAssetAmountTooBig(u64),
     #[error("subtracting {subtrahend} from fungible asset amount {minuend} would overflow")]
     FungibleAssetAmountNotSufficient { minuend: u64, subtrahend: u64 },
+    #[error("subtracting fungible asset amount {subtrahend} from {minuend} would underflow")]
+    FungibleAssetAmountUnderflow { minuend: u64, subtrahend: u64 },
     #[error("fungible asset word {hex} does not contain expected ZERO at word index 1",
       hex = vm_core::utils::to_hex(Felt::elements_as_bytes(.0))
     )]
     FungibleAssetExpectedZero(Word),
-    #[error("cannot add fungible asset with issuer {other_issuer} to fungible asset with issuer {original_issuer}")]
+    #[error("cannot combine fungible asset with issuer {other_issuer} with fungible asset with issuer {original_issuer}")]
     FungibleAssetInconsistentFaucetIds {
         original_issuer: AccountId,
         other_issuer: AccountId,
     },
     #[error("faucet account ID in asset is invalid")]
     InvalidFaucetAccountId(#[source] Box<dyn Error + Send + Sync + 'static>),
+    #[error("value {0} of the asset encoding is not a valid field element")]
+    InvalidFieldElement(u64),
     #[error(
       "faucet id {0} of type {id_type} must be of type {expected_ty} for fungible assets",
       id_type = .0.account_type(),
@@ -217,8 +300,29 @@ pub enum AssetError {
       expected_ty = AccountType::NonFungibleFaucet
     )]
     NonFungibleFaucetIdTypeMismatch(AccountIdPrefix),
-    #[error("{0}")]
-    TokenSymbolError(String),
+    #[error("invalid token symbol")]
+    TokenSymbolError(#[source] TokenSymbolError),
+}
+
+// TOKEN SYMBOL ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TokenSymbolError {
+    #[error(
+        "token symbol of length {0} is not between 1 and {max} characters long",
+        max = TokenSymbol::MAX_SYMBOL_LENGTH
+    )]
+    InvalidLength(usize),
+    #[error(
+        "token symbol contains character `{char}` at position {position} which is not an ASCII letter"
+    )]
+    InvalidCharacter { char: char, position: usize },
+    #[error(
+        "token symbol value {0} must be smaller than {max}",
+        max = TokenSymbol::MAX_ENCODED_VALUE
+    )]
+    ValueTooLarge(u64),
 }
 
 // ASSET VAULT ERROR
@@ -226,20 +330,22 @@ pub enum AssetError {
 
 #[derive(Debug, Error)]
 pub enum AssetVaultError {
-    #[error("adding fungible asset amounts would exceed maximum allowed amount")]
-    AddFungibleAssetBalanceError(#[source] AssetError),
     #[error("provided assets contain duplicates")]
     DuplicateAsset(#[source] MerkleError),
     #[error("non fungible asset {0} already exists in the vault")]
     DuplicateNonFungibleAsset(NonFungibleAsset),
     #[error("fungible asset {0} does not exist in the vault")]
     FungibleAssetNotFound(FungibleAsset),
+    #[error("adding fungible asset amounts would exceed maximum allowed amount")]
+    FungibleAssetOverflow(#[source] AssetError),
+    #[error(
+        "cannot remove {requested} units of fungible asset from a balance of {available} units"
+    )]
+    InsufficientBalance { requested: u64, available: u64 },
     #[error("faucet id {0} is not a fungible faucet id")]
     NotAFungibleFaucetId(AccountId),
     #[error("non fungible asset {0} does not exist in the vault")]
     NonFungibleAssetNotFound(NonFungibleAsset),
-    #[error("subtracting fungible asset amounts would underflow")]
-    SubtractFungibleAssetBalanceError(#[source] AssetError),
 }
 
 // NOTE ERROR
@@ -251,14 +357,34 @@ pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     #[error("duplicate non fungible asset {0} in note")]
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    #[error("asset {asset:?} was not issued by faucet {faucet_id}")]
+    AssetNotIssuedByFaucet { faucet_id: AccountId, asset: Asset },
+    #[error("faucet {0} is not a fungible faucet")]
+    NotAFungibleFaucet(AccountId),
     #[error("note type {0:?} is inconsistent with note tag {1}")]
     InconsistentNoteTag(NoteType, u64),
     #[error("adding fungible asset amounts would exceed maximum allowed amount")]
     AddFungibleAssetBalanceError(#[source] AssetError),
     #[error("note sender is not a valid account ID")]
     NoteSenderInvalidAccountId(#[source] AccountIdError),
+    #[error("failed to decode bech32 string into note ID")]
+    NoteIdBech32DecodeError(#[source] Bech32Error),
+    #[error("bytes do not encode a valid note ID")]
+    InvalidNoteIdBytes(#[source] HexParseError),
     #[error("note tag use case {0} must be less than 2^{exp}", exp = NoteTag::MAX_USE_CASE_ID_EXPONENT)]
     NoteTagUseCaseTooLarge(u16),
+    #[error("note tag payload prefix of {0} bits must not exceed the {bits} payload bits", bits = NoteTag::USE_CASE_PAYLOAD_BITS)]
+    NoteTagPayloadPrefixTooLarge(u8),
+    #[error("note tag string `{0}` does not match any of the known note tag formats")]
+    UnknownNoteTagFormat(Box<str>),
+    #[error("failed to parse value of note tag string `{input}`")]
+    InvalidNoteTagValue {
+        input: Box<str>,
+        #[source]
+        source: ParseIntError,
+    },
+    #[error("network account note tag {0:#x} must fit into 30 bits")]
+    NoteTagAccountBitsTooLarge(u32),
     #[error(
         "note execution hint tag {0} must be in range {from}..={to}",
         from = NoteExecutionHint::NONE_TAG,
@@ -280,6 +406,12 @@ pub enum NoteError {
         node_index_in_block: u16,
         highest_index: usize,
     },
+    #[error("assets of note {0} are not known")]
+    NotePartsMissingAssets(NoteId),
+    #[error("recipient of note {0} is not known")]
+    NotePartsMissingRecipient(NoteId),
+    #[error("note ID {actual} built from the note details does not match the ID {expected} of the note header")]
+    NotePartsIdMismatch { expected: NoteId, actual: NoteId },
     #[error("note network execution requires account stored on chain")]
     NetworkExecutionRequiresOnChainAccount,
     #[error("note network execution requires a public note but note is of type {0:?}")]
@@ -292,8 +424,8 @@ pub enum NoteError {
     PublicUseCaseRequiresPublicNote(NoteType),
     #[error("note contains {0} assets which exceeds the maximum of {max}", max = NoteAssets::MAX_NUM_ASSETS)]
     TooManyAssets(usize),
-    #[error("note contains {0} inputs which exceeds the maximum of {max}", max = MAX_INPUTS_PER_NOTE)]
-    TooManyInputs(usize),
+    #[error("note contains {actual} inputs which exceeds the maximum of {max}")]
+    TooManyInputs { max: usize, actual: usize },
 }
 
 // CHAIN MMR ERROR
@@ -333,6 +465,16 @@ impl ChainMmrError {
 pub enum TransactionScriptError {
     #[error("failed to assemble transaction script:\n{}", PrintDiagnostic::new(.0))]
     AssemblyError(Report),
+    #[error(
+        "read-only transaction script invokes state-mutating procedure {name} with MAST root {root}"
+    )]
+    StateMutatingProcedure { name: String, root: Digest },
+    #[error("read-only transaction script calls procedure {callee} which cannot be verified")]
+    UnverifiableCall { callee: Digest },
+    #[error("read-only transaction script contains a dynamic call which cannot be verified")]
+    UnverifiableDynamicCall,
+    #[error("read-only transaction script invokes external procedure {root} which is not known to be read-only")]
+    UnverifiableExternalProcedure { root: Digest },
 }
 
 // TRANSACTION INPUT ERROR
@@ -379,8 +521,10 @@ pub enum TransactionInputError {
 pub enum TransactionOutputError {
     #[error("transaction output note with id {0} is a duplicate")]
     DuplicateOutputNote(NoteId),
-    #[error("final account hash is not in the advice map")]
-    FinalAccountHashMissingInAdviceMap,
+    #[error("advice map does not contain an entry for key {key}")]
+    MissingAdviceMapEntry { key: Digest },
+    #[error("public output note {note_id} is missing its details; the full note must be provided via the transaction arguments")]
+    MissingOutputNoteDetails { note_id: NoteId },
     #[error("failed to parse final account header")]
     FinalAccountHeaderParseFailure(#[source] AccountError),
     #[error("output notes commitment {expected} from kernel does not match computed commitment {actual}")]
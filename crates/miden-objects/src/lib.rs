@@ -16,6 +16,8 @@ pub mod transaction;
 #[cfg(any(feature = "testing", test))]
 pub mod testing;
 
+mod base58;
+mod bech32;
 mod constants;
 mod errors;
 
@@ -24,16 +26,16 @@ mod errors;
 
 pub use constants::*;
 pub use errors::{
-    AccountDeltaError, AccountError, AccountIdError, AssetError, AssetVaultError, BlockError,
-    ChainMmrError, NoteError, ProvenTransactionError, TransactionInputError,
-    TransactionOutputError, TransactionScriptError,
+    AccountDeltaError, AccountError, AccountIdError, AssetError, AssetVaultError, Base58Error,
+    Bech32Error, BlockError, ChainMmrError, NoteError, ProvenTransactionError, TokenSymbolError,
+    TransactionInputError, TransactionOutputError, TransactionScriptError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
 
 pub mod assembly {
     pub use assembly::{
-        mast, Assembler, AssemblyError, DefaultSourceManager, KernelLibrary, Library,
+        mast, Assembler, AssemblyError, Compile, DefaultSourceManager, KernelLibrary, Library,
         LibraryNamespace, LibraryPath, SourceManager, Version,
     };
 }
@@ -55,7 +57,24 @@ pub mod utils {
         };
     }
 
-    pub const fn parse_hex_string_as_word(hex: &str) -> Result<[Felt; 4], &'static str> {
+    /// Parses a '0x' prefixed hex string into a [`Word`](vm_core::Word).
+    ///
+    /// The hex digits are interpreted in the same layout as `Digest::try_from(String)`: each pair
+    /// of digits encodes one byte, and every group of 8 bytes encodes one little-endian `u64`
+    /// element.
+    ///
+    /// Inputs shorter than 64 digits (including odd-length ones) are implicitly right-padded with
+    /// zeros. This means that a trailing unpaired digit is the high nibble of its byte, e.g.
+    /// `"0x1"` is equivalent to `"0x10"` and `"0x123"` is equivalent to `"0x1230"`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the string does not start with the "0x" prefix.
+    /// - the string contains more than 64 hex digits.
+    /// - the string contains a non-hex character.
+    /// - any of the encoded elements is not a canonical field element, i.e. is greater than or
+    ///   equal to the field modulus.
+    pub const fn parse_hex_word(hex: &str) -> Result<[Felt; 4], &'static str> {
         const fn parse_hex_digit(digit: u8) -> Result<u8, &'static str> {
             match digit {
                 b'0'..=b'9' => Ok(digit - b'0'),
@@ -98,7 +117,7 @@ pub mod utils {
         // This matches the behaviour of `Digest::try_from(String)`.
         let mut idx = 0;
         while idx < felts.len() {
-            if felts[idx] > Felt::MODULUS {
+            if felts[idx] >= Felt::MODULUS {
                 return Err("Felt overflow");
             }
             idx += 1;
@@ -112,13 +131,22 @@ pub mod utils {
         ])
     }
 
+    /// Parses a '0x' prefixed hex string into a [`Word`](vm_core::Word).
+    ///
+    /// This is an alias of [`parse_hex_word`], see its documentation for the parsing rules.
+    #[deprecated(since = "0.8.0", note = "use `parse_hex_word` instead")]
+    pub const fn parse_hex_string_as_word(hex: &str) -> Result<[Felt; 4], &'static str> {
+        parse_hex_word(hex)
+    }
+
     /// Construct a new `Digest` from a hex value.
     ///
-    /// Expects a '0x' prefixed hex string followed by up to 64 hex digits.
+    /// Expects a '0x' prefixed hex string followed by up to 64 hex digits. Shorter inputs are
+    /// right-padded with zeros, see [`parse_hex_word`] for the exact parsing rules.
     #[macro_export]
     macro_rules! digest {
         ($hex:expr) => {{
-            let felts: [$crate::Felt; 4] = match $crate::utils::parse_hex_string_as_word($hex) {
+            let felts: [$crate::Felt; 4] = match $crate::utils::parse_hex_word($hex) {
                 Ok(v) => v,
                 Err(e) => panic!("{}", e),
             };
@@ -127,8 +155,24 @@ pub mod utils {
         }};
     }
 
+    /// Construct a new [`Word`](crate::Word) from a hex value.
+    ///
+    /// Expects a '0x' prefixed hex string followed by up to 64 hex digits. Shorter inputs are
+    /// right-padded with zeros, see [`parse_hex_word`] for the exact parsing rules.
+    #[macro_export]
+    macro_rules! word {
+        ($hex:expr) => {{
+            let felts: [$crate::Felt; 4] = match $crate::utils::parse_hex_word($hex) {
+                Ok(v) => v,
+                Err(e) => panic!("{}", e),
+            };
+
+            felts
+        }};
+    }
+
     pub fn parse_hex_to_felts(hex: &str) -> Result<[Felt; 4], String> {
-        match parse_hex_string_as_word(hex) {
+        match parse_hex_word(hex) {
             Ok(felts) => Ok(felts),
             Err(e) => Err(e.to_string()),
         }
@@ -152,11 +196,63 @@ pub mod utils {
         #[case::overflow_felt3(
             "0x000000000000000000000000000000000000000000000000ffffffffffffffff"
         )]
+        #[case::modulus_felt0("0x01000000ffffffff")]
         #[should_panic]
         fn digest_macro_invalid(#[case] bad_input: &str) {
             digest!(bad_input);
         }
 
+        #[rstest::rstest]
+        #[case::missing_prefix("1234")]
+        #[case::uppercase_prefix("0X1234")]
+        #[case::odd_too_long("0x00000000000000000000000000000000000000000000000000000000000000001")]
+        #[case::modulus_felt3("0x00000000000000000000000000000000000000000000000001000000ffffffff")]
+        #[should_panic]
+        fn word_macro_invalid(#[case] bad_input: &str) {
+            word!(bad_input);
+        }
+
+        #[rstest::rstest]
+        #[case::missing_prefix("1234", "Hex string must have a \"0x\" prefix")]
+        #[case::invalid_character("0x123g", "Invalid hex character")]
+        #[case::odd_invalid_character("0x12g", "Invalid hex character")]
+        #[case::too_long(
+            "0x00000000000000000000000000000000000000000000000000000000000000000",
+            "Hex string has more than 64 characters"
+        )]
+        #[case::overflow("0xffffffffffffffff", "Felt overflow")]
+        #[case::modulus("0x01000000ffffffff", "Felt overflow")]
+        #[case::odd_overflow("0x01000000fffffffff", "Felt overflow")]
+        fn parse_hex_word_invalid(#[case] bad_input: &str, #[case] expected_err: &str) {
+            assert_eq!(super::parse_hex_word(bad_input).unwrap_err(), expected_err);
+        }
+
+        #[rstest::rstest]
+        #[case::one("0x1", "0x10")]
+        #[case::three("0x123", "0x1230")]
+        #[case::felt_boundary("0x123456789abcdef", "0x123456789abcdef0")]
+        #[case::last_digit(
+            "0x000000000000000000000000000000000000000000000000000000000000000",
+            "0x0000000000000000000000000000000000000000000000000000000000000000"
+        )]
+        fn parse_hex_word_odd_length_is_right_padded(#[case] odd: &str, #[case] padded: &str) {
+            assert_eq!(super::parse_hex_word(odd), super::parse_hex_word(padded));
+            assert_eq!(word!(odd), word!(padded));
+            assert_eq!(digest!(odd), digest!(padded));
+        }
+
+        #[test]
+        fn word_macro_matches_digest_macro() {
+            let hex = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+            assert_eq!(word!(hex), <[crate::Felt; 4]>::from(digest!(hex)));
+        }
+
+        #[test]
+        fn parse_hex_word_is_const() {
+            const WORD: Result<[crate::Felt; 4], &str> = super::parse_hex_word("0x01");
+            assert_eq!(WORD.unwrap(), [crate::ONE, crate::ZERO, crate::ZERO, crate::ZERO]);
+        }
+
         #[rstest::rstest]
         #[case::each_digit("0x1234567890abcdef")]
         #[case::empty("0x")]
@@ -82,6 +82,17 @@ impl NoteAssets {
         self.assets.iter()
     }
 
+    /// Returns an iterator over all assets in their canonical order, see [`Asset`]'s [`Ord`]
+    /// implementation.
+    ///
+    /// The order in which the assets are stored, and thus the note asset commitment, is not
+    /// affected by this.
+    pub fn iter_sorted(&self) -> impl Iterator<Item = &Asset> {
+        let mut assets: Vec<&Asset> = self.assets.iter().collect();
+        assets.sort();
+        assets.into_iter()
+    }
+
     /// Returns all assets represented as a vector of field elements.
     ///
     /// The vector is padded with ZEROs so that its length is a multiple of 8. This is useful
@@ -204,6 +215,13 @@ impl Serializable for NoteAssets {
         target.write_u8(self.assets.len().try_into().expect("Asset number must fit into `u8`"));
         target.write_many(&self.assets);
     }
+
+    fn get_size_hint(&self) -> usize {
+        // Size of the serialized number of assets.
+        let u8_size = 0u8.get_size_hint();
+
+        u8_size + self.assets.iter().map(Asset::get_size_hint).sum::<usize>()
+    }
 }
 
 impl Deserializable for NoteAssets {
@@ -219,12 +237,19 @@ impl Deserializable for NoteAssets {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use assert_matches::assert_matches;
+
     use super::{compute_asset_commitment, NoteAssets};
     use crate::{
         account::AccountId,
-        asset::{Asset, FungibleAsset},
-        testing::account_id::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN,
-        Digest,
+        asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        testing::account_id::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        },
+        Digest, NoteError,
     };
 
     #[test]
@@ -249,4 +274,64 @@ mod tests {
         assert_eq!(assets.assets, vec![expected_asset]);
         assert_eq!(assets.hash, compute_asset_commitment(&[expected_asset]));
     }
+
+    #[test]
+    fn iter_sorted() {
+        let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let non_fungible_faucet_id =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible_1 = Asset::Fungible(FungibleAsset::new(faucet_id_1, 100).unwrap());
+        let fungible_2 = Asset::Fungible(FungibleAsset::new(faucet_id_2, 50).unwrap());
+        let non_fungible_1 = Asset::NonFungible(
+            NonFungibleAsset::new(
+                &NonFungibleAssetDetails::new(non_fungible_faucet_id.prefix(), vec![1, 2, 3])
+                    .unwrap(),
+            )
+            .unwrap(),
+        );
+        let non_fungible_2 = Asset::NonFungible(
+            NonFungibleAsset::new(
+                &NonFungibleAssetDetails::new(non_fungible_faucet_id.prefix(), vec![4, 5, 6])
+                    .unwrap(),
+            )
+            .unwrap(),
+        );
+
+        let mut expected = vec![fungible_1, fungible_2, non_fungible_1, non_fungible_2];
+        expected.sort();
+        assert!(expected[..2].iter().all(Asset::is_fungible));
+        assert!(expected[2..].iter().all(Asset::is_non_fungible));
+
+        // the sorted order does not depend on the order in which the assets were added
+        let inserted = vec![expected[3], expected[1], expected[2], expected[0]];
+        let assets = NoteAssets::new(inserted.clone()).unwrap();
+        assert_eq!(assets.iter_sorted().copied().collect::<Vec<_>>(), expected);
+
+        // neither the stored order nor the commitment are affected
+        assert_eq!(assets.iter().copied().collect::<Vec<_>>(), inserted);
+        assert_eq!(assets.commitment(), compute_asset_commitment(&inserted));
+    }
+
+    #[test]
+    fn too_many_assets() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let assets: Vec<Asset> = (0..=NoteAssets::MAX_NUM_ASSETS as u16)
+            .map(|i| {
+                let details =
+                    NonFungibleAssetDetails::new(faucet_id.prefix(), i.to_le_bytes().to_vec())
+                        .unwrap();
+                Asset::NonFungible(NonFungibleAsset::new(&details).unwrap())
+            })
+            .collect();
+
+        // a note can be filled up to the maximum number of assets
+        NoteAssets::new(assets[..NoteAssets::MAX_NUM_ASSETS].to_vec()).unwrap();
+
+        // the error mirrors the ERR_NOTE_TOO_MANY_ASSETS error of the transaction kernel
+        let err = NoteAssets::new(assets).unwrap_err();
+        assert_matches!(err, NoteError::TooManyAssets(256));
+        assert_eq!(err.to_string(), "note contains 256 assets which exceeds the maximum of 255");
+    }
 }
@@ -24,16 +24,25 @@ pub struct NoteInputs {
 }
 
 impl NoteInputs {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of input values a note can be associated with.
+    pub const MAX_INPUTS: usize = MAX_INPUTS_PER_NOTE;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
     /// Returns [NoteInputs] instantiated from the provided values.
     ///
     /// # Errors
-    /// Returns an error if the number of provided inputs is greater than 128.
+    /// Returns an error if the number of provided inputs is greater than [Self::MAX_INPUTS].
     pub fn new(values: Vec<Felt>) -> Result<Self, NoteError> {
-        if values.len() > MAX_INPUTS_PER_NOTE {
-            return Err(NoteError::TooManyInputs(values.len()));
+        if values.len() > Self::MAX_INPUTS {
+            return Err(NoteError::TooManyInputs {
+                max: Self::MAX_INPUTS,
+                actual: values.len(),
+            });
         }
 
         Ok(pad_and_build(values))
@@ -49,11 +58,11 @@ impl NoteInputs {
 
     /// Returns the number of input values.
     ///
-    /// The returned value is guaranteed to be smaller than or equal to 128.
+    /// The returned value is guaranteed to be smaller than or equal to [Self::MAX_INPUTS].
     pub fn num_values(&self) -> u8 {
-        const _: () = assert!(MAX_INPUTS_PER_NOTE <= u8::MAX as usize);
+        const _: () = assert!(NoteInputs::MAX_INPUTS <= u8::MAX as usize);
         debug_assert!(
-            self.values.len() < MAX_INPUTS_PER_NOTE,
+            self.values.len() <= Self::MAX_INPUTS,
             "The constructor should have checked the number of inputs"
         );
         self.values.len() as u8
@@ -145,6 +154,13 @@ impl Serializable for NoteInputs {
         target.write_u8(values.len().try_into().expect("inputs len is not a u8 value"));
         target.write_many(values);
     }
+
+    fn get_size_hint(&self) -> usize {
+        // Size of the serialized number of values.
+        let u8_size = 0u8.get_size_hint();
+
+        u8_size + self.values.iter().map(Felt::get_size_hint).sum::<usize>()
+    }
 }
 
 impl Deserializable for NoteInputs {
@@ -160,9 +176,10 @@ impl Deserializable for NoteInputs {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use miden_crypto::utils::Deserializable;
 
-    use super::{Felt, NoteInputs, Serializable};
+    use super::{Felt, NoteError, NoteInputs, Serializable};
 
     #[test]
     fn test_input_ordering() {
@@ -184,4 +201,24 @@ mod tests {
         let parsed_note_inputs = NoteInputs::read_from_bytes(&bytes).unwrap();
         assert_eq!(note_inputs, parsed_note_inputs);
     }
+
+    #[test]
+    fn test_max_inputs() {
+        let values = vec![Felt::new(7); NoteInputs::MAX_INPUTS];
+        let note_inputs = NoteInputs::new(values).unwrap();
+        assert_eq!(note_inputs.num_values() as usize, NoteInputs::MAX_INPUTS);
+
+        let bytes = note_inputs.to_bytes();
+        assert_eq!(NoteInputs::read_from_bytes(&bytes).unwrap(), note_inputs);
+    }
+
+    #[test]
+    fn test_too_many_inputs() {
+        let values = vec![Felt::new(7); NoteInputs::MAX_INPUTS + 1];
+        assert_matches!(
+            NoteInputs::new(values),
+            Err(NoteError::TooManyInputs { max, actual })
+                if max == NoteInputs::MAX_INPUTS && actual == NoteInputs::MAX_INPUTS + 1
+        );
+    }
 }
@@ -2,7 +2,8 @@ use alloc::string::ToString;
 
 use super::{
     execution_hint::NoteExecutionHint, AccountId, ByteReader, ByteWriter, Deserializable,
-    DeserializationError, Felt, NoteError, NoteTag, NoteType, Serializable, Word,
+    DeserializationError, Felt, NoteError, NoteExecutionMode, NoteTag, NoteType, Serializable,
+    Word, ZERO,
 };
 
 // NOTE METADATA
@@ -76,6 +77,14 @@ impl NoteMetadata {
         })
     }
 
+    /// Returns a new [NoteMetadataBuilder] for metadata of a note created by the specified sender.
+    ///
+    /// This is an alternative to [NoteMetadata::new] which avoids mixing up its positional
+    /// arguments. See [NoteMetadataBuilder] for the default values of the metadata fields.
+    pub fn builder(sender: AccountId) -> NoteMetadataBuilder {
+        NoteMetadataBuilder::new(sender)
+    }
+
     /// Returns the account which created the note.
     pub fn sender(&self) -> AccountId {
         self.sender
@@ -107,6 +116,79 @@ impl NoteMetadata {
     }
 }
 
+// NOTE METADATA BUILDER
+// ================================================================================================
+
+/// A builder for [NoteMetadata].
+///
+/// By default, the builder is initialized with:
+/// - The `note_type` set to [NoteType::Private].
+/// - The `tag` set to the local execution tag of the sender, i.e. [`NoteTag::from_account_id`] with
+///   [NoteExecutionMode::Local].
+/// - The `execution_hint` set to [NoteExecutionHint::always].
+/// - The `aux` set to [ZERO].
+#[derive(Clone, Copy, Debug)]
+pub struct NoteMetadataBuilder {
+    sender: AccountId,
+    note_type: NoteType,
+    tag: Option<NoteTag>,
+    execution_hint: NoteExecutionHint,
+    aux: Felt,
+}
+
+impl NoteMetadataBuilder {
+    /// Returns a new builder for metadata of a note created by the specified sender.
+    pub fn new(sender: AccountId) -> Self {
+        Self {
+            sender,
+            note_type: NoteType::Private,
+            tag: None,
+            execution_hint: NoteExecutionHint::always(),
+            aux: ZERO,
+        }
+    }
+
+    /// Sets the [NoteType] of the note.
+    pub fn note_type(mut self, note_type: NoteType) -> Self {
+        self.note_type = note_type;
+        self
+    }
+
+    /// Sets the [NoteTag] of the note.
+    pub fn tag(mut self, tag: NoteTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the [NoteExecutionHint] of the note.
+    pub fn execution_hint(mut self, execution_hint: NoteExecutionHint) -> Self {
+        self.execution_hint = execution_hint;
+        self
+    }
+
+    /// Sets the aux field of the note.
+    pub fn aux(mut self, aux: Felt) -> Self {
+        self.aux = aux;
+        self
+    }
+
+    /// Builds the [NoteMetadata].
+    ///
+    /// # Errors
+    /// Returns an error if the note type and note tag are inconsistent.
+    pub fn build(self) -> Result<NoteMetadata, NoteError> {
+        let tag = match self.tag {
+            Some(tag) => tag,
+            None => NoteTag::from_account_id(self.sender, NoteExecutionMode::Local)?,
+        };
+
+        NoteMetadata::new(self.sender, self.note_type, tag, self.execution_hint, self.aux)
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
 impl From<NoteMetadata> for Word {
     /// Convert a [`NoteMetadata`] into a [`Word`].
     ///
@@ -163,6 +245,10 @@ impl Serializable for NoteMetadata {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         Word::from(self).write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        Word::from(self).get_size_hint()
+    }
 }
 
 impl Deserializable for NoteMetadata {
@@ -289,9 +375,47 @@ fn unmerge_note_tag_and_hint_payload(
 mod tests {
 
     use anyhow::Context;
+    use assert_matches::assert_matches;
 
     use super::*;
-    use crate::{note::NoteExecutionMode, testing::account_id::ACCOUNT_ID_MAX_ONES};
+    use crate::testing::account_id::ACCOUNT_ID_MAX_ONES;
+
+    #[test]
+    fn note_metadata_builder() {
+        let sender = AccountId::try_from(ACCOUNT_ID_MAX_ONES).unwrap();
+        let tag = NoteTag::for_public_use_case(4, 5, NoteExecutionMode::Local).unwrap();
+        let execution_hint = NoteExecutionHint::on_block_slot(1, 2, 3);
+        let aux = Felt::new(27);
+
+        let metadata = NoteMetadata::builder(sender)
+            .note_type(NoteType::Public)
+            .tag(tag)
+            .execution_hint(execution_hint)
+            .aux(aux)
+            .build()
+            .unwrap();
+        assert_eq!(
+            metadata,
+            NoteMetadata::new(sender, NoteType::Public, tag, execution_hint, aux).unwrap()
+        );
+
+        let metadata = NoteMetadata::builder(sender).build().unwrap();
+        assert_eq!(
+            metadata,
+            NoteMetadata::new(
+                sender,
+                NoteType::Private,
+                NoteTag::from_account_id(sender, NoteExecutionMode::Local).unwrap(),
+                NoteExecutionHint::always(),
+                ZERO
+            )
+            .unwrap()
+        );
+
+        // the tag is validated against the note type
+        let err = NoteMetadata::builder(sender).tag(tag).build().unwrap_err();
+        assert_matches!(err, NoteError::PublicUseCaseRequiresPublicNote(NoteType::Private));
+    }
 
     #[test]
     fn note_metadata_serde() -> anyhow::Result<()> {
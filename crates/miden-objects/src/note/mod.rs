@@ -6,7 +6,11 @@ use miden_crypto::{
 };
 use vm_processor::DeserializationError;
 
-use crate::{account::AccountId, asset::Asset, Digest, Felt, Hasher, NoteError, WORD_SIZE, ZERO};
+use crate::{
+    account::{AccountId, AccountIdPrefix},
+    asset::Asset,
+    Digest, Felt, Hasher, NoteError, WORD_SIZE, ZERO,
+};
 
 mod assets;
 pub use assets::NoteAssets;
@@ -21,7 +25,7 @@ mod inputs;
 pub use inputs::NoteInputs;
 
 mod metadata;
-pub use metadata::NoteMetadata;
+pub use metadata::{NoteMetadata, NoteMetadataBuilder};
 
 mod execution_hint;
 pub use execution_hint::{AfterBlockNumber, NoteExecutionHint};
@@ -38,18 +42,27 @@ pub use note_type::NoteType;
 mod nullifier;
 pub use nullifier::Nullifier;
 
+mod nullifier_link;
+pub use nullifier_link::NullifierLinkProof;
+
 mod location;
 pub use location::{NoteInclusionProof, NoteLocation};
 
 mod partial;
 pub use partial::PartialNote;
 
+mod parts;
+pub use parts::NoteParts;
+
 mod recipient;
 pub use recipient::NoteRecipient;
 
 mod script;
 pub use script::NoteScript;
 
+mod script_info;
+pub use script_info::ScriptInfo;
+
 mod file;
 pub use file::NoteFile;
 
@@ -150,6 +163,14 @@ impl Note {
         self.nullifier
     }
 
+    /// Returns a proof that the nullifier of this note belongs to its ID.
+    ///
+    /// The proof reveals the serial number and script root of this note, and commitments to its
+    /// inputs and assets. See [NullifierLinkProof] for details.
+    pub fn nullifier_link_proof(&self) -> NullifierLinkProof {
+        NullifierLinkProof::new(self)
+    }
+
     /// Returns a commitment to the note and its metadata.
     ///
     /// > hash(NOTE_ID || NOTE_METADATA)
@@ -233,6 +254,10 @@ impl Serializable for Note {
         header.metadata().write_into(target);
         details.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.metadata().get_size_hint() + self.details.get_size_hint()
+    }
 }
 
 impl Deserializable for Note {
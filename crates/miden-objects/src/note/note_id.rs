@@ -1,10 +1,15 @@
 use alloc::string::String;
 use core::fmt::Display;
 
-use super::{Digest, Felt, Hasher, NoteDetails, Word};
-use crate::utils::{
-    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    HexParseError,
+use super::{Digest, Felt, Hasher, NoteDetails, NoteError, Word};
+use crate::{
+    account::{AddressType, NetworkId},
+    bech32,
+    errors::Bech32Error,
+    utils::{
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        HexParseError,
+    },
 };
 
 // NOTE ID
@@ -28,6 +33,10 @@ use crate::utils::{
 pub struct NoteId(Digest);
 
 impl NoteId {
+    /// The length of the data part of a bech32-encoded note ID, i.e. the address type byte
+    /// followed by the 32 bytes of the ID.
+    const BECH32_DATA_LENGTH: usize = 1 + 32;
+
     /// Returns a new [NoteId] instantiated from the provided note components.
     pub fn new(recipient: Digest, asset_commitment: Digest) -> Self {
         Self(Hasher::merge(&[recipient, asset_commitment]))
@@ -52,6 +61,59 @@ impl NoteId {
     pub fn inner(&self) -> Digest {
         self.0
     }
+
+    /// Encodes this ID into a [bech32m](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)
+    /// string with the provided [`NetworkId`] as the human-readable part.
+    ///
+    /// The data part consists of the [`AddressType::NoteId`] byte followed by the 32 bytes of the
+    /// ID. The returned string is always lowercase.
+    pub fn to_bech32(&self, network_id: NetworkId) -> String {
+        let mut data = [0; Self::BECH32_DATA_LENGTH];
+        data[0] = AddressType::NoteId as u8;
+        data[1..].copy_from_slice(&self.as_bytes());
+
+        bech32::encode(network_id.as_str(), &data)
+    }
+
+    /// Decodes a bech32m string into the [`NetworkId`] and the [`NoteId`] it encodes.
+    ///
+    /// The string may be all lowercase or all uppercase, but must not mix the two.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the string is not a valid bech32m string, which includes strings containing both uppercase
+    ///   and lowercase characters.
+    /// - the address type of the string is not [`AddressType::NoteId`].
+    /// - the data part does not consist of the address type and 32 bytes.
+    /// - the decoded bytes are not a valid [`NoteId`].
+    pub fn from_bech32(bech32_string: &str) -> Result<(NetworkId, Self), NoteError> {
+        let (hrp, data) =
+            bech32::decode(bech32_string).map_err(NoteError::NoteIdBech32DecodeError)?;
+        let network_id = NetworkId::new(&hrp).map_err(NoteError::NoteIdBech32DecodeError)?;
+
+        let address_type = data
+            .first()
+            .ok_or(Bech32Error::MissingAddressType)
+            .and_then(|address_type| AddressType::try_from(*address_type))
+            .map_err(NoteError::NoteIdBech32DecodeError)?;
+        if address_type != AddressType::NoteId {
+            return Err(NoteError::NoteIdBech32DecodeError(Bech32Error::UnexpectedAddressType {
+                expected: AddressType::NoteId,
+                actual: address_type,
+            }));
+        }
+
+        let id_bytes: [u8; 32] = data[1..].try_into().map_err(|_| {
+            NoteError::NoteIdBech32DecodeError(Bech32Error::InvalidDataLength {
+                expected: Self::BECH32_DATA_LENGTH,
+                actual: data.len(),
+            })
+        })?;
+
+        Digest::try_from(id_bytes)
+            .map(|digest| (network_id, NoteId::from(digest)))
+            .map_err(NoteError::InvalidNoteIdBytes)
+    }
 }
 
 impl Display for NoteId {
@@ -128,6 +190,10 @@ impl Serializable for NoteId {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write_bytes(&self.0.to_bytes());
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.0.get_size_hint()
+    }
 }
 
 impl Deserializable for NoteId {
@@ -141,7 +207,10 @@ impl Deserializable for NoteId {
 mod tests {
     use alloc::string::ToString;
 
-    use super::NoteId;
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::account::AccountId;
 
     #[test]
     fn note_id_try_from_hex() {
@@ -150,4 +219,96 @@ mod tests {
 
         assert_eq!(note_id.inner().to_string(), note_id_hex)
     }
+
+    #[test]
+    fn bech32_encode_decode_roundtrip() {
+        let note_id = NoteId::try_from_hex(
+            "0xc9d31c82c098e060c9b6e3af2710b3fc5009a1a6f82ef9465f8f35d1f5ba4a80",
+        )
+        .unwrap();
+
+        for network_id in [
+            NetworkId::Mainnet,
+            NetworkId::Testnet,
+            NetworkId::Devnet,
+            NetworkId::new("mcst").unwrap(),
+        ] {
+            let bech32_string = note_id.to_bech32(network_id.clone());
+            assert!(bech32_string.starts_with(&alloc::format!("{network_id}1")));
+
+            let (decoded_network_id, decoded_note_id) =
+                NoteId::from_bech32(&bech32_string).unwrap();
+            assert_eq!(decoded_network_id, network_id);
+            assert_eq!(decoded_note_id, note_id);
+
+            let (_, decoded_note_id) =
+                NoteId::from_bech32(&bech32_string.to_ascii_uppercase()).unwrap();
+            assert_eq!(decoded_note_id, note_id);
+        }
+    }
+
+    #[test]
+    fn bech32_address_types_are_distinguishable() {
+        let note_id = NoteId::from(Digest::default());
+        let note_bech32 = note_id.to_bech32(NetworkId::Testnet);
+
+        let account_id = AccountId::try_from(
+            crate::testing::account_id::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        )
+        .unwrap();
+        let account_bech32 = account_id.to_bech32(NetworkId::Testnet);
+
+        // the first character after the separator differs between notes and accounts
+        assert_ne!(note_bech32.as_bytes()[5], account_bech32.as_bytes()[5]);
+
+        assert_matches!(
+            NoteId::from_bech32(&account_bech32),
+            Err(NoteError::NoteIdBech32DecodeError(Bech32Error::UnexpectedAddressType {
+                expected: AddressType::NoteId,
+                actual: AddressType::AccountId
+            }))
+        );
+    }
+
+    #[test]
+    fn bech32_invalid_data() {
+        let note_id = NoteId::from(Digest::default());
+        let bech32_string = note_id.to_bech32(NetworkId::Mainnet);
+
+        // changing a character of the data part invalidates the checksum
+        let mut tampered = String::from(&bech32_string[..bech32_string.len() - 1]);
+        tampered.push(if bech32_string.ends_with('q') { 'p' } else { 'q' });
+        assert_matches!(
+            NoteId::from_bech32(&tampered),
+            Err(NoteError::NoteIdBech32DecodeError(Bech32Error::InvalidChecksum))
+        );
+
+        // valid bech32 strings which do not use the bech32m checksum are rejected
+        assert_matches!(
+            NoteId::from_bech32("a12uel5l"),
+            Err(NoteError::NoteIdBech32DecodeError(Bech32Error::LegacyChecksum))
+        );
+
+        // invalid data length
+        let mut data = [0; NoteId::BECH32_DATA_LENGTH];
+        data[0] = AddressType::NoteId as u8;
+        assert_matches!(
+            NoteId::from_bech32(&bech32::encode("mm", &data[..20])),
+            Err(NoteError::NoteIdBech32DecodeError(Bech32Error::InvalidDataLength {
+                expected: 33,
+                actual: 20
+            }))
+        );
+        assert_matches!(
+            NoteId::from_bech32(&bech32::encode("mm", &[])),
+            Err(NoteError::NoteIdBech32DecodeError(Bech32Error::MissingAddressType))
+        );
+
+        // bytes which do not encode valid field elements
+        data[1..].copy_from_slice(&[0xff; 32]);
+        assert_matches!(
+            NoteId::from_bech32(&bech32::encode("mm", &data)),
+            Err(NoteError::InvalidNoteIdBytes(_))
+        );
+    }
 }
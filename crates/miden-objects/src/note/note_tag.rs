@@ -1,10 +1,10 @@
-use core::{fmt, num::TryFromIntError};
+use core::{fmt, num::TryFromIntError, str::FromStr};
 
 use miden_crypto::Felt;
 
 use super::{
-    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError, NoteType,
-    Serializable,
+    AccountId, AccountIdPrefix, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    NoteError, NoteType, Serializable,
 };
 
 // CONSTANTS
@@ -17,6 +17,13 @@ const LOCAL_EXECUTION_WITH_ALL_NOTE_TYPES_ALLOWED: u32 = 0xc000_0000;
 // The 2 most significant bits are set to `0b10`.
 const PUBLIC_USECASE: u32 = 0x8000_0000;
 
+// The names of the tag variants in the string representation of a tag, see [NoteTag]'s `Display`
+// implementation.
+const NETWORK_ACCOUNT: &str = "network-account";
+const NETWORK_PUBLIC_USECASE: &str = "network-public-usecase";
+const LOCAL_PUBLIC_USECASE: &str = "local-public-usecase";
+const LOCAL_USECASE: &str = "local-usecase";
+
 /// [super::Note]'s execution mode hints.
 ///
 /// The execution hints are _not_ enforced, therefore function only as hints. For example, if a
@@ -73,6 +80,12 @@ impl NoteTag {
     /// allowed use case id.
     pub(crate) const MAX_USE_CASE_ID_EXPONENT: u8 = 14;
 
+    /// The number of payload bits of use case tags.
+    pub const USE_CASE_PAYLOAD_BITS: u8 = 16;
+
+    /// The use case ID of tags for SWAP notes, see [`NoteTag::for_swap`].
+    pub const SWAP_USE_CASE_ID: u16 = 0;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -164,6 +177,33 @@ impl NoteTag {
         Ok(Self(execution_bits | use_case_bits | payload_bits))
     }
 
+    /// Returns a new [NoteTag] for a custom public use case, whose payload is truncated to its
+    /// `prefix_bits` most significant bits.
+    ///
+    /// The tag is constructed like [`NoteTag::for_public_use_case`] with
+    /// [`NoteExecutionMode::Local`], except that the `16 - prefix_bits` least significant bits of
+    /// the `payload` are set to 0. Together with [`NoteTag::matches_prefix`], this allows
+    /// subscribing to all tags of a use case whose payloads share a prefix, e.g., to all tags of a
+    /// use case if `prefix_bits` is 0.
+    ///
+    /// # Errors
+    ///
+    /// - If `use_case_id` is larger than or equal to 2^14.
+    /// - If `prefix_bits` is larger than [`NoteTag::USE_CASE_PAYLOAD_BITS`].
+    pub fn for_public_use_case_prefix(
+        use_case_id: u16,
+        payload: u16,
+        prefix_bits: u8,
+    ) -> Result<Self, NoteError> {
+        if prefix_bits > Self::USE_CASE_PAYLOAD_BITS {
+            return Err(NoteError::NoteTagPayloadPrefixTooLarge(prefix_bits));
+        }
+
+        let payload = payload & Self::payload_prefix_mask(prefix_bits) as u16;
+
+        Self::for_public_use_case(use_case_id, payload, NoteExecutionMode::Local)
+    }
+
     /// Returns a new [NoteTag] instantiated for a custom local use case.
     ///
     /// The local use_case tag is the only tag type that allows for [NoteType::Private] notes.
@@ -186,6 +226,47 @@ impl NoteTag {
         Ok(Self(execution_bits | use_case_bits | payload_bits))
     }
 
+    /// Returns a new [NoteTag] for a SWAP note offering an asset issued by `offered_faucet` in
+    /// exchange for an asset issued by `requested_faucet`.
+    ///
+    /// The tag is a local use case tag, so it can be used for notes of any [NoteType]. Its bits are
+    /// allocated as follows:
+    ///
+    /// | Bits     | Value                                                  |
+    /// | -------- | ------------------------------------------------------ |
+    /// | `31..30` | `0b11`, i.e. local execution with any note type        |
+    /// | `29..16` | [`NoteTag::SWAP_USE_CASE_ID`]                          |
+    /// | `15..8`  | The 8 most significant bits of the offered faucet ID   |
+    /// | `7..0`   | The 8 most significant bits of the requested faucet ID |
+    ///
+    /// Since only 8 bits of each faucet ID are encoded, the tag is a best effort filter: two pairs
+    /// map to the same tag if and only if their offered faucets and their requested faucets
+    /// respectively share the 8 most significant bits. The direction of the swap is preserved, so
+    /// the tag for offering A in exchange for B generally differs from the one for offering B in
+    /// exchange for A. Use [`NoteTag::matches_swap_pair`] to filter notes for a pair.
+    pub fn for_swap(offered_faucet: AccountIdPrefix, requested_faucet: AccountIdPrefix) -> Self {
+        let payload = Self::swap_payload(offered_faucet, requested_faucet);
+
+        Self::for_local_use_case(Self::SWAP_USE_CASE_ID, payload)
+            .expect("swap use case ID should be smaller than 2^14")
+    }
+
+    /// Returns a new [NoteTag] for a public SWAP note offering an asset issued by
+    /// `offered_faucet` in exchange for an asset issued by `requested_faucet`.
+    ///
+    /// The tag has the same layout as the one returned by [`NoteTag::for_swap`], except that the
+    /// two most significant bits are set to `0b10`, which requires a [NoteType::Public] note but
+    /// allows users to discover it without an off-band communication channel.
+    pub fn for_public_swap(
+        offered_faucet: AccountIdPrefix,
+        requested_faucet: AccountIdPrefix,
+    ) -> Self {
+        let payload = Self::swap_payload(offered_faucet, requested_faucet);
+
+        Self::for_public_use_case(Self::SWAP_USE_CASE_ID, payload, NoteExecutionMode::Local)
+            .expect("swap use case ID should be smaller than 2^14")
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -216,6 +297,38 @@ impl NoteTag {
         self.0
     }
 
+    /// Returns `true` if this tag is a SWAP tag for offering an asset issued by `offered_faucet`
+    /// in exchange for an asset issued by `requested_faucet`, `false` otherwise.
+    ///
+    /// Both the tags returned by [`NoteTag::for_swap`] and [`NoteTag::for_public_swap`] are
+    /// matched. As described in [`NoteTag::for_swap`], a match can be a false positive for pairs
+    /// whose faucet IDs share their 8 most significant bits with the provided faucet IDs.
+    pub fn matches_swap_pair(
+        &self,
+        offered_faucet: AccountIdPrefix,
+        requested_faucet: AccountIdPrefix,
+    ) -> bool {
+        // Both `0b10` and `0b11` are local use case tags, so only the most significant bit has to
+        // be set, while the rest of the tag must match the swap tag exactly.
+        let is_local_use_case = (self.0 & PUBLIC_USECASE) == PUBLIC_USECASE;
+        let swap_tag = Self::for_swap(offered_faucet, requested_faucet);
+        let use_case_mask = !LOCAL_EXECUTION_WITH_ALL_NOTE_TYPES_ALLOWED;
+
+        is_local_use_case && (self.0 & use_case_mask) == (swap_tag.0 & use_case_mask)
+    }
+
+    /// Returns `true` if this tag and `other` share the two most significant bits, the use case ID
+    /// and the `prefix_bits` most significant bits of the payload, `false` otherwise.
+    ///
+    /// This matches all tags created by [`NoteTag::for_public_use_case`] whose payload starts with
+    /// the prefix of a tag created by [`NoteTag::for_public_use_case_prefix`]. If `prefix_bits` is
+    /// larger than [`NoteTag::USE_CASE_PAYLOAD_BITS`], the entire tags are compared.
+    pub fn matches_prefix(&self, other: &NoteTag, prefix_bits: u8) -> bool {
+        let mask = 0xffff_0000 | Self::payload_prefix_mask(prefix_bits);
+
+        (self.0 & mask) == (other.0 & mask)
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -233,11 +346,103 @@ impl NoteTag {
             Ok(*self)
         }
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a mask selecting the `prefix_bits` most significant bits of the payload of a use
+    /// case tag.
+    ///
+    /// If `prefix_bits` is larger than [`NoteTag::USE_CASE_PAYLOAD_BITS`], the entire payload is
+    /// selected.
+    fn payload_prefix_mask(prefix_bits: u8) -> u32 {
+        let prefix_bits = prefix_bits.min(Self::USE_CASE_PAYLOAD_BITS);
+
+        (0xffff << (Self::USE_CASE_PAYLOAD_BITS - prefix_bits)) & 0xffff
+    }
+
+    /// Returns the payload of a SWAP tag, which consists of the 8 most significant bits of the
+    /// offered faucet ID followed by the 8 most significant bits of the requested faucet ID.
+    fn swap_payload(offered_faucet: AccountIdPrefix, requested_faucet: AccountIdPrefix) -> u16 {
+        let offered_faucet_bits = (u64::from(offered_faucet) >> 56) as u16;
+        let requested_faucet_bits = (u64::from(requested_faucet) >> 56) as u16;
+
+        (offered_faucet_bits << 8) | requested_faucet_bits
+    }
 }
 
+// STRING REPRESENTATION
+// ================================================================================================
+
+/// Formats the tag according to the variant encoded in its two most significant bits:
+///
+/// | Prefix | Format                                            |
+/// | ------ | ------------------------------------------------- |
+/// | `0b00` | `network-account:<30 account ID bits in hex>`     |
+/// | `0b01` | `network-public-usecase:<use case ID>:<payload>`  |
+/// | `0b10` | `local-public-usecase:<use case ID>:<payload>`    |
+/// | `0b11` | `local-usecase:<use case ID>:<payload>`           |
+///
+/// Tags created via [NoteTag::from_account_id] for local execution are rendered as local use case
+/// tags, since they share their bit pattern with those.
 impl fmt::Display for NoteTag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        let use_case_id = (self.0 >> 16) & 0x3fff;
+        let payload = self.0 & 0xffff;
+
+        match self.0 >> 30 {
+            0b00 => write!(f, "{NETWORK_ACCOUNT}:{:#010x}", self.0),
+            0b01 => write!(f, "{NETWORK_PUBLIC_USECASE}:{use_case_id}:{payload}"),
+            0b10 => write!(f, "{LOCAL_PUBLIC_USECASE}:{use_case_id}:{payload}"),
+            _ => write!(f, "{LOCAL_USECASE}:{use_case_id}:{payload}"),
+        }
+    }
+}
+
+/// Parses a tag from the format produced by its `Display` implementation.
+///
+/// Parsing a tag does not validate it against a [NoteType], which still happens when the tag is
+/// used in [NoteMetadata](super::NoteMetadata).
+impl FromStr for NoteTag {
+    type Err = NoteError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let unknown_format = || NoteError::UnknownNoteTagFormat(string.into());
+        let invalid_value =
+            |source| NoteError::InvalidNoteTagValue { input: string.into(), source };
+        let parse_use_case = |value: &str| {
+            let (use_case_id, payload) = value.split_once(':').ok_or_else(unknown_format)?;
+            let use_case_id = use_case_id.parse::<u16>().map_err(invalid_value)?;
+            let payload = payload.parse::<u16>().map_err(invalid_value)?;
+
+            Ok::<_, NoteError>((use_case_id, payload))
+        };
+
+        let (variant, value) = string.split_once(':').ok_or_else(unknown_format)?;
+        match variant {
+            NETWORK_ACCOUNT => {
+                let bits = value.strip_prefix("0x").ok_or_else(unknown_format)?;
+                let bits = u32::from_str_radix(bits, 16).map_err(invalid_value)?;
+                if bits >> 30 != 0 {
+                    return Err(NoteError::NoteTagAccountBitsTooLarge(bits));
+                }
+
+                Ok(Self(bits))
+            },
+            NETWORK_PUBLIC_USECASE => {
+                let (use_case_id, payload) = parse_use_case(value)?;
+                Self::for_public_use_case(use_case_id, payload, NoteExecutionMode::Network)
+            },
+            LOCAL_PUBLIC_USECASE => {
+                let (use_case_id, payload) = parse_use_case(value)?;
+                Self::for_public_use_case(use_case_id, payload, NoteExecutionMode::Local)
+            },
+            LOCAL_USECASE => {
+                let (use_case_id, payload) = parse_use_case(value)?;
+                Self::for_local_use_case(use_case_id, payload)
+            },
+            _ => Err(unknown_format()),
+        }
     }
 }
 
@@ -308,11 +513,13 @@ impl Deserializable for NoteTag {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use assert_matches::assert_matches;
 
     use super::{NoteExecutionMode, NoteTag};
     use crate::{
-        account::AccountId,
+        account::{AccountId, AccountIdPrefix, AccountIdVersion, AccountStorageMode, AccountType},
         note::NoteType,
         testing::account_id::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
@@ -513,6 +720,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_for_public_use_case_prefix() {
+        let use_case_id = 42;
+
+        // a prefix of all payload bits does not mask the payload
+        let full_prefix_tag = NoteTag::for_public_use_case_prefix(use_case_id, 0xabcd, 16).unwrap();
+        assert_eq!(
+            full_prefix_tag,
+            NoteTag::for_public_use_case(use_case_id, 0xabcd, NoteExecutionMode::Local).unwrap()
+        );
+
+        let prefix_tag = NoteTag::for_public_use_case_prefix(use_case_id, 0xabcd, 8).unwrap();
+        assert_eq!(prefix_tag, NoteTag(0b10000000_00101010_10101011_00000000));
+        prefix_tag.validate(NoteType::Public).unwrap();
+
+        // tags of the same use case match if their payloads share the prefix
+        for (payload, is_match) in
+            [(0xab00, true), (0xabff, true), (0xac00, false), (0x00cd, false)]
+        {
+            let tag = NoteTag::for_public_use_case(use_case_id, payload, NoteExecutionMode::Local)
+                .unwrap();
+            assert_eq!(tag.matches_prefix(&prefix_tag, 8), is_match);
+            assert_eq!(prefix_tag.matches_prefix(&tag, 8), is_match);
+        }
+
+        // a prefix of 0 bits matches all tags of the use case, but not of other use cases or
+        // execution modes
+        let use_case_tag = NoteTag::for_public_use_case_prefix(use_case_id, 0xabcd, 0).unwrap();
+        assert_eq!(use_case_tag, NoteTag(0b10000000_00101010_00000000_00000000));
+        for payload in [0, 0xabcd, u16::MAX] {
+            let tag = NoteTag::for_public_use_case(use_case_id, payload, NoteExecutionMode::Local)
+                .unwrap();
+            assert!(tag.matches_prefix(&use_case_tag, 0));
+        }
+        let other_use_case_tag =
+            NoteTag::for_public_use_case(use_case_id + 1, 0, NoteExecutionMode::Local).unwrap();
+        assert!(!other_use_case_tag.matches_prefix(&use_case_tag, 0));
+        let network_tag =
+            NoteTag::for_public_use_case(use_case_id, 0, NoteExecutionMode::Network).unwrap();
+        assert!(!network_tag.matches_prefix(&use_case_tag, 0));
+
+        // prefixes larger than the payload are compared as entire tags
+        assert!(full_prefix_tag.matches_prefix(&full_prefix_tag, 17));
+        assert!(!full_prefix_tag.matches_prefix(&prefix_tag, 17));
+
+        assert_matches!(
+            NoteTag::for_public_use_case_prefix(use_case_id, 0, 17).unwrap_err(),
+            NoteError::NoteTagPayloadPrefixTooLarge(17)
+        );
+        assert_matches!(
+            NoteTag::for_public_use_case_prefix(1 << 14, 0, 8).unwrap_err(),
+            NoteError::NoteTagUseCaseTooLarge(use_case) if use_case == 1 << 14
+        );
+    }
+
     #[test]
     fn test_for_private_use_case() {
         let tag = NoteTag::for_local_use_case(0b0, 0b0).unwrap();
@@ -543,4 +805,211 @@ mod tests {
           NoteError::NoteTagUseCaseTooLarge(use_case) if use_case == 1 << 14
         );
     }
+
+    /// Returns the prefix of a dummy faucet ID whose two most significant bytes are set to the
+    /// provided values.
+    fn faucet_prefix(msb: u8, second_msb: u8) -> AccountIdPrefix {
+        let mut bytes = [0; 15];
+        bytes[0] = msb;
+        bytes[1] = second_msb;
+
+        AccountId::dummy(
+            bytes,
+            AccountIdVersion::Version0,
+            AccountType::FungibleFaucet,
+            AccountStorageMode::Public,
+        )
+        .prefix()
+    }
+
+    #[test]
+    fn test_for_swap() {
+        let offered_faucet = faucet_prefix(0xcd, 0xb1);
+        let requested_faucet = faucet_prefix(0xab, 0xec);
+
+        let tag = NoteTag::for_swap(offered_faucet, requested_faucet);
+        assert_eq!(tag, NoteTag(0b11000000_00000000_11001101_10101011));
+        assert_eq!(tag, NoteTag::for_local_use_case(NoteTag::SWAP_USE_CASE_ID, 0xcdab).unwrap());
+        for note_type in [NoteType::Public, NoteType::Private, NoteType::Encrypted] {
+            tag.validate(note_type).expect("swap tag should support all note types");
+        }
+
+        let public_tag = NoteTag::for_public_swap(offered_faucet, requested_faucet);
+        assert_eq!(public_tag, NoteTag(0b10000000_00000000_11001101_10101011));
+        public_tag.validate(NoteType::Public).unwrap();
+        assert_matches!(
+            public_tag.validate(NoteType::Private),
+            Err(NoteError::PublicUseCaseRequiresPublicNote(NoteType::Private))
+        );
+    }
+
+    #[test]
+    fn test_matches_swap_pair() {
+        let offered_faucet = faucet_prefix(0xcd, 0xb1);
+        let requested_faucet = faucet_prefix(0xab, 0xec);
+
+        let tag = NoteTag::for_swap(offered_faucet, requested_faucet);
+        let public_tag = NoteTag::for_public_swap(offered_faucet, requested_faucet);
+        assert!(tag.matches_swap_pair(offered_faucet, requested_faucet));
+        assert!(public_tag.matches_swap_pair(offered_faucet, requested_faucet));
+
+        // The direction of the swap is part of the tag.
+        assert!(!tag.matches_swap_pair(requested_faucet, offered_faucet));
+        assert!(!public_tag.matches_swap_pair(requested_faucet, offered_faucet));
+
+        // Tags with the same payload but a different use case or execution mode are not matched.
+        let other_use_case = NoteTag::for_local_use_case(1, 0xcdab).unwrap();
+        assert!(!other_use_case.matches_swap_pair(offered_faucet, requested_faucet));
+        let network_use_case = NoteTag::for_public_use_case(
+            NoteTag::SWAP_USE_CASE_ID,
+            0xcdab,
+            NoteExecutionMode::Network,
+        )
+        .unwrap();
+        assert!(!network_use_case.matches_swap_pair(offered_faucet, requested_faucet));
+        let network_account = NoteTag(0xcdab);
+        assert!(!network_account.matches_swap_pair(offered_faucet, requested_faucet));
+    }
+
+    /// Characterizes the false positives of [`NoteTag::matches_swap_pair`]: the tag of a pair
+    /// matches another pair if and only if both offered and both requested faucet IDs share their
+    /// most significant byte, regardless of the rest of the IDs.
+    #[test]
+    fn test_swap_tag_collisions() {
+        let faucets = [
+            faucet_prefix(0x00, 0x00),
+            faucet_prefix(0x00, 0x01),
+            faucet_prefix(0x01, 0x00),
+            faucet_prefix(0x80, 0x00),
+            faucet_prefix(0xff, 0x00),
+            faucet_prefix(0xff, 0xff),
+        ];
+        let msb = |faucet: AccountIdPrefix| u64::from(faucet) >> 56;
+
+        let mut matches = 0;
+        let mut false_positives = 0;
+        for &offered in faucets.iter() {
+            for &requested in faucets.iter() {
+                let tag = NoteTag::for_swap(offered, requested);
+
+                for &other_offered in faucets.iter() {
+                    for &other_requested in faucets.iter() {
+                        let is_match = tag.matches_swap_pair(other_offered, other_requested);
+                        let shares_msb = msb(offered) == msb(other_offered)
+                            && msb(requested) == msb(other_requested);
+                        assert_eq!(is_match, shares_msb);
+                        assert_eq!(
+                            is_match,
+                            tag == NoteTag::for_swap(other_offered, other_requested)
+                        );
+
+                        if is_match {
+                            matches += 1;
+                            if (offered, requested) != (other_offered, other_requested) {
+                                false_positives += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // The faucets are grouped by their most significant byte into groups of sizes [2, 1, 1, 2],
+        // so a pair matches (sum of squared group sizes)^2 = 10^2 pairs in total, 36 of which are
+        // the pairs themselves.
+        assert_eq!(matches, 100);
+        assert_eq!(false_positives, 64);
+    }
+
+    #[test]
+    fn test_string_representation_roundtrip() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let network_account_tag =
+            NoteTag::from_account_id(account_id, NoteExecutionMode::Network).unwrap();
+
+        let tags = [
+            (
+                network_account_tag,
+                format!("network-account:{:#010x}", network_account_tag.inner()),
+            ),
+            (
+                NoteTag::for_public_use_case(1337, 42, NoteExecutionMode::Network).unwrap(),
+                "network-public-usecase:1337:42".into(),
+            ),
+            (
+                NoteTag::for_public_use_case(1337, 42, NoteExecutionMode::Local).unwrap(),
+                "local-public-usecase:1337:42".into(),
+            ),
+            (NoteTag::for_local_use_case(1337, 42).unwrap(), "local-usecase:1337:42".into()),
+            (
+                NoteTag::for_local_use_case((1 << 14) - 1, u16::MAX).unwrap(),
+                "local-usecase:16383:65535".into(),
+            ),
+        ];
+
+        for (tag, string) in tags {
+            assert_eq!(tag.to_string(), string);
+            assert_eq!(string.parse::<NoteTag>().unwrap(), tag);
+        }
+
+        // every bit pattern corresponds to one of the variants
+        for tag in
+            [0, 0x3fff_ffff, 0x4000_0000, 0x8000_0000, 0xc000_0000, u32::MAX].map(NoteTag::from)
+        {
+            assert_eq!(tag.to_string().parse::<NoteTag>().unwrap(), tag);
+        }
+
+        // local account tags share their bit pattern with local use case tags
+        let local_account_tag =
+            NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+        assert!(local_account_tag.to_string().starts_with("local-usecase:"));
+        assert_eq!(local_account_tag.to_string().parse::<NoteTag>().unwrap(), local_account_tag);
+    }
+
+    #[test]
+    fn test_string_representation_errors() {
+        for string in
+            ["", "local-usecase", "local-usecase:1", "account:0x1234", "network-account:1234"]
+        {
+            assert_matches!(string.parse::<NoteTag>(), Err(NoteError::UnknownNoteTagFormat(_)));
+        }
+
+        for string in [
+            "local-usecase:1:x",
+            "local-usecase:1:65536",
+            "network-account:0xg",
+            "local-usecase:1:2:3",
+        ] {
+            assert_matches!(string.parse::<NoteTag>(), Err(NoteError::InvalidNoteTagValue { .. }));
+        }
+
+        assert_matches!(
+            "local-usecase:16384:0".parse::<NoteTag>(),
+            Err(NoteError::NoteTagUseCaseTooLarge(16384))
+        );
+        assert_matches!(
+            "network-account:0x40000000".parse::<NoteTag>(),
+            Err(NoteError::NoteTagAccountBitsTooLarge(0x4000_0000))
+        );
+    }
+
+    #[test]
+    fn test_parsed_tag_validation() {
+        // parsing does not bypass the validation of the tag against the note type
+        let tag: NoteTag = "local-public-usecase:1337:42".parse().unwrap();
+        assert_matches!(
+            tag.validate(NoteType::Private),
+            Err(NoteError::PublicUseCaseRequiresPublicNote(NoteType::Private))
+        );
+
+        let tag: NoteTag = "network-public-usecase:1337:42".parse().unwrap();
+        assert_matches!(
+            tag.validate(NoteType::Private),
+            Err(NoteError::NetworkExecutionRequiresPublicNote(NoteType::Private))
+        );
+
+        let tag: NoteTag = "local-usecase:1337:42".parse().unwrap();
+        assert!(tag.validate(NoteType::Private).is_ok());
+    }
 }
@@ -14,7 +14,7 @@ const ENCRYPTED: u8 = 0b11;
 // NOTE TYPE
 // ================================================================================================
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 pub enum NoteType {
     /// Notes with this type have only their hash published to the network.
@@ -0,0 +1,180 @@
+use super::{
+    recipient::compute_recipient_digest, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Digest, Note, NoteId, Nullifier, Serializable, Word,
+};
+
+// NULLIFIER LINK PROOF
+// ================================================================================================
+
+/// A proof that a [Nullifier] belongs to the note with a given [NoteId].
+///
+/// The ID and the nullifier of a note cannot be linked without knowing the note's details. This
+/// proof lets the holder of a note selectively reveal the link, e.g. to an auditor, by disclosing
+/// the preimage of the nullifier:
+///
+/// > hash(serial_num, script_root, inputs_commitment, assets_commitment)
+///
+/// The same four values also determine the note ID, so both identifiers can be recomputed from the
+/// proof. Thus, the proof reveals:
+/// - the serial number of the note.
+/// - the root of the note script.
+/// - the commitment to the note inputs, but not the inputs themselves.
+/// - the commitment to the note assets, but not the assets themselves.
+///
+/// Since the nullifier is a flat hash of these values, none of them can be omitted without the
+/// nullifier becoming unverifiable. In particular, the script root is always revealed.
+///
+/// Revealing the serial number does not allow the verifier to consume the note, as this also
+/// requires the note inputs and assets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierLinkProof {
+    serial_num: Word,
+    script_root: Digest,
+    inputs_commitment: Digest,
+    assets_commitment: Digest,
+}
+
+impl NullifierLinkProof {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NullifierLinkProof] for the note with the provided details.
+    pub fn new(note: &Note) -> Self {
+        Self {
+            serial_num: note.serial_num(),
+            script_root: note.script().hash(),
+            inputs_commitment: note.inputs().commitment(),
+            assets_commitment: note.assets().commitment(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note ID implied by this proof.
+    pub fn note_id(&self) -> NoteId {
+        let recipient =
+            compute_recipient_digest(self.serial_num, self.script_root, self.inputs_commitment);
+        NoteId::new(recipient, self.assets_commitment)
+    }
+
+    /// Returns the nullifier implied by this proof.
+    pub fn nullifier(&self) -> Nullifier {
+        Nullifier::new(
+            self.script_root,
+            self.inputs_commitment,
+            self.assets_commitment,
+            self.serial_num,
+        )
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `true` if this proof shows that the provided nullifier belongs to the note with the
+    /// provided ID, `false` otherwise.
+    pub fn verify(&self, note_id: NoteId, nullifier: Nullifier) -> bool {
+        self.note_id() == note_id && self.nullifier() == nullifier
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierLinkProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.serial_num.write_into(target);
+        self.script_root.write_into(target);
+        self.inputs_commitment.write_into(target);
+        self.assets_commitment.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierLinkProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let serial_num = Word::read_from(source)?;
+        let script_root = Digest::read_from(source)?;
+        let inputs_commitment = Digest::read_from(source)?;
+        let assets_commitment = Digest::read_from(source)?;
+
+        Ok(Self {
+            serial_num,
+            script_root,
+            inputs_commitment,
+            assets_commitment,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        asset::FungibleAsset,
+        note::{
+            NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata,
+            NoteRecipient, NoteScript, NoteTag, NoteType,
+        },
+        Felt, ZERO,
+    };
+
+    fn mock_note(serial_num: Word, inputs: NoteInputs) -> Note {
+        let metadata = NoteMetadata::new(
+            FungibleAsset::mock_issuer(),
+            NoteType::Private,
+            NoteTag::from_account_id(FungibleAsset::mock_issuer(), NoteExecutionMode::Local)
+                .unwrap(),
+            NoteExecutionHint::None,
+            ZERO,
+        )
+        .unwrap();
+        let recipient = NoteRecipient::new(serial_num, NoteScript::mock(), inputs);
+
+        Note::new(NoteAssets::new(vec![FungibleAsset::mock(100)]).unwrap(), metadata, recipient)
+    }
+
+    #[test]
+    fn nullifier_link_proof() {
+        let inputs = NoteInputs::new(vec![Felt::new(0xaaaa_bbbb), Felt::new(0xcccc_dddd)]).unwrap();
+        let note = mock_note([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)], inputs);
+        let other_note = mock_note([Felt::new(5); 4], NoteInputs::default());
+
+        let proof = note.nullifier_link_proof();
+        assert_eq!(proof.note_id(), note.id());
+        assert_eq!(proof.nullifier(), note.nullifier());
+        assert!(proof.verify(note.id(), note.nullifier()));
+
+        // the proof does not link the note to the nullifier of another note or vice versa
+        assert!(!proof.verify(note.id(), other_note.nullifier()));
+        assert!(!proof.verify(other_note.id(), note.nullifier()));
+        assert!(!other_note.nullifier_link_proof().verify(note.id(), note.nullifier()));
+
+        let bytes = proof.to_bytes();
+        assert_eq!(NullifierLinkProof::read_from_bytes(&bytes).unwrap(), proof);
+    }
+
+    #[test]
+    fn nullifier_link_proof_hides_note_contents() {
+        let inputs = NoteInputs::new(vec![Felt::new(0xaaaa_bbbb), Felt::new(0xcccc_dddd)]).unwrap();
+        let note = mock_note([Felt::new(1); 4], inputs);
+
+        let bytes = note.nullifier_link_proof().to_bytes();
+        let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|window| window == needle);
+
+        // the note inputs and assets are revealed only through their commitments
+        for input in note.inputs().values() {
+            assert!(!contains(&input.as_int().to_le_bytes()));
+        }
+        for asset in note.assets().iter() {
+            assert!(!contains(&Word::from(*asset).to_bytes()));
+        }
+        assert!(contains(&note.inputs().commitment().to_bytes()));
+        assert!(contains(&note.assets().commitment().to_bytes()));
+
+        // the script root is part of the nullifier preimage and thus always revealed
+        assert!(contains(&note.script().hash().to_bytes()));
+    }
+}
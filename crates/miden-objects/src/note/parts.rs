@@ -0,0 +1,216 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Note, NoteAssets, NoteError,
+    NoteHeader, NoteId, NoteMetadata, NoteRecipient, Serializable,
+};
+
+// NOTE PARTS
+// ================================================================================================
+
+/// A note which is being reconstructed from its header and separately obtained details.
+///
+/// This is useful when only the [NoteHeader] of a note is known initially (e.g., from a block),
+/// while its assets and recipient are obtained later and possibly from different sources. Note
+/// parts can be displayed as soon as any of the details are known, and can be converted into a
+/// full [Note] via [NoteParts::try_into_full] once both the assets and the recipient are present.
+///
+/// Unlike [super::PartialNote], which always contains the assets and the recipient digest, all
+/// details of note parts are optional.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteParts {
+    header: NoteHeader,
+    assets: Option<NoteAssets>,
+    recipient: Option<NoteRecipient>,
+}
+
+impl NoteParts {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [NoteParts] containing only the provided note header.
+    pub fn new(header: NoteHeader) -> Self {
+        Self { header, assets: None, recipient: None }
+    }
+
+    /// Sets the assets of the note.
+    pub fn with_assets(mut self, assets: NoteAssets) -> Self {
+        self.assets = Some(assets);
+        self
+    }
+
+    /// Sets the recipient of the note.
+    pub fn with_recipient(mut self, recipient: NoteRecipient) -> Self {
+        self.recipient = Some(recipient);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the header of the note.
+    pub fn header(&self) -> &NoteHeader {
+        &self.header
+    }
+
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.header.id()
+    }
+
+    /// Returns the metadata of the note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        self.header.metadata()
+    }
+
+    /// Returns the assets of the note, if they are known.
+    pub fn assets(&self) -> Option<&NoteAssets> {
+        self.assets.as_ref()
+    }
+
+    /// Returns the recipient of the note, if it is known.
+    pub fn recipient(&self) -> Option<&NoteRecipient> {
+        self.recipient.as_ref()
+    }
+
+    /// Returns `true` if both the assets and the recipient of the note are known.
+    pub fn is_complete(&self) -> bool {
+        self.assets.is_some() && self.recipient.is_some()
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Converts these note parts into a full [Note].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The assets or the recipient of the note are not known.
+    /// - The ID of the note built from the assets and the recipient does not match the ID in the
+    ///   note header.
+    pub fn try_into_full(self) -> Result<Note, NoteError> {
+        let assets = self.assets.ok_or(NoteError::NotePartsMissingAssets(self.header.id()))?;
+        let recipient =
+            self.recipient.ok_or(NoteError::NotePartsMissingRecipient(self.header.id()))?;
+
+        let note = Note::new(assets, *self.header.metadata(), recipient);
+        if note.id() != self.header.id() {
+            return Err(NoteError::NotePartsIdMismatch {
+                expected: self.header.id(),
+                actual: note.id(),
+            });
+        }
+
+        Ok(note)
+    }
+}
+
+impl From<NoteHeader> for NoteParts {
+    fn from(header: NoteHeader) -> Self {
+        Self::new(header)
+    }
+}
+
+impl From<Note> for NoteParts {
+    fn from(note: Note) -> Self {
+        Self::new(*note.header())
+            .with_assets(note.assets().clone())
+            .with_recipient(note.recipient().clone())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteParts {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.header.write_into(target);
+        self.assets.write_into(target);
+        self.recipient.write_into(target);
+    }
+}
+
+impl Deserializable for NoteParts {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = NoteHeader::read_from(source)?;
+        let assets = Option::<NoteAssets>::read_from(source)?;
+        let recipient = Option::<NoteRecipient>::read_from(source)?;
+
+        Ok(Self { header, assets, recipient })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::{
+        Deserializable, Note, NoteAssets, NoteError, NoteHeader, NoteMetadata, NoteParts,
+        NoteRecipient, Serializable,
+    };
+    use crate::{
+        asset::FungibleAsset,
+        note::{NoteExecutionHint, NoteInputs, NoteScript, NoteTag, NoteType},
+        Felt, ZERO,
+    };
+
+    fn mock_note() -> Note {
+        let metadata = NoteMetadata::new(
+            FungibleAsset::mock_issuer(),
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::None,
+            ZERO,
+        )
+        .unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let recipient =
+            NoteRecipient::new(serial_num, NoteScript::mock(), NoteInputs::new(vec![]).unwrap());
+
+        Note::new(NoteAssets::new(vec![FungibleAsset::mock(100)]).unwrap(), metadata, recipient)
+    }
+
+    #[test]
+    fn note_parts_completed_from_header() {
+        let note = mock_note();
+
+        let parts = NoteParts::from(*note.header());
+        assert_eq!(parts.id(), note.id());
+        assert_eq!(parts.metadata(), note.metadata());
+        assert!(parts.assets().is_none() && parts.recipient().is_none());
+        assert!(!parts.is_complete());
+        assert_matches!(
+            parts.clone().try_into_full(),
+            Err(NoteError::NotePartsMissingAssets(id)) if id == note.id()
+        );
+
+        // the assets are obtained first and can be displayed before the note is complete
+        let parts = parts.with_assets(note.assets().clone());
+        assert_eq!(parts.assets(), Some(note.assets()));
+        assert!(!parts.is_complete());
+        assert_matches!(
+            parts.clone().try_into_full(),
+            Err(NoteError::NotePartsMissingRecipient(id)) if id == note.id()
+        );
+
+        let parts = parts.with_recipient(note.recipient().clone());
+        assert!(parts.is_complete());
+        assert_eq!(NoteParts::read_from_bytes(&parts.to_bytes()).unwrap(), parts);
+        assert_eq!(parts.try_into_full().unwrap(), note);
+    }
+
+    #[test]
+    fn note_parts_with_mismatching_details() {
+        let note = mock_note();
+
+        let parts = NoteParts::new(NoteHeader::new(note.id(), *note.metadata()))
+            .with_assets(NoteAssets::new(vec![FungibleAsset::mock(99)]).unwrap())
+            .with_recipient(note.recipient().clone());
+
+        assert_matches!(
+            parts.try_into_full(),
+            Err(NoteError::NotePartsIdMismatch { expected, .. }) if expected == note.id()
+        );
+    }
+}
@@ -28,7 +28,7 @@ pub struct NoteRecipient {
 
 impl NoteRecipient {
     pub fn new(serial_num: Word, script: NoteScript, inputs: NoteInputs) -> Self {
-        let digest = compute_recipient_digest(serial_num, &script, &inputs);
+        let digest = compute_recipient_digest(serial_num, script.hash(), inputs.commitment());
         Self { serial_num, script, inputs, digest }
     }
 
@@ -67,10 +67,15 @@ impl NoteRecipient {
     }
 }
 
-fn compute_recipient_digest(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Digest {
+/// Computes the digest of a recipient from its serial number, script root and inputs commitment.
+pub(super) fn compute_recipient_digest(
+    serial_num: Word,
+    script_root: Digest,
+    inputs_commitment: Digest,
+) -> Digest {
     let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-    let merge_script = Hasher::merge(&[serial_num_hash, script.hash()]);
-    Hasher::merge(&[merge_script, inputs.commitment()])
+    let merge_script = Hasher::merge(&[serial_num_hash, script_root]);
+    Hasher::merge(&[merge_script, inputs_commitment])
 }
 
 // SERIALIZATION
@@ -92,6 +97,10 @@ impl Serializable for NoteRecipient {
         inputs.write_into(target);
         serial_num.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.script.get_size_hint() + self.inputs.get_size_hint() + self.serial_num.get_size_hint()
+    }
 }
 
 impl Deserializable for NoteRecipient {
@@ -2,13 +2,14 @@ use alloc::{sync::Arc, vec::Vec};
 use core::fmt::Display;
 
 use assembly::{Assembler, Compile};
+use miden_crypto::utils::SliceReader;
 use vm_core::{
     mast::{MastForest, MastNodeId},
     prettier::PrettyPrint,
     Program,
 };
 
-use super::{Digest, Felt};
+use super::{Digest, Felt, ScriptInfo};
 use crate::{
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     NoteError,
@@ -21,10 +22,15 @@ use crate::{
 ///
 /// A note's script represents a program which must be executed for a note to be consumed. As such
 /// it defines the rules and side effects of consuming a given note.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// A script can optionally carry a [ScriptInfo] with its human-readable name and version. The info
+/// is serialized together with the script, but it is not part of the script's MAST root and is
+/// ignored when comparing scripts for equality.
+#[derive(Debug, Clone)]
 pub struct NoteScript {
     mast: Arc<MastForest>,
     entrypoint: MastNodeId,
+    info: Option<ScriptInfo>,
 }
 
 impl NoteScript {
@@ -36,6 +42,7 @@ impl NoteScript {
         Self {
             entrypoint: code.entrypoint(),
             mast: code.mast_forest().clone(),
+            info: None,
         }
     }
 
@@ -51,6 +58,19 @@ impl NoteScript {
         Ok(Self::new(program))
     }
 
+    /// Returns a new [NoteScript] compiled from the provided source code using the specified
+    /// assembler, with the provided [ScriptInfo] attached.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_named(
+        source_code: impl Compile,
+        assembler: Assembler,
+        info: ScriptInfo,
+    ) -> Result<Self, NoteError> {
+        Ok(Self::compile(source_code, assembler)?.with_info(info))
+    }
+
     /// Returns a new [NoteScript] deserialized from the provided bytes.
     ///
     /// # Errors
@@ -65,7 +85,15 @@ impl NoteScript {
     /// Panics if the specified entrypoint is not in the provided MAST forest.
     pub fn from_parts(mast: Arc<MastForest>, entrypoint: MastNodeId) -> Self {
         assert!(mast.get_node_by_id(entrypoint).is_some());
-        Self { mast, entrypoint }
+        Self { mast, entrypoint, info: None }
+    }
+
+    /// Returns this [NoteScript] with the provided [ScriptInfo] attached.
+    ///
+    /// The info does not change the MAST root of the script.
+    pub fn with_info(mut self, info: ScriptInfo) -> Self {
+        self.info = Some(info);
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -80,11 +108,46 @@ impl NoteScript {
     pub fn mast(&self) -> Arc<MastForest> {
         self.mast.clone()
     }
+
+    /// Returns the digest of the entrypoint of this note script.
+    ///
+    /// The digest is the MAST root of the script, i.e., it is equal to [NoteScript::hash]. Scripts
+    /// compiled from the same source code using the same libraries have the same entrypoint, so it
+    /// can be used to identify a script without executing it.
+    pub fn entrypoint(&self) -> Digest {
+        self.mast[self.entrypoint].digest()
+    }
+
+    /// Returns a reference to the [MastForest] backing this note script.
+    pub fn mast_forest(&self) -> &Arc<MastForest> {
+        &self.mast
+    }
+
+    /// Returns the [ScriptInfo] attached to this note script, if any.
+    pub fn info(&self) -> Option<&ScriptInfo> {
+        self.info.as_ref()
+    }
+}
+
+// EQUALITY
+// ================================================================================================
+
+/// Two note scripts are equal if they have the same MAST and entrypoint, regardless of their
+/// [ScriptInfo].
+impl PartialEq for NoteScript {
+    fn eq(&self, other: &Self) -> bool {
+        self.mast == other.mast && self.entrypoint == other.entrypoint
+    }
 }
 
+impl Eq for NoteScript {}
+
 // CONVERSIONS INTO NOTE SCRIPT
 // ================================================================================================
 
+/// Encodes the MAST of the note script as field elements, as expected by the transaction kernel.
+///
+/// The [ScriptInfo] of the script is not encoded.
 impl From<&NoteScript> for Vec<Felt> {
     fn from(script: &NoteScript) -> Self {
         let mut bytes = script.mast.to_bytes();
@@ -164,15 +227,61 @@ impl Serializable for NoteScript {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.mast.write_into(target);
         target.write_u32(self.entrypoint.as_u32());
+        self.info.write_into(target);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        // TODO: Replace with proper calculation.
+        let mut mast_forest_target = Vec::new();
+        self.mast.write_into(&mut mast_forest_target);
+
+        mast_forest_target.len()
+            + self.entrypoint.as_u32().get_size_hint()
+            + self.info.get_size_hint()
     }
 }
 
-impl Deserializable for NoteScript {
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+impl NoteScript {
+    /// Reads a note script from the source, using the provided closure to read the script info
+    /// which follows the entrypoint.
+    fn read_with_info<R: ByteReader>(
+        source: &mut R,
+        read_info: impl FnOnce(&mut R) -> Result<Option<ScriptInfo>, DeserializationError>,
+    ) -> Result<Self, DeserializationError> {
         let mast = MastForest::read_from(source)?;
         let entrypoint = MastNodeId::from_u32_safe(source.read_u32()?, &mast)?;
+        let info = read_info(source)?;
 
-        Ok(Self::from_parts(Arc::new(mast), entrypoint))
+        Ok(Self {
+            info,
+            ..Self::from_parts(Arc::new(mast), entrypoint)
+        })
+    }
+}
+
+impl Deserializable for NoteScript {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_with_info(source, Option::<ScriptInfo>::read_from)
+    }
+
+    fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+
+        // the script info was added after the entrypoint, so scripts serialized without it end
+        // right after the entrypoint
+        let note_script = Self::read_with_info(&mut source, |source| {
+            if source.has_more_bytes() {
+                Option::<ScriptInfo>::read_from(source)
+            } else {
+                Ok(None)
+            }
+        })?;
+
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Ok(note_script)
     }
 }
 
@@ -199,8 +308,14 @@ impl Display for NoteScript {
 
 #[cfg(test)]
 mod tests {
-    use super::{Assembler, Felt, NoteScript, Vec};
-    use crate::testing::note::DEFAULT_NOTE_CODE;
+    use semver::Version;
+
+    use super::{Assembler, Deserializable, Felt, NoteScript, ScriptInfo, Serializable, Vec};
+    use crate::{
+        note::{NoteInputs, NoteRecipient},
+        testing::note::DEFAULT_NOTE_CODE,
+        Word,
+    };
 
     #[test]
     fn test_note_script_to_from_felt() {
@@ -213,4 +328,64 @@ mod tests {
 
         assert_eq!(note_script, decoded);
     }
+
+    #[test]
+    fn note_script_serialization_with_and_without_info() {
+        let note_script = NoteScript::compile(DEFAULT_NOTE_CODE, Assembler::default()).unwrap();
+        let decoded = NoteScript::read_from_bytes(&note_script.to_bytes()).unwrap();
+        assert_eq!(decoded, note_script);
+        assert!(decoded.info().is_none());
+
+        for info in [
+            ScriptInfo::new("p2id"),
+            ScriptInfo::new("p2id").with_version(Version::new(1, 2, 3)),
+        ] {
+            let named_script =
+                NoteScript::compile_named(DEFAULT_NOTE_CODE, Assembler::default(), info.clone())
+                    .unwrap();
+            let decoded = NoteScript::read_from_bytes(&named_script.to_bytes()).unwrap();
+            assert_eq!(decoded, named_script);
+            assert_eq!(decoded.info(), Some(&info));
+        }
+    }
+
+    #[test]
+    fn note_script_serialized_without_info_still_parses() {
+        let note_script = NoteScript::compile(DEFAULT_NOTE_CODE, Assembler::default()).unwrap();
+
+        // scripts serialized before the script info was added end right after the entrypoint
+        let mut legacy_bytes = note_script.mast().to_bytes();
+        legacy_bytes.extend(note_script.entrypoint.as_u32().to_le_bytes());
+
+        let decoded = NoteScript::read_from_bytes(&legacy_bytes).unwrap();
+        assert_eq!(decoded, note_script);
+        assert!(decoded.info().is_none());
+    }
+
+    #[test]
+    fn note_script_equality_ignores_info() {
+        let note_script = NoteScript::compile(DEFAULT_NOTE_CODE, Assembler::default()).unwrap();
+        let named_script = note_script.clone().with_info(ScriptInfo::new("default"));
+
+        assert_eq!(named_script, note_script);
+        assert_ne!(named_script.to_bytes(), note_script.to_bytes());
+    }
+
+    #[test]
+    fn note_script_info_does_not_affect_commitments() {
+        let note_script = NoteScript::compile(DEFAULT_NOTE_CODE, Assembler::default()).unwrap();
+        let named_script = note_script
+            .clone()
+            .with_info(ScriptInfo::new("default").with_version(Version::new(0, 1, 0)));
+
+        assert_eq!(named_script.hash(), note_script.hash());
+        assert_eq!(Vec::<Felt>::from(&named_script), Vec::<Felt>::from(&note_script));
+
+        let serial_num: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let inputs = NoteInputs::new(vec![Felt::new(5)]).unwrap();
+        assert_eq!(
+            NoteRecipient::new(serial_num, named_script, inputs.clone()).digest(),
+            NoteRecipient::new(serial_num, note_script, inputs).digest()
+        );
+    }
 }
@@ -0,0 +1,72 @@
+use alloc::string::{String, ToString};
+use core::str::FromStr;
+
+use semver::Version;
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::DeserializationError;
+
+// SCRIPT INFO
+// ================================================================================================
+
+/// Human-readable information about a note or transaction script, intended for tooling such as
+/// explorers and debuggers.
+///
+/// The info is purely informational: it is carried along with the serialized script, but it is not
+/// part of the script's MAST root and hence does not affect any commitment the script is part of.
+/// For the same reason, it is ignored when comparing scripts for equality.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptInfo {
+    name: String,
+    version: Option<Version>,
+}
+
+impl ScriptInfo {
+    /// Returns a new [ScriptInfo] with the provided name and no version.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), version: None }
+    }
+
+    /// Sets the semantic version of the script.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Returns the name of the script.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the semantic version of the script, if any.
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ScriptInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.name.write_into(target);
+        self.version.as_ref().map(Version::to_string).write_into(target);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        self.name.get_size_hint() + self.version.as_ref().map(Version::to_string).get_size_hint()
+    }
+}
+
+impl Deserializable for ScriptInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let name = String::read_from(source)?;
+        let version = Option::<String>::read_from(source)?
+            .map(|version| {
+                Version::from_str(&version)
+                    .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+            })
+            .transpose()?;
+
+        Ok(Self { name, version })
+    }
+}
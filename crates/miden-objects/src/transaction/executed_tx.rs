@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 use core::cell::OnceCell;
 
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
-use vm_processor::DeserializationError;
+use vm_processor::{DeserializationError, NUM_RAND_ROWS};
 
 use super::{
     Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote,
@@ -11,6 +11,12 @@ use super::{
 };
 use crate::account::AccountCode;
 
+// CONSTANTS
+// ================================================================================================
+
+/// The minimum length of the execution trace of a program executed by the VM.
+const MIN_TRACE_LENGTH: usize = 64;
+
 // EXECUTED TRANSACTION
 // ================================================================================================
 
@@ -133,6 +139,28 @@ impl ExecutedTransaction {
         &self.tx_measurements
     }
 
+    /// Returns an estimate of the length of the execution trace which is built when this
+    /// transaction is proven.
+    ///
+    /// The estimate is computed from the total number of cycles spent executing the transaction
+    /// (see [TransactionMeasurements::total_cycles]) using the padding rule of the VM: the trace
+    /// is extended by the rows reserved for random values and padded to the next power of two, but
+    /// it is never shorter than 64 rows.
+    ///
+    /// Since the estimate only accounts for the executed cycles, the actual trace may be longer
+    /// if the chiplets or the range checker require more rows than the number of executed cycles.
+    ///
+    /// Returns `None` if the cycle counts were not measured, which is the case unless the
+    /// transaction was executed with tracing enabled.
+    pub fn trace_length_estimate(&self) -> Option<usize> {
+        let total_cycles = self.tx_measurements.total_cycles();
+        if total_cycles == 0 {
+            return None;
+        }
+
+        Some((total_cycles + NUM_RAND_ROWS).next_power_of_two().max(MIN_TRACE_LENGTH))
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -0,0 +1,150 @@
+use alloc::vec::Vec;
+
+use miden_crypto::merkle::{LeafIndex, MerkleError, MerklePath, SmtProof};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::DeserializationError;
+
+use super::{AccountHeader, AccountId, Digest};
+use crate::{
+    account::{AccountCode, AccountStorageHeader},
+    ACCOUNT_TREE_DEPTH,
+};
+
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// Contains the public state of a foreign account which is required to invoke its procedures
+/// during the execution of a transaction against another account.
+///
+/// Foreign account inputs consist of:
+/// - The header of the foreign account.
+/// - The header of the foreign account's storage.
+/// - The code of the foreign account.
+/// - Merkle proofs of the storage map entries which are read by the invoked procedures.
+/// - The Merkle path from the account root of the reference block to the foreign account's
+///   commitment, which proves that the account state is the one recorded in that block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    account_header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    account_code: AccountCode,
+    storage_map_proofs: Vec<SmtProof>,
+    account_witness: MerklePath,
+}
+
+impl ForeignAccountInputs {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ForeignAccountInputs] instantiated from the provided components.
+    pub fn new(
+        account_header: AccountHeader,
+        storage_header: AccountStorageHeader,
+        account_code: AccountCode,
+        storage_map_proofs: Vec<SmtProof>,
+        account_witness: MerklePath,
+    ) -> Self {
+        Self {
+            account_header,
+            storage_header,
+            account_code,
+            storage_map_proofs,
+            account_witness,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the foreign account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_header.id()
+    }
+
+    /// Returns the header of the foreign account.
+    pub fn account_header(&self) -> &AccountHeader {
+        &self.account_header
+    }
+
+    /// Returns the header of the foreign account's storage.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the code of the foreign account.
+    pub fn account_code(&self) -> &AccountCode {
+        &self.account_code
+    }
+
+    /// Returns the Merkle proofs of the foreign account's storage map entries.
+    pub fn storage_map_proofs(&self) -> &[SmtProof] {
+        &self.storage_map_proofs
+    }
+
+    /// Returns the Merkle path from the account root to the foreign account's commitment.
+    pub fn account_witness(&self) -> &MerklePath {
+        &self.account_witness
+    }
+
+    /// Computes the account root implied by the account witness and the foreign account's
+    /// commitment.
+    ///
+    /// The inputs are valid for a block if the returned root is equal to the account root of that
+    /// block.
+    ///
+    /// # Errors
+    /// Returns an error if the length of the account witness does not match the depth of the
+    /// account tree.
+    pub fn compute_account_root(&self) -> Result<Digest, MerkleError> {
+        let leaf_index = LeafIndex::<ACCOUNT_TREE_DEPTH>::from(self.account_id());
+        self.account_witness
+            .compute_root(leaf_index.value(), self.account_header.hash())
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes these inputs and returns their individual components.
+    pub fn into_parts(
+        self,
+    ) -> (AccountHeader, AccountStorageHeader, AccountCode, Vec<SmtProof>, MerklePath) {
+        (
+            self.account_header,
+            self.storage_header,
+            self.account_code,
+            self.storage_map_proofs,
+            self.account_witness,
+        )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ForeignAccountInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_header.write_into(target);
+        self.storage_header.write_into(target);
+        self.account_code.write_into(target);
+        self.storage_map_proofs.write_into(target);
+        self.account_witness.write_into(target);
+    }
+}
+
+impl Deserializable for ForeignAccountInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_header = AccountHeader::read_from(source)?;
+        let storage_header = AccountStorageHeader::read_from(source)?;
+        let account_code = AccountCode::read_from(source)?;
+        let storage_map_proofs = Vec::<SmtProof>::read_from(source)?;
+        let account_witness = MerklePath::read_from(source)?;
+
+        Ok(Self::new(
+            account_header,
+            storage_header,
+            account_code,
+            storage_map_proofs,
+            account_witness,
+        ))
+    }
+}
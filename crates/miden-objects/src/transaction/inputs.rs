@@ -236,6 +236,15 @@ impl<T: ToInputNoteCommitments> InputNotes<T> {
         &self.notes[idx]
     }
 
+    /// Returns the index and a reference to the note with the specified nullifier, or `None` if
+    /// this [InputNotes] does not contain such a note.
+    ///
+    /// Since nullifiers are guaranteed to be unique at construction time, at most one note can
+    /// match.
+    pub fn find_by_nullifier(&self, nullifier: Nullifier) -> Option<(usize, &T)> {
+        self.iter_with_index().find(|(_, note)| note.nullifier() == nullifier)
+    }
+
     // ITERATORS
     // --------------------------------------------------------------------------------------------
 
@@ -244,6 +253,13 @@ impl<T: ToInputNoteCommitments> InputNotes<T> {
         self.notes.iter()
     }
 
+    /// Returns an iterator over notes in this [InputNotes] together with their indices.
+    ///
+    /// The index of each note is the same as the one accepted by [InputNotes::get_note].
+    pub fn iter_with_index(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.notes.iter().enumerate()
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -253,6 +269,34 @@ impl<T: ToInputNoteCommitments> InputNotes<T> {
     }
 }
 
+impl InputNotes<InputNote> {
+    /// Returns the index and a reference to the note with the specified ID, or `None` if this
+    /// [InputNotes] does not contain such a note.
+    ///
+    /// Since a note's nullifier is derived from its details, the uniqueness of nullifiers enforced
+    /// at construction time implies that at most one note can match.
+    pub fn find_by_id(&self, note_id: NoteId) -> Option<(usize, &InputNote)> {
+        self.iter_with_index().find(|(_, note)| note.id() == note_id)
+    }
+
+    /// Returns an iterator over the authenticated notes in this [InputNotes], i.e., the notes
+    /// whose inclusion in the chain is verified by the transaction kernel.
+    pub fn authenticated(&self) -> impl Iterator<Item = &InputNote> {
+        self.iter().filter(|note| note.is_authenticated())
+    }
+
+    /// Returns an iterator over the unauthenticated notes in this [InputNotes], i.e., the notes
+    /// whose inclusion in the chain is verified by the rollup kernels instead.
+    pub fn unauthenticated(&self) -> impl Iterator<Item = &InputNote> {
+        self.iter().filter(|note| !note.is_authenticated())
+    }
+
+    /// Returns the number of unauthenticated notes in this [InputNotes].
+    pub fn num_unauthenticated(&self) -> usize {
+        self.unauthenticated().count()
+    }
+}
+
 impl<T> IntoIterator for InputNotes<T> {
     type Item = T;
     type IntoIter = alloc::vec::IntoIter<Self::Item>;
@@ -388,6 +432,11 @@ impl InputNote {
     pub fn location(&self) -> Option<&NoteLocation> {
         self.proof().map(|proof| proof.location())
     }
+
+    /// Returns true if this note is authenticated, i.e., if it contains an inclusion proof.
+    pub fn is_authenticated(&self) -> bool {
+        matches!(self, Self::Authenticated { .. })
+    }
 }
 
 /// Validates whether the provided note belongs to the note tree of the specified block.
@@ -517,3 +566,136 @@ pub fn validate_account_seed(
         (false, None) => Ok(()),
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::{InputNote, InputNotes};
+    use crate::{
+        account::AccountId,
+        block::BlockNumber,
+        crypto::merkle::MerklePath,
+        note::{
+            Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInclusionProof, NoteInputs,
+            NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        },
+        testing::account_id::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        transaction::InputNoteCommitment,
+        Felt, TransactionInputError, ZERO,
+    };
+
+    fn mock_input_note(serial_num: u64) -> InputNote {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            NoteTag::from_account_id(sender, NoteExecutionMode::Local).unwrap(),
+            NoteExecutionHint::none(),
+            ZERO,
+        )
+        .unwrap();
+        let recipient = NoteRecipient::new(
+            [Felt::new(serial_num), ZERO, ZERO, ZERO],
+            NoteScript::mock(),
+            NoteInputs::default(),
+        );
+
+        InputNote::unauthenticated(Note::new(NoteAssets::default(), metadata, recipient))
+    }
+
+    #[test]
+    fn input_notes_find_by_id_and_nullifier() {
+        let notes: Vec<_> = (0..4).map(mock_input_note).collect();
+        let input_notes = InputNotes::new(notes.clone()).unwrap();
+
+        for (idx, note) in notes.iter().enumerate() {
+            let (found_idx, found_note) = input_notes.find_by_id(note.id()).unwrap();
+            assert_eq!(found_idx, idx);
+            assert_eq!(found_note, note);
+
+            let (found_idx, found_note) =
+                input_notes.find_by_nullifier(note.note().nullifier()).unwrap();
+            assert_eq!(found_idx, idx);
+            assert_eq!(found_note, note);
+        }
+
+        let missing_note = mock_input_note(10);
+        assert!(input_notes.find_by_id(missing_note.id()).is_none());
+        assert!(input_notes.find_by_nullifier(missing_note.note().nullifier()).is_none());
+        assert!(InputNotes::<InputNote>::default().find_by_id(missing_note.id()).is_none());
+    }
+
+    #[test]
+    fn input_note_commitments_find_by_nullifier() {
+        let notes: Vec<_> = (0..3).map(mock_input_note).collect();
+        let commitments =
+            InputNotes::new(notes.iter().map(InputNoteCommitment::from).collect::<Vec<_>>())
+                .unwrap();
+
+        let (idx, commitment) = commitments.find_by_nullifier(notes[2].note().nullifier()).unwrap();
+        assert_eq!(idx, 2);
+        assert_eq!(commitment.header(), Some(notes[2].note().header()));
+        assert!(commitments.find_by_nullifier(mock_input_note(10).note().nullifier()).is_none());
+    }
+
+    #[test]
+    fn input_notes_with_duplicate_ids_cannot_be_constructed() {
+        let note = mock_input_note(0);
+        assert_matches!(
+            InputNotes::new(vec![note.clone(), note.clone()]),
+            Err(TransactionInputError::DuplicateInputNote(nullifier))
+                if nullifier == note.note().nullifier()
+        );
+    }
+
+    #[test]
+    fn input_notes_authenticated_and_unauthenticated() {
+        let proof =
+            NoteInclusionProof::new(BlockNumber::from(1), 0, MerklePath::default()).unwrap();
+        let notes: Vec<_> = (0..5)
+            .map(|serial_num| match mock_input_note(serial_num) {
+                note if serial_num % 2 == 0 => {
+                    InputNote::authenticated(note.note().clone(), proof.clone())
+                },
+                note => note,
+            })
+            .collect();
+        let input_notes = InputNotes::new(notes.clone()).unwrap();
+
+        let authenticated: Vec<_> = input_notes.authenticated().collect();
+        let unauthenticated: Vec<_> = input_notes.unauthenticated().collect();
+        assert_eq!(authenticated.len(), 3);
+        assert_eq!(unauthenticated.len(), 2);
+        assert_eq!(input_notes.num_unauthenticated(), 2);
+        assert!(authenticated.iter().all(|note| note.is_authenticated()));
+        assert!(unauthenticated.iter().all(|note| !note.is_authenticated()));
+
+        // the two iterators partition the whole set
+        for note in notes.iter() {
+            assert_ne!(authenticated.contains(&note), unauthenticated.contains(&note));
+        }
+        assert_eq!(authenticated.len() + unauthenticated.len(), input_notes.num_notes());
+
+        assert_eq!(InputNotes::<InputNote>::default().num_unauthenticated(), 0);
+    }
+
+    #[test]
+    fn input_notes_iter_with_index() {
+        let notes: Vec<_> = (0..3).map(mock_input_note).collect();
+        let input_notes = InputNotes::new(notes.clone()).unwrap();
+
+        let indexed: Vec<_> = input_notes.iter_with_index().collect();
+        assert_eq!(indexed.len(), notes.len());
+        for (idx, note) in indexed {
+            assert_eq!(note, &notes[idx]);
+            assert_eq!(input_notes.get_note(idx), note);
+        }
+    }
+}
@@ -8,6 +8,7 @@ use super::{
 
 mod chain_mmr;
 mod executed_tx;
+mod foreign_account;
 mod inputs;
 mod outputs;
 mod proven_tx;
@@ -17,6 +18,7 @@ mod tx_witness;
 
 pub use chain_mmr::ChainMmr;
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
+pub use foreign_account::ForeignAccountInputs;
 pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
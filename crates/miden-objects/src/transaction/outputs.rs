@@ -1,4 +1,8 @@
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
 use core::fmt::Debug;
 
 use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
@@ -7,7 +11,10 @@ use vm_processor::DeserializationError;
 use crate::{
     account::AccountHeader,
     block::BlockNumber,
-    note::{compute_note_hash, Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, PartialNote},
+    note::{
+        compute_note_hash, Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, NoteType,
+        PartialNote,
+    },
     Digest, Felt, Hasher, TransactionOutputError, Word, MAX_OUTPUT_NOTES_PER_TX,
 };
 // TRANSACTION OUTPUTS
@@ -108,6 +115,28 @@ impl OutputNotes {
         &self.notes[idx]
     }
 
+    /// Returns the index and a reference to the note with the specified ID, or `None` if this
+    /// [OutputNotes] does not contain such a note.
+    ///
+    /// Since note IDs are guaranteed to be unique at construction time, at most one note can
+    /// match.
+    pub fn find_by_id(&self, note_id: NoteId) -> Option<(usize, &OutputNote)> {
+        self.iter_with_index().find(|(_, note)| note.id() == note_id)
+    }
+
+    /// Returns the IDs of the notes in this [OutputNotes] grouped by their [NoteType].
+    ///
+    /// Within each group, the IDs are in the same order as the notes in this [OutputNotes]. Note
+    /// types for which there are no notes are not included in the returned map.
+    pub fn partition_by_type(&self) -> BTreeMap<NoteType, Vec<NoteId>> {
+        let mut partition = BTreeMap::<NoteType, Vec<NoteId>>::new();
+        for note in self.notes.iter() {
+            partition.entry(note.metadata().note_type()).or_default().push(note.id());
+        }
+
+        partition
+    }
+
     // ITERATORS
     // --------------------------------------------------------------------------------------------
 
@@ -115,6 +144,13 @@ impl OutputNotes {
     pub fn iter(&self) -> impl Iterator<Item = &OutputNote> {
         self.notes.iter()
     }
+
+    /// Returns an iterator over notes in this [OutputNotes] together with their indices.
+    ///
+    /// The index of each note is the same as the one accepted by [OutputNotes::get_note].
+    pub fn iter_with_index(&self) -> impl Iterator<Item = (usize, &OutputNote)> {
+        self.notes.iter().enumerate()
+    }
 }
 
 // SERIALIZATION
@@ -276,6 +312,17 @@ impl Serializable for OutputNote {
             },
         }
     }
+
+    fn get_size_hint(&self) -> usize {
+        // Size of the serialized enum tag.
+        let u8_size = 0u8.get_size_hint();
+
+        match self {
+            OutputNote::Full(note) => u8_size + note.get_size_hint(),
+            OutputNote::Partial(note) => u8_size + note.get_size_hint(),
+            OutputNote::Header(note) => u8_size + note.get_size_hint(),
+        }
+    }
 }
 
 impl Deserializable for OutputNote {
@@ -288,3 +335,99 @@ impl Deserializable for OutputNote {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::{OutputNote, OutputNotes};
+    use crate::{
+        account::AccountId,
+        note::{
+            Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata,
+            NoteRecipient, NoteScript, NoteTag, NoteType,
+        },
+        testing::account_id::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        Felt, TransactionOutputError, ZERO,
+    };
+
+    fn mock_output_note(serial_num: u64, note_type: NoteType) -> OutputNote {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let metadata = NoteMetadata::new(
+            sender,
+            note_type,
+            NoteTag::from_account_id(sender, NoteExecutionMode::Local).unwrap(),
+            NoteExecutionHint::none(),
+            ZERO,
+        )
+        .unwrap();
+        let recipient = NoteRecipient::new(
+            [Felt::new(serial_num), ZERO, ZERO, ZERO],
+            NoteScript::mock(),
+            NoteInputs::default(),
+        );
+
+        OutputNote::Full(Note::new(NoteAssets::default(), metadata, recipient))
+    }
+
+    #[test]
+    fn output_notes_find_by_id() {
+        let notes: Vec<_> = (0..4).map(|i| mock_output_note(i, NoteType::Public)).collect();
+        let output_notes = OutputNotes::new(notes.clone()).unwrap();
+
+        for (idx, note) in notes.iter().enumerate() {
+            let (found_idx, found_note) = output_notes.find_by_id(note.id()).unwrap();
+            assert_eq!(found_idx, idx);
+            assert_eq!(found_note, note);
+            assert_eq!(output_notes.get_note(found_idx), note);
+        }
+
+        let missing_note = mock_output_note(10, NoteType::Public);
+        assert!(output_notes.find_by_id(missing_note.id()).is_none());
+        assert!(OutputNotes::new(vec![]).unwrap().find_by_id(missing_note.id()).is_none());
+    }
+
+    #[test]
+    fn output_notes_with_duplicate_ids_cannot_be_constructed() {
+        let note = mock_output_note(0, NoteType::Public);
+        assert_matches!(
+            OutputNotes::new(vec![note.clone(), note.clone()]),
+            Err(TransactionOutputError::DuplicateOutputNote(note_id)) if note_id == note.id()
+        );
+    }
+
+    #[test]
+    fn output_notes_iter_with_index() {
+        let notes: Vec<_> = (0..3).map(|i| mock_output_note(i, NoteType::Private)).collect();
+        let output_notes = OutputNotes::new(notes.clone()).unwrap();
+
+        let indexed: Vec<_> = output_notes.iter_with_index().collect();
+        assert_eq!(indexed.len(), notes.len());
+        for (idx, note) in indexed {
+            assert_eq!(note, &notes[idx]);
+        }
+    }
+
+    #[test]
+    fn output_notes_partition_by_type() {
+        let private_0 = mock_output_note(0, NoteType::Private);
+        let public_1 = mock_output_note(1, NoteType::Public);
+        let private_2 = mock_output_note(2, NoteType::Private);
+        let output_notes =
+            OutputNotes::new(vec![private_0.clone(), public_1.clone(), private_2.clone()]).unwrap();
+
+        let partition = output_notes.partition_by_type();
+        assert_eq!(partition.len(), 2);
+        assert_eq!(partition[&NoteType::Private], vec![private_0.id(), private_2.id()]);
+        assert_eq!(partition[&NoteType::Public], vec![public_1.id()]);
+        assert!(!partition.contains_key(&NoteType::Encrypted));
+
+        assert!(OutputNotes::new(vec![]).unwrap().partition_by_type().is_empty());
+    }
+}
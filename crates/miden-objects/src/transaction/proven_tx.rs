@@ -1,5 +1,9 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
+use miden_crypto::utils::SliceReader;
 use miden_verifier::ExecutionProof;
 
 use super::{InputNote, ToInputNoteCommitments};
@@ -19,7 +23,10 @@ use crate::{
 
 /// Result of executing and proving a transaction. Contains all the data required to verify that a
 /// transaction was executed correctly.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Two proven transactions are equal regardless of their kernel version, see
+/// [ProvenTransaction::kernel_version].
+#[derive(Debug, Clone)]
 pub struct ProvenTransaction {
     /// A unique identifier for the transaction, see [TransactionId] for additional details.
     id: TransactionId,
@@ -42,6 +49,9 @@ pub struct ProvenTransaction {
 
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
+
+    /// The version of the transaction kernel build which produced the proof, if known.
+    kernel_version: Option<String>,
 }
 
 impl ProvenTransaction {
@@ -75,11 +85,24 @@ impl ProvenTransaction {
         &self.proof
     }
 
+    /// Returns the size of the serialized proof of the transaction in bytes.
+    pub fn proof_size_bytes(&self) -> usize {
+        self.proof.to_bytes().len()
+    }
+
     /// Returns the block reference the transaction was executed against.
     pub fn block_ref(&self) -> Digest {
         self.block_ref
     }
 
+    /// Returns the version of the transaction kernel build which produced the proof, if known.
+    ///
+    /// The version is metadata intended for diagnostics only. It is neither part of the proven
+    /// statement nor of the transaction ID, and thus cannot be trusted.
+    pub fn kernel_version(&self) -> Option<&str> {
+        self.kernel_version.as_deref()
+    }
+
     /// Returns an iterator of the headers of unauthenticated input notes in this transaction.
     pub fn get_unauthenticated_notes(&self) -> impl Iterator<Item = &NoteHeader> {
         self.input_notes.iter().filter_map(|note| note.header())
@@ -90,6 +113,16 @@ impl ProvenTransaction {
         self.expiration_block_num
     }
 
+    /// Returns `true` if the transaction can no longer be included in a block which references
+    /// the block with the provided number, i.e. if the expiration block number of the transaction
+    /// is at or below `block_num`.
+    ///
+    /// The expiration block number is bound to the transaction proof, so this check can be used to
+    /// cheaply discard expired transactions before verifying their proofs.
+    pub fn is_expired_at(&self, block_num: BlockNumber) -> bool {
+        self.expiration_block_num <= block_num
+    }
+
     /// Returns an iterator over the nullifiers of all input notes in this transaction.
     ///
     /// This includes both authenticated and unauthenticated notes.
@@ -97,10 +130,21 @@ impl ProvenTransaction {
         self.input_notes.iter().map(InputNoteCommitment::nullifier)
     }
 
-    // HELPER METHODS
+    // VALIDATION
     // --------------------------------------------------------------------------------------------
 
-    fn validate(self) -> Result<Self, ProvenTransactionError> {
+    /// Validates that the account update of this transaction has the shape required by the
+    /// storage mode of the account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account is public and the account update exceeds [`ACCOUNT_UPDATE_MAX_SIZE`].
+    /// - The account is public and the update does not contain the account details, or contains the
+    ///   full account state for an existing account, or a delta for a new account.
+    /// - The account is public and the full account state in the update does not match the account
+    ///   ID or the final account hash of the transaction.
+    /// - The account is private and the update contains account details.
+    pub fn validate_account_update(&self) -> Result<(), ProvenTransactionError> {
         if self.account_id().is_public() {
             self.account_update.validate()?;
 
@@ -144,10 +188,33 @@ impl ProvenTransaction {
             return Err(ProvenTransactionError::OffChainAccountWithDetails(self.account_id()));
         }
 
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn validate(self) -> Result<Self, ProvenTransactionError> {
+        self.validate_account_update()?;
+
         Ok(self)
     }
 }
 
+impl PartialEq for ProvenTransaction {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.account_update == other.account_update
+            && self.input_notes == other.input_notes
+            && self.output_notes == other.output_notes
+            && self.block_ref == other.block_ref
+            && self.expiration_block_num == other.expiration_block_num
+            && self.proof == other.proof
+    }
+}
+
+impl Eq for ProvenTransaction {}
+
 impl Serializable for ProvenTransaction {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.account_update.write_into(target);
@@ -156,11 +223,17 @@ impl Serializable for ProvenTransaction {
         self.block_ref.write_into(target);
         self.expiration_block_num.write_into(target);
         self.proof.write_into(target);
+        self.kernel_version.write_into(target);
     }
 }
 
-impl Deserializable for ProvenTransaction {
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+impl ProvenTransaction {
+    /// Reads a proven transaction from the source, using the provided closure to read the kernel
+    /// version which follows the proof.
+    fn read_with_kernel_version<R: ByteReader>(
+        source: &mut R,
+        read_kernel_version: impl FnOnce(&mut R) -> Result<Option<String>, DeserializationError>,
+    ) -> Result<Self, DeserializationError> {
         let account_update = TxAccountUpdate::read_from(source)?;
 
         let input_notes = <InputNotes<InputNoteCommitment>>::read_from(source)?;
@@ -170,6 +243,8 @@ impl Deserializable for ProvenTransaction {
         let expiration_block_num = BlockNumber::read_from(source)?;
         let proof = ExecutionProof::read_from(source)?;
 
+        let kernel_version = read_kernel_version(source)?;
+
         let id = TransactionId::new(
             account_update.init_state_hash(),
             account_update.final_state_hash(),
@@ -185,6 +260,7 @@ impl Deserializable for ProvenTransaction {
             block_ref,
             expiration_block_num,
             proof,
+            kernel_version,
         };
 
         proven_transaction
@@ -193,6 +269,32 @@ impl Deserializable for ProvenTransaction {
     }
 }
 
+impl Deserializable for ProvenTransaction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_with_kernel_version(source, Option::<String>::read_from)
+    }
+
+    fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+
+        // the kernel version was added after the proof, so transactions serialized without it end
+        // right after the proof
+        let proven_transaction = Self::read_with_kernel_version(&mut source, |source| {
+            if source.has_more_bytes() {
+                Option::<String>::read_from(source)
+            } else {
+                Ok(None)
+            }
+        })?;
+
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Ok(proven_transaction)
+    }
+}
+
 // PROVEN TRANSACTION BUILDER
 // ================================================================================================
 
@@ -225,6 +327,9 @@ pub struct ProvenTransactionBuilder {
 
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
+
+    /// The version of the transaction kernel build which produced the proof, if known.
+    kernel_version: Option<String>,
 }
 
 impl ProvenTransactionBuilder {
@@ -250,6 +355,7 @@ impl ProvenTransactionBuilder {
             block_ref,
             expiration_block_num,
             proof,
+            kernel_version: None,
         }
     }
 
@@ -281,6 +387,12 @@ impl ProvenTransactionBuilder {
         self
     }
 
+    /// Sets the version of the transaction kernel build which produced the proof.
+    pub fn kernel_version(mut self, kernel_version: String) -> Self {
+        self.kernel_version = Some(kernel_version);
+        self
+    }
+
     /// Builds the [ProvenTransaction].
     ///
     /// # Errors
@@ -313,6 +425,7 @@ impl ProvenTransactionBuilder {
             block_ref: self.block_ref,
             expiration_block_num: self.expiration_block_num,
             proof: self.proof,
+            kernel_version: self.kernel_version,
         };
 
         proven_transaction.validate()
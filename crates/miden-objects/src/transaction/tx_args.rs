@@ -1,10 +1,15 @@
-use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
 use core::ops::Deref;
 
 use assembly::{Assembler, Compile};
-use miden_crypto::merkle::InnerNodeInfo;
+use miden_crypto::{merkle::InnerNodeInfo, utils::SliceReader};
 use vm_core::{
-    mast::{MastForest, MastNodeId},
+    mast::{MastForest, MastNode, MastNodeId},
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
     AdviceMap, Program,
 };
@@ -12,8 +17,8 @@ use vm_processor::{AdviceInputs, DeserializationError};
 
 use super::{Digest, Felt, Word};
 use crate::{
-    note::{NoteDetails, NoteId},
-    TransactionScriptError,
+    note::{NoteDetails, NoteId, ScriptInfo},
+    Hasher, TransactionScriptError,
 };
 
 // TRANSACTION ARGS
@@ -27,11 +32,17 @@ use crate::{
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
 ///   args.
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
+///   Entries of the advice map are either shared by all scripts of the transaction or scoped to a
+///   single note, see [TransactionArgs::add_note_advice].
+/// - Expected output notes: the IDs of the notes whose details were provided via
+///   [TransactionArgs::add_expected_output_note], which the executor can optionally check to be
+///   created by the transaction.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
+    expected_output_notes: BTreeSet<NoteId>,
 }
 
 impl TransactionArgs {
@@ -59,6 +70,7 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_inputs,
+            expected_output_notes: BTreeSet::new(),
         }
     }
 
@@ -97,6 +109,20 @@ impl TransactionArgs {
         &self.advice_inputs
     }
 
+    /// Returns the IDs of the notes added via [TransactionArgs::add_expected_output_note].
+    pub fn expected_output_notes(&self) -> &BTreeSet<NoteId> {
+        &self.expected_output_notes
+    }
+
+    /// Returns the key under which an advice map entry with the specified key is stored when it
+    /// is scoped to the note with the specified ID.
+    ///
+    /// The scoped key is computed as `hash(note_id || key)`, which is mirrored by the
+    /// `miden::note::adv_push_scoped` procedure.
+    pub fn note_advice_key(note_id: NoteId, key: Digest) -> Digest {
+        Hasher::merge(&[note_id.inner(), key])
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -108,6 +134,8 @@ impl TransactionArgs {
     /// - inputs_key |-> inputs, where inputs_key is computed by taking note inputs commitment and
     ///   adding ONE to its most significant element.
     /// - script_hash |-> script.
+    ///
+    /// The ID of the note is recorded in [TransactionArgs::expected_output_notes].
     pub fn add_expected_output_note<T: Deref<Target = NoteDetails>>(&mut self, note: &T) {
         let recipient = note.recipient();
         let inputs = note.inputs();
@@ -121,6 +149,7 @@ impl TransactionArgs {
         ];
 
         self.advice_inputs.extend_map(new_elements);
+        self.expected_output_notes.insert(note.id());
     }
 
     /// Populates the advice inputs with the specified note details.
@@ -141,11 +170,31 @@ impl TransactionArgs {
         }
     }
 
+    /// Adds the provided values to the advice inputs' map under the specified key scoped to the
+    /// note with the specified ID.
+    ///
+    /// The entry is stored under [TransactionArgs::note_advice_key] and can be read by the script
+    /// of the note via `miden::note::adv_push_scoped`. Thus, notes with identical scripts which
+    /// read the same key do not read each other's values. Entries which should be shared by all
+    /// scripts of the transaction should be added via [TransactionArgs::extend_advice_map].
+    pub fn add_note_advice(&mut self, note_id: NoteId, key: Digest, values: Vec<Felt>) {
+        self.advice_inputs.extend_map([(Self::note_advice_key(note_id, key), values)]);
+    }
+
     /// Extends the internal advice inputs' map with the provided key-value pairs.
     pub fn extend_advice_map<T: IntoIterator<Item = (Digest, Vec<Felt>)>>(&mut self, iter: T) {
         self.advice_inputs.extend_map(iter)
     }
 
+    /// Returns these [TransactionArgs] with the advice map and Merkle store of the advice inputs
+    /// removed, while the transaction script, note arguments and advice stack are preserved.
+    pub(crate) fn without_advice_map_and_store(self) -> Self {
+        let advice_inputs =
+            AdviceInputs::default().with_stack(self.advice_inputs.stack().iter().copied());
+
+        Self { advice_inputs, ..self }
+    }
+
     /// Extends the internal advice inputs' merkle store with the provided nodes.
     pub fn extend_merkle_store<I: Iterator<Item = InnerNodeInfo>>(&mut self, iter: I) {
         self.advice_inputs.extend_merkle_store(iter)
@@ -157,6 +206,7 @@ impl Serializable for TransactionArgs {
         self.tx_script.write_into(target);
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
+        self.expected_output_notes.write_into(target);
     }
 }
 
@@ -165,8 +215,14 @@ impl Deserializable for TransactionArgs {
         let tx_script = Option::<TransactionScript>::read_from(source)?;
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
+        let expected_output_notes = BTreeSet::<NoteId>::read_from(source)?;
 
-        Ok(Self { tx_script, note_args, advice_inputs })
+        Ok(Self {
+            tx_script,
+            note_args,
+            advice_inputs,
+            expected_output_notes,
+        })
     }
 }
 
@@ -182,11 +238,15 @@ impl Deserializable for TransactionArgs {
 /// - An executable program defined by a [MastForest] and an associated entrypoint.
 /// - A set of transaction script inputs defined by a map of key-value inputs that are loaded into
 ///   the advice inputs' map such that the transaction script can access them.
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// - An optional [ScriptInfo] with the human-readable name and version of the script, which is not
+///   part of the script's MAST root and is ignored when comparing scripts for equality.
+#[derive(Clone, Debug)]
 pub struct TransactionScript {
     mast: Arc<MastForest>,
     entrypoint: MastNodeId,
     inputs: BTreeMap<Digest, Vec<Felt>>,
+    info: Option<ScriptInfo>,
+    readonly: bool,
 }
 
 impl TransactionScript {
@@ -199,6 +259,8 @@ impl TransactionScript {
             entrypoint: code.entrypoint(),
             mast: code.mast_forest().clone(),
             inputs: inputs.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+            info: None,
+            readonly: false,
         }
     }
 
@@ -218,6 +280,50 @@ impl TransactionScript {
         Ok(Self::new(program, inputs))
     }
 
+    /// Returns a new [TransactionScript] compiled from the provided source code and inputs using
+    /// the specified assembler, with the provided [ScriptInfo] attached.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_named(
+        source_code: impl Compile,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+        assembler: Assembler,
+        info: ScriptInfo,
+    ) -> Result<Self, TransactionScriptError> {
+        Ok(Self::compile(source_code, inputs, assembler)?.with_info(info))
+    }
+
+    /// Returns a new read-only [TransactionScript] compiled from the provided source code and
+    /// inputs using the specified assembler.
+    ///
+    /// `mutating_procedures` maps the MAST roots of the procedures which change the state of the
+    /// account to their names and `readonly_procedures` contains the MAST roots of the external
+    /// procedures the script may invoke. For scripts assembled with the transaction kernel, these
+    /// are returned by `TransactionKernel::state_mutating_procedures()` and
+    /// `TransactionKernel::readonly_procedures()` of miden-lib, and
+    /// `TransactionKernel::compile_readonly_script()` compiles scripts against them.
+    ///
+    /// The compiled script is checked via [Self::verify_readonly] and marked as read-only.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The compilation of the provided source code fails.
+    /// - The compiled script is not read-only, see [Self::verify_readonly].
+    pub fn compile_readonly(
+        source_code: impl Compile,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+        assembler: Assembler,
+        mutating_procedures: &BTreeMap<Digest, String>,
+        readonly_procedures: &BTreeSet<Digest>,
+    ) -> Result<Self, TransactionScriptError> {
+        let mut tx_script = Self::compile(source_code, inputs, assembler)?;
+        tx_script.verify_readonly(mutating_procedures, readonly_procedures)?;
+        tx_script.readonly = true;
+
+        Ok(tx_script)
+    }
+
     /// Returns a new [TransactionScript] instantiated from the provided components.
     ///
     /// # Panics
@@ -228,7 +334,21 @@ impl TransactionScript {
         inputs: BTreeMap<Digest, Vec<Felt>>,
     ) -> Self {
         assert!(mast.get_node_by_id(entrypoint).is_some());
-        Self { mast, entrypoint, inputs }
+        Self {
+            mast,
+            entrypoint,
+            inputs,
+            info: None,
+            readonly: false,
+        }
+    }
+
+    /// Returns this [TransactionScript] with the provided [ScriptInfo] attached.
+    ///
+    /// The info does not change the MAST root of the script.
+    pub fn with_info(mut self, info: ScriptInfo) -> Self {
+        self.info = Some(info);
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -248,8 +368,108 @@ impl TransactionScript {
     pub fn inputs(&self) -> &BTreeMap<Digest, Vec<Felt>> {
         &self.inputs
     }
+
+    /// Returns the [ScriptInfo] attached to this transaction script, if any.
+    pub fn info(&self) -> Option<&ScriptInfo> {
+        self.info.as_ref()
+    }
+
+    /// Returns `true` if this transaction script was compiled via [Self::compile_readonly].
+    ///
+    /// The flag is carried in the serialized script and thus is only trusted as far as its source
+    /// is. It can be re-checked via [Self::verify_readonly].
+    pub fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that executing this transaction script cannot change the state of the account.
+    ///
+    /// `mutating_procedures` maps the MAST roots of the procedures which change the state of the
+    /// account to their names. The check is performed statically on the MAST forest of the script,
+    /// which must not contain:
+    /// - any of the `mutating_procedures`.
+    /// - `call` or `dyncall` instructions, nor `dynexec` instructions, since the procedures they
+    ///   invoke, e.g. the procedures of the account code, are not part of the script and thus
+    ///   cannot be checked.
+    /// - references to external procedures, i.e. procedures of dynamically linked libraries, which
+    ///   are not contained in `readonly_procedures`, since their MAST is not part of the script
+    ///   either. Only the callees of syscalls are exempt from this.
+    ///
+    /// Syscalls are allowed, so that the script can use the getters of the transaction kernel via
+    /// miden-lib. Hence, a script which invokes the state-mutating kernel procedures via a
+    /// hand-written `syscall` instead of the respective miden-lib procedure is not detected.
+    ///
+    /// # Errors
+    /// Returns an error if the MAST forest of the script contains a node of the kinds listed above.
+    pub fn verify_readonly(
+        &self,
+        mutating_procedures: &BTreeMap<Digest, String>,
+        readonly_procedures: &BTreeSet<Digest>,
+    ) -> Result<(), TransactionScriptError> {
+        let syscall_callees: BTreeSet<Digest> = self
+            .mast
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                MastNode::Call(call_node) if call_node.is_syscall() => {
+                    Some(self.mast[call_node.callee()].digest())
+                },
+                _ => None,
+            })
+            .collect();
+
+        for node in self.mast.nodes() {
+            if let Some(name) = mutating_procedures.get(&node.digest()) {
+                return Err(TransactionScriptError::StateMutatingProcedure {
+                    name: name.clone(),
+                    root: node.digest(),
+                });
+            }
+
+            match node {
+                MastNode::Call(call_node) if !call_node.is_syscall() => {
+                    return Err(TransactionScriptError::UnverifiableCall {
+                        callee: self.mast[call_node.callee()].digest(),
+                    });
+                },
+                MastNode::Dyn(_) => {
+                    return Err(TransactionScriptError::UnverifiableDynamicCall);
+                },
+                MastNode::External(external_node)
+                    if !readonly_procedures.contains(&external_node.digest())
+                        && !syscall_callees.contains(&external_node.digest()) =>
+                {
+                    return Err(TransactionScriptError::UnverifiableExternalProcedure {
+                        root: external_node.digest(),
+                    });
+                },
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
 }
 
+// EQUALITY
+// ================================================================================================
+
+/// Two transaction scripts are equal if they have the same MAST, entrypoint, inputs and read-only
+/// flag, regardless of their [ScriptInfo].
+impl PartialEq for TransactionScript {
+    fn eq(&self, other: &Self) -> bool {
+        self.mast == other.mast
+            && self.entrypoint == other.entrypoint
+            && self.inputs == other.inputs
+            && self.readonly == other.readonly
+    }
+}
+
+impl Eq for TransactionScript {}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -258,27 +478,86 @@ impl Serializable for TransactionScript {
         self.mast.write_into(target);
         target.write_u32(self.entrypoint.as_u32());
         self.inputs.write_into(target);
+        self.info.write_into(target);
+        target.write_bool(self.readonly);
     }
 }
 
-impl Deserializable for TransactionScript {
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+impl TransactionScript {
+    /// Reads a transaction script from the source, using the provided closure to read the script
+    /// info and the read-only flag which follow the inputs.
+    fn read_with_info<R: ByteReader>(
+        source: &mut R,
+        read_info: impl FnOnce(&mut R) -> Result<(Option<ScriptInfo>, bool), DeserializationError>,
+    ) -> Result<Self, DeserializationError> {
         let mast = MastForest::read_from(source)?;
         let entrypoint = MastNodeId::from_u32_safe(source.read_u32()?, &mast)?;
         let inputs = BTreeMap::<Digest, Vec<Felt>>::read_from(source)?;
+        let (info, readonly) = read_info(source)?;
+
+        Ok(Self {
+            info,
+            readonly,
+            ..Self::from_parts(Arc::new(mast), entrypoint, inputs)
+        })
+    }
+
+    /// Reads the script info and the read-only flag of a transaction script.
+    fn read_info_and_readonly<R: ByteReader>(
+        source: &mut R,
+    ) -> Result<(Option<ScriptInfo>, bool), DeserializationError> {
+        let info = Option::<ScriptInfo>::read_from(source)?;
+        let readonly = source.read_bool()?;
 
-        Ok(Self::from_parts(Arc::new(mast), entrypoint, inputs))
+        Ok((info, readonly))
+    }
+}
+
+impl Deserializable for TransactionScript {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_with_info(source, Self::read_info_and_readonly)
+    }
+
+    fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+
+        // the script info was added after the inputs, so scripts serialized without it end right
+        // after the inputs
+        let tx_script = Self::read_with_info(&mut source, |source| {
+            if source.has_more_bytes() {
+                Self::read_info_and_readonly(source)
+            } else {
+                Ok((None, false))
+            }
+        })?;
+
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Ok(tx_script)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::collections::{BTreeMap, BTreeSet};
+
+    use assembly::Assembler;
+    use assert_matches::assert_matches;
+    use semver::Version;
     use vm_core::{
         utils::{Deserializable, Serializable},
         AdviceMap,
     };
 
-    use crate::transaction::TransactionArgs;
+    use crate::{
+        note::ScriptInfo,
+        transaction::{TransactionArgs, TransactionScript},
+        TransactionScriptError, ONE, ZERO,
+    };
+
+    const TX_SCRIPT_CODE: &str = "begin push.1 drop end";
 
     #[test]
     fn test_tx_args_serialization() {
@@ -288,4 +567,131 @@ mod tests {
 
         assert_eq!(args, decoded);
     }
+
+    #[test]
+    fn tx_script_serialization_with_and_without_info() {
+        let tx_script =
+            TransactionScript::compile(TX_SCRIPT_CODE, vec![], Assembler::default()).unwrap();
+        let decoded = TransactionScript::read_from_bytes(&tx_script.to_bytes()).unwrap();
+        assert_eq!(decoded, tx_script);
+        assert!(decoded.info().is_none());
+
+        let info = ScriptInfo::new("noop").with_version(Version::new(1, 0, 0));
+        let named_script = TransactionScript::compile_named(
+            TX_SCRIPT_CODE,
+            [],
+            Assembler::default(),
+            info.clone(),
+        )
+        .unwrap();
+        let decoded = TransactionScript::read_from_bytes(&named_script.to_bytes()).unwrap();
+        assert_eq!(decoded, named_script);
+        assert_eq!(decoded.info(), Some(&info));
+
+        // the info is preserved when the script is serialized as part of the transaction args
+        let args = TransactionArgs::new(Some(named_script), None, AdviceMap::default());
+        let decoded = TransactionArgs::read_from_bytes(&args.to_bytes()).unwrap();
+        assert_eq!(decoded.tx_script().unwrap().info(), Some(&info));
+    }
+
+    #[test]
+    fn tx_script_serialized_without_info_still_parses() {
+        let tx_script =
+            TransactionScript::compile(TX_SCRIPT_CODE, vec![], Assembler::default()).unwrap();
+
+        // scripts serialized before the script info was added end right after the inputs
+        let mut legacy_bytes = tx_script.mast().to_bytes();
+        legacy_bytes.extend(tx_script.entrypoint.as_u32().to_le_bytes());
+        legacy_bytes.extend(tx_script.inputs().to_bytes());
+
+        let decoded = TransactionScript::read_from_bytes(&legacy_bytes).unwrap();
+        assert_eq!(decoded, tx_script);
+        assert!(decoded.info().is_none());
+        assert!(!decoded.is_readonly());
+    }
+
+    #[test]
+    fn tx_script_readonly_flag_survives_serialization() {
+        let tx_script = TransactionScript::compile_readonly(
+            TX_SCRIPT_CODE,
+            [],
+            Assembler::default(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+        )
+        .unwrap();
+        assert!(tx_script.is_readonly());
+
+        let decoded = TransactionScript::read_from_bytes(&tx_script.to_bytes()).unwrap();
+        assert_eq!(decoded, tx_script);
+        assert!(decoded.is_readonly());
+
+        // the flag does not change the MAST root of the script
+        let regular_script =
+            TransactionScript::compile(TX_SCRIPT_CODE, [], Assembler::default()).unwrap();
+        assert!(!regular_script.is_readonly());
+        assert_eq!(regular_script.hash(), tx_script.hash());
+        assert_ne!(regular_script, tx_script);
+
+        let args = TransactionArgs::new(Some(tx_script), None, AdviceMap::default());
+        let decoded = TransactionArgs::read_from_bytes(&args.to_bytes()).unwrap();
+        assert!(decoded.tx_script().unwrap().is_readonly());
+    }
+
+    #[test]
+    fn readonly_tx_script_rejects_calls() {
+        let code = "proc.foo push.1 drop end begin call.foo end";
+        let tx_script = TransactionScript::compile(code, [], Assembler::default()).unwrap();
+
+        // the procedures invoked via `call` are checked by neither the script nor the kernel
+        assert_matches!(
+            tx_script.verify_readonly(&BTreeMap::new(), &BTreeSet::new()),
+            Err(TransactionScriptError::UnverifiableCall { .. })
+        );
+        assert_matches!(
+            TransactionScript::compile_readonly(
+                code,
+                [],
+                Assembler::default(),
+                &BTreeMap::new(),
+                &BTreeSet::new()
+            ),
+            Err(TransactionScriptError::UnverifiableCall { .. })
+        );
+    }
+
+    #[test]
+    fn tx_script_info_does_not_affect_root() {
+        let tx_script =
+            TransactionScript::compile(TX_SCRIPT_CODE, vec![], Assembler::default()).unwrap();
+        let named_script = tx_script.clone().with_info(ScriptInfo::new("noop"));
+
+        assert_eq!(named_script.hash(), tx_script.hash());
+        assert_eq!(named_script, tx_script);
+        assert_ne!(named_script.to_bytes(), tx_script.to_bytes());
+    }
+
+    #[test]
+    fn tx_script_from_pre_assembled_program() {
+        let inputs = [([ONE; 4], vec![ONE, ZERO])];
+        let compiled =
+            TransactionScript::compile(TX_SCRIPT_CODE, inputs.clone(), Assembler::default())
+                .unwrap();
+
+        // a program assembled once can be reused to create scripts without assembling it again
+        let program = Assembler::default().assemble_program(TX_SCRIPT_CODE).unwrap();
+        let from_program = TransactionScript::new(program.clone(), inputs);
+        assert_eq!(from_program, compiled);
+        assert_eq!(from_program.hash(), compiled.hash());
+        assert_eq!(from_program.to_bytes(), compiled.to_bytes());
+
+        let from_parts = TransactionScript::from_parts(
+            program.mast_forest().clone(),
+            program.entrypoint(),
+            compiled.inputs().clone(),
+        );
+        assert_eq!(from_parts, compiled);
+        assert_eq!(from_parts.hash(), compiled.hash());
+        assert_eq!(from_parts.to_bytes(), compiled.to_bytes());
+    }
 }
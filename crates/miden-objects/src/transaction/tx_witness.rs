@@ -3,7 +3,9 @@ use alloc::vec::Vec;
 use vm_core::utils::{ByteReader, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{AdviceInputs, TransactionArgs, TransactionInputs};
+use super::{
+    AdviceInputs, Digest, ExecutedTransaction, Hasher, TransactionArgs, TransactionInputs,
+};
 use crate::account::AccountCode;
 
 // TRANSACTION WITNESS
@@ -35,6 +37,31 @@ pub struct TransactionWitness {
     pub account_codes: Vec<AccountCode>,
 }
 
+impl TransactionWitness {
+    /// Returns a new [TransactionWitness] for the provided executed transaction which contains only
+    /// the advice data that was accessed while executing the transaction.
+    ///
+    /// The advice witness of an executed transaction records all advice map entries and Merkle
+    /// store nodes requested by the VM, while the advice inputs of the transaction arguments
+    /// contain all data provided before the execution, whether it was accessed or not. The
+    /// returned witness omits the advice map and Merkle store of the latter, which can
+    /// considerably reduce its size.
+    pub fn from_executed_minimized(tx: ExecutedTransaction) -> Self {
+        let mut tx_witness = Self::from(tx);
+        tx_witness.tx_args = tx_witness.tx_args.without_advice_map_and_store();
+
+        tx_witness
+    }
+
+    /// Returns a digest of this witness, computed as the hash of its serialized bytes.
+    ///
+    /// The digest can be used to correlate a witness with the transaction proven from it when the
+    /// two are stored separately.
+    pub fn digest(&self) -> Digest {
+        Hasher::hash(&self.to_bytes())
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
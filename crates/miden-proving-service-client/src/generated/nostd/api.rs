@@ -9,6 +9,14 @@ pub struct ProveTransactionResponse {
     #[prost(bytes = "vec", tag = "1")]
     pub proven_transaction: ::prost::alloc::vec::Vec<u8>,
 }
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StatusRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StatusResponse {
+    /// Maximum trace length of the transactions the worker is configured to prove.
+    #[prost(uint64, tag = "1")]
+    pub max_trace_length: u64,
+}
 /// Generated client implementations.
 pub mod api_client {
     #![allow(
@@ -110,5 +118,23 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("api.Api", "ProveTransaction"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StatusRequest>,
+        ) -> core::result::Result<tonic::Response<super::StatusResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        alloc::format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/Status");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "Status"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
@@ -23,6 +23,31 @@ pub use prover::RemoteTransactionProver;
 /// Protobuf definition for the Miden proving service
 pub const SERVICE_PROTO: &str = include_str!("../proto/api.proto");
 
+// METADATA KEYS
+// ================================================================================================
+
+/// The key of the request metadata entry containing the estimated trace length of the transaction
+/// to prove (see `ExecutedTransaction::trace_length_estimate`).
+///
+/// The proxy of the proving service uses it to route the request to a worker which is configured
+/// to prove transactions with traces of that length.
+pub const TRACE_LENGTH_ESTIMATE_METADATA_KEY: &str = "x-trace-length-estimate";
+
+/// The key of the request metadata entry containing the minimum proof version accepted by the
+/// client (see `miden_tx::PROOF_VERSION`).
+pub const MIN_PROOF_VERSION_METADATA_KEY: &str = "x-min-proof-version";
+
+/// The key of the request metadata entry containing the maximum proof version accepted by the
+/// client (see `miden_tx::PROOF_VERSION`).
+pub const MAX_PROOF_VERSION_METADATA_KEY: &str = "x-max-proof-version";
+
+/// The key of the error metadata entry containing the version of the proofs generated by the
+/// remote prover.
+///
+/// It is set by the remote prover when it rejects a request because it cannot generate proofs of
+/// a version accepted by the client.
+pub const PROOF_VERSION_METADATA_KEY: &str = "x-proof-version";
+
 /// ERRORS
 /// ===============================================================================================
 
@@ -34,6 +59,16 @@ pub enum RemoteProverError {
     #[error("failed to connect to prover {0}")]
     /// Indicates that the connection to the server failed.
     ConnectionFailed(String),
+    /// Indicates that the server generates proofs of a version outside of the range accepted by
+    /// the client.
+    #[error(
+        "remote prover generates proofs of version {server_version}, but only versions {min_version} to {max_version} are accepted"
+    )]
+    ProofVersionMismatch {
+        server_version: u32,
+        min_version: u32,
+        max_version: u32,
+    },
 }
 
 impl From<RemoteProverError> for String {
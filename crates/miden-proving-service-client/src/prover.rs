@@ -1,12 +1,18 @@
 use alloc::{
     boxed::Box,
     string::{String, ToString},
+    vec::Vec,
 };
+use core::ops::RangeInclusive;
 
 use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
-use miden_tx::{utils::sync::RwLock, TransactionProver, TransactionProverError};
+use miden_tx::{utils::sync::RwLock, TransactionProver, TransactionProverError, PROOF_VERSION};
 
-use crate::{generated::api_client::ApiClient, RemoteProverError};
+use crate::{
+    generated::{api_client::ApiClient, ProveTransactionRequest},
+    RemoteProverError, MAX_PROOF_VERSION_METADATA_KEY, MIN_PROOF_VERSION_METADATA_KEY,
+    PROOF_VERSION_METADATA_KEY, TRACE_LENGTH_ESTIMATE_METADATA_KEY,
+};
 
 // REMOTE TRANSACTION PROVER
 // ================================================================================================
@@ -18,6 +24,11 @@ use crate::{generated::api_client::ApiClient, RemoteProverError};
 /// transport. Otherwise, it uses the built-in `tonic::transport` for native platforms.
 ///
 /// The transport layer connection is established lazily when the first transaction is proven.
+///
+/// Every request carries the range of proof versions accepted by the prover, which by default
+/// only contains [miden_tx::PROOF_VERSION]. If the remote prover generates proofs of another
+/// version, it rejects the request and proving fails with an error whose source is
+/// [RemoteProverError::ProofVersionMismatch].
 pub struct RemoteTransactionProver {
     #[cfg(target_arch = "wasm32")]
     client: RwLock<Option<ApiClient<tonic_web_wasm_client::Client>>>,
@@ -26,6 +37,8 @@ pub struct RemoteTransactionProver {
     client: RwLock<Option<ApiClient<tonic::transport::Channel>>>,
 
     endpoint: String,
+
+    accepted_proof_versions: RangeInclusive<u32>,
 }
 
 impl RemoteTransactionProver {
@@ -35,9 +48,19 @@ impl RemoteTransactionProver {
         RemoteTransactionProver {
             endpoint: endpoint.to_string(),
             client: RwLock::new(None),
+            accepted_proof_versions: PROOF_VERSION..=PROOF_VERSION,
         }
     }
 
+    /// Sets the range of proof versions accepted by this prover and returns `self`.
+    ///
+    /// The remote prover rejects requests if the version of the proofs it generates is not in the
+    /// provided range.
+    pub fn with_accepted_proof_versions(mut self, versions: RangeInclusive<u32>) -> Self {
+        self.accepted_proof_versions = versions;
+        self
+    }
+
     /// Establishes a connection to the remote transaction prover server. The connection is
     /// maintained for the lifetime of the prover. If the connection is already established, this
     /// method does nothing.
@@ -64,13 +87,28 @@ impl RemoteTransactionProver {
 
         Ok(())
     }
-}
 
-#[async_trait::async_trait(?Send)]
-impl TransactionProver for RemoteTransactionProver {
-    async fn prove(
+    /// Proves the transaction described by the provided witness like
+    /// [TransactionProver::prove], and sends the provided trace length estimate of the
+    /// transaction along with the request.
+    ///
+    /// The estimate allows the proving service to route the request to a worker which can prove
+    /// transactions with traces of that length. It can be obtained from the executed transaction
+    /// via `ExecutedTransaction::trace_length_estimate`. If no estimate is available, the request
+    /// is sent without one, like in [TransactionProver::prove].
+    pub async fn prove_with_trace_length_estimate(
         &self,
         tx_witness: TransactionWitness,
+        trace_length_estimate: Option<usize>,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.prove_witness(tx_witness, trace_length_estimate).await
+    }
+
+    /// Sends the provided witness to the remote prover and returns the proven transaction.
+    async fn prove_witness(
+        &self,
+        tx_witness: TransactionWitness,
+        trace_length_estimate: Option<usize>,
     ) -> Result<ProvenTransaction, TransactionProverError> {
         use miden_objects::utils::Serializable;
         self.connect().await.map_err(|err| {
@@ -84,13 +122,16 @@ impl TransactionProver for RemoteTransactionProver {
             .ok_or_else(|| TransactionProverError::other("client should be connected"))?
             .clone();
 
-        let request = tonic::Request::new(crate::generated::ProveTransactionRequest {
-            transaction_witness: tx_witness.to_bytes(),
-        });
+        let request = build_prove_request(
+            tx_witness.to_bytes(),
+            trace_length_estimate,
+            &self.accepted_proof_versions,
+        );
 
-        let response = client.prove_transaction(request).await.map_err(|err| {
-            TransactionProverError::other_with_source("failed to prove transaction", err)
-        })?;
+        let response = client
+            .prove_transaction(request)
+            .await
+            .map_err(|status| prove_transaction_error(status, &self.accepted_proof_versions))?;
 
         // Deserialize the response bytes back into a ProvenTransaction.
         let proven_transaction =
@@ -103,3 +144,143 @@ impl TransactionProver for RemoteTransactionProver {
         Ok(proven_transaction)
     }
 }
+
+#[async_trait::async_trait(?Send)]
+impl TransactionProver for RemoteTransactionProver {
+    async fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.prove_witness(tx_witness, None).await
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Builds a request to prove the transaction with the provided serialized witness.
+///
+/// The bounds of the accepted proof versions are added to the metadata of the request under the
+/// [MIN_PROOF_VERSION_METADATA_KEY] and [MAX_PROOF_VERSION_METADATA_KEY] keys. If a trace length
+/// estimate is provided, it is added under the [TRACE_LENGTH_ESTIMATE_METADATA_KEY] key.
+fn build_prove_request(
+    transaction_witness: Vec<u8>,
+    trace_length_estimate: Option<usize>,
+    accepted_proof_versions: &RangeInclusive<u32>,
+) -> tonic::Request<ProveTransactionRequest> {
+    let mut request = tonic::Request::new(ProveTransactionRequest { transaction_witness });
+
+    let metadata = request.metadata_mut();
+    metadata.insert(MIN_PROOF_VERSION_METADATA_KEY, (*accepted_proof_versions.start()).into());
+    metadata.insert(MAX_PROOF_VERSION_METADATA_KEY, (*accepted_proof_versions.end()).into());
+
+    if let Some(trace_length_estimate) = trace_length_estimate {
+        request
+            .metadata_mut()
+            .insert(TRACE_LENGTH_ESTIMATE_METADATA_KEY, (trace_length_estimate as u64).into());
+    }
+
+    request
+}
+
+/// Converts the status returned by the remote prover for a prove request into an error.
+///
+/// If the remote prover rejected the request because of the version of the proofs it generates,
+/// the source of the returned error is [RemoteProverError::ProofVersionMismatch].
+fn prove_transaction_error(
+    status: tonic::Status,
+    accepted_proof_versions: &RangeInclusive<u32>,
+) -> TransactionProverError {
+    match proof_version_mismatch(&status, accepted_proof_versions) {
+        Some(err) => TransactionProverError::other_with_source(
+            "remote prover cannot generate proofs of an accepted version",
+            err,
+        ),
+        None => TransactionProverError::other_with_source("failed to prove transaction", status),
+    }
+}
+
+/// Returns a [RemoteProverError::ProofVersionMismatch] error if the provided status indicates that
+/// the remote prover rejected the request because of the version of the proofs it generates.
+fn proof_version_mismatch(
+    status: &tonic::Status,
+    accepted_proof_versions: &RangeInclusive<u32>,
+) -> Option<RemoteProverError> {
+    if status.code() != tonic::Code::FailedPrecondition {
+        return None;
+    }
+
+    let server_version = status
+        .metadata()
+        .get(PROOF_VERSION_METADATA_KEY)?
+        .to_str()
+        .ok()?
+        .parse::<u32>()
+        .ok()?;
+
+    Some(RemoteProverError::ProofVersionMismatch {
+        server_version,
+        min_version: *accepted_proof_versions.start(),
+        max_version: *accepted_proof_versions.end(),
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use core::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn trace_length_estimate_is_sent_as_metadata() {
+        let versions = PROOF_VERSION..=PROOF_VERSION;
+
+        let request = build_prove_request(vec![1, 2, 3], Some(1 << 16), &versions);
+        assert_eq!(request.get_ref().transaction_witness, vec![1, 2, 3]);
+        assert_eq!(request.metadata().get(TRACE_LENGTH_ESTIMATE_METADATA_KEY).unwrap(), "65536");
+
+        let request = build_prove_request(vec![1, 2, 3], None, &versions);
+        assert!(request.metadata().get(TRACE_LENGTH_ESTIMATE_METADATA_KEY).is_none());
+    }
+
+    #[test]
+    fn accepted_proof_versions_are_sent_as_metadata() {
+        let request = build_prove_request(vec![1, 2, 3], None, &(2..=5));
+        assert_eq!(request.metadata().get(MIN_PROOF_VERSION_METADATA_KEY).unwrap(), "2");
+        assert_eq!(request.metadata().get(MAX_PROOF_VERSION_METADATA_KEY).unwrap(), "5");
+    }
+
+    #[test]
+    fn proof_version_mismatch_is_detected() {
+        let versions = 2..=5;
+
+        // the server generates proofs of version 1, which the client does not accept
+        let mut status = tonic::Status::failed_precondition("unsupported proof version");
+        status.metadata_mut().insert(PROOF_VERSION_METADATA_KEY, 1u32.into());
+        assert!(matches!(
+            proof_version_mismatch(&status, &versions),
+            Some(RemoteProverError::ProofVersionMismatch {
+                server_version: 1,
+                min_version: 2,
+                max_version: 5
+            })
+        ));
+
+        // the mismatch is the source of the error returned when proving fails
+        let err = prove_transaction_error(status, &versions);
+        assert!(matches!(
+            err.source().and_then(|source| source.downcast_ref::<RemoteProverError>()),
+            Some(RemoteProverError::ProofVersionMismatch { server_version: 1, .. })
+        ));
+
+        // other errors are not reported as version mismatches
+        let mut status = tonic::Status::internal("failed to prove transaction");
+        status.metadata_mut().insert(PROOF_VERSION_METADATA_KEY, 1u32.into());
+        assert!(proof_version_mismatch(&status, &versions).is_none());
+        assert!(proof_version_mismatch(&tonic::Status::failed_precondition("busy"), &versions)
+            .is_none());
+    }
+}
@@ -69,6 +69,22 @@ impl<R: Rng> BasicAuthenticator<R> {
             rng: Arc::new(RwLock::new(rng)),
         }
     }
+
+    /// Returns the secret keys managed by this authenticator, keyed by their public keys.
+    ///
+    /// This is only available for testing, e.g. to persist the authenticators of a mock chain.
+    #[cfg(any(feature = "testing", test))]
+    pub fn keys(&self) -> &BTreeMap<Digest, AuthSecretKey> {
+        &self.keys
+    }
+
+    /// Returns the random number generator used to generate signatures.
+    ///
+    /// This is only available for testing, e.g. to persist the authenticators of a mock chain.
+    #[cfg(any(feature = "testing", test))]
+    pub fn rng(&self) -> Arc<RwLock<R>> {
+        self.rng.clone()
+    }
 }
 
 impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
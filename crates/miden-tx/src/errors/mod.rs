@@ -2,8 +2,9 @@ use alloc::{boxed::Box, string::String};
 use core::error::Error;
 
 use miden_objects::{
-    account::AccountId, block::BlockNumber, note::NoteId, AccountError, Felt,
-    ProvenTransactionError, TransactionInputError, TransactionOutputError,
+    account::AccountId, block::BlockNumber, crypto::merkle::MerkleError, note::NoteId,
+    transaction::TransactionId, AccountError, Digest, Felt, ProvenTransactionError,
+    TransactionInputError, TransactionOutputError, TransactionScriptError,
 };
 use miden_verifier::VerificationError;
 use thiserror::Error;
@@ -18,6 +19,8 @@ pub enum TransactionExecutorError {
     TransactionProgramExecutionFailed(#[source] ExecutionError),
     #[error("failed to fetch transaction inputs from the data store")]
     FetchTransactionInputsFailed(#[source] DataStoreError),
+    #[error("failed to fetch the latest block header from the data store")]
+    FetchLatestBlockHeaderFailed(#[source] DataStoreError),
     #[error("input account ID {input_id} does not match output account ID {output_id}")]
     InconsistentAccountId {
         input_id: AccountId,
@@ -32,6 +35,77 @@ pub enum TransactionExecutorError {
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to create transaction host")]
     TransactionHostCreationFailed(#[source] TransactionHostError),
+    #[error("transaction execution exceeded the cycle limit of {0} cycles")]
+    CycleLimitExceeded(u32),
+    #[error("note targets account {expected} and cannot be consumed by account {actual}")]
+    TargetAccountMismatch { expected: AccountId, actual: AccountId },
+    #[error("expected output note {note_id} was not created by the transaction")]
+    MissingExpectedOutputNote { note_id: NoteId },
+    #[error("transaction script {0} is not read-only")]
+    TransactionScriptNotReadonly(Digest),
+    #[error("transaction script marked as read-only failed verification")]
+    InvalidReadonlyTransactionScript(#[source] TransactionScriptError),
+    #[error("account witness of foreign account {account_id} is invalid")]
+    InvalidForeignAccountWitness {
+        account_id: AccountId,
+        source: MerkleError,
+    },
+    #[error(
+        "account root {actual} computed from the witness of foreign account {account_id} does not match the account root {expected} of the reference block"
+    )]
+    ForeignAccountRootMismatch {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+}
+
+// CHAIN EXECUTION ERROR
+// ================================================================================================
+
+/// Errors that can occur when executing a chain of dependent transactions with
+/// [`TransactionExecutor::execute_chain`](crate::TransactionExecutor::execute_chain).
+///
+/// Every variant identifies the index of the step at which the chain execution failed.
+#[cfg(feature = "std")]
+#[derive(Debug, Error)]
+pub enum ChainExecutionError {
+    #[error(
+        "step {step} references output note {note_index} of step {referenced_step}, which was not executed before it or has no such note"
+    )]
+    InvalidNoteReference {
+        step: usize,
+        referenced_step: usize,
+        note_index: usize,
+    },
+    #[error(
+        "step {step} references output note {note_index} of step {referenced_step}, whose details are not known"
+    )]
+    OutputNoteDetailsMissing {
+        step: usize,
+        referenced_step: usize,
+        note_index: usize,
+    },
+    #[error("failed to execute the transaction of step {step}")]
+    StepExecutionFailed {
+        step: usize,
+        source: TransactionExecutorError,
+    },
+    #[error("failed to apply the account delta of the transaction of step {step}")]
+    AccountDeltaApplicationFailed { step: usize, source: AccountError },
+}
+
+#[cfg(feature = "std")]
+impl ChainExecutionError {
+    /// Returns the index of the step at which the chain execution failed.
+    pub fn step(&self) -> usize {
+        match self {
+            Self::InvalidNoteReference { step, .. }
+            | Self::OutputNoteDetailsMissing { step, .. }
+            | Self::StepExecutionFailed { step, .. }
+            | Self::AccountDeltaApplicationFailed { step, .. } => *step,
+        }
+    }
 }
 
 // TRANSACTION PROVER ERROR
@@ -91,6 +165,71 @@ pub enum TransactionVerifierError {
         "transaction proof security level is {actual} but must be at least {expected_minimum}"
     )]
     InsufficientProofSecurityLevel { actual: u32, expected_minimum: u32 },
+    #[error("failed to verify transaction {tx_id} at index {index} of the batch")]
+    BatchTransactionVerificationFailed {
+        index: usize,
+        tx_id: TransactionId,
+        #[source]
+        source: Box<TransactionVerifierError>,
+    },
+}
+
+// STATELESS VALIDATION ERROR
+// ================================================================================================
+
+/// The stages of
+/// [`validate_proven_transaction_stateless`](crate::validate_proven_transaction_stateless), in the
+/// order in which they are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatelessValidationStage {
+    /// The root of the transaction kernel is checked against the accepted kernel roots.
+    Kernel,
+    /// The number of input and output notes and the size of output notes are checked.
+    NoteLimits,
+    /// The senders of the output notes are checked against the account of the transaction.
+    OutputNoteSenders,
+    /// The account update is checked against the size limit.
+    AccountUpdate,
+    /// The transaction proof is verified.
+    Proof,
+}
+
+/// Errors that can occur when validating a proven transaction without access to the chain state.
+#[derive(Debug, Error)]
+pub enum StatelessValidationError {
+    #[error(
+        "root {0} of the transaction kernel against which proofs are verified is not accepted"
+    )]
+    KernelRootNotAccepted(Digest),
+    #[error("transaction consumes {num_notes} input notes but at most {max} are allowed")]
+    TooManyInputNotes { num_notes: usize, max: usize },
+    #[error("transaction creates {num_notes} output notes but at most {max} are allowed")]
+    TooManyOutputNotes { num_notes: usize, max: usize },
+    #[error("output note {note_id} has size {size} bytes but at most {max} bytes are allowed")]
+    OutputNoteTooLarge { note_id: NoteId, size: usize, max: usize },
+    #[error(
+        "output note {note_id} was created by account {sender} instead of the transaction account"
+    )]
+    OutputNoteSenderMismatch { note_id: NoteId, sender: AccountId },
+    #[error("account update has size {size} bytes but at most {max} bytes are allowed")]
+    AccountUpdateTooLarge { size: usize, max: usize },
+    #[error("failed to verify transaction proof")]
+    ProofVerificationFailed(#[source] TransactionVerifierError),
+}
+
+impl StatelessValidationError {
+    /// Returns the validation stage at which the error occurred.
+    pub fn stage(&self) -> StatelessValidationStage {
+        match self {
+            Self::KernelRootNotAccepted(_) => StatelessValidationStage::Kernel,
+            Self::TooManyInputNotes { .. }
+            | Self::TooManyOutputNotes { .. }
+            | Self::OutputNoteTooLarge { .. } => StatelessValidationStage::NoteLimits,
+            Self::OutputNoteSenderMismatch { .. } => StatelessValidationStage::OutputNoteSenders,
+            Self::AccountUpdateTooLarge { .. } => StatelessValidationStage::AccountUpdate,
+            Self::ProofVerificationFailed(_) => StatelessValidationStage::Proof,
+        }
+    }
 }
 
 // TRANSACTION HOST ERROR
@@ -115,6 +254,8 @@ pub enum DataStoreError {
     BlockNotFound(BlockNumber),
     #[error("failed to create transaction inputs")]
     InvalidTransactionInput(#[source] TransactionInputError),
+    #[error("data store does not provide the latest block header")]
+    LatestBlockUnavailable,
     #[error("note with id {0} is already consumed")]
     NoteAlreadyConsumed(NoteId),
     #[error("not with id {0} not found in data store")]
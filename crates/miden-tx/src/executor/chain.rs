@@ -0,0 +1,261 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use miden_objects::{
+    account::{Account, AccountId},
+    block::{BlockHeader, BlockNumber},
+    note::{Note, NoteId},
+    transaction::{
+        ExecutedTransaction, InputNote, InputNotes, OutputNote, TransactionArgs, TransactionInputs,
+    },
+    AccountError,
+};
+use winter_maybe_async::*;
+
+use super::{DataStore, TransactionExecutor};
+use crate::{ChainExecutionError, DataStoreError, TransactionExecutorError};
+
+// TRANSACTION STEP
+// ================================================================================================
+
+/// An input note of a [TxStep].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxStepNote {
+    /// A note with the specified ID which is fetched from the [DataStore] of the executor.
+    Store(NoteId),
+    /// The output note at index `note_index` of the transaction executed at step `step` of the
+    /// same chain. The note is consumed as an unauthenticated note.
+    Output { step: usize, note_index: usize },
+}
+
+/// A single transaction of a chain of transactions executed by
+/// [TransactionExecutor::execute_chain].
+#[derive(Debug, Clone)]
+pub struct TxStep {
+    account_id: AccountId,
+    input_notes: Vec<TxStepNote>,
+    tx_args: TransactionArgs,
+}
+
+impl TxStep {
+    /// Returns a new [TxStep] executing a transaction against the account with the specified ID,
+    /// which consumes the provided `input_notes` and uses the provided `tx_args`.
+    pub fn new(
+        account_id: AccountId,
+        input_notes: Vec<TxStepNote>,
+        tx_args: TransactionArgs,
+    ) -> Self {
+        Self { account_id, input_notes, tx_args }
+    }
+
+    /// Returns the ID of the account executing this step.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the input notes consumed in this step.
+    pub fn input_notes(&self) -> &[TxStepNote] {
+        &self.input_notes
+    }
+
+    /// Returns the transaction arguments of this step.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+}
+
+// CHAIN EXECUTION
+// ================================================================================================
+
+impl TransactionExecutor {
+    /// Executes a chain of dependent transactions and returns the [ExecutedTransaction] of every
+    /// step in the order of the steps.
+    ///
+    /// All transactions are executed against the reference block `block_ref`. The state of an
+    /// account updated in a step is used as the initial state of that account in all subsequent
+    /// steps, and output notes of previous steps can be consumed as unauthenticated notes by
+    /// referencing them with [TxStepNote::Output]. All other data is fetched from the [DataStore]
+    /// of this executor, which is not modified.
+    ///
+    /// # Errors
+    /// Returns an error identifying the failing step if:
+    /// - A step references an output note of a step which is not executed before it, or an output
+    ///   note which does not exist.
+    /// - A step references an output note whose details are not known, e.g. a private note whose
+    ///   recipient was not provided as an expected output note.
+    /// - The transaction of a step fails to execute.
+    /// - The resulting account delta of a step cannot be applied to the account.
+    #[maybe_async]
+    pub fn execute_chain(
+        &self,
+        block_ref: BlockNumber,
+        steps: Vec<TxStep>,
+    ) -> Result<Vec<ExecutedTransaction>, ChainExecutionError> {
+        let mut data_store = ChainDataStore::new(self.data_store.as_ref());
+        let mut executed_transactions: Vec<ExecutedTransaction> = Vec::with_capacity(steps.len());
+
+        for (step, TxStep { account_id, input_notes, tx_args }) in steps.into_iter().enumerate() {
+            let note_ids = input_notes
+                .iter()
+                .map(|note| data_store.resolve_note(step, *note, &executed_transactions))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let tx_inputs =
+                maybe_await!(data_store.get_transaction_inputs(account_id, block_ref, &note_ids))
+                    .map_err(TransactionExecutorError::FetchTransactionInputsFailed)
+                    .map_err(|source| ChainExecutionError::StepExecutionFailed { step, source })?;
+
+            let executed_transaction = self
+                .execute_transaction_inputs(tx_inputs, tx_args)
+                .map_err(|source| ChainExecutionError::StepExecutionFailed { step, source })?;
+
+            data_store.apply_transaction(&executed_transaction).map_err(|source| {
+                ChainExecutionError::AccountDeltaApplicationFailed { step, source }
+            })?;
+
+            executed_transactions.push(executed_transaction);
+        }
+
+        Ok(executed_transactions)
+    }
+}
+
+// CHAIN DATA STORE
+// ================================================================================================
+
+/// An in-memory overlay over a [DataStore] which tracks the state changes of the transactions
+/// executed in a chain.
+struct ChainDataStore<'store> {
+    data_store: &'store dyn DataStore,
+    /// The latest states of the accounts updated in the chain.
+    accounts: BTreeMap<AccountId, Account>,
+    /// The output notes of the chain which are referenced as input notes by its steps.
+    notes: BTreeMap<NoteId, Note>,
+    /// The notes consumed in the chain.
+    consumed_notes: BTreeSet<NoteId>,
+}
+
+impl<'store> ChainDataStore<'store> {
+    fn new(data_store: &'store dyn DataStore) -> Self {
+        Self {
+            data_store,
+            accounts: BTreeMap::new(),
+            notes: BTreeMap::new(),
+            consumed_notes: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the ID of the provided input note of the step with index `step` and makes the
+    /// details of output notes of previous steps available in this data store.
+    fn resolve_note(
+        &mut self,
+        step: usize,
+        note: TxStepNote,
+        executed_transactions: &[ExecutedTransaction],
+    ) -> Result<NoteId, ChainExecutionError> {
+        let (referenced_step, note_index) = match note {
+            TxStepNote::Store(note_id) => return Ok(note_id),
+            TxStepNote::Output { step: referenced_step, note_index } => {
+                (referenced_step, note_index)
+            },
+        };
+
+        let output_note = executed_transactions
+            .get(referenced_step)
+            .and_then(|tx| tx.output_notes().iter().nth(note_index))
+            .ok_or(ChainExecutionError::InvalidNoteReference {
+                step,
+                referenced_step,
+                note_index,
+            })?;
+
+        match output_note {
+            OutputNote::Full(note) => {
+                self.notes.insert(note.id(), note.clone());
+                Ok(note.id())
+            },
+            _ => Err(ChainExecutionError::OutputNoteDetailsMissing {
+                step,
+                referenced_step,
+                note_index,
+            }),
+        }
+    }
+
+    /// Updates the state of the account and marks the input notes of the provided transaction as
+    /// consumed.
+    fn apply_transaction(
+        &mut self,
+        executed_transaction: &ExecutedTransaction,
+    ) -> Result<(), AccountError> {
+        let mut account = executed_transaction.initial_account().clone();
+        account.apply_delta(executed_transaction.account_delta())?;
+        self.accounts.insert(account.id(), account);
+
+        self.consumed_notes
+            .extend(executed_transaction.input_notes().iter().map(|note| note.id()));
+
+        Ok(())
+    }
+}
+
+#[maybe_async_trait]
+impl DataStore for ChainDataStore<'_> {
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        if let Some(note_id) = notes.iter().find(|note_id| self.consumed_notes.contains(note_id)) {
+            return Err(DataStoreError::NoteAlreadyConsumed(*note_id));
+        }
+
+        let store_notes: Vec<NoteId> = notes
+            .iter()
+            .filter(|note_id| !self.notes.contains_key(note_id))
+            .copied()
+            .collect();
+        let tx_inputs = maybe_await!(self.data_store.get_transaction_inputs(
+            account_id,
+            block_ref,
+            &store_notes
+        ))?;
+        let (account, account_seed, block_header, block_chain, store_input_notes) =
+            tx_inputs.into_parts();
+
+        // accounts updated in the chain are not new anymore and hence do not require a seed
+        let (account, account_seed) = match self.accounts.get(&account_id) {
+            Some(account) => (account.clone(), None),
+            None => (account, account_seed),
+        };
+
+        // keep the input notes in the requested order
+        let mut store_input_notes: BTreeMap<NoteId, InputNote> =
+            store_input_notes.into_iter().map(|note| (note.id(), note)).collect();
+        let input_notes = notes
+            .iter()
+            .map(|note_id| match self.notes.get(note_id) {
+                Some(note) => Ok(InputNote::unauthenticated(note.clone())),
+                None => {
+                    store_input_notes.remove(note_id).ok_or(DataStoreError::NoteNotFound(*note_id))
+                },
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+            .map_err(DataStoreError::InvalidTransactionInput)
+    }
+
+    #[maybe_async]
+    fn latest_block_header(&self) -> Result<BlockHeader, DataStoreError> {
+        maybe_await!(self.data_store.latest_block_header())
+    }
+}
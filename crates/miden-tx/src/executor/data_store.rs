@@ -2,7 +2,10 @@
 use alloc::boxed::Box;
 
 use miden_objects::{
-    account::AccountId, block::BlockNumber, note::NoteId, transaction::TransactionInputs,
+    account::AccountId,
+    block::{BlockHeader, BlockNumber},
+    note::NoteId,
+    transaction::TransactionInputs,
 };
 use winter_maybe_async::*;
 
@@ -37,4 +40,14 @@ pub trait DataStore {
         block_ref: BlockNumber,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError>;
+
+    /// Returns the header of the latest block available in the data store.
+    ///
+    /// This allows executing transactions against the latest state of the chain without choosing
+    /// a reference block up front. The default implementation returns
+    /// [DataStoreError::LatestBlockUnavailable] for data stores which cannot provide it.
+    #[maybe_async]
+    fn latest_block_header(&self) -> Result<BlockHeader, DataStoreError> {
+        Err(DataStoreError::LatestBlockUnavailable)
+    }
 }
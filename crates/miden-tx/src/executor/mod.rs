@@ -1,16 +1,26 @@
 use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    account::wallets::create_basic_wallet, transaction::TransactionKernel, AuthScheme,
+};
 use miden_objects::{
-    account::{AccountCode, AccountId},
+    account::{Account, AccountCode, AccountId, AccountIdAnchor, AccountStorageMode, AccountType},
     assembly::Library,
-    block::BlockNumber,
+    block::{BlockHeader, BlockNumber},
+    crypto::{
+        dsa::rpo_falcon512::PublicKey,
+        merkle::{Mmr, PartialMmr},
+    },
     note::NoteId,
-    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
-    vm::StackOutputs,
-    MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
+    transaction::{
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNotes, TransactionArgs,
+        TransactionInputs, TransactionScript,
+    },
+    utils::sync::LazyLock,
+    vm::{AdviceInputs, StackOutputs},
+    Digest, EMPTY_WORD, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ONE, ZERO,
 };
-use vm_processor::{ExecutionOptions, RecAdviceProvider};
+use vm_processor::{ExecutionError, ExecutionOptions, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
 use super::{TransactionExecutorError, TransactionHost};
@@ -22,6 +32,14 @@ pub use data_store::DataStore;
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+mod notes_checker;
+pub use notes_checker::{NoteAccountExecution, NoteConsumptionChecker};
+
+#[cfg(feature = "std")]
+mod chain;
+#[cfg(feature = "std")]
+pub use chain::{TxStep, TxStepNote};
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -42,6 +60,8 @@ pub struct TransactionExecutor {
     /// [Self::load_account_code()] method.
     account_codes: BTreeSet<AccountCode>,
     exec_options: ExecutionOptions,
+    validate_expected_output_notes: bool,
+    require_readonly_dry_run_scripts: bool,
 }
 
 impl TransactionExecutor {
@@ -68,6 +88,8 @@ impl TransactionExecutor {
             )
             .expect("Must not fail while max cycles is more than min trace length"),
             account_codes: BTreeSet::new(),
+            validate_expected_output_notes: false,
+            require_readonly_dry_run_scripts: false,
         }
     }
 
@@ -91,6 +113,31 @@ impl TransactionExecutor {
         self
     }
 
+    /// Enables validation of the expected output notes for the created instance of
+    /// [TransactionExecutor].
+    ///
+    /// When enabled, the executor checks after each execution that all notes added to the
+    /// transaction arguments via [TransactionArgs::add_expected_output_note] were created by the
+    /// transaction. Notes created by the transaction which were not expected, e.g. notes created by
+    /// the scripts of input notes, are allowed.
+    pub fn with_expected_output_validation(mut self) -> Self {
+        self.validate_expected_output_notes = true;
+        self
+    }
+
+    /// Requires the scripts executed via [Self::dry_run_script] to be read-only for the created
+    /// instance of [TransactionExecutor].
+    ///
+    /// When enabled, the executor rejects scripts which were not compiled via
+    /// [TransactionScript::compile_readonly]. Since the read-only flag is carried in the serialized
+    /// script, the scripts marked as read-only are checked again against
+    /// [TransactionKernel::state_mutating_procedures] and [TransactionKernel::readonly_procedures]
+    /// before they are executed.
+    pub fn with_strict_readonly_scripts(mut self) -> Self {
+        self.require_readonly_dry_run_scripts = true;
+        self
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -137,6 +184,285 @@ impl TransactionExecutor {
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        self.execute_transaction_inputs(tx_inputs, tx_args)
+    }
+
+    /// Prepares and executes a transaction like [Self::execute_transaction], using the latest
+    /// block available in the [DataStore] as the reference block.
+    ///
+    /// The chosen reference block is recorded in the returned [ExecutedTransaction] and can be
+    /// retrieved via [ExecutedTransaction::block_header], e.g. to check whether the transaction
+    /// became stale before submitting it.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - The latest block header can not be fetched from the [DataStore].
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction fails to execute.
+    #[maybe_async]
+    pub fn execute_transaction_latest(
+        &self,
+        account_id: AccountId,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let block_header = maybe_await!(self.data_store.latest_block_header())
+            .map_err(TransactionExecutorError::FetchLatestBlockHeaderFailed)?;
+
+        maybe_await!(self.execute_transaction(account_id, block_header.block_num(), notes, tx_args))
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments like
+    /// [Self::execute_transaction], and additionally returns the commitment of the native account
+    /// at the end of the execution of each input note.
+    ///
+    /// The intermediate commitments are returned in the order in which the notes were executed.
+    /// The account nonce is usually only incremented after all notes were executed, so the
+    /// intermediate commitments usually contain the initial nonce. This is intended for debugging
+    /// and requires tracing, which is enabled for this execution regardless of the executor's
+    /// options.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction fails to execute.
+    #[maybe_async]
+    pub fn execute_with_trace(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(ExecutedTransaction, Vec<Digest>), TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        let (executed_transaction, note_account_commitments) = self
+            .execute_transaction_inputs_with_host_options(
+                tx_inputs,
+                tx_args,
+                self.exec_options,
+                true,
+                &[],
+            )?;
+
+        let intermediate_commitments =
+            note_account_commitments.into_iter().map(|(_, commitment)| commitment).collect();
+
+        Ok((executed_transaction, intermediate_commitments))
+    }
+
+    /// Prepares and executes a transaction like [Self::execute_transaction], but aborts the
+    /// execution once it exceeds `max_cycles` VM cycles.
+    ///
+    /// This is useful when executing untrusted note scripts, which could otherwise run until the
+    /// default limit of [MAX_TX_EXECUTION_CYCLES] cycles is reached. The limit applies to the whole
+    /// transaction, including the cycles spent in the transaction kernel, and is clamped to the
+    /// range between [MIN_TX_EXECUTION_CYCLES] and [MAX_TX_EXECUTION_CYCLES].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction exceeds the cycle limit, in which case
+    ///   [TransactionExecutorError::CycleLimitExceeded] is returned.
+    /// - If the transaction fails to execute for any other reason.
+    #[maybe_async]
+    pub fn execute_transaction_with_limit(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+        max_cycles: u32,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        self.execute_transaction_inputs_with_limit(tx_inputs, tx_args, max_cycles)
+    }
+
+    /// Executes a transaction against the provided [TransactionInputs] like
+    /// [Self::execute_transaction_with_limit].
+    fn execute_transaction_inputs_with_limit(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+        max_cycles: u32,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let exec_options = ExecutionOptions::new(
+            Some(max_cycles.clamp(MIN_TX_EXECUTION_CYCLES, MAX_TX_EXECUTION_CYCLES)),
+            MIN_TX_EXECUTION_CYCLES,
+            self.exec_options.enable_tracing(),
+            self.exec_options.enable_debugging(),
+        )
+        .expect("Must not fail while max cycles is more than min trace length");
+
+        self.execute_transaction_inputs_with_host_options(
+            tx_inputs,
+            tx_args,
+            exec_options,
+            false,
+            &[],
+        )
+        .map(|(executed_transaction, _)| executed_transaction)
+        .map_err(|err| match err {
+            TransactionExecutorError::TransactionProgramExecutionFailed(
+                ExecutionError::CycleLimitExceeded(max_cycles),
+            ) => TransactionExecutorError::CycleLimitExceeded(max_cycles),
+            err => err,
+        })
+    }
+
+    /// Prepares and executes a transaction like [Self::execute_transaction], making the procedures
+    /// of the provided foreign accounts available for foreign procedure invocation.
+    ///
+    /// For each foreign account, the required account data and Merkle paths are added to the advice
+    /// inputs of the transaction, and the account code is loaded into the [TransactionMastStore].
+    /// As with [Self::load_account_code()], the loaded codes remain available to subsequent
+    /// executions of this executor.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the account witness of a foreign account is malformed, or if the account root computed
+    ///   from it does not match the account root of the reference block, e.g. because the witness
+    ///   is stale. In this case, the transaction is not executed.
+    /// - If the transaction fails to execute.
+    #[maybe_async]
+    pub fn execute_transaction_with_foreign_accounts(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+        foreign_accounts: Vec<ForeignAccountInputs>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        let account_root = tx_inputs.block_header().account_root();
+        let mut advice_inputs = AdviceInputs::default();
+        let mut foreign_account_codes = Vec::with_capacity(foreign_accounts.len());
+        for foreign_account in foreign_accounts {
+            let account_id = foreign_account.account_id();
+
+            // make sure the foreign account state is the one recorded in the reference block
+            // before executing the transaction, as the kernel would otherwise abort the execution
+            let computed_root = foreign_account.compute_account_root().map_err(|source| {
+                TransactionExecutorError::InvalidForeignAccountWitness { account_id, source }
+            })?;
+            if computed_root != account_root {
+                return Err(TransactionExecutorError::ForeignAccountRootMismatch {
+                    account_id,
+                    expected: account_root,
+                    actual: computed_root,
+                });
+            }
+
+            TransactionKernel::extend_advice_inputs_for_foreign_account(
+                &mut advice_inputs,
+                &foreign_account,
+            )
+            .map_err(|source| {
+                TransactionExecutorError::InvalidForeignAccountWitness { account_id, source }
+            })?;
+
+            self.mast_store.load_account_code(foreign_account.account_code());
+            let (_, _, account_code, ..) = foreign_account.into_parts();
+            foreign_account_codes.push(account_code);
+        }
+
+        self.execute_transaction_inputs_with_host_options(
+            tx_inputs,
+            tx_args.with_advice_inputs(advice_inputs),
+            self.exec_options,
+            false,
+            &foreign_account_codes,
+        )
+        .map(|(executed_transaction, _)| executed_transaction)
+    }
+
+    /// Executes the provided transaction script against a minimal standard account.
+    ///
+    /// This is intended for quickly checking that a script compiles into a program which runs
+    /// within a transaction, without having to set up a [DataStore]. The script is executed without
+    /// input notes against an existing basic wallet which holds no assets and is authenticated by
+    /// a placeholder public key, using the genesis block of an empty chain as the reference block.
+    /// Thus, the script must not depend on the state of a specific account or of the chain.
+    ///
+    /// The transaction kernel discards any values the script leaves on the stack, so only the
+    /// success of the execution is reported.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - The transaction fails to execute, e.g. if the script fails or changes the state of the
+    ///   account without incrementing its nonce.
+    /// - The executor was created with [Self::with_strict_readonly_scripts] and the script is not
+    ///   read-only.
+    pub fn dry_run_script(
+        &self,
+        tx_script: TransactionScript,
+    ) -> Result<(), TransactionExecutorError> {
+        if self.require_readonly_dry_run_scripts {
+            if !tx_script.is_readonly() {
+                return Err(TransactionExecutorError::TransactionScriptNotReadonly(
+                    tx_script.hash(),
+                ));
+            }
+            tx_script
+                .verify_readonly(
+                    TransactionKernel::state_mutating_procedures(),
+                    TransactionKernel::readonly_procedures(),
+                )
+                .map_err(TransactionExecutorError::InvalidReadonlyTransactionScript)?;
+        }
+
+        self.execute_transaction_inputs(
+            DRY_RUN_INPUTS.clone(),
+            TransactionArgs::with_tx_script(tx_script),
+        )?;
+
+        Ok(())
+    }
+
+    /// Executes a transaction against the provided [TransactionInputs] and returns an
+    /// [ExecutedTransaction].
+    fn execute_transaction_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        self.execute_transaction_inputs_with_host_options(
+            tx_inputs,
+            tx_args,
+            self.exec_options,
+            false,
+            &[],
+        )
+        .map(|(executed_transaction, _)| executed_transaction)
+    }
+
+    /// Executes a transaction against the provided [TransactionInputs] using the provided
+    /// [ExecutionOptions] and returns an [ExecutedTransaction] together with the account
+    /// commitments recorded at the end of the execution of each input note.
+    ///
+    /// The commitments are only recorded if `record_note_account_commitments` is `true`, in which
+    /// case tracing is enabled for this execution.
+    ///
+    /// The `foreign_account_codes` are treated like the codes loaded via
+    /// [Self::load_account_code()] for this execution only. Their MAST forests must already be
+    /// loaded into the MAST store.
+    fn execute_transaction_inputs_with_host_options(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+        exec_options: ExecutionOptions,
+        record_note_account_commitments: bool,
+        foreign_account_codes: &[AccountCode],
+    ) -> Result<(ExecutedTransaction, Vec<(NoteId, Digest)>), TransactionExecutorError> {
         let (stack_inputs, advice_inputs) =
             TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
@@ -149,23 +475,37 @@ impl TransactionExecutor {
             advice_recorder,
             self.mast_store.clone(),
             self.authenticator.clone(),
-            self.account_codes.iter().map(|code| code.commitment()).collect(),
+            self.account_codes
+                .iter()
+                .chain(foreign_account_codes)
+                .map(|code| code.commitment())
+                .collect(),
         )
         .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
 
+        let exec_options = if record_note_account_commitments {
+            host = host.with_note_account_commitments();
+            exec_options.with_tracing()
+        } else {
+            exec_options
+        };
+
         // execute the transaction kernel
         let result = vm_processor::execute(
             &TransactionKernel::main(),
             stack_inputs,
             &mut host,
-            self.exec_options,
+            exec_options,
         )
         .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
 
+        let note_account_commitments = host.tx_progress().note_account_commitments().to_vec();
+
         // Attempt to retrieve used account codes based on the advice map
         let account_codes = self
             .account_codes
             .iter()
+            .chain(foreign_account_codes)
             .filter_map(|code| {
                 tx_args
                     .advice_inputs()
@@ -174,19 +514,87 @@ impl TransactionExecutor {
             })
             .collect();
 
-        build_executed_transaction(
+        let executed_transaction = build_executed_transaction(
             tx_args,
             tx_inputs,
             result.stack_outputs().clone(),
             host,
             account_codes,
-        )
+        )?;
+
+        if self.validate_expected_output_notes {
+            validate_expected_output_notes(&executed_transaction)?;
+        }
+
+        Ok((executed_transaction, note_account_commitments))
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Checks that all notes expected by the transaction arguments of the provided transaction were
+/// created by the transaction.
+fn validate_expected_output_notes(
+    executed_transaction: &ExecutedTransaction,
+) -> Result<(), TransactionExecutorError> {
+    let output_notes: BTreeSet<NoteId> =
+        executed_transaction.output_notes().iter().map(|note| note.id()).collect();
+
+    match executed_transaction
+        .tx_args()
+        .expected_output_notes()
+        .iter()
+        .find(|note_id| !output_notes.contains(note_id))
+    {
+        Some(&note_id) => Err(TransactionExecutorError::MissingExpectedOutputNote { note_id }),
+        None => Ok(()),
+    }
+}
+
+/// The inputs of the transactions executed by [TransactionExecutor::dry_run_script], which are
+/// built only once since creating the basic wallet requires grinding an account seed.
+static DRY_RUN_INPUTS: LazyLock<TransactionInputs> = LazyLock::new(dry_run_inputs);
+
+/// Returns the inputs of a transaction without input notes against an existing basic wallet, using
+/// the genesis block of an empty chain as the reference block.
+fn dry_run_inputs() -> TransactionInputs {
+    let chain_mmr = ChainMmr::new(PartialMmr::from(Mmr::default().peaks()), Vec::new())
+        .expect("empty chain mmr should be valid");
+
+    let genesis_header = BlockHeader::new(
+        0,
+        Digest::default(),
+        BlockNumber::GENESIS,
+        chain_mmr.peaks().hash_peaks(),
+        Digest::default(),
+        Digest::default(),
+        Digest::default(),
+        Digest::default(),
+        TransactionKernel::kernel_root(),
+        Digest::default(),
+        0,
+    );
+    let anchor =
+        AccountIdAnchor::try_from(&genesis_header).expect("genesis block should be a valid anchor");
+
+    let (account, _) = create_basic_wallet(
+        [0; 32],
+        anchor,
+        AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(EMPTY_WORD) },
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageMode::Private,
+    )
+    .expect("basic wallet should be valid");
+
+    // use nonce 1 so that the wallet is treated as an existing account which requires no seed
+    let (id, vault, storage, code, _) = account.into_parts();
+    let account = Account::from_parts(id, vault, storage, code, ONE);
+
+    TransactionInputs::new(account, None, genesis_header, chain_mmr, InputNotes::default())
+        .expect("dry run transaction inputs should be valid")
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
 fn build_executed_transaction(
     tx_args: TransactionArgs,
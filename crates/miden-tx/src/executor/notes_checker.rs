@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+
+use miden_lib::note::WellKnownNote;
+use miden_objects::{
+    account::AccountId,
+    block::BlockNumber,
+    note::{Note, NoteId},
+    transaction::{TransactionArgs, TransactionInputs},
+};
+use winter_maybe_async::{maybe_async, maybe_await};
+
+use super::TransactionExecutor;
+use crate::TransactionExecutorError;
+
+// NOTE ACCOUNT EXECUTION
+// ================================================================================================
+
+/// The result of checking whether a single note can be consumed by an account.
+#[derive(Debug)]
+pub enum NoteAccountExecution {
+    /// The note can be consumed by the account.
+    Success { note_id: NoteId },
+    /// The note cannot be consumed by the account because the transaction consuming it failed.
+    Failure {
+        note_id: NoteId,
+        error: TransactionExecutorError,
+    },
+    /// The transaction consuming the note was aborted because it exceeded the cycle limit.
+    ///
+    /// The VM does not report the exact cycle at which it aborted the execution, so
+    /// `cycles_consumed` holds the configured cycle limit, i.e., the limit passed to the checker
+    /// clamped to the range accepted by the [TransactionExecutor]. The transaction consumed all of
+    /// these cycles before it was aborted.
+    CycleLimitExceeded { note_id: NoteId, cycles_consumed: u32 },
+}
+
+impl NoteAccountExecution {
+    /// Returns the ID of the note this result refers to.
+    pub fn note_id(&self) -> NoteId {
+        match self {
+            Self::Success { note_id }
+            | Self::Failure { note_id, .. }
+            | Self::CycleLimitExceeded { note_id, .. } => *note_id,
+        }
+    }
+
+    /// Returns `true` if the note can be consumed by the account.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success { .. })
+    }
+}
+
+// NOTE CONSUMPTION CHECKER
+// ================================================================================================
+
+/// This struct performs input notes check against provided target account.
+///
+/// The check is performed by executing transactions consuming the notes against the target account
+/// using the wrapped [TransactionExecutor]. The results of these executions are discarded, so no
+/// state is modified by the check.
+///
+/// Before executing a transaction, the checker verifies that none of the notes is a P2ID or P2IDR
+/// note targeting another account, and reports such notes with
+/// [TransactionExecutorError::TargetAccountMismatch] without executing the transaction.
+pub struct NoteConsumptionChecker<'a>(&'a TransactionExecutor);
+
+impl<'a> NoteConsumptionChecker<'a> {
+    /// Creates a new [`NoteConsumptionChecker`] instance with the given transaction executor.
+    pub fn new(tx_executor: &'a TransactionExecutor) -> Self {
+        NoteConsumptionChecker(tx_executor)
+    }
+
+    /// Checks whether the provided input notes could be consumed together by the provided
+    /// account in a single transaction.
+    ///
+    /// # Errors
+    /// Returns the error of the transaction execution if the notes cannot be consumed together.
+    #[maybe_async]
+    pub fn check_notes_consumability(
+        &self,
+        target_account_id: AccountId,
+        block_ref: BlockNumber,
+        note_ids: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(), TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.0.data_store.get_transaction_inputs(
+            target_account_id,
+            block_ref,
+            note_ids
+        ))
+        .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+        check_note_targets(&tx_inputs)?;
+
+        self.0.execute_transaction_inputs(tx_inputs, tx_args).map(|_| ())
+    }
+
+    /// Checks whether each of the provided input notes could be consumed by the provided account,
+    /// and returns the result of the check for every note in the order the notes were provided.
+    ///
+    /// Every note is checked in a separate transaction consuming only that note, with a copy of
+    /// the provided `tx_args`. Hence, the failure of one note does not affect the results for the
+    /// other notes, and the error returned for a note describes why that note specifically cannot
+    /// be consumed.
+    #[maybe_async]
+    pub fn check_notes_detailed(
+        &self,
+        target_account_id: AccountId,
+        block_ref: BlockNumber,
+        note_ids: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Vec<(NoteId, Result<(), TransactionExecutorError>)> {
+        let mut results = Vec::with_capacity(note_ids.len());
+        for &note_id in note_ids {
+            let result = maybe_await!(self.0.data_store.get_transaction_inputs(
+                target_account_id,
+                block_ref,
+                &[note_id]
+            ))
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)
+            .and_then(|tx_inputs| {
+                check_note_targets(&tx_inputs)?;
+                self.0.execute_transaction_inputs(tx_inputs, tx_args.clone()).map(|_| ())
+            });
+
+            results.push((note_id, result));
+        }
+
+        results
+    }
+
+    /// Checks whether each of the provided input notes could be consumed by the provided account
+    /// like [Self::check_notes_detailed], but aborts the check of a note once the transaction
+    /// consuming it exceeds `max_cycles` VM cycles.
+    ///
+    /// The limit applies to every note separately and includes the cycles spent in the
+    /// transaction kernel. A note exceeding the limit is reported as
+    /// [NoteAccountExecution::CycleLimitExceeded], and the notes after it are still checked.
+    #[maybe_async]
+    pub fn check_note_consumability_with_limit(
+        &self,
+        target_account_id: AccountId,
+        block_ref: BlockNumber,
+        note_ids: &[NoteId],
+        tx_args: TransactionArgs,
+        max_cycles: u32,
+    ) -> Vec<NoteAccountExecution> {
+        let mut results = Vec::with_capacity(note_ids.len());
+        for &note_id in note_ids {
+            let result = maybe_await!(self.0.data_store.get_transaction_inputs(
+                target_account_id,
+                block_ref,
+                &[note_id]
+            ))
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)
+            .and_then(|tx_inputs| {
+                check_note_targets(&tx_inputs)?;
+                self.0
+                    .execute_transaction_inputs_with_limit(tx_inputs, tx_args.clone(), max_cycles)
+            });
+
+            results.push(match result {
+                Ok(_) => NoteAccountExecution::Success { note_id },
+                Err(TransactionExecutorError::CycleLimitExceeded(cycles_consumed)) => {
+                    NoteAccountExecution::CycleLimitExceeded { note_id, cycles_consumed }
+                },
+                Err(error) => NoteAccountExecution::Failure { note_id, error },
+            });
+        }
+
+        results
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that none of the input notes is a P2ID or P2IDR note which targets an account other than
+/// the account of the provided transaction inputs.
+///
+/// This only improves the diagnostics for the most common reason why such notes cannot be
+/// consumed. The transaction kernel remains the source of truth, so notes passing this check may
+/// still fail to be consumed.
+///
+/// # Errors
+/// Returns [TransactionExecutorError::TargetAccountMismatch] for the first note which targets
+/// another account.
+fn check_note_targets(tx_inputs: &TransactionInputs) -> Result<(), TransactionExecutorError> {
+    let account_id = tx_inputs.account().id();
+    for input_note in tx_inputs.input_notes() {
+        if let Some(target_id) = p2id_target(input_note.note(), account_id) {
+            if target_id != account_id {
+                return Err(TransactionExecutorError::TargetAccountMismatch {
+                    expected: target_id,
+                    actual: account_id,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the ID of the account targeted by the provided note if it is a P2ID or P2IDR note which
+/// only the target account may consume in a transaction against the account with the provided ID.
+///
+/// The sender of a P2IDR note may reclaim it after the reclaim block height, so no target is
+/// returned for P2IDR notes sent by `account_id`.
+fn p2id_target(note: &Note, account_id: AccountId) -> Option<AccountId> {
+    let well_known_note = WellKnownNote::from_note(note)?;
+
+    let is_p2id = well_known_note == WellKnownNote::P2ID;
+    let is_p2idr =
+        well_known_note == WellKnownNote::P2IDR && note.metadata().sender() != account_id;
+    if !(is_p2id || is_p2idr) {
+        return None;
+    }
+
+    WellKnownNote::target_account_id(note)
+}
@@ -9,17 +9,21 @@ use alloc::{
 use miden_lib::{
     errors::tx_kernel_errors::TX_KERNEL_ERRORS,
     transaction::{
-        memory::{CURRENT_INPUT_NOTE_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR},
+        memory::{
+            CURRENT_INPUT_NOTE_PTR, NATIVE_ACCT_CODE_COMMITMENT_PTR, NATIVE_ACCT_ID_AND_NONCE_PTR,
+            NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR, NATIVE_ACCT_VAULT_ROOT_PTR,
+            NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR,
+        },
         TransactionEvent, TransactionEventError, TransactionKernelError, TransactionTrace,
     },
 };
 use miden_objects::{
-    account::{AccountDelta, AccountHeader},
+    account::{AccountDelta, AccountHeader, StorageSlot},
     asset::Asset,
     note::NoteId,
     transaction::{OutputNote, TransactionMeasurements},
     vm::{RowIndex, SystemEvent},
-    Digest, Hasher,
+    Digest, Hasher, Word, EMPTY_WORD, ZERO,
 };
 use vm_processor::{
     AdviceProvider, AdviceSource, ContextId, ExecutionError, Felt, Host, MastForest,
@@ -87,6 +91,10 @@ pub struct TransactionHost<A> {
     /// This field is updated by the [TransactionHost::on_trace()] handler.
     tx_progress: TransactionProgress,
 
+    /// Specifies whether the commitment of the native account at the end of the execution of each
+    /// input note is recorded in `tx_progress`.
+    record_note_account_commitments: bool,
+
     /// Contains mappings from error codes to the related error messages.
     ///
     /// This map is initialized at construction time from the [`TX_KERNEL_ERRORS`] array.
@@ -119,10 +127,21 @@ impl<A: AdviceProvider> TransactionHost<A> {
             authenticator,
             tx_progress: TransactionProgress::default(),
             generated_signatures: BTreeMap::new(),
+            record_note_account_commitments: false,
             error_messages: kernel_assertion_errors,
         })
     }
 
+    /// Enables recording the commitment of the native account at the end of the execution of each
+    /// input note in the [TransactionProgress] of this host.
+    ///
+    /// The commitments are recorded on the note execution trace events, so tracing must be enabled
+    /// in the execution options for them to be recorded.
+    pub fn with_note_account_commitments(mut self) -> Self {
+        self.record_note_account_commitments = true;
+        self
+    }
+
     /// Consumes `self` and returns the advice provider, account delta, output notes, generated
     /// signatures, and transaction progress.
     pub fn into_parts(
@@ -446,6 +465,53 @@ impl<A: AdviceProvider> TransactionHost<A> {
         }
     }
 
+    /// Returns the commitment of the native account computed from its current state in the kernel
+    /// memory.
+    ///
+    /// The vault root of the account in memory is kept up to date by the kernel, but the storage
+    /// commitment is only updated in the epilogue. Hence, the storage commitment is computed from
+    /// the storage slots section of the account.
+    ///
+    /// # Errors
+    /// Returns an error if the memory location supposed to contain the account storage slot number
+    /// has not been initialized, or if any of the account memory addresses is invalid.
+    fn get_native_account_commitment(process: ProcessState) -> Result<Digest, ExecutionError> {
+        let ctx = ContextId::root();
+        let read_word = |addr: u32| -> Result<Word, ExecutionError> {
+            Ok(process.get_mem_word(ctx, addr)?.unwrap_or(EMPTY_WORD))
+        };
+
+        let num_storage_slots = process
+            .get_mem_value(ctx, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR)
+            .ok_or_else(|| {
+                ExecutionError::EventError(Box::new(
+                    TransactionKernelError::AccountStorageSlotsNumMissing(
+                        NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR,
+                    ),
+                ))
+            })?
+            .as_int() as u32;
+
+        // every storage slot occupies two words: [SLOT_VALUE, slot_type, 0, 0, 0]
+        let mut storage_elements = Vec::with_capacity(
+            num_storage_slots as usize * StorageSlot::NUM_ELEMENTS_PER_STORAGE_SLOT,
+        );
+        for word_idx in 0..num_storage_slots * 2 {
+            storage_elements
+                .extend(read_word(NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR + word_idx * 4)?);
+        }
+
+        // the account ID and nonce word has the layout [suffix, prefix, 0, nonce], which is the
+        // layout expected by the account hash
+        let mut elements = [ZERO; 16];
+        elements[..4].copy_from_slice(&read_word(NATIVE_ACCT_ID_AND_NONCE_PTR)?);
+        elements[4..8].copy_from_slice(&read_word(NATIVE_ACCT_VAULT_ROOT_PTR)?);
+        elements[8..12].copy_from_slice(Hasher::hash_elements(&storage_elements).as_elements());
+        elements[12..].copy_from_slice(&read_word(NATIVE_ACCT_CODE_COMMITMENT_PTR)?);
+
+        Ok(Hasher::hash_elements(&elements))
+    }
+
     /// Returns the number of storage slots initialized for the current account.
     ///
     /// # Errors
@@ -541,6 +607,10 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
 
             TransactionEvent::NoteBeforeAddAsset => self.on_note_before_add_asset(process),
             TransactionEvent::NoteAfterAddAsset => Ok(()),
+
+            // the forwarded asset is added to the output note via the add asset events
+            TransactionEvent::NoteBeforeForwardAsset => Ok(()),
+            TransactionEvent::NoteAfterForwardAsset => Ok(()),
         }
         .map_err(|err| ExecutionError::EventError(Box::new(err)))?;
 
@@ -563,7 +633,13 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
                 );
                 self.tx_progress.start_note_execution(process.clk(), note_id);
             },
-            NoteExecutionEnd => self.tx_progress.end_note_execution(process.clk()),
+            NoteExecutionEnd => {
+                self.tx_progress.end_note_execution(process.clk());
+                if self.record_note_account_commitments {
+                    let account_commitment = Self::get_native_account_commitment(process)?;
+                    self.tx_progress.record_note_account_commitment(account_commitment);
+                }
+            },
             TxScriptProcessingStart => self.tx_progress.start_tx_script_processing(process.clk()),
             TxScriptProcessingEnd => self.tx_progress.end_tx_script_processing(process.clk()),
             EpilogueStart => self.tx_progress.start_epilogue(process.clk()),
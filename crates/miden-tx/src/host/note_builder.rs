@@ -38,7 +38,9 @@ impl OutputNoteBuilder {
     /// - A combination of note type, sender account ID, and note tag do not form a valid
     ///   [NoteMetadata] object.
     /// - Recipient information in the advice provider is present but is malformed.
-    /// - A non-private note is missing recipient details.
+    ///
+    /// A non-private note without recipient details results in a partial note; such notes are
+    /// rejected once the transaction outputs are built.
     pub fn new<A: AdviceProvider>(
         stack: Vec<Felt>,
         adv_provider: &A,
@@ -105,14 +107,8 @@ impl OutputNoteBuilder {
             let recipient = NoteRecipient::new(serial_num, script, inputs);
 
             Some(recipient)
-        } else if metadata.is_private() {
-            None
         } else {
-            // if there are no recipient details and the note is not private, return an error
-            return Err(TransactionKernelError::PublicNoteMissingDetails(
-                metadata,
-                recipient_digest,
-            ));
+            None
         };
         Ok(Self {
             metadata,
@@ -1,17 +1,21 @@
 pub use alloc::vec::Vec;
 
-use super::{NoteId, RowIndex, TransactionMeasurements};
+use super::{Digest, NoteId, RowIndex, TransactionMeasurements};
 
 // TRANSACTION PROGRESS
 // ================================================================================================
 
 /// Contains the information about the number of cycles for each of the transaction execution
 /// stages.
+///
+/// If enabled on the [TransactionHost](super::TransactionHost), it also contains the commitment of
+/// the native account at the end of the execution of each input note.
 #[derive(Clone, Default, Debug)]
 pub struct TransactionProgress {
     prologue: CycleInterval,
     notes_processing: CycleInterval,
     note_execution: Vec<(NoteId, CycleInterval)>,
+    note_account_commitments: Vec<(NoteId, Digest)>,
     tx_script_processing: CycleInterval,
     epilogue: CycleInterval,
 }
@@ -32,6 +36,12 @@ impl TransactionProgress {
         &self.note_execution
     }
 
+    /// Returns the commitments of the native account at the end of the execution of each input
+    /// note, in the order the notes were executed.
+    pub fn note_account_commitments(&self) -> &[(NoteId, Digest)] {
+        &self.note_account_commitments
+    }
+
     pub fn tx_script_processing(&self) -> &CycleInterval {
         &self.tx_script_processing
     }
@@ -69,6 +79,14 @@ impl TransactionProgress {
         }
     }
 
+    /// Records the commitment of the native account at the end of the execution of the last
+    /// started input note.
+    pub fn record_note_account_commitment(&mut self, account_commitment: Digest) {
+        if let Some((note_id, _)) = self.note_execution.last() {
+            self.note_account_commitments.push((*note_id, account_commitment));
+        }
+    }
+
     pub fn start_tx_script_processing(&mut self, cycle: RowIndex) {
         self.tx_script_processing.set_start(cycle);
     }
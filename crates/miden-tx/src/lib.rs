@@ -9,21 +9,34 @@ extern crate std;
 pub use miden_objects::transaction::TransactionInputs;
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor, TransactionMastStore};
+pub use executor::{
+    DataStore, NoteAccountExecution, NoteConsumptionChecker, TransactionExecutor,
+    TransactionMastStore,
+};
+#[cfg(feature = "std")]
+pub use executor::{TxStep, TxStepNote};
 
 pub mod host;
 pub use host::{TransactionHost, TransactionProgress};
 
 mod prover;
-pub use prover::{LocalTransactionProver, ProvingOptions, TransactionProver};
+pub use prover::{
+    LocalTransactionProver, ProvenTransactionWithWitness, ProvingOptions, ProvingOptionsExt,
+    TransactionProver, TransactionProvingOptions, PROOF_VERSION,
+};
 
 mod verifier;
-pub use verifier::TransactionVerifier;
+pub use verifier::{
+    validate_proven_transaction_stateless, StatelessValidationConfig, TransactionVerifier,
+    DEFAULT_MAX_OUTPUT_NOTE_SIZE,
+};
 
 mod errors;
+#[cfg(feature = "std")]
+pub use errors::ChainExecutionError;
 pub use errors::{
-    AuthenticationError, DataStoreError, TransactionExecutorError, TransactionProverError,
-    TransactionVerifierError,
+    AuthenticationError, DataStoreError, StatelessValidationError, StatelessValidationStage,
+    TransactionExecutorError, TransactionProverError, TransactionVerifierError,
 };
 
 pub mod auth;
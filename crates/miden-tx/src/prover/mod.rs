@@ -1,21 +1,33 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
 
+use miden_air::trace::{AUX_TRACE_WIDTH, TRACE_WIDTH as MAIN_TRACE_WIDTH};
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     account::delta::AccountUpdateDetails,
     assembly::Library,
     transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness},
 };
-use miden_prover::prove;
 pub use miden_prover::ProvingOptions;
+use miden_prover::{prove, HashFunction};
 use vm_processor::MemAdviceProvider;
+use winter_air::{FieldExtension, ProofOptions as WinterProofOptions};
 use winter_maybe_async::*;
 
 use super::{TransactionHost, TransactionProverError};
 use crate::executor::TransactionMastStore;
 
+// CONSTANTS
+// ================================================================================================
+
+/// The version of the proofs generated by [LocalTransactionProver].
+///
+/// It must be incremented whenever a change to the transaction prover makes the generated proofs
+/// incompatible with verifiers of previous versions. Remote provers use it to make sure that the
+/// proofs they generate can be verified by their clients.
+pub const PROOF_VERSION: u32 = 1;
+
 // TRANSACTION PROVER TRAIT
 // ================================================================================================
 
@@ -44,15 +56,18 @@ pub trait TransactionProver {
 /// Local Transaction Prover implements the [TransactionProver] trait.
 pub struct LocalTransactionProver {
     mast_store: Arc<TransactionMastStore>,
-    proof_options: ProvingOptions,
+    proof_options: TransactionProvingOptions,
 }
 
 impl LocalTransactionProver {
     /// Creates a new [LocalTransactionProver] instance.
-    pub fn new(proof_options: ProvingOptions) -> Self {
+    ///
+    /// The options can either be plain [ProvingOptions] or [TransactionProvingOptions], which are
+    /// created via [ProvingOptionsExt::retain_witness].
+    pub fn new(proof_options: impl Into<TransactionProvingOptions>) -> Self {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
-            proof_options,
+            proof_options: proof_options.into(),
         }
     }
 
@@ -63,6 +78,26 @@ impl LocalTransactionProver {
     pub fn load_library(&mut self, library: &Library) {
         self.mast_store.insert(library.mast_forest().clone());
     }
+
+    /// Proves the provided transaction and returns the [ProvenTransaction] together with the
+    /// witness it was proven from, if witness retention is enabled (see
+    /// [ProvingOptionsExt::retain_witness]).
+    ///
+    /// The retained witness is the exact witness passed to this method, so it can be used to
+    /// re-prove the transaction later, e.g., with a higher security level.
+    ///
+    /// # Errors
+    /// Returns an error if the transaction could not be proven (see [TransactionProver::prove]).
+    #[maybe_async]
+    pub fn prove_retaining(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransactionWithWitness, TransactionProverError> {
+        let witness = self.proof_options.retains_witness().then(|| tx_witness.clone());
+        let proven = maybe_await!(self.prove(tx_witness))?;
+
+        Ok(ProvenTransactionWithWitness { proven, witness })
+    }
 }
 
 impl Default for LocalTransactionProver {
@@ -118,7 +153,7 @@ impl TransactionProver for LocalTransactionProver {
             &TransactionKernel::main(),
             stack_inputs,
             &mut host,
-            self.proof_options.clone()
+            self.proof_options.proving_options().clone()
         ))
         .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
 
@@ -142,7 +177,8 @@ impl TransactionProver for LocalTransactionProver {
             proof,
         )
         .add_input_notes(input_notes)
-        .add_output_notes(output_notes);
+        .add_output_notes(output_notes)
+        .kernel_version(TransactionKernel::version().to_string());
 
         let builder = match account.is_public() {
             true => {
@@ -165,3 +201,156 @@ impl TransactionProver for LocalTransactionProver {
         builder.build().map_err(TransactionProverError::ProvenTransactionBuildFailed)
     }
 }
+
+// PROVEN TRANSACTION WITH WITNESS
+// ================================================================================================
+
+/// A [ProvenTransaction] together with the [TransactionWitness] it was proven from.
+///
+/// The witness is only present if the prover was configured to retain it. When the two are stored
+/// separately, they can be correlated via [TransactionWitness::digest].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProvenTransactionWithWitness {
+    pub proven: ProvenTransaction,
+    pub witness: Option<TransactionWitness>,
+}
+
+// TRANSACTION PROVING OPTIONS
+// ================================================================================================
+
+/// [ProvingOptions] together with the options of [LocalTransactionProver] which are not defined by
+/// the Miden prover.
+///
+/// These options are created from [ProvingOptions] via [ProvingOptionsExt::retain_witness].
+#[derive(Clone, Debug, Default)]
+pub struct TransactionProvingOptions {
+    proving_options: ProvingOptions,
+    retain_witness: bool,
+}
+
+impl TransactionProvingOptions {
+    /// Sets whether [LocalTransactionProver::prove_retaining] returns the witness from which a
+    /// transaction was proven alongside the proven transaction.
+    pub fn retain_witness(mut self, retain: bool) -> Self {
+        self.retain_witness = retain;
+        self
+    }
+
+    /// Returns the options with which the transaction program is proven.
+    pub fn proving_options(&self) -> &ProvingOptions {
+        &self.proving_options
+    }
+
+    /// Returns true if the witness from which a transaction was proven is retained.
+    pub fn retains_witness(&self) -> bool {
+        self.retain_witness
+    }
+}
+
+impl From<ProvingOptions> for TransactionProvingOptions {
+    fn from(proving_options: ProvingOptions) -> Self {
+        Self { proving_options, retain_witness: false }
+    }
+}
+
+// PROVING OPTIONS EXTENSION
+// ================================================================================================
+
+/// The number of bytes of a base field element.
+const FELT_BYTES: usize = 8;
+
+/// The number of bytes of a node of the Merkle trees committing to the trace segments.
+const DIGEST_BYTES: usize = 32;
+
+/// The number of bits of the base field of the VM.
+const FIELD_BITS: u32 = 64;
+
+/// Extends [ProvingOptions] with preset profiles and resource estimations for proving
+/// transactions.
+pub trait ProvingOptionsExt: Sized {
+    /// Returns options which prove transactions at the minimum security level accepted by the
+    /// network, i.e. [MIN_PROOF_SECURITY_LEVEL](miden_objects::MIN_PROOF_SECURITY_LEVEL).
+    ///
+    /// These are the same options as [ProvingOptions::default].
+    fn fast() -> Self;
+
+    /// Returns options which trade some proving time for a security level of 112 bits.
+    fn balanced() -> Self;
+
+    /// Returns options which prove transactions at a security level of 128 bits, which is the
+    /// maximum level attainable with the collision resistance of the RPO hash function.
+    fn maximum_security() -> Self;
+
+    /// Returns the conjectured security level, in bits, of the proofs created with these options.
+    ///
+    /// The security level is computed as in the verifier, except that the bound imposed by the
+    /// size of the extension field is not reduced by the size of the low-degree extension domain.
+    /// For the presets, this bound is far from the binding one for transactions of any realistic
+    /// length, so the returned level matches the level reported by the verifier.
+    fn security_level(&self) -> u32;
+
+    /// Returns a rough estimate of the number of bytes of memory required to prove a transaction
+    /// whose execution trace has `trace_len` rows (see
+    /// [ExecutedTransaction::trace_length_estimate](miden_objects::transaction::ExecutedTransaction::trace_length_estimate)).
+    ///
+    /// The estimate models the dominant allocations of the prover, which are proportional to the
+    /// size of the low-degree extension domain, i.e. `trace_len * blowup_factor` rows:
+    /// - the extended main trace segment, with one base field element per column and row.
+    /// - the extended auxiliary trace segment, with one extension field element per column and row.
+    /// - the Merkle trees committing to both extended segments, with two digests per row.
+    ///
+    /// Other allocations, e.g. the constraint composition polynomial and the FRI layers, are not
+    /// included, so the estimate is only meant to compare the requirements of transactions.
+    fn estimated_memory_bytes(&self, trace_len: usize) -> usize;
+
+    /// Returns [TransactionProvingOptions] with these options which make
+    /// [LocalTransactionProver::prove_retaining] return the witness a transaction was proven from
+    /// if `retain` is true.
+    ///
+    /// Witness retention is disabled by default.
+    fn retain_witness(self, retain: bool) -> TransactionProvingOptions;
+}
+
+impl ProvingOptionsExt for ProvingOptions {
+    fn fast() -> Self {
+        ProvingOptions::with_96_bit_security(false)
+    }
+
+    fn balanced() -> Self {
+        ProvingOptions::new(32, 8, 17, FieldExtension::Cubic, 8, 255, HashFunction::Rpo256)
+    }
+
+    fn maximum_security() -> Self {
+        ProvingOptions::with_128_bit_security(false)
+    }
+
+    fn security_level(&self) -> u32 {
+        let proof_options = WinterProofOptions::from(self.clone());
+        let field_security = FIELD_BITS * proof_options.field_extension().degree();
+        let query_security = proof_options.blowup_factor().ilog2()
+            * proof_options.num_queries() as u32
+            + proof_options.grinding_factor();
+        let collision_resistance = match self.hash_fn() {
+            HashFunction::Blake3_192 => 96,
+            HashFunction::Blake3_256 | HashFunction::Rpo256 | HashFunction::Rpx256 => 128,
+        };
+
+        (field_security.min(query_security) - 1).min(collision_resistance)
+    }
+
+    fn estimated_memory_bytes(&self, trace_len: usize) -> usize {
+        let proof_options = WinterProofOptions::from(self.clone());
+        let lde_domain_size = trace_len * proof_options.blowup_factor();
+        let extension_degree = proof_options.field_extension().degree() as usize;
+
+        let main_segment_bytes = MAIN_TRACE_WIDTH * FELT_BYTES;
+        let aux_segment_bytes = AUX_TRACE_WIDTH * extension_degree * FELT_BYTES;
+        let commitment_bytes = 2 * 2 * DIGEST_BYTES;
+
+        lde_domain_size * (main_segment_bytes + aux_segment_bytes + commitment_bytes)
+    }
+
+    fn retain_witness(self, retain: bool) -> TransactionProvingOptions {
+        TransactionProvingOptions::from(self).retain_witness(retain)
+    }
+}
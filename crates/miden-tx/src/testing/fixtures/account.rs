@@ -0,0 +1,101 @@
+use miden_lib::account::{auth::RpoFalcon512, faucets::BasicFungibleFaucet, wallets::BasicWallet};
+use miden_objects::{
+    account::{Account, AccountBuilder, AccountStorageMode, AccountType, AuthSecretKey},
+    asset::TokenSymbol,
+    crypto::dsa::rpo_falcon512::SecretKey,
+    Felt,
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+use crate::auth::BasicAuthenticator;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The seed of the secret key which authenticates all fixture accounts.
+pub const FIXTURE_KEY_SEED: [u8; 32] = [0x5e; 32];
+
+/// The seed of [basic_wallet_a].
+pub const BASIC_WALLET_A_SEED: [u8; 32] = [0xa1; 32];
+
+/// The seed of [basic_wallet_b].
+pub const BASIC_WALLET_B_SEED: [u8; 32] = [0xb2; 32];
+
+/// The seed of [public_faucet_usdt].
+pub const PUBLIC_FAUCET_USDT_SEED: [u8; 32] = [0xc3; 32];
+
+/// The seed of [public_faucet_btc].
+pub const PUBLIC_FAUCET_BTC_SEED: [u8; 32] = [0xd4; 32];
+
+// ACCOUNT FIXTURES
+// ================================================================================================
+
+/// Returns an existing public basic wallet with updatable code and an empty vault.
+pub fn basic_wallet_a() -> Account {
+    AccountBuilder::new(BASIC_WALLET_A_SEED)
+        .storage_mode(AccountStorageMode::Public)
+        .with_component(BasicWallet)
+        .with_component(auth_component())
+        .build_existing()
+        .expect("fixture account should be valid")
+}
+
+/// Returns an existing private basic wallet with updatable code and an empty vault.
+pub fn basic_wallet_b() -> Account {
+    AccountBuilder::new(BASIC_WALLET_B_SEED)
+        .storage_mode(AccountStorageMode::Private)
+        .with_component(BasicWallet)
+        .with_component(auth_component())
+        .build_existing()
+        .expect("fixture account should be valid")
+}
+
+/// Returns an existing public fungible faucet issuing "USDT" with 6 decimals and a maximum supply
+/// of 10^15 base units, which has not issued any tokens yet.
+pub fn public_faucet_usdt() -> Account {
+    fungible_faucet(PUBLIC_FAUCET_USDT_SEED, "USDT", 6, 1_000_000_000_000_000)
+}
+
+/// Returns an existing public fungible faucet issuing "BTC" with 8 decimals and a maximum supply
+/// of 21 * 10^14 base units, which has not issued any tokens yet.
+pub fn public_faucet_btc() -> Account {
+    fungible_faucet(PUBLIC_FAUCET_BTC_SEED, "BTC", 8, 2_100_000_000_000_000)
+}
+
+/// Returns an authenticator holding the secret key of all fixture accounts.
+pub fn authenticator() -> BasicAuthenticator<ChaCha20Rng> {
+    let mut rng = ChaCha20Rng::from_seed(FIXTURE_KEY_SEED);
+    let sec_key = SecretKey::with_rng(&mut rng);
+
+    BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+        &[(sec_key.public_key().into(), AuthSecretKey::RpoFalcon512(sec_key))],
+        rng,
+    )
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the authentication component with the public key of the fixture accounts.
+fn auth_component() -> RpoFalcon512 {
+    let mut rng = ChaCha20Rng::from_seed(FIXTURE_KEY_SEED);
+    RpoFalcon512::new(SecretKey::with_rng(&mut rng).public_key())
+}
+
+fn fungible_faucet(seed: [u8; 32], symbol: &str, decimals: u8, max_supply: u64) -> Account {
+    let faucet = BasicFungibleFaucet::new(
+        TokenSymbol::new(symbol).expect("fixture token symbol should be valid"),
+        decimals,
+        Felt::new(max_supply),
+    )
+    .expect("fixture faucet metadata should be valid");
+
+    AccountBuilder::new(seed)
+        .account_type(AccountType::FungibleFaucet)
+        .storage_mode(AccountStorageMode::Public)
+        .with_component(faucet)
+        .with_component(auth_component())
+        .build_existing()
+        .expect("fixture account should be valid")
+}
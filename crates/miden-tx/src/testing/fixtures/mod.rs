@@ -0,0 +1,83 @@
+//! Named, deterministic accounts and notes shared by tests across crates.
+//!
+//! All fixtures are derived from fixed seeds, so the IDs and commitments of the returned objects
+//! are the same across runs and crates. This makes failures of tests which use the same fixture
+//! directly comparable.
+//!
+//! # Examples
+//!
+//! The IDs of the fixture accounts are pinned:
+//! ```
+//! # use miden_tx::testing::fixtures;
+//! assert_eq!(
+//!     fixtures::account::basic_wallet_a().id().to_hex(),
+//!     "0xa1a1a1a0a1a1a1100000a1a1a1a1a1"
+//! );
+//! assert_eq!(
+//!     fixtures::account::basic_wallet_b().id().to_hex(),
+//!     "0xb2b2b2b2b2b2b2900000b2b2b2b2b2"
+//! );
+//! assert_eq!(
+//!     fixtures::account::public_faucet_usdt().id().to_hex(),
+//!     "0xc3c3c3c2c3c3c3200000c3c3c3c3c3"
+//! );
+//! assert_eq!(
+//!     fixtures::account::public_faucet_btc().id().to_hex(),
+//!     "0xd4d4d4d4d4d4d4200000d4d4d4d4d4"
+//! );
+//! ```
+//!
+//! So are the commitments to their initial states:
+//! ```
+//! # use miden_tx::testing::fixtures;
+//! assert_eq!(
+//!     fixtures::account::basic_wallet_a().hash().to_hex(),
+//!     "0xf0558dbbcdfa374d38127fb054614a50e88597723f5cf911c9faaacf89595173"
+//! );
+//! assert_eq!(
+//!     fixtures::account::basic_wallet_b().hash().to_hex(),
+//!     "0xd651eb217c52d01a3568e551ad97e624ec93af12592f14fad2bccf3d75f19162"
+//! );
+//! assert_eq!(
+//!     fixtures::account::public_faucet_usdt().hash().to_hex(),
+//!     "0x2c6779e80221cfb7abb64af151d402e3df58da1a76570947b91e1c26eeaf62cf"
+//! );
+//! assert_eq!(
+//!     fixtures::account::public_faucet_btc().hash().to_hex(),
+//!     "0xabd218a60689c224b6315807e433794f223bfe7f9fc28d35c710422d939e07ed"
+//! );
+//! ```
+//!
+//! As well as the IDs, recipients and nullifiers of the notes:
+//! ```
+//! # use miden_objects::block::BlockNumber;
+//! # use miden_tx::testing::fixtures;
+//! let target = fixtures::account::basic_wallet_a().id();
+//!
+//! let p2id_note = fixtures::note::p2id_100_to(target);
+//! assert_eq!(
+//!     p2id_note.id().to_hex(),
+//!     "0x67bb61608eeface53618c5c2c3a2f7c3794fcef891faf8a82ab9d0990ca3a048"
+//! );
+//! assert_eq!(
+//!     p2id_note.recipient().digest().to_hex(),
+//!     "0x8fcf595b453bbaa20ba0fb05b482e100d03e5eef58d80cb1a669da27a56e9df7"
+//! );
+//! assert_eq!(
+//!     p2id_note.nullifier().to_hex(),
+//!     "0x35ae3171d7b71b69e08e23211207681d7576ee0d162cbaa52780cec88ccd9e57"
+//! );
+//!
+//! let p2idr_note = fixtures::note::p2idr_100_to(target, BlockNumber::from(10));
+//! assert_eq!(
+//!     p2idr_note.id().to_hex(),
+//!     "0x0579583bc82d44320f396324acd11eaa3a9f89b544f562a73958fa9670b006b7"
+//! );
+//! assert_eq!(
+//!     p2idr_note.nullifier().to_hex(),
+//!     "0x5ca46d665562ff1cf51488ab42148a29a80e44c7c21b3811c634651bc73f9c79"
+//! );
+//! ```
+
+pub mod account;
+pub mod note;
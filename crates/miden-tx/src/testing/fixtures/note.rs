@@ -0,0 +1,55 @@
+use miden_lib::note::{create_p2id_note, create_p2idr_note};
+use miden_objects::{
+    account::AccountId,
+    asset::FungibleAsset,
+    block::BlockNumber,
+    crypto::rand::RpoRandomCoin,
+    note::{Note, NoteType},
+    Felt, Word, ZERO,
+};
+
+use super::account::public_faucet_usdt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The seed from which the serial numbers of the fixture notes are drawn.
+pub const NOTE_SERIAL_NUM_SEED: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+// NOTE FIXTURES
+// ================================================================================================
+
+/// Returns a public P2ID note sent by [public_faucet_usdt] which carries 100 USDT base units and
+/// can be consumed by the `target` account.
+pub fn p2id_100_to(target: AccountId) -> Note {
+    let faucet_id = public_faucet_usdt().id();
+    let asset = FungibleAsset::new(faucet_id, 100).expect("fixture asset should be valid");
+
+    create_p2id_note(
+        faucet_id,
+        target,
+        vec![asset.into()],
+        NoteType::Public,
+        ZERO,
+        &mut RpoRandomCoin::new(NOTE_SERIAL_NUM_SEED),
+    )
+    .expect("fixture note should be valid")
+}
+
+/// Returns a public P2IDR note sent by [public_faucet_usdt] which carries 100 USDT base units and
+/// can be consumed by the `target` account, or by the faucet from `recall_height` on.
+pub fn p2idr_100_to(target: AccountId, recall_height: BlockNumber) -> Note {
+    let faucet_id = public_faucet_usdt().id();
+    let asset = FungibleAsset::new(faucet_id, 100).expect("fixture asset should be valid");
+
+    create_p2idr_note(
+        faucet_id,
+        target,
+        vec![asset.into()],
+        NoteType::Public,
+        ZERO,
+        recall_height,
+        &mut RpoRandomCoin::new(NOTE_SERIAL_NUM_SEED),
+    )
+    .expect("fixture note should be valid")
+}
@@ -1,14 +1,20 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
 
 use miden_lib::{
     account::{auth::RpoFalcon512, faucets::BasicFungibleFaucet, wallets::BasicWallet},
-    note::{create_p2id_note, create_p2idr_note},
+    note::utils::{build_p2id_recipient, build_p2idr_recipient},
     transaction::{memory, TransactionKernel},
 };
 use miden_objects::{
     account::{
         delta::AccountUpdateDetails, Account, AccountBuilder, AccountComponent, AccountDelta,
-        AccountId, AccountIdAnchor, AccountType, AuthSecretKey,
+        AccountId, AccountIdAnchor, AccountType, AuthSecretKey, StorageSlot,
     },
     asset::{Asset, FungibleAsset, TokenSymbol},
     block::{
@@ -19,32 +25,53 @@ use miden_objects::{
         dsa::rpo_falcon512::SecretKey,
         merkle::{Mmr, MmrError, PartialMmr, Smt},
     },
-    note::{Note, NoteId, NoteInclusionProof, NoteType, Nullifier},
+    note::{
+        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteId, NoteInclusionProof,
+        NoteMetadata, NoteTag, NoteType, Nullifier,
+    },
     testing::account_code::DEFAULT_AUTH_SCRIPT,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ToInputNoteCommitments,
-        TransactionId, TransactionInputs, TransactionScript,
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes, OutputNote,
+        ToInputNoteCommitments, TransactionId, TransactionInputs, TransactionScript,
+    },
+    utils::{
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        sync::LazyLock,
     },
     AccountError, NoteError, ACCOUNT_TREE_DEPTH,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
-use vm_processor::{
-    crypto::{RpoRandomCoin, SimpleSmt},
-    Digest, Felt, Word, ZERO,
-};
+use vm_processor::{crypto::SimpleSmt, Digest, Felt, Word, ZERO};
+use winter_maybe_async::*;
 
 use super::TransactionContextBuilder;
-use crate::auth::BasicAuthenticator;
+use crate::{auth::BasicAuthenticator, DataStore, DataStoreError};
 
 // CONSTANTS
 // ================================================================================================
 
 /// Initial timestamp value
 const TIMESTAMP_START_SECS: u32 = 1693348223;
-/// Timestamp increment on each new block
+/// Default timestamp increment on each new block
 const TIMESTAMP_STEP_SECS: u32 = 10;
 
+// DEFAULT AUTH SCRIPT
+// ================================================================================================
+
+/// [DEFAULT_AUTH_SCRIPT] compiled with [TransactionKernel::testing_assembler_with_mock_account].
+///
+/// The script is compiled only once; [MockChain::build_tx_context] attaches a cheap clone of it
+/// which shares its MAST forest with the cached instance.
+static DEFAULT_AUTH_TX_SCRIPT: LazyLock<TransactionScript> = LazyLock::new(|| {
+    TransactionScript::compile(
+        DEFAULT_AUTH_SCRIPT,
+        vec![],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .expect("default auth script should compile with the testing assembler")
+});
+
 // AUTH
 // ================================================================================================
 
@@ -263,6 +290,9 @@ pub struct MockChain {
     /// Tree containing the latest hash of each account.
     accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
 
+    /// IDs of the accounts which have been committed to the accounts tree by a sealed block.
+    committed_accounts: BTreeSet<AccountId>,
+
     /// Objects that have not yet been finalized.
     ///
     /// These will become available once the block is sealed.
@@ -279,6 +309,9 @@ pub struct MockChain {
 
     removed_notes: Vec<NoteId>,
 
+    /// Timestamp increment in seconds between consecutive sealed blocks.
+    block_time_step_secs: u32,
+
     rng: ChaCha20Rng, // RNG field
 }
 
@@ -289,10 +322,12 @@ impl Default for MockChain {
             blocks: vec![],
             nullifiers: Smt::default(),
             accounts: SimpleSmt::<ACCOUNT_TREE_DEPTH>::new().expect("depth too big for SimpleSmt"),
+            committed_accounts: BTreeSet::new(),
             pending_objects: PendingObjects::new(),
             available_notes: BTreeMap::new(),
             available_accounts: BTreeMap::new(),
             removed_notes: vec![],
+            block_time_step_secs: TIMESTAMP_STEP_SECS,
             rng: ChaCha20Rng::from_seed(Default::default()), // Initialize RNG with default seed
         }
     }
@@ -337,6 +372,14 @@ impl MockChain {
         self.rng = ChaCha20Rng::from_seed(seed);
     }
 
+    /// Sets the number of seconds by which the timestamp of each subsequently sealed block is
+    /// increased relative to the previous block.
+    ///
+    /// The timestamps of already sealed blocks are not affected. Defaults to 10 seconds.
+    pub fn set_block_time_step(&mut self, secs: u32) {
+        self.block_time_step_secs = secs;
+    }
+
     /// Applies the transaction, adding the entities to the mockchain.
     /// Returns the resulting state of the executing account after executing the transaction.
     pub fn apply_executed_transaction(&mut self, transaction: &ExecutedTransaction) -> Account {
@@ -378,6 +421,10 @@ impl MockChain {
 
     /// Adds a P2ID [Note] to the pending objects and returns it.
     /// A block has to be created to finalize the new entity.
+    ///
+    /// If `reclaim_height` is provided, a P2IDR note is created instead. The serial number of the
+    /// note is drawn from the RNG of the chain, so repeated calls with the same arguments create
+    /// distinct notes.
     pub fn add_p2id_note(
         &mut self,
         sender_account_id: AccountId,
@@ -386,29 +433,47 @@ impl MockChain {
         note_type: NoteType,
         reclaim_height: Option<BlockNumber>,
     ) -> Result<Note, NoteError> {
-        let mut rng = RpoRandomCoin::new(Word::default());
-
-        let note = if let Some(height) = reclaim_height {
-            create_p2idr_note(
-                sender_account_id,
-                target_account_id,
-                asset.to_vec(),
-                note_type,
-                Default::default(),
-                height,
-                &mut rng,
-            )?
+        let serial_num = core::array::from_fn(|_| Felt::new(self.rng.gen()));
+
+        self.add_p2id_note_with_serial(
+            sender_account_id,
+            target_account_id,
+            asset,
+            note_type,
+            reclaim_height,
+            serial_num,
+        )
+    }
+
+    /// Adds a P2ID [Note] with the provided serial number to the pending objects and returns it.
+    /// A block has to be created to finalize the new entity.
+    ///
+    /// If `reclaim_height` is provided, a P2IDR note is created instead.
+    pub fn add_p2id_note_with_serial(
+        &mut self,
+        sender_account_id: AccountId,
+        target_account_id: AccountId,
+        asset: &[Asset],
+        note_type: NoteType,
+        reclaim_height: Option<BlockNumber>,
+        serial_num: Word,
+    ) -> Result<Note, NoteError> {
+        let recipient = if let Some(height) = reclaim_height {
+            build_p2idr_recipient(target_account_id, height, serial_num)?
         } else {
-            create_p2id_note(
-                sender_account_id,
-                target_account_id,
-                asset.to_vec(),
-                note_type,
-                Default::default(),
-                &mut rng,
-            )?
+            build_p2id_recipient(target_account_id, serial_num)?
         };
 
+        let tag = NoteTag::from_account_id(target_account_id, NoteExecutionMode::Local)?;
+        let metadata = NoteMetadata::new(
+            sender_account_id,
+            note_type,
+            tag,
+            NoteExecutionHint::always(),
+            ZERO,
+        )?;
+        let note = Note::new(NoteAssets::new(asset.to_vec())?, metadata, recipient);
+
         self.add_pending_note(note.clone());
 
         Ok(note)
@@ -506,6 +571,19 @@ impl MockChain {
         MockFungibleFaucet(account)
     }
 
+    /// Adds an existing account, such as one of the [fixtures](crate::testing::fixtures), which
+    /// is authenticated by the provided authenticator.
+    ///
+    /// The account is immediately available without having to seal a block.
+    pub fn add_existing_account(
+        &mut self,
+        account: Account,
+        authenticator: Option<BasicAuthenticator<ChaCha20Rng>>,
+    ) {
+        self.available_accounts
+            .insert(account.id(), MockAccount::new(account, None, authenticator));
+    }
+
     /// Adds the [`AccountComponent`] corresponding to `auth_method` to the account in the builder
     /// and builds a new or existing account depending on `account_state`.
     ///
@@ -579,12 +657,7 @@ impl MockChain {
             .tx_inputs(tx_inputs);
 
         if mock_account.authenticator.is_some() {
-            let tx_script = TransactionScript::compile(
-                DEFAULT_AUTH_SCRIPT,
-                vec![],
-                TransactionKernel::testing_assembler_with_mock_account(),
-            )
-            .unwrap();
+            let tx_script = DEFAULT_AUTH_TX_SCRIPT.clone();
             tx_context_builder = tx_context_builder.tx_script(tx_script);
         }
 
@@ -652,7 +725,33 @@ impl MockChain {
     /// Creates the next block or generates blocks up to the input number if specified.
     /// This will also make all the objects currently pending available for use.
     /// If `block_num` is `Some(number)`, blocks will be generated up to `number`.
+    ///
+    /// The timestamp of each block is the timestamp of its predecessor advanced by the block time
+    /// step (see [MockChain::set_block_time_step]).
+    ///
+    /// # Panics
+    /// - If the timestamp of a generated block would exceed `u32::MAX` seconds.
     pub fn seal_block(&mut self, block_num: Option<u32>) -> Block {
+        self.seal_blocks(block_num, None)
+    }
+
+    /// Creates the next block or generates blocks up to the input number if specified, like
+    /// [MockChain::seal_block], and sets the timestamp of the last generated block to `timestamp`.
+    ///
+    /// Intermediate blocks generated when `block_num` is specified are timestamped like in
+    /// [MockChain::seal_block].
+    ///
+    /// # Panics
+    /// - If `timestamp` is smaller than the timestamp of the block preceding the last generated
+    ///   block.
+    /// - If the timestamp of an intermediate block would exceed `u32::MAX` seconds.
+    pub fn seal_block_with_timestamp(&mut self, block_num: Option<u32>, timestamp: u32) -> Block {
+        self.seal_blocks(block_num, Some(timestamp))
+    }
+
+    /// Generates the blocks requested via [MockChain::seal_block] or
+    /// [MockChain::seal_block_with_timestamp].
+    fn seal_blocks(&mut self, block_num: Option<u32>, last_timestamp: Option<u32>) -> Block {
         let next_block_num =
             self.blocks.last().map_or(0, |b| b.header().block_num().child().as_u32());
 
@@ -667,6 +766,7 @@ impl MockChain {
         for current_block_num in next_block_num..=target_block_num {
             for update in self.pending_objects.updated_accounts.iter() {
                 self.accounts.insert(update.account_id().into(), *update.new_state_hash());
+                self.committed_accounts.insert(update.account_id());
 
                 if let Some(mock_account) = self.available_accounts.get(&update.account_id()) {
                     let account = match update.details() {
@@ -699,9 +799,24 @@ impl MockChain {
             let prev_hash = previous.map_or(Digest::default(), |block| block.hash());
             let nullifier_root = self.nullifiers.root();
             let note_root = notes_tree.root();
-            let timestamp = previous.map_or(TIMESTAMP_START_SECS, |block| {
-                block.header().timestamp() + TIMESTAMP_STEP_SECS
-            });
+            let timestamp = match last_timestamp {
+                Some(timestamp) if current_block_num == target_block_num => {
+                    if let Some(previous_timestamp) = previous.map(|b| b.header().timestamp()) {
+                        assert!(
+                            timestamp >= previous_timestamp,
+                            "block timestamp {timestamp} is smaller than the timestamp {previous_timestamp} of the previous block"
+                        );
+                    }
+                    timestamp
+                },
+                _ => previous.map_or(TIMESTAMP_START_SECS, |block| {
+                    block
+                        .header()
+                        .timestamp()
+                        .checked_add(self.block_time_step_secs)
+                        .expect("block timestamp should not exceed u32::MAX seconds")
+                }),
+            };
             let tx_hash =
                 compute_tx_hash(self.pending_objects.included_transactions.clone().into_iter());
 
@@ -796,6 +911,26 @@ impl MockChain {
         &self.nullifiers
     }
 
+    /// Returns `true` if the provided nullifier is recorded in the nullifier tree, i.e., if the
+    /// corresponding note was consumed in a sealed block.
+    pub fn is_nullifier_spent(&self, nullifier: &Nullifier) -> bool {
+        self.spent_block(nullifier).is_some()
+    }
+
+    /// Returns the number of the block in which the provided nullifier was recorded, or `None` if
+    /// it is not recorded in the nullifier tree.
+    ///
+    /// Nullifiers of consumed notes are only recorded once the block containing the consuming
+    /// transaction is sealed.
+    pub fn spent_block(&self, nullifier: &Nullifier) -> Option<BlockNumber> {
+        match self.nullifiers.get_value(&nullifier.inner()) {
+            value if value == Smt::EMPTY_VALUE => None,
+            value => Some(BlockNumber::from(
+                u32::try_from(value[0].as_int()).expect("block number should fit into a u32"),
+            )),
+        }
+    }
+
     /// Get the vector of IDs of the currently available notes.
     pub fn available_notes(&self) -> Vec<InputNote> {
         self.available_notes.values().cloned().collect()
@@ -805,6 +940,456 @@ impl MockChain {
     pub fn accounts(&self) -> &SimpleSmt<ACCOUNT_TREE_DEPTH> {
         &self.accounts
     }
+
+    /// Returns the [ForeignAccountInputs] of the available account with the specified ID, which
+    /// allow invoking the account's procedures in transactions referencing the latest sealed block.
+    ///
+    /// The inputs contain Merkle proofs of all entries of the account's storage maps.
+    ///
+    /// # Panics
+    /// Panics if the account with the specified ID is not available.
+    pub fn get_foreign_account_inputs(&self, account_id: AccountId) -> ForeignAccountInputs {
+        let account = self
+            .available_accounts
+            .get(&account_id)
+            .expect("foreign account should be available")
+            .account();
+
+        let storage_map_proofs = account
+            .storage()
+            .slots()
+            .iter()
+            .filter_map(|slot| match slot {
+                StorageSlot::Map(map) => Some(map),
+                StorageSlot::Value(_) => None,
+            })
+            .flat_map(|map| map.entries().map(|(key, _)| map.open(key)))
+            .collect();
+
+        ForeignAccountInputs::new(
+            account.into(),
+            account.storage().get_header(),
+            account.code().clone(),
+            storage_map_proofs,
+            self.accounts.open(&account_id.into()).path,
+        )
+    }
+
+    /// Returns the authenticator of the account with the specified ID, if the account is available
+    /// and was created with [Auth::BasicAuth].
+    pub fn account_authenticator(
+        &self,
+        account_id: AccountId,
+    ) -> Option<BasicAuthenticator<ChaCha20Rng>> {
+        self.available_accounts
+            .get(&account_id)
+            .and_then(|mock_account| mock_account.authenticator().clone())
+    }
+
+    /// Returns the total amount of tokens issued by the fungible faucet with the specified ID.
+    ///
+    /// The amount is read from the reserved storage slot of the latest state of the faucet
+    /// available in the chain, so issuance by executed transactions is only reflected once the
+    /// transactions are applied and a block is sealed.
+    ///
+    /// # Panics
+    /// Panics if the faucet is not available or if it is not a fungible faucet.
+    pub fn faucet_total_issued(&self, faucet_id: AccountId) -> u64 {
+        assert_eq!(
+            faucet_id.account_type(),
+            AccountType::FungibleFaucet,
+            "account {faucet_id} is not a fungible faucet"
+        );
+
+        let faucet = self
+            .available_accounts
+            .get(&faucet_id)
+            .unwrap_or_else(|| panic!("faucet {faucet_id} is not available"))
+            .account();
+
+        faucet
+            .storage()
+            .get_item(memory::FAUCET_STORAGE_DATA_SLOT)
+            .expect("faucet storage should contain the reserved slot")[3]
+            .as_int()
+    }
+
+    // SNAPSHOTS
+    // =========================================================================================
+
+    /// Returns a snapshot of the full state of this chain, which can be restored via
+    /// [MockChain::import_state].
+    ///
+    /// The snapshot contains the seeds and secret keys of the available accounts, and must thus
+    /// only be shared for testing purposes.
+    pub fn export_state(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    /// Returns a chain restored from a snapshot created via [MockChain::export_state].
+    ///
+    /// The restored chain continues deterministically, i.e., it seals the same blocks and executes
+    /// the same transactions as the chain from which the snapshot was exported.
+    ///
+    /// # Errors
+    /// Returns an error if the provided bytes are not a valid snapshot.
+    pub fn import_state(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        Self::read_from_bytes(bytes)
+    }
+
+    // INVARIANTS
+    // =========================================================================================
+
+    /// Asserts that the state of the chain is consistent with the header of the latest block.
+    ///
+    /// This is a self-check of the mock chain intended for debugging tests. Specifically, it
+    /// asserts that:
+    /// - The accounts tree and the nullifier tree match the roots of the latest block header.
+    /// - The note tree built from the notes of the latest block matches its note root.
+    /// - The chain MMR contains the hashes of all blocks, and its state before the latest block was
+    ///   added matches the chain root of the latest block header.
+    /// - Each block header references the hash of its predecessor.
+    /// - All available accounts which are neither new nor pending match their commitments in the
+    ///   accounts tree.
+    /// - The accounts tree contains exactly the accounts committed by the sealed blocks.
+    ///
+    /// # Panics
+    /// Panics if any of the above does not hold or if the chain does not contain any blocks.
+    pub fn assert_consistent(&self) {
+        let latest_block = self.blocks.last().expect("chain should contain at least one block");
+        let latest_header = latest_block.header();
+
+        assert_eq!(
+            latest_header.account_root(),
+            self.accounts.root(),
+            "account root of the latest block does not match the accounts tree"
+        );
+        assert_eq!(
+            latest_header.nullifier_root(),
+            self.nullifiers.root(),
+            "nullifier root of the latest block does not match the nullifier tree"
+        );
+        assert_eq!(
+            latest_header.note_root(),
+            latest_block.build_note_tree().root(),
+            "note root of the latest block does not match the notes of the block"
+        );
+
+        let mut chain = Mmr::default();
+        for (block_num, block) in self.blocks.iter().enumerate() {
+            let header = block.header();
+            assert_eq!(header.block_num().as_usize(), block_num, "block number is out of order");
+            assert_eq!(
+                header.chain_root(),
+                chain.peaks().hash_peaks(),
+                "chain root of block {block_num} does not match the preceding blocks"
+            );
+            let prev_hash = block_num
+                .checked_sub(1)
+                .map_or(Digest::default(), |prev_block_num| self.blocks[prev_block_num].hash());
+            assert_eq!(
+                header.prev_hash(),
+                prev_hash,
+                "block {block_num} does not reference the hash of the preceding block"
+            );
+
+            chain.add(block.hash());
+        }
+        assert_eq!(
+            (self.chain.forest(), self.chain.peaks().hash_peaks()),
+            (chain.forest(), chain.peaks().hash_peaks()),
+            "chain MMR does not match the hashes of the blocks"
+        );
+
+        for (account_id, mock_account) in self.available_accounts.iter() {
+            // new accounts are only added to the accounts tree by the transaction creating them
+            let is_pending = mock_account.account().is_new()
+                || self
+                    .pending_objects
+                    .updated_accounts
+                    .iter()
+                    .any(|update| update.account_id() == *account_id);
+            if is_pending {
+                continue;
+            }
+
+            // existing accounts are made available without being committed to the accounts tree,
+            // until they are updated by a transaction
+            if !self.committed_accounts.contains(account_id) {
+                continue;
+            }
+
+            assert_eq!(
+                Digest::from(self.accounts.get_leaf(&(*account_id).into())),
+                mock_account.account().hash(),
+                "account {account_id} does not match its commitment in the accounts tree"
+            );
+        }
+
+        for account_id in self.committed_accounts.iter() {
+            assert_ne!(
+                Digest::from(self.accounts.get_leaf(&(*account_id).into())),
+                Digest::default(),
+                "committed account {account_id} is missing from the accounts tree"
+            );
+        }
+        assert_eq!(
+            self.accounts.leaves().count(),
+            self.committed_accounts.len(),
+            "accounts tree contains accounts which were not committed"
+        );
+    }
+}
+
+// DATA STORE
+// ================================================================================================
+
+#[maybe_async_trait]
+impl DataStore for MockChain {
+    /// Returns [TransactionInputs] for the specified available account consuming the specified
+    /// available notes, against the latest block of the chain.
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        let mock_account = self
+            .available_accounts
+            .get(&account_id)
+            .ok_or(DataStoreError::AccountNotFound(account_id))?;
+
+        let latest_block_num = self.blocks.last().map(|block| block.header().block_num());
+        if latest_block_num != Some(block_ref) {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+
+        if let Some(note_id) =
+            notes.iter().find(|note_id| !self.available_notes.contains_key(note_id))
+        {
+            return Err(DataStoreError::NoteNotFound(*note_id));
+        }
+
+        Ok(MockChain::get_transaction_inputs(
+            self,
+            mock_account.account().clone(),
+            mock_account.seed().cloned(),
+            notes,
+            &[],
+        ))
+    }
+
+    /// Returns the header of the latest sealed block of the chain.
+    #[maybe_async]
+    fn latest_block_header(&self) -> Result<BlockHeader, DataStoreError> {
+        self.blocks
+            .last()
+            .map(|block| block.header())
+            .ok_or(DataStoreError::LatestBlockUnavailable)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+/// Allows snapshotting the full state of a [MockChain], e.g. to build an expensive scenario once
+/// and restore it cheaply in other tests.
+///
+/// The snapshot includes the pending objects, the seeds and secret keys of the available accounts,
+/// and the state of all random number generators, so that a restored chain behaves exactly like
+/// the original one.
+impl Serializable for MockChain {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // the chain MMR is rebuilt from the block hashes on deserialization
+        self.blocks.write_into(target);
+
+        let nullifiers: Vec<(Digest, Word)> = self.nullifiers.entries().copied().collect();
+        nullifiers.len().write_into(target);
+        for (nullifier, value) in nullifiers {
+            nullifier.write_into(target);
+            value.write_into(target);
+        }
+
+        let accounts: Vec<(u64, Word)> =
+            self.accounts.leaves().map(|(index, hash)| (index, *hash)).collect();
+        accounts.len().write_into(target);
+        for (index, hash) in accounts {
+            index.write_into(target);
+            hash.write_into(target);
+        }
+        let committed_accounts: Vec<AccountId> = self.committed_accounts.iter().copied().collect();
+        committed_accounts.write_into(target);
+
+        self.pending_objects.write_into(target);
+
+        let available_notes: Vec<&InputNote> = self.available_notes.values().collect();
+        available_notes.write_into(target);
+
+        let available_accounts: Vec<&MockAccount> = self.available_accounts.values().collect();
+        available_accounts.write_into(target);
+
+        self.removed_notes.write_into(target);
+        self.block_time_step_secs.write_into(target);
+        write_rng(&self.rng, target);
+    }
+}
+
+impl Deserializable for MockChain {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let blocks = Vec::<Block>::read_from(source)?;
+        let mut chain = Mmr::default();
+        for block in blocks.iter() {
+            chain.add(block.hash());
+        }
+
+        let num_nullifiers = usize::read_from(source)?;
+        let mut nullifiers = Vec::with_capacity(num_nullifiers);
+        for _ in 0..num_nullifiers {
+            nullifiers.push((Digest::read_from(source)?, Word::read_from(source)?));
+        }
+        let nullifiers = Smt::with_entries(nullifiers)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let num_accounts = usize::read_from(source)?;
+        let mut accounts = Vec::with_capacity(num_accounts);
+        for _ in 0..num_accounts {
+            accounts.push((u64::read_from(source)?, Word::read_from(source)?));
+        }
+        let accounts = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves(accounts)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        let committed_accounts = Vec::<AccountId>::read_from(source)?.into_iter().collect();
+
+        let pending_objects = PendingObjects::read_from(source)?;
+
+        let available_notes = Vec::<InputNote>::read_from(source)?
+            .into_iter()
+            .map(|note| (note.id(), note))
+            .collect();
+
+        let available_accounts = Vec::<MockAccount>::read_from(source)?
+            .into_iter()
+            .map(|mock_account| (mock_account.account().id(), mock_account))
+            .collect();
+
+        let removed_notes = Vec::<NoteId>::read_from(source)?;
+        let block_time_step_secs = u32::read_from(source)?;
+        let rng = read_rng(source)?;
+
+        Ok(Self {
+            chain,
+            blocks,
+            nullifiers,
+            accounts,
+            committed_accounts,
+            pending_objects,
+            available_notes,
+            available_accounts,
+            removed_notes,
+            block_time_step_secs,
+            rng,
+        })
+    }
+}
+
+impl Serializable for MockAccount {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.seed.write_into(target);
+
+        match &self.authenticator {
+            Some(authenticator) => {
+                target.write_bool(true);
+                let keys: Vec<(&Digest, &AuthSecretKey)> = authenticator.keys().iter().collect();
+                keys.len().write_into(target);
+                for (pub_key, secret_key) in keys {
+                    pub_key.write_into(target);
+                    secret_key.write_into(target);
+                }
+                write_rng(&authenticator.rng().read(), target);
+            },
+            None => target.write_bool(false),
+        }
+    }
+}
+
+impl Deserializable for MockAccount {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = Account::read_from(source)?;
+        let seed = Option::<Word>::read_from(source)?;
+
+        let authenticator = if source.read_bool()? {
+            let num_keys = usize::read_from(source)?;
+            let mut keys = Vec::with_capacity(num_keys);
+            for _ in 0..num_keys {
+                let pub_key = Digest::read_from(source)?;
+                keys.push((Word::from(pub_key), AuthSecretKey::read_from(source)?));
+            }
+            let rng = read_rng(source)?;
+
+            Some(BasicAuthenticator::<ChaCha20Rng>::new_with_rng(&keys, rng))
+        } else {
+            None
+        };
+
+        Ok(Self::new(account, seed, authenticator))
+    }
+}
+
+impl Serializable for PendingObjects {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.updated_accounts.write_into(target);
+        self.output_note_batches.write_into(target);
+        self.created_nullifiers.write_into(target);
+
+        self.included_transactions.len().write_into(target);
+        for (transaction_id, account_id) in self.included_transactions.iter() {
+            transaction_id.write_into(target);
+            account_id.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for PendingObjects {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let updated_accounts = Vec::<BlockAccountUpdate>::read_from(source)?;
+        let output_note_batches = Vec::<NoteBatch>::read_from(source)?;
+        let created_nullifiers = Vec::<Nullifier>::read_from(source)?;
+
+        let num_transactions = usize::read_from(source)?;
+        let mut included_transactions = Vec::with_capacity(num_transactions);
+        for _ in 0..num_transactions {
+            included_transactions
+                .push((TransactionId::read_from(source)?, AccountId::read_from(source)?));
+        }
+
+        Ok(Self {
+            updated_accounts,
+            output_note_batches,
+            created_nullifiers,
+            included_transactions,
+        })
+    }
+}
+
+/// Writes the seed, stream and position of the provided RNG, which fully determine its state.
+fn write_rng<W: ByteWriter>(rng: &ChaCha20Rng, target: &mut W) {
+    target.write_bytes(&rng.get_seed());
+    rng.get_stream().write_into(target);
+    target.write_bytes(&rng.get_word_pos().to_le_bytes());
+}
+
+/// Reads an RNG written by [write_rng], restoring it to the same state.
+fn read_rng<R: ByteReader>(source: &mut R) -> Result<ChaCha20Rng, DeserializationError> {
+    let seed = source.read_array::<32>()?;
+    let stream = u64::read_from(source)?;
+    let word_pos = u128::from_le_bytes(source.read_array::<16>()?);
+
+    let mut rng = ChaCha20Rng::from_seed(seed);
+    rng.set_stream(stream);
+    rng.set_word_pos(word_pos);
+
+    Ok(rng)
 }
 
 // HELPER TYPES
@@ -833,3 +1418,408 @@ fn mmr_to_chain_mmr(mmr: &Mmr, blocks: &[BlockHeader]) -> Result<ChainMmr, MmrEr
 
     Ok(ChainMmr::new(partial_mmr, blocks.to_vec()).unwrap())
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use miden_lib::transaction::TransactionKernel;
+    use miden_objects::{
+        asset::FungibleAsset,
+        block::{compute_tx_hash, BlockNumber},
+        note::{NoteTag, NoteType, Nullifier},
+        transaction::TransactionScript,
+        Digest, ONE, ZERO,
+    };
+
+    use super::{Auth, Deserializable, MockChain, Serializable, DEFAULT_AUTH_TX_SCRIPT};
+
+    /// Returns a transaction script which distributes `amount` tokens of a basic fungible faucet
+    /// into a private note.
+    fn distribute_tx_script(amount: u64) -> TransactionScript {
+        let code = format!(
+            "
+            begin
+                # pad the stack before call
+                push.0.0.0 padw
+
+                push.0.1.2.3
+                push.0
+                push.{note_type}
+                push.0
+                push.{tag}
+                push.{amount}
+                # => [amount, tag, aux, note_type, execution_hint, RECIPIENT, pad(7)]
+
+                call.::miden::contracts::faucets::basic_fungible::distribute
+                # => [note_idx, pad(15)]
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                # => [note_idx, pad(15)]
+
+                # truncate the stack
+                dropw dropw dropw dropw
+            end
+            ",
+            note_type = NoteType::Private as u8,
+            tag = u32::from(NoteTag::for_local_use_case(0, 0).unwrap()),
+        );
+
+        TransactionScript::compile(code, vec![], TransactionKernel::testing_assembler()).unwrap()
+    }
+
+    #[test]
+    fn add_p2id_note_creates_distinct_notes() {
+        let mut mock_chain = MockChain::new();
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let assets = [FungibleAsset::mock(100)];
+
+        let note_1 = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &assets, NoteType::Public, None)
+            .unwrap();
+        let note_2 = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &assets, NoteType::Public, None)
+            .unwrap();
+        assert_ne!(note_1.nullifier(), note_2.nullifier());
+
+        // the serial number can be chosen explicitly
+        let serial_num = [ONE, ONE, ONE, ONE];
+        let note_3 = mock_chain
+            .add_p2id_note_with_serial(
+                sender.id(),
+                target.id(),
+                &assets,
+                NoteType::Public,
+                None,
+                serial_num,
+            )
+            .unwrap();
+        assert_eq!(note_3.serial_num(), serial_num);
+        assert_eq!(note_3.inputs(), note_1.inputs());
+        assert_eq!(note_3.metadata(), note_1.metadata());
+    }
+
+    #[test]
+    fn build_tx_context_reuses_compiled_default_auth_script() {
+        let mut mock_chain = MockChain::new();
+        let account =
+            mock_chain.add_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+
+        let tx_script_1 = mock_chain
+            .build_tx_context(account.id(), &[], &[])
+            .build()
+            .tx_args()
+            .tx_script()
+            .cloned()
+            .unwrap();
+        let tx_script_2 = mock_chain
+            .build_tx_context(account.id(), &[], &[])
+            .build()
+            .tx_args()
+            .tx_script()
+            .cloned()
+            .unwrap();
+
+        assert_eq!(tx_script_1.hash(), tx_script_2.hash());
+        assert!(Arc::ptr_eq(&tx_script_1.mast(), &tx_script_2.mast()));
+        assert!(Arc::ptr_eq(&tx_script_1.mast(), &DEFAULT_AUTH_TX_SCRIPT.mast()));
+    }
+
+    #[test]
+    fn mock_chain_snapshot_roundtrip() {
+        let mut mock_chain = MockChain::new();
+        let asset = FungibleAsset::mock(100);
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![asset]);
+        let target = mock_chain.add_new_wallet(Auth::BasicAuth);
+        let note = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &[asset], NoteType::Public, None)
+            .unwrap();
+        mock_chain.seal_block(Some(5));
+        mock_chain.add_nullifier(Nullifier::from(Digest::new([ONE, ONE, ONE, ONE])));
+
+        let mut restored = MockChain::read_from_bytes(&mock_chain.to_bytes()).unwrap();
+
+        assert_eq!(
+            (restored.chain.forest(), restored.chain.peaks().hash_peaks()),
+            (mock_chain.chain.forest(), mock_chain.chain.peaks().hash_peaks())
+        );
+        assert_eq!(restored.accounts().root(), mock_chain.accounts().root());
+        assert_eq!(restored.nullifiers().root(), mock_chain.nullifiers().root());
+        assert_eq!(restored.available_notes(), mock_chain.available_notes());
+        assert_eq!(restored.to_bytes(), mock_chain.to_bytes());
+
+        // pending objects and RNG states are restored, so both chains evolve identically
+        assert_eq!(restored.seal_block(None).hash(), mock_chain.seal_block(None).hash());
+
+        // the restored authenticator can sign transactions of the restored accounts
+        let executed_transaction = restored
+            .build_tx_context(target.id(), &[note.id()], &[])
+            .build()
+            .execute()
+            .unwrap();
+        assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
+        restored.apply_executed_transaction(&executed_transaction);
+    }
+
+    #[test]
+    fn imported_chain_continues_deterministically() {
+        let mut mock_chain = MockChain::new();
+        let asset = FungibleAsset::mock(100);
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![asset]);
+        let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let note = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &[asset], NoteType::Public, None)
+            .unwrap();
+        mock_chain.seal_block(Some(5));
+
+        let mut imported = MockChain::import_state(&mock_chain.export_state()).unwrap();
+        assert_eq!(imported.export_state(), mock_chain.export_state());
+
+        assert_eq!(imported.seal_block(None).hash(), mock_chain.seal_block(None).hash());
+
+        let executed_transaction = mock_chain
+            .build_tx_context(target.id(), &[note.id()], &[])
+            .build()
+            .execute()
+            .unwrap();
+        let imported_transaction = imported
+            .build_tx_context(target.id(), &[note.id()], &[])
+            .build()
+            .execute()
+            .unwrap();
+        assert_eq!(imported_transaction.id(), executed_transaction.id());
+        assert_eq!(imported_transaction.account_delta(), executed_transaction.account_delta());
+        assert_eq!(
+            imported_transaction.final_account().hash(),
+            executed_transaction.final_account().hash()
+        );
+
+        // a truncated snapshot is rejected
+        let snapshot = mock_chain.export_state();
+        assert!(MockChain::import_state(&snapshot[..snapshot.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn consumed_note_nullifier_is_spent_in_sealed_block() {
+        let mut mock_chain = MockChain::new();
+        let asset = FungibleAsset::mock(100);
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![asset]);
+        let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let note = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &[asset], NoteType::Public, None)
+            .unwrap();
+        mock_chain.seal_block(Some(3));
+        assert!(!mock_chain.is_nullifier_spent(&note.nullifier()));
+        assert_eq!(mock_chain.spent_block(&note.nullifier()), None);
+
+        let executed_transaction = mock_chain
+            .build_tx_context(target.id(), &[note.id()], &[])
+            .build()
+            .execute()
+            .unwrap();
+        mock_chain.apply_executed_transaction(&executed_transaction);
+
+        // the nullifier is only recorded once the block is sealed
+        assert!(!mock_chain.is_nullifier_spent(&note.nullifier()));
+        let block = mock_chain.seal_block(None);
+
+        assert!(mock_chain.is_nullifier_spent(&note.nullifier()));
+        assert_eq!(mock_chain.spent_block(&note.nullifier()), Some(block.header().block_num()));
+        assert_eq!(block.header().block_num(), BlockNumber::from(4));
+    }
+
+    #[test]
+    fn sealed_chain_is_consistent() {
+        let mut mock_chain = MockChain::new();
+        let asset = FungibleAsset::mock(100);
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![asset]);
+        let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let note = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &[asset], NoteType::Public, None)
+            .unwrap();
+        mock_chain.seal_block(Some(3));
+        mock_chain.assert_consistent();
+
+        let executed_transaction = mock_chain
+            .build_tx_context(target.id(), &[note.id()], &[])
+            .build()
+            .execute()
+            .unwrap();
+        mock_chain.apply_executed_transaction(&executed_transaction);
+
+        // pending objects do not affect the consistency of the chain
+        mock_chain.assert_consistent();
+        mock_chain.seal_block(None);
+        mock_chain.assert_consistent();
+    }
+
+    #[test]
+    #[should_panic(expected = "nullifier root of the latest block does not match")]
+    fn corrupted_chain_is_inconsistent() {
+        let mut mock_chain = MockChain::new();
+        mock_chain.seal_block(None);
+
+        // the nullifier is recorded without sealing a block
+        mock_chain
+            .nullifiers
+            .insert(Digest::new([ONE, ONE, ONE, ONE]), [ONE, ZERO, ZERO, ZERO]);
+
+        mock_chain.assert_consistent();
+    }
+
+    #[test]
+    #[should_panic(expected = "is missing from the accounts tree")]
+    fn missing_committed_account_is_inconsistent() {
+        let mut mock_chain = MockChain::new();
+        let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        mock_chain.add_pending_account(account.clone());
+        mock_chain.seal_block(None);
+
+        // the account commitment is removed without updating the block header
+        mock_chain.accounts.insert(account.id().into(), Word::default());
+
+        mock_chain.assert_consistent();
+    }
+
+    #[test]
+    fn block_timestamps_advance_by_configured_step() {
+        let mut mock_chain = MockChain::new();
+        let genesis_timestamp = mock_chain.block_header(0).timestamp();
+        mock_chain.seal_block(None);
+        assert_eq!(mock_chain.block_header(1).timestamp(), genesis_timestamp + 10);
+
+        mock_chain.set_block_time_step(60);
+        mock_chain.seal_block(None);
+        mock_chain.seal_block(None);
+
+        // previously sealed blocks keep their timestamps
+        assert_eq!(mock_chain.block_header(1).timestamp(), genesis_timestamp + 10);
+        assert_eq!(mock_chain.block_header(2).timestamp(), genesis_timestamp + 70);
+        assert_eq!(mock_chain.block_header(3).timestamp(), genesis_timestamp + 130);
+    }
+
+    #[test]
+    fn block_timestamp_can_be_set_explicitly() {
+        let mut mock_chain = MockChain::new();
+        let genesis_timestamp = mock_chain.block_header(0).timestamp();
+
+        let block = mock_chain.seal_block_with_timestamp(None, genesis_timestamp + 3600);
+        assert_eq!(block.header().timestamp(), genesis_timestamp + 3600);
+        assert_eq!(mock_chain.block_header(1).timestamp(), genesis_timestamp + 3600);
+
+        // the timestamp may be equal to the one of the previous block
+        mock_chain.seal_block_with_timestamp(None, genesis_timestamp + 3600);
+        assert_eq!(mock_chain.block_header(2).timestamp(), genesis_timestamp + 3600);
+
+        // intermediate blocks advance by the block time step
+        let block = mock_chain.seal_block_with_timestamp(Some(5), genesis_timestamp + 7200);
+        assert_eq!(block.header().block_num(), BlockNumber::from(5));
+        assert_eq!(mock_chain.block_header(3).timestamp(), genesis_timestamp + 3610);
+        assert_eq!(mock_chain.block_header(4).timestamp(), genesis_timestamp + 3620);
+        assert_eq!(mock_chain.block_header(5).timestamp(), genesis_timestamp + 7200);
+
+        // subsequent blocks advance from the explicit timestamp
+        mock_chain.seal_block(None);
+        assert_eq!(mock_chain.block_header(6).timestamp(), genesis_timestamp + 7210);
+    }
+
+    #[test]
+    #[should_panic(expected = "is smaller than the timestamp")]
+    fn block_timestamp_must_not_decrease() {
+        let mut mock_chain = MockChain::new();
+        let genesis_timestamp = mock_chain.block_header(0).timestamp();
+
+        mock_chain.seal_block_with_timestamp(None, genesis_timestamp - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "block timestamp should not exceed u32::MAX seconds")]
+    fn block_timestamp_must_not_overflow() {
+        let mut mock_chain = MockChain::new();
+        mock_chain.seal_block_with_timestamp(None, u32::MAX - 5);
+
+        mock_chain.seal_block(None);
+    }
+
+    #[test]
+    fn faucet_total_issued_tracks_minted_amounts() {
+        let mut mock_chain = MockChain::new();
+        let faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "TST", 1000, Some(100));
+        assert_eq!(mock_chain.faucet_total_issued(faucet.id()), 100);
+
+        let mut expected_total = 100;
+        for amount in [10, 250, 1] {
+            let executed_transaction = mock_chain
+                .build_tx_context(faucet.id(), &[], &[])
+                .tx_script(distribute_tx_script(amount))
+                .build()
+                .execute()
+                .unwrap();
+            mock_chain.apply_executed_transaction(&executed_transaction);
+
+            // the issuance is only updated once the block is sealed
+            assert_eq!(mock_chain.faucet_total_issued(faucet.id()), expected_total);
+            mock_chain.seal_block(None);
+
+            expected_total += amount;
+            assert_eq!(mock_chain.faucet_total_issued(faucet.id()), expected_total);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn faucet_total_issued_panics_for_non_faucet() {
+        let mut mock_chain = MockChain::new();
+        let wallet = mock_chain.add_new_wallet(Auth::NoAuth);
+
+        mock_chain.faucet_total_issued(wallet.id());
+    }
+
+    #[test]
+    fn sealed_block_header_exposes_all_roots() {
+        let mut mock_chain = MockChain::new();
+        let sender = mock_chain.add_existing_wallet(Auth::NoAuth, vec![FungibleAsset::mock(10)]);
+        let target = mock_chain.add_new_wallet(Auth::NoAuth);
+        mock_chain
+            .add_p2id_note(
+                sender.id(),
+                target.id(),
+                &[FungibleAsset::mock(5)],
+                NoteType::Public,
+                None,
+            )
+            .unwrap();
+        mock_chain.add_nullifier(Nullifier::from(Digest::new([ONE, ONE, ONE, ONE])));
+
+        let prev_header = mock_chain.block_header(0);
+        let chain_root = mock_chain.chain.peaks().hash_peaks();
+        let block = mock_chain.seal_block(None);
+        let header = block.header();
+
+        assert_eq!(header.prev_hash(), prev_header.hash());
+        assert_eq!(header.chain_root(), chain_root);
+        assert_eq!(header.account_root(), mock_chain.accounts().root());
+        assert_eq!(header.nullifier_root(), mock_chain.nullifiers().root());
+        assert_eq!(header.note_root(), block.build_note_tree().root());
+        assert_eq!(header.tx_hash(), compute_tx_hash(core::iter::empty()));
+        assert_eq!(header.tx_hash(), block.compute_tx_hash());
+        assert_eq!(header.kernel_root(), TransactionKernel::kernel_root());
+        assert_eq!(header.proof_hash(), Digest::default());
+
+        // all roots are distinct from each other, so no accessor returns the wrong field
+        let roots = [
+            header.chain_root(),
+            header.account_root(),
+            header.nullifier_root(),
+            header.note_root(),
+            header.kernel_root(),
+        ];
+        for (i, root) in roots.iter().enumerate() {
+            assert!(roots[i + 1..].iter().all(|other| other != root));
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod executor;
 
+pub mod fixtures;
+
 pub use mock_host::MockHost;
 mod mock_host;
 
@@ -1,7 +1,10 @@
 // TRANSACTION CONTEXT BUILDER
 // ================================================================================================
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
@@ -23,7 +26,9 @@ use miden_objects::{
         prepare_word,
         storage::prepare_assets,
     },
-    transaction::{OutputNote, TransactionArgs, TransactionInputs, TransactionScript},
+    transaction::{
+        InputNote, InputNotes, OutputNote, TransactionArgs, TransactionInputs, TransactionScript,
+    },
     vm::AdviceMap,
     FieldElement,
 };
@@ -74,6 +79,7 @@ pub struct TransactionContextBuilder {
     expected_output_notes: Vec<Note>,
     foreign_account_codes: Vec<AccountCode>,
     input_notes: Vec<Note>,
+    unauthenticated_input_notes: Vec<Note>,
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     transaction_inputs: Option<TransactionInputs>,
@@ -87,6 +93,7 @@ impl TransactionContextBuilder {
             account,
             account_seed: None,
             input_notes: Vec::new(),
+            unauthenticated_input_notes: Vec::new(),
             expected_output_notes: Vec::new(),
             rng: ChaCha20Rng::from_seed([0_u8; 32]),
             tx_script: None,
@@ -115,6 +122,7 @@ impl TransactionContextBuilder {
             account_seed: None,
             authenticator: None,
             input_notes: Vec::new(),
+            unauthenticated_input_notes: Vec::new(),
             expected_output_notes: Vec::new(),
             advice_inputs: Default::default(),
             rng: ChaCha20Rng::from_seed([0_u8; 32]),
@@ -179,6 +187,13 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Extend the set of used input notes with notes which are consumed without being
+    /// authenticated, i.e. without an inclusion proof
+    pub fn unauthenticated_input_notes(mut self, input_notes: Vec<Note>) -> Self {
+        self.unauthenticated_input_notes.extend(input_notes);
+        self
+    }
+
     /// Set the desired transaction script
     pub fn tx_script(mut self, tx_script: TransactionScript) -> Self {
         self.tx_script = Some(tx_script);
@@ -274,7 +289,7 @@ impl TransactionContextBuilder {
             PUBLIC_NOTE = NoteType::Public as u8,
             recipient = prepare_word(&output.recipient().digest()),
             aux = output.metadata().aux(),
-            tag = output.metadata().tag(),
+            tag = Felt::from(output.metadata().tag()),
             asset = prepare_assets(output.assets())[0],
             execution_hint_always = Felt::from(NoteExecutionHint::always())
         );
@@ -348,11 +363,11 @@ impl TransactionContextBuilder {
             PUBLIC_NOTE = NoteType::Public as u8,
             recipient0 = prepare_word(&output0.recipient().digest()),
             aux0 = output0.metadata().aux(),
-            tag0 = output0.metadata().tag(),
+            tag0 = Felt::from(output0.metadata().tag()),
             asset0 = prepare_assets(output0.assets())[0],
             recipient1 = prepare_word(&output1.recipient().digest()),
             aux1 = output1.metadata().aux(),
-            tag1 = output1.metadata().tag(),
+            tag1 = Felt::from(output1.metadata().tag()),
             asset1 = prepare_assets(output1.assets())[0],
             execution_hint_always = Felt::from(NoteExecutionHint::always())
         );
@@ -625,10 +640,44 @@ impl TransactionContextBuilder {
     /// Builds the [TransactionContext].
     ///
     /// If no transaction inputs were provided manually, an ad-hoc MockChain is created in order
-    /// to generate valid block data for the required notes.
+    /// to generate valid block data for the required notes. The unauthenticated input notes are
+    /// not added to the chain and are passed to the transaction without inclusion proofs. If
+    /// transaction inputs were provided, the unauthenticated input notes are appended to their
+    /// input notes.
+    ///
+    /// # Panics
+    /// Panics if the same note was added more than once, either as an authenticated or as an
+    /// unauthenticated input note, or if the unauthenticated input notes cannot be added to the
+    /// provided transaction inputs.
     pub fn build(self) -> TransactionContext {
+        let mut note_ids = BTreeSet::new();
+        for note in self.input_notes.iter().chain(self.unauthenticated_input_notes.iter()) {
+            assert!(
+                note_ids.insert(note.id()),
+                "input note {} was added more than once",
+                note.id()
+            );
+        }
+
         let tx_inputs = match self.transaction_inputs {
-            Some(tx_inputs) => tx_inputs,
+            Some(tx_inputs) => {
+                let (account, account_seed, block_header, block_chain, input_notes) =
+                    tx_inputs.into_parts();
+                let unauthenticated_input_notes =
+                    self.unauthenticated_input_notes.into_iter().map(InputNote::unauthenticated);
+                let input_notes =
+                    input_notes.into_iter().chain(unauthenticated_input_notes).collect();
+
+                TransactionInputs::new(
+                    account,
+                    account_seed,
+                    block_header,
+                    block_chain,
+                    InputNotes::new(input_notes)
+                        .expect("unauthenticated input notes should be valid input notes"),
+                )
+                .expect("transaction inputs should remain valid")
+            },
             None => {
                 // If no specific transaction inputs was provided, initialize an ad-hoc mockchain
                 // to generate valid block header/MMR data
@@ -648,7 +697,7 @@ impl TransactionContextBuilder {
                     self.account.clone(),
                     self.account_seed,
                     &input_note_ids,
-                    &[],
+                    &self.unauthenticated_input_notes,
                 )
             },
         };
@@ -7,7 +7,7 @@ use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     account::{Account, AccountCode, AccountId},
     assembly::Assembler,
-    block::BlockNumber,
+    block::{BlockHeader, BlockNumber},
     note::{Note, NoteId},
     transaction::{ExecutedTransaction, InputNote, InputNotes, TransactionArgs, TransactionInputs},
 };
@@ -143,10 +143,44 @@ impl DataStore for TransactionInputs {
         block_num: BlockNumber,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError> {
-        assert_eq!(account_id, self.account().id());
-        assert_eq!(block_num, self.block_header().block_num());
-        assert_eq!(notes.len(), self.input_notes().num_notes());
+        if account_id != self.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+        if block_num != self.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_num));
+        }
+        if let Some(note_id) =
+            notes.iter().find(|note_id| self.input_notes().find_by_id(**note_id).is_none())
+        {
+            return Err(DataStoreError::NoteNotFound(*note_id));
+        }
+
+        if self.input_notes().iter().all(|note| notes.contains(&note.id())) {
+            return Ok(self.clone());
+        }
+
+        // a subset of the input notes was requested, e.g. to execute notes one by one
+        let input_notes = self
+            .input_notes()
+            .iter()
+            .filter(|note| notes.contains(&note.id()))
+            .cloned()
+            .collect();
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        TransactionInputs::new(
+            self.account().clone(),
+            self.account_seed(),
+            *self.block_header(),
+            self.block_chain().clone(),
+            input_notes,
+        )
+        .map_err(DataStoreError::InvalidTransactionInput)
+    }
 
-        Ok(self.clone())
+    #[maybe_async]
+    fn latest_block_header(&self) -> Result<BlockHeader, DataStoreError> {
+        Ok(*self.block_header())
     }
 }
@@ -2,7 +2,8 @@ use miden_lib::{
     errors::tx_kernel_errors::{
         ERR_ACCOUNT_ID_EPOCH_MUST_BE_LESS_THAN_U16_MAX,
         ERR_ACCOUNT_ID_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO, ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE,
-        ERR_ACCOUNT_ID_UNKNOWN_VERSION, TX_KERNEL_ERRORS,
+        ERR_ACCOUNT_ID_UNKNOWN_VERSION, ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32,
+        ERR_ACCOUNT_NONCE_MUST_STRICTLY_INCREASE, TX_KERNEL_ERRORS,
     },
     transaction::TransactionKernel,
 };
@@ -29,7 +30,10 @@ use rand_chacha::ChaCha20Rng;
 use vm_processor::{Digest, ExecutionError, MemAdviceProvider, ProcessState};
 
 use super::{Felt, StackInputs, Word, ONE, ZERO};
-use crate::testing::{executor::CodeExecutor, TransactionContextBuilder};
+use crate::{
+    assert_execution_error,
+    testing::{executor::CodeExecutor, TransactionContextBuilder},
+};
 
 // ACCOUNT CODE TESTS
 // ================================================================================================
@@ -639,6 +643,75 @@ fn test_account_component_storage_offset() {
     );
 }
 
+// ACCOUNT NONCE TESTS
+// ================================================================================================
+
+#[test]
+fn test_incr_nonce() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let code = "
+        use.kernel::account
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.5 exec.account::incr_nonce
+
+            exec.account::get_nonce
+            push.6 assert_eq
+        end
+        ";
+
+    tx_context.execute_code(code).unwrap();
+}
+
+#[test]
+fn test_incr_nonce_rejects_non_increasing_nonce() {
+    let incr_nonce_code = |increment: &str| {
+        format!(
+            "
+            use.kernel::account
+            use.kernel::prologue
+
+            begin
+                exec.prologue::prepare_transaction
+
+                push.{increment} exec.account::incr_nonce
+            end
+            "
+        )
+    };
+
+    // the increment does not fit into a u32
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let process = tx_context.execute_code(&incr_nonce_code("4294967296"));
+    match process {
+        Err(ExecutionError::NotU32Value(value, err_code)) => {
+            assert_eq!(value, Felt::new(1 << 32));
+            assert_eq!(err_code, Felt::from(ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32));
+        },
+        Ok(_) => panic!("Execution was unexpectedly successful"),
+        Err(err) => panic!("Execution error was not as expected: {err}"),
+    }
+
+    // (initial nonce, increment)
+    let cases = [
+        // the nonce would stay the same
+        (ONE, "0"),
+        // the nonce would wrap around the field modulus and decrease
+        (ZERO - ONE, "1"),
+        (ZERO - ONE - ONE, "5"),
+    ];
+
+    for (init_nonce, increment) in cases {
+        let tx_context = TransactionContextBuilder::with_standard_account(init_nonce).build();
+        let process = tx_context.execute_code(&incr_nonce_code(increment));
+        assert_execution_error!(process, ERR_ACCOUNT_NONCE_MUST_STRICTLY_INCREASE);
+    }
+}
+
 // ACCOUNT VAULT TESTS
 // ================================================================================================
 
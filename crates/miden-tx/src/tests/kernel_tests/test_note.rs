@@ -1,16 +1,27 @@
 use alloc::{collections::BTreeMap, string::String};
 
 use miden_lib::{
-    errors::tx_kernel_errors::ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT,
-    transaction::memory::CURRENT_INPUT_NOTE_PTR,
+    errors::tx_kernel_errors::{
+        ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT, ERR_NOTE_TOO_MANY_ASSETS,
+        ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT,
+    },
+    transaction::{memory::CURRENT_INPUT_NOTE_PTR, TransactionKernel},
 };
 use miden_objects::{
     account::AccountId,
-    note::{Note, NoteExecutionHint, NoteExecutionMode, NoteMetadata, NoteTag, NoteType},
-    testing::{account_id::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, prepare_word},
+    note::{
+        Note, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata, NoteTag, NoteType,
+    },
+    testing::{
+        account_id::{ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER},
+        note::NoteBuilder,
+        prepare_word,
+    },
     transaction::TransactionArgs,
-    Hasher, WORD_SIZE,
+    Digest, Hasher, MAX_ASSETS_PER_NOTE, MAX_INPUTS_PER_NOTE, WORD_SIZE,
 };
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
 use vm_processor::{ProcessState, Word, EMPTY_WORD, ONE};
 
 use super::{Felt, Process, ZERO};
@@ -522,6 +533,116 @@ fn test_get_inputs_hash() {
     assert_eq!(process_state.get_stack_state()[0..16], expected_stack);
 }
 
+/// Tests that the commitment computed by `note::compute_inputs_commitment` matches the commitment
+/// of the same [NoteInputs] for every padding case of the first two blocks of 8 inputs and for a
+/// selection of larger inputs up to the maximum number of inputs.
+#[test]
+fn test_compute_inputs_commitment_parity() {
+    const INPUTS_PTR: u32 = 4000;
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let num_inputs_cases =
+        (0..=17).chain([23, 24, 25, 31, 32, 33, 63, 64, 65, 100, 120, 127, MAX_INPUTS_PER_NOTE]);
+    for num_inputs in num_inputs_cases {
+        let inputs =
+            NoteInputs::new((0..num_inputs).map(|i| Felt::new(i as u64 + 1)).collect()).unwrap();
+
+        // write the inputs to memory, followed by non-zero elements which must not be committed to
+        let mut write_inputs = String::new();
+        for (i, value) in inputs.values().iter().enumerate() {
+            write_inputs += &format!("push.{value}.{} mem_store\n", INPUTS_PTR + i as u32);
+        }
+        for i in num_inputs..num_inputs.next_multiple_of(8) + 8 {
+            write_inputs += &format!("push.{}.{} mem_store\n", 1000 + i, INPUTS_PTR + i as u32);
+        }
+
+        let code = format!(
+            "
+            use.miden::note
+
+            begin
+                {write_inputs}
+
+                push.{num_inputs}.{INPUTS_PTR}
+                exec.note::compute_inputs_commitment
+                # => [COMMITMENT]
+
+                # truncate the stack
+                swapw dropw
+            end
+            "
+        );
+
+        let process = tx_context.execute_code(&code).unwrap();
+
+        assert_eq!(
+            process.stack.get_word(0),
+            inputs.commitment().as_elements(),
+            "commitment mismatch for {num_inputs} inputs"
+        );
+    }
+
+    // more inputs than allowed
+    let code = format!(
+        "
+        use.miden::note
+
+        begin
+            push.{}.{INPUTS_PTR} exec.note::compute_inputs_commitment
+        end
+        ",
+        MAX_INPUTS_PER_NOTE + 1
+    );
+    assert_execution_error!(
+        tx_context.execute_code(&code),
+        ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT
+    );
+}
+
+#[test]
+fn test_get_remaining_asset_slots() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    for num_assets in [0, 1, 100, MAX_ASSETS_PER_NOTE - 1, MAX_ASSETS_PER_NOTE] {
+        let code = format!(
+            "
+            use.miden::note
+
+            begin
+                push.{num_assets}
+                exec.note::get_remaining_asset_slots
+                # => [remaining_asset_slots]
+
+                # truncate the stack
+                swap drop
+            end
+            "
+        );
+
+        let process = tx_context.execute_code(&code).unwrap();
+
+        assert_eq!(
+            process.stack.get(0),
+            Felt::from((MAX_ASSETS_PER_NOTE - num_assets) as u32),
+            "remaining asset slots mismatch for {num_assets} assets"
+        );
+    }
+
+    // more assets than allowed
+    let code = format!(
+        "
+        use.miden::note
+
+        begin
+            push.{} exec.note::get_remaining_asset_slots
+        end
+        ",
+        MAX_ASSETS_PER_NOTE + 1
+    );
+    assert_execution_error!(tx_context.execute_code(&code), ERR_NOTE_TOO_MANY_ASSETS);
+}
+
 #[test]
 fn test_get_current_script_hash() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -593,7 +714,7 @@ fn test_build_note_metadata() {
             execution_hint = Felt::from(test_metadata.execution_hint()),
             note_type = Felt::from(test_metadata.note_type()),
             aux = test_metadata.aux(),
-            tag = test_metadata.tag(),
+            tag = Felt::from(test_metadata.tag()),
         );
 
         let process = tx_context.execute_code(&code).unwrap();
@@ -608,3 +729,64 @@ fn test_build_note_metadata() {
         assert_eq!(Word::from(test_metadata), metadata_word, "failed in iteration {iteration}");
     }
 }
+
+/// Tests that notes with identical scripts which read the same advice key via
+/// `note::adv_push_scoped` each read the values scoped to them, and that the scoped entries do not
+/// collide with a global entry under the same key.
+#[test]
+fn test_adv_push_scoped() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let assembler = TransactionKernel::testing_assembler();
+    let mut rng = ChaCha20Rng::from_seed([7; 32]);
+    let key = Digest::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    // the script asserts that the first scoped value is equal to the first note input
+    let code = format!(
+        "
+        use.miden::note
+
+        begin
+            push.{key} exec.note::adv_push_scoped adv_push.1
+            # => [value]
+
+            push.0 exec.note::get_inputs drop mem_load
+            # => [expected_value, value]
+
+            assert_eq
+            # => []
+        end
+        ",
+        key = prepare_word(&key),
+    );
+
+    let note_1 = NoteBuilder::new(sender, &mut rng)
+        .note_inputs([Felt::new(11)])
+        .unwrap()
+        .code(&code)
+        .build(&assembler)
+        .unwrap();
+    let note_2 = NoteBuilder::new(sender, &mut rng)
+        .note_inputs([Felt::new(22)])
+        .unwrap()
+        .code(&code)
+        .build(&assembler)
+        .unwrap();
+    assert_eq!(note_1.script(), note_2.script());
+
+    let execute = |value_1: u64, value_2: u64| {
+        let mut tx_context = TransactionContextBuilder::with_standard_account(ONE)
+            .input_notes(vec![note_1.clone(), note_2.clone()])
+            .build();
+
+        let mut tx_args = tx_context.tx_args().clone();
+        tx_args.extend_advice_map([(key, vec![Felt::new(33)])]);
+        tx_args.add_note_advice(note_1.id(), key, vec![Felt::new(value_1)]);
+        tx_args.add_note_advice(note_2.id(), key, vec![Felt::new(value_2)]);
+        tx_context.set_tx_args(tx_args);
+
+        tx_context.execute()
+    };
+
+    execute(11, 22).expect("each note should read its own scoped value");
+    execute(22, 11).expect_err("notes should not read the scoped values of other notes");
+}
@@ -1,4 +1,7 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 
 use anyhow::Context;
 use miden_lib::{
@@ -29,7 +32,7 @@ use miden_lib::{
 };
 use miden_objects::{
     account::{
-        Account, AccountBuilder, AccountId, AccountIdAnchor, AccountIdVersion,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountIdAnchor, AccountIdVersion,
         AccountProcedureInfo, AccountStorageMode, AccountType, StorageSlot,
     },
     block::{BlockHeader, BlockNumber},
@@ -455,13 +458,7 @@ pub fn create_multiple_accounts_test(
                 AccountIdAnchor::try_from(anchor_block_header)
                     .context("block header to anchor conversion failed")?,
             )
-            .with_component(
-                AccountMockComponent::new_with_slots(
-                    TransactionKernel::testing_assembler(),
-                    vec![StorageSlot::Value([Felt::new(255); 4])],
-                )
-                .unwrap(),
-            )
+            .with_component(mock_component_for_type(account_type))
             .build()
             .context("account build failed")?;
 
@@ -479,6 +476,25 @@ pub fn create_multiple_accounts_test(
     Ok(())
 }
 
+/// Returns a mock component for an account of the provided type.
+///
+/// Faucet accounts must have exactly one faucet component, so for faucet types the component only
+/// supports the provided type.
+fn mock_component_for_type(account_type: AccountType) -> AccountComponent {
+    let component: AccountComponent = AccountMockComponent::new_with_slots(
+        TransactionKernel::testing_assembler(),
+        vec![StorageSlot::Value([Felt::new(255); 4])],
+    )
+    .unwrap()
+    .into();
+
+    if account_type.is_faucet() {
+        component.with_supported_types(BTreeSet::from([account_type]))
+    } else {
+        component
+    }
+}
+
 /// Tests that a valid account of each type can be created successfully with the genesis block used
 /// as the anchor block for the account IDs.
 #[test]
@@ -1,9 +1,11 @@
 use alloc::vec::Vec;
 use std::string::{String, ToString};
 
+use assert_matches::assert_matches;
 use miden_lib::{
     errors::tx_kernel_errors::{
-        ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
+        ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, ERR_NOTE_TOO_MANY_ASSETS,
+        ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
     },
     transaction::{
         memory::{
@@ -36,18 +38,18 @@ use miden_objects::{
         storage::STORAGE_LEAVES_2,
     },
     transaction::{OutputNote, OutputNotes, TransactionScript},
-    FieldElement, ACCOUNT_TREE_DEPTH,
+    FieldElement, ACCOUNT_TREE_DEPTH, MAX_ASSETS_PER_NOTE,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
-use vm_processor::AdviceInputs;
+use vm_processor::{AdviceInputs, ExecutionError};
 
 use super::{Felt, Process, ProcessState, Word, ONE, ZERO};
 use crate::{
     assert_execution_error,
     testing::{MockChain, TransactionContextBuilder},
     tests::kernel_tests::{read_root_mem_word, try_read_root_mem_word},
-    TransactionExecutor,
+    TransactionExecutor, TransactionExecutorError,
 };
 
 #[test]
@@ -83,7 +85,7 @@ fn test_create_note() {
         recipient = prepare_word(&recipient),
         PUBLIC_NOTE = NoteType::Public as u8,
         note_execution_hint = Felt::from(NoteExecutionHint::after_block(23.into()).unwrap()),
-        tag = tag,
+        tag = Felt::from(tag),
     );
 
     let process = &tx_context.execute_code(&code).unwrap();
@@ -323,14 +325,14 @@ fn test_get_output_notes_commitment() {
         PUBLIC_NOTE = NoteType::Public as u8,
         NOTE_EXECUTION_HINT_1 = Felt::from(output_note_1.metadata().execution_hint()),
         recipient_1 = prepare_word(&output_note_1.recipient().digest()),
-        tag_1 = output_note_1.metadata().tag(),
+        tag_1 = Felt::from(output_note_1.metadata().tag()),
         aux_1 = output_note_1.metadata().aux(),
         asset_1 = prepare_word(&Word::from(
             **output_note_1.assets().iter().take(1).collect::<Vec<_>>().first().unwrap()
         )),
         recipient_2 = prepare_word(&output_note_2.recipient().digest()),
         NOTE_EXECUTION_HINT_2 = Felt::from(output_note_2.metadata().execution_hint()),
-        tag_2 = output_note_2.metadata().tag(),
+        tag_2 = Felt::from(output_note_2.metadata().tag()),
         aux_2 = output_note_2.metadata().aux(),
         asset_2 = prepare_word(&Word::from(
             **output_note_2.assets().iter().take(1).collect::<Vec<_>>().first().unwrap()
@@ -584,6 +586,84 @@ fn test_create_note_and_add_same_nft_twice() {
     assert_execution_error!(process, ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS);
 }
 
+#[test]
+fn test_create_note_and_add_assets_up_to_max() {
+    let process = add_nft_to_note_with_num_assets(MAX_ASSETS_PER_NOTE - 1).unwrap();
+
+    assert_eq!(
+        process.stack.get(0),
+        Felt::from(MAX_ASSETS_PER_NOTE as u32),
+        "note must contain the maximum number of assets"
+    );
+}
+
+#[test]
+fn test_create_note_and_add_too_many_assets() {
+    let process = add_nft_to_note_with_num_assets(MAX_ASSETS_PER_NOTE);
+
+    assert_execution_error!(process, ERR_NOTE_TOO_MANY_ASSETS);
+}
+
+/// Creates an output note which is set up to already contain `num_assets` assets, adds a
+/// non-fungible asset to it and returns the process with the resulting number of assets of the
+/// note on top of the stack.
+fn add_nft_to_note_with_num_assets(num_assets: usize) -> Result<Process, ExecutionError> {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let non_fungible_asset = NonFungibleAsset::mock(&[1, 2, 3]);
+    let encoded = Word::from(non_fungible_asset);
+
+    let code = format!(
+        "
+        use.kernel::memory
+        use.kernel::prologue
+        use.test::account
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            exec.prologue::prepare_transaction
+            # => []
+
+            padw padw
+            push.{recipient}
+            push.{execution_hint_always}
+            push.{PUBLIC_NOTE}
+            push.{aux}
+            push.{tag}
+
+            call.wallet::create_note
+            # => [note_idx, pad(15)]
+
+            # pretend that the note already contains the given number of assets
+            push.{num_assets} dup.1 exec.memory::get_output_note_ptr
+            exec.memory::set_output_note_num_assets
+            # => [note_idx, pad(15)]
+
+            push.{nft}
+            call.account::add_asset_to_note
+            # => [NFT, note_idx, pad(15)]
+            dropw
+
+            exec.memory::get_output_note_ptr exec.memory::get_output_note_num_assets
+            # => [num_assets, pad(15)]
+
+            # truncate the stack
+            swapdw dropw dropw swapdw dropw dropw
+        end
+        ",
+        recipient = prepare_word(&recipient),
+        PUBLIC_NOTE = NoteType::Public as u8,
+        execution_hint_always = Felt::from(NoteExecutionHint::always()),
+        aux = Felt::new(0),
+        tag = tag,
+        nft = prepare_word(&encoded),
+    );
+
+    tx_context.execute_code(&code)
+}
+
 #[test]
 fn test_build_recipient_hash() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -1074,6 +1154,134 @@ fn test_fpi_execute_foreign_procedure() {
         .unwrap();
 }
 
+/// Test that foreign procedures can be invoked when the foreign account is provided to the executor
+/// as [ForeignAccountInputs](miden_objects::transaction::ForeignAccountInputs), without providing
+/// its data via advice inputs or loading its code into the executor.
+#[test]
+fn test_fpi_execute_with_foreign_account_inputs() {
+    let foreign_account = foreign_account_with_getters();
+    let native_account = native_account_without_storage();
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()]);
+    mock_chain.seal_block(None);
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id());
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # get the storage item at index 0
+            padw padw padw push.0.0 push.0
+            push.{get_item_foreign_hash}
+            push.{foreign_suffix}.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, pad(14)]
+
+            exec.tx::execute_foreign_procedure
+            # => [STORAGE_VALUE]
+
+            push.1.2.3.4 assert_eqw
+            # => []
+
+            # get the storage map item at index 1
+            padw padw push.0.0
+            push.{map_key} push.1
+            push.{get_map_item_foreign_hash}
+            push.{foreign_suffix}.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, MAP_ITEM_KEY, pad(10)]
+
+            exec.tx::execute_foreign_procedure
+            # => [MAP_VALUE]
+
+            push.1.2.3.4 assert_eqw
+            # => []
+
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+        get_item_foreign_hash = foreign_account.code().procedures()[0].mast_root(),
+        get_map_item_foreign_hash = foreign_account.code().procedures()[1].mast_root(),
+        map_key = STORAGE_LEAVES_2[0].0,
+    );
+
+    let tx_script =
+        TransactionScript::compile(code, vec![], TransactionKernel::testing_assembler()).unwrap();
+
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .tx_script(tx_script)
+        .build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let executed_transaction = executor
+        .execute_transaction_with_foreign_accounts(
+            native_account.id(),
+            block_ref,
+            &[],
+            tx_context.tx_args().clone(),
+            vec![foreign_account_inputs],
+        )
+        .unwrap();
+
+    // the foreign account code is part of the executed transaction, so that it can be proven
+    let (_, _, tx_witness, _) = executed_transaction.into_parts();
+    assert!(tx_witness.account_codes.contains(foreign_account.code()));
+}
+
+/// Test that a foreign account witness which does not match the account root of the reference
+/// block is rejected before the transaction is executed.
+#[test]
+fn test_fpi_stale_foreign_account_witness() {
+    let foreign_account = foreign_account_with_getters();
+    let native_account = native_account_without_storage();
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()]);
+    mock_chain.seal_block(None);
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id());
+
+    // adding another account to the chain changes the account root of the reference block, which
+    // makes the previously obtained witness stale
+    mock_chain.add_pending_account(
+        AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+            .with_component(
+                AccountMockComponent::new_with_slots(
+                    TransactionKernel::testing_assembler(),
+                    vec![],
+                )
+                .unwrap(),
+            )
+            .build_existing()
+            .unwrap(),
+    );
+    mock_chain.seal_block(None);
+
+    let tx_context = mock_chain.build_tx_context(native_account.id(), &[], &[]).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let result = executor.execute_transaction_with_foreign_accounts(
+        native_account.id(),
+        block_ref,
+        &[],
+        tx_context.tx_args().clone(),
+        vec![foreign_account_inputs],
+    );
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::ForeignAccountRootMismatch { account_id, expected, .. })
+            if account_id == foreign_account.id()
+                && expected == tx_context.tx_inputs().block_header().account_root()
+    );
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1112,6 +1320,57 @@ fn get_mock_fpi_adv_inputs(foreign_account: &Account, mock_chain: &MockChain) ->
     advice_inputs
 }
 
+/// Returns an account which exports the `get_item_foreign` and `get_map_item_foreign` getters for
+/// its storage slots 0 (a value slot) and 1 (a map slot).
+fn foreign_account_with_getters() -> Account {
+    let storage_slots =
+        vec![AccountStorage::mock_item_0().slot, AccountStorage::mock_item_2().slot];
+    let foreign_account_code_source = "
+        use.miden::account
+
+        export.get_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the
+            # foreign account, not the native one
+            push.1 drop
+            exec.account::get_item
+
+            # truncate the stack
+            movup.6 movup.6 movup.6 drop drop drop
+        end
+
+        export.get_map_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the
+            # foreign account, not the native one
+            push.2 drop
+            exec.account::get_map_item
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::compile(
+        foreign_account_code_source,
+        TransactionKernel::testing_assembler(),
+        storage_slots,
+    )
+    .unwrap()
+    .with_supports_all_types();
+
+    AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(foreign_account_component)
+        .build_existing()
+        .unwrap()
+}
+
+/// Returns an account with the mock component and no storage slots.
+fn native_account_without_storage() -> Account {
+    AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(TransactionKernel::testing_assembler(), vec![])
+                .unwrap(),
+        )
+        .build_existing()
+        .unwrap()
+}
+
 fn foreign_account_data_memory_assertions(foreign_account: &Account, process: &Process) {
     let foreign_account_data_ptr = NATIVE_ACCOUNT_DATA_PTR + ACCOUNT_DATA_LENGTH as u32;
 
@@ -1,6 +1,6 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
-    string::String,
+    string::{String, ToString},
     sync::Arc,
     vec::Vec,
 };
@@ -9,58 +9,80 @@ use ::assembly::{
     ast::{Module, ModuleKind},
     LibraryPath,
 };
-use miden_lib::transaction::TransactionKernel;
+use assert_matches::assert_matches;
+use miden_lib::{
+    errors::tx_kernel_errors::{
+        ERR_NOTE_ATTEMPT_TO_FORWARD_ASSET_FROM_INCORRECT_CONTEXT,
+        ERR_NOTE_FORWARD_ASSET_TO_NOTE_NOT_CREATED_BY_CURRENT_NOTE,
+        ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND,
+    },
+    note::{create_p2id_note, utils::build_p2id_recipient},
+    transaction::TransactionKernel,
+};
 use miden_objects::{
-    account::{AccountBuilder, AccountComponent, AccountStorage, StorageSlot},
+    account::{AccountBuilder, AccountComponent, AccountId, AccountStorage, StorageSlot},
     assembly::DefaultSourceManager,
     asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    crypto::rand::RpoRandomCoin,
     note::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
-        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType, Nullifier,
     },
     testing::{
+        account_code::DEFAULT_AUTH_SCRIPT,
         account_component::AccountMockComponent,
         account_id::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
         },
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
-        note::DEFAULT_NOTE_CODE,
+        note::{NoteBuilder, DEFAULT_NOTE_CODE},
         prepare_word,
         storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
     },
-    transaction::{ProvenTransaction, TransactionArgs, TransactionScript},
-    Felt, Word, MIN_PROOF_SECURITY_LEVEL,
+    transaction::{
+        ExecutedTransaction, InputNote, InputNoteCommitment, OutputNote, ProvenTransaction,
+        ProvenTransactionBuilder, TransactionArgs, TransactionScript, TransactionWitness,
+    },
+    vm::AdviceMap,
+    Felt, TransactionOutputError, TransactionScriptError, Word, MAX_TX_EXECUTION_CYCLES,
+    MIN_PROOF_SECURITY_LEVEL,
 };
-use miden_prover::ProvingOptions;
+use miden_prover::{ExecutionProof, ProvingOptions};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider, ONE,
+    Digest, ExecutionError, MemAdviceProvider, ONE, ZERO,
 };
 
 use super::{
-    LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
-    TransactionVerifier,
+    validate_proven_transaction_stateless, ChainExecutionError, DataStore, DataStoreError,
+    LocalTransactionProver, NoteAccountExecution, NoteConsumptionChecker,
+    ProvenTransactionWithWitness, ProvingOptionsExt, StatelessValidationConfig,
+    StatelessValidationError, StatelessValidationStage, TransactionExecutor,
+    TransactionExecutorError, TransactionHost, TransactionProver, TransactionVerifier,
+    TransactionVerifierError, TxStep, TxStepNote, DEFAULT_MAX_OUTPUT_NOTE_SIZE,
+};
+use crate::{
+    testing::{fixtures, Auth, MockChain, TransactionContext, TransactionContextBuilder},
+    TransactionMastStore,
 };
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
 
 mod kernel_tests;
 
 // TESTS
 // ================================================================================================
 
-#[test]
-fn transaction_executor_witness() {
-    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
-        .with_mock_notes_preserved()
-        .build();
-
-    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
-
+/// Executes a transaction against the account of the provided context which consumes all input
+/// notes of the context, and returns the executed transaction together with its witness.
+fn execute_with_witness(
+    executor: &TransactionExecutor,
+    tx_context: &TransactionContext,
+    tx_args: TransactionArgs,
+) -> (ExecutedTransaction, TransactionWitness) {
     let account_id = tx_context.account().id();
-
     let block_ref = tx_context.tx_inputs().block_header().block_num();
     let note_ids = tx_context
         .tx_inputs()
@@ -69,18 +91,31 @@ fn transaction_executor_witness() {
         .map(|note| note.id())
         .collect::<Vec<_>>();
 
-    let executed_transaction = executor
-        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
-        .unwrap();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    let tx_witness = TransactionWitness::from(executed_transaction.clone());
+
+    (executed_transaction, tx_witness)
+}
+
+#[test]
+fn transaction_executor_witness() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (executed_transaction, tx_witness) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
 
-    let tx_inputs = executed_transaction.tx_inputs();
-    let tx_args = executed_transaction.tx_args();
+    let tx_inputs = &tx_witness.tx_inputs;
+    let tx_args = &tx_witness.tx_args;
 
     // use the witness to execute the transaction again
     let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(
         tx_inputs,
         tx_args,
-        Some(executed_transaction.advice_witness().clone()),
+        Some(tx_witness.advice_witness.clone()),
     );
     let mem_advice_provider: MemAdviceProvider = advice_inputs.into();
 
@@ -117,6 +152,215 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+/// Tests that reconstructing the transaction outputs from an advice map which lacks the final
+/// account data reports the missing key.
+#[test]
+fn transaction_outputs_missing_advice_map_entry() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executed_transaction = tx_context.execute().unwrap();
+    let (_, tx_outputs, ..) = executed_transaction.into_parts();
+
+    let final_account_hash = tx_outputs.account.hash();
+    let stack_outputs = TransactionKernel::build_output_stack(
+        final_account_hash,
+        tx_outputs.output_notes.commitment(),
+        tx_outputs.expiration_block_num,
+    );
+
+    let err = TransactionKernel::from_transaction_parts(
+        &stack_outputs,
+        &AdviceMap::default(),
+        tx_outputs.output_notes.iter().cloned().collect(),
+    )
+    .unwrap_err();
+    assert_matches!(
+        err,
+        TransactionOutputError::MissingAdviceMapEntry { key } if key == final_account_hash
+    );
+}
+
+/// Tests that creating a public output note whose details were not provided via the transaction
+/// arguments results in an error identifying the note.
+#[test]
+fn public_output_note_missing_details() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    let note_script =
+        NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
+    let serial_num = Word::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::new(vec![]).unwrap());
+    let tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+    let metadata = NoteMetadata::builder(account_id)
+        .note_type(NoteType::Public)
+        .tag(tag)
+        .build()
+        .unwrap();
+    let note = Note::new(NoteAssets::default(), metadata, recipient);
+
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{RECIPIENT}
+            push.{EXECUTION_HINT}
+            push.{NOTE_TYPE}
+            push.0
+            push.{tag}
+            # => [tag, aux, note_type, execution_hint, RECIPIENT]
+
+            padw padw swapdw
+            call.wallet::create_note
+            # => [note_idx, pad(15)]
+
+            dropw dropw dropw dropw
+        end
+        ",
+        RECIPIENT = prepare_word(&Word::from(note.recipient().digest())),
+        EXECUTION_HINT = Felt::from(NoteExecutionHint::always()),
+        NOTE_TYPE = NoteType::Public as u8,
+        tag = Felt::from(tag),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    // the note is not added via `TransactionArgs::add_expected_output_note`
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .tx_script(tx_script)
+        .build();
+    let err = tx_context.execute().unwrap_err();
+
+    assert_matches!(
+        err,
+        TransactionExecutorError::TransactionOutputConstructionFailed(
+            TransactionOutputError::MissingOutputNoteDetails { note_id }
+        ) if note_id == note.id()
+    );
+}
+
+/// Returns a note without assets which can be created by the standard mock account.
+fn output_note_without_assets(serial_num: Word, note_type: NoteType) -> Note {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    let note_script =
+        NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
+    let recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::new(vec![]).unwrap());
+    let tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+    let metadata = NoteMetadata::builder(account_id).note_type(note_type).tag(tag).build().unwrap();
+
+    Note::new(NoteAssets::default(), metadata, recipient)
+}
+
+/// Returns a transaction script which creates the provided notes via the basic wallet.
+fn create_notes_tx_script(notes: &[&Note]) -> TransactionScript {
+    let create_notes_code = notes
+        .iter()
+        .map(|note| {
+            format!(
+                "
+                push.{RECIPIENT}
+                push.{EXECUTION_HINT}
+                push.{NOTE_TYPE}
+                push.0
+                push.{tag}
+                # => [tag, aux, note_type, execution_hint, RECIPIENT]
+
+                padw padw swapdw
+                call.wallet::create_note
+                # => [note_idx, pad(15)]
+
+                dropw dropw dropw dropw
+                ",
+                RECIPIENT = prepare_word(&Word::from(note.recipient().digest())),
+                EXECUTION_HINT = Felt::from(note.metadata().execution_hint()),
+                NOTE_TYPE = note.metadata().note_type() as u8,
+                tag = Felt::from(note.metadata().tag()),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            {create_notes_code}
+        end
+        "
+    );
+
+    TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap()
+}
+
+/// Tests that the validation of expected output notes passes if all expected notes are created,
+/// even if the transaction creates further notes.
+#[test]
+fn expected_output_notes_are_created() {
+    let expected_note = output_note_without_assets([Felt::new(1); 4], NoteType::Public);
+    let other_note = output_note_without_assets([Felt::new(2); 4], NoteType::Private);
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let mut tx_args =
+        TransactionArgs::with_tx_script(create_notes_tx_script(&[&expected_note, &other_note]));
+    tx_args.add_expected_output_note(&expected_note);
+    assert!(tx_args.expected_output_notes().contains(&expected_note.id()));
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None)
+        .with_expected_output_validation();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &[], tx_args).unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+}
+
+/// Tests that the validation of expected output notes fails with the ID of the expected note if
+/// the transaction does not create it.
+#[test]
+fn expected_output_note_is_missing() {
+    let expected_note = output_note_without_assets([Felt::new(1); 4], NoteType::Public);
+    let other_note = output_note_without_assets([Felt::new(2); 4], NoteType::Private);
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the transaction script does not create the expected note
+    let mut tx_args = TransactionArgs::with_tx_script(create_notes_tx_script(&[&other_note]));
+    tx_args.add_expected_output_note(&expected_note);
+
+    // without the validation, the missing note goes unnoticed
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    executor
+        .execute_transaction(account_id, block_ref, &[], tx_args.clone())
+        .unwrap();
+
+    let executor = executor.with_expected_output_validation();
+    let err = executor.execute_transaction(account_id, block_ref, &[], tx_args).unwrap_err();
+
+    assert_matches!(
+        err,
+        TransactionExecutorError::MissingExpectedOutputNote { note_id }
+            if note_id == expected_note.id()
+    );
+}
+
 #[test]
 fn executed_transaction_account_delta_new() {
     let account_assets = AssetVault::mock().assets().collect::<Vec<Asset>>();
@@ -213,7 +457,7 @@ fn executed_transaction_account_delta_new() {
             EXECUTION_HINT = hints[i],
             NOTETYPE = note_types[i] as u8,
             aux = aux_array[i],
-            tag = tags[i],
+            tag = Felt::from(tags[i]),
             REMOVED_ASSET = prepare_word(&Word::from(removed_assets[i]))
         ));
     }
@@ -353,6 +597,15 @@ fn executed_transaction_account_delta_new() {
         removed_assets.len(),
         executed_transaction.account_delta().vault().removed_assets().count()
     );
+
+    // delta summary
+    // --------------------------------------------------------------------------------------------
+    let summary = executed_transaction.account_delta().summary();
+    assert_eq!(summary.nonce, Some(Felt::new(2)));
+    assert_eq!(summary.num_updated_values, 1);
+    assert_eq!(summary.num_updated_map_entries, 1);
+    assert_eq!(summary.num_added_assets, added_assets.len());
+    assert_eq!(summary.num_removed_assets, removed_assets.len());
 }
 
 #[test]
@@ -360,7 +613,6 @@ fn test_empty_delta_nonce_update() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
 
     let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
-    let account_id = tx_context.tx_inputs().account().id();
 
     let tx_script_src = "
         use.test::account
@@ -387,19 +639,10 @@ fn test_empty_delta_nonce_update() {
         tx_context.tx_args().advice_inputs().clone().map,
     );
 
-    let block_ref = tx_context.tx_inputs().block_header().block_num();
-    let note_ids = tx_context
-        .tx_inputs()
-        .input_notes()
-        .iter()
-        .map(|note| note.id())
-        .collect::<Vec<_>>();
-
     // expected delta
     // --------------------------------------------------------------------------------------------
     // execute the transaction and get the witness
-    let executed_transaction =
-        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    let (executed_transaction, _) = execute_with_witness(&executor, &tx_context, tx_args);
 
     // nonce delta
     // --------------------------------------------------------------------------------------------
@@ -413,6 +656,86 @@ fn test_empty_delta_nonce_update() {
     assert_eq!(executed_transaction.account_delta().storage().maps().len(), 0);
 }
 
+#[test]
+fn test_consume_unauthenticated_p2id_note() {
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let p2id_note = fixtures::note::p2id_100_to(target_account_id);
+
+    let tx_script_src = "
+        use.test::account
+        begin
+            push.1
+            call.account::incr_nonce
+            drop
+        end
+    ";
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .unauthenticated_input_notes(vec![p2id_note.clone()])
+        .tx_script(tx_script)
+        .build();
+    assert_eq!(tx_context.account().id(), target_account_id);
+
+    let input_note = tx_context.tx_inputs().input_notes().get_note(0);
+    assert_eq!(input_note.id(), p2id_note.id());
+    assert_matches!(input_note, InputNote::Unauthenticated { .. });
+
+    let executed_transaction = tx_context.execute().unwrap();
+    assert_eq!(
+        executed_transaction.account_delta().vault().added_assets().collect::<Vec<_>>(),
+        p2id_note.assets().iter().cloned().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_unauthenticated_input_notes_with_provided_tx_inputs() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([6; 32]))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    let tx_inputs = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build()
+        .tx_inputs()
+        .clone();
+    let num_input_notes = tx_inputs.input_notes().num_notes();
+
+    // the unauthenticated input notes are appended to the input notes of the provided inputs
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .tx_inputs(tx_inputs)
+        .unauthenticated_input_notes(vec![note.clone()])
+        .build();
+    let input_notes = tx_context.tx_inputs().input_notes();
+    assert_eq!(input_notes.num_notes(), num_input_notes + 1);
+    assert_eq!(input_notes.num_unauthenticated(), 1);
+    assert_matches!(
+        input_notes.get_note(num_input_notes),
+        InputNote::Unauthenticated { note: unauthenticated_note } if *unauthenticated_note == note
+    );
+}
+
+#[test]
+#[should_panic(expected = "was added more than once")]
+fn test_duplicate_authenticated_and_unauthenticated_input_note() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([5; 32]))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note.clone()])
+        .unauthenticated_input_notes(vec![note])
+        .build();
+}
+
 #[test]
 fn test_send_note_proc() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -420,7 +743,6 @@ fn test_send_note_proc() {
         .build();
 
     let executor = TransactionExecutor::new(tx_context.get_data_store(), None).with_debug_mode();
-    let account_id = tx_context.tx_inputs().account().id();
 
     // removed assets
     let removed_asset_1 = FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT / 2);
@@ -451,7 +773,7 @@ fn test_send_note_proc() {
         vec![removed_asset_1, removed_asset_2, removed_asset_3],
     ];
 
-    for (idx, removed_assets) in assets_matrix.into_iter().enumerate() {
+    for removed_assets in assets_matrix {
         // Prepare the string containing the procedures required for adding assets to the note.
         // Depending on the number of the assets to remove, the resulting string will be extended
         // with the corresponding number of procedure "blocks"
@@ -509,6 +831,7 @@ fn test_send_note_proc() {
             end
         ",
             note_type = note_type as u8,
+            tag = Felt::from(tag),
         );
 
         let tx_script = TransactionScript::compile(
@@ -523,20 +846,10 @@ fn test_send_note_proc() {
             tx_context.tx_args().advice_inputs().clone().map,
         );
 
-        let block_ref = tx_context.tx_inputs().block_header().block_num();
-        let note_ids = tx_context
-            .tx_inputs()
-            .input_notes()
-            .iter()
-            .map(|note| note.id())
-            .collect::<Vec<_>>();
-
         // expected delta
         // --------------------------------------------------------------------------------------------
         // execute the transaction and get the witness
-        let executed_transaction = executor
-            .execute_transaction(account_id, block_ref, &note_ids, tx_args)
-            .unwrap_or_else(|_| panic!("test failed in iteration {idx}"));
+        let (executed_transaction, _) = execute_with_witness(&executor, &tx_context, tx_args);
 
         // nonce delta
         // --------------------------------------------------------------------------------------------
@@ -602,8 +915,6 @@ fn executed_transaction_output_notes() {
     let note_type3 = NoteType::Public;
 
     tag1.validate(note_type1).expect("note tag 1 should support private notes");
-    tag2.validate(note_type2).expect("note tag 2 should support public notes");
-    tag3.validate(note_type3).expect("note tag 3 should support public notes");
 
     // In this test we create 3 notes. Note 1 is private, Note 2 is public and Note 3 is public
     // without assets.
@@ -613,8 +924,13 @@ fn executed_transaction_output_notes() {
     let note_script_2 =
         NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
     let inputs_2 = NoteInputs::new(vec![]).unwrap();
-    let metadata_2 =
-        NoteMetadata::new(account_id, note_type2, tag2, NoteExecutionHint::none(), aux2).unwrap();
+    let metadata_2 = NoteMetadata::builder(account_id)
+        .note_type(note_type2)
+        .tag(tag2)
+        .execution_hint(NoteExecutionHint::none())
+        .aux(aux2)
+        .build()
+        .expect("note tag 2 should support public notes");
     let vault_2 = NoteAssets::new(vec![removed_asset_3, removed_asset_4]).unwrap();
     let recipient_2 = NoteRecipient::new(serial_num_2, note_script_2, inputs_2);
     let expected_output_note_2 = Note::new(vault_2, metadata_2, recipient_2);
@@ -624,14 +940,13 @@ fn executed_transaction_output_notes() {
     let note_script_3 =
         NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
     let inputs_3 = NoteInputs::new(vec![]).unwrap();
-    let metadata_3 = NoteMetadata::new(
-        account_id,
-        note_type3,
-        tag3,
-        NoteExecutionHint::on_block_slot(1, 2, 3),
-        aux3,
-    )
-    .unwrap();
+    let metadata_3 = NoteMetadata::builder(account_id)
+        .note_type(note_type3)
+        .tag(tag3)
+        .execution_hint(NoteExecutionHint::on_block_slot(1, 2, 3))
+        .aux(aux3)
+        .build()
+        .expect("note tag 3 should support public notes");
     let vault_3 = NoteAssets::new(vec![]).unwrap();
     let recipient_3 = NoteRecipient::new(serial_num_3, note_script_3, inputs_3);
     let expected_output_note_3 = Note::new(vault_3, metadata_3, recipient_3);
@@ -740,6 +1055,9 @@ fn executed_transaction_output_notes() {
         EXECUTION_HINT_1 = Felt::from(NoteExecutionHint::always()),
         EXECUTION_HINT_2 = Felt::from(NoteExecutionHint::none()),
         EXECUTION_HINT_3 = Felt::from(NoteExecutionHint::on_block_slot(11, 22, 33)),
+        tag1 = Felt::from(tag1),
+        tag2 = Felt::from(tag2),
+        tag3 = Felt::from(tag3),
     );
 
     let tx_script = TransactionScript::compile(
@@ -757,20 +1075,10 @@ fn executed_transaction_output_notes() {
     tx_args.add_expected_output_note(&expected_output_note_2);
     tx_args.add_expected_output_note(&expected_output_note_3);
 
-    let block_ref = tx_context.tx_inputs().block_header().block_num();
-    let note_ids = tx_context
-        .tx_inputs()
-        .input_notes()
-        .iter()
-        .map(|note| note.id())
-        .collect::<Vec<_>>();
-
     // expected delta
     // --------------------------------------------------------------------------------------------
     // execute the transaction and get the witness
-
-    let executed_transaction =
-        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    let (executed_transaction, _) = execute_with_witness(&executor, &tx_context, tx_args);
 
     // output notes
     // --------------------------------------------------------------------------------------------
@@ -780,22 +1088,31 @@ fn executed_transaction_output_notes() {
     // NOTE: the mock state already contains 3 output notes
     assert_eq!(output_notes.num_notes(), 6);
 
-    let output_note_id_3 = executed_transaction.output_notes().get_note(3).id();
-    let recipient_3 = Digest::from([Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)]);
-    let note_assets_3 = NoteAssets::new(vec![combined_asset]).unwrap();
-    let expected_note_id_3 = NoteId::new(recipient_3, note_assets_3.commitment());
-    assert_eq!(output_note_id_3, expected_note_id_3);
+    // assert that the expected output note 1 is present
+    let recipient_1 = Digest::from([Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)]);
+    let note_assets_1 = NoteAssets::new(vec![combined_asset]).unwrap();
+    let expected_note_id_1 = NoteId::new(recipient_1, note_assets_1.commitment());
+    assert!(output_notes.find_by_id(expected_note_id_1).is_some());
 
     // assert that the expected output note 2 is present
-    let output_note = executed_transaction.output_notes().get_note(4);
+    let (_, output_note) = output_notes
+        .find_by_id(expected_output_note_2.id())
+        .expect("output note 2 should be present");
     let note_id = expected_output_note_2.id();
     let note_metadata = expected_output_note_2.metadata();
     assert_eq!(NoteHeader::from(output_note), NoteHeader::new(note_id, *note_metadata));
 
     // assert that the expected output note 3 is present and has no assets
-    let output_note_3 = executed_transaction.output_notes().get_note(5);
-    assert_eq!(expected_output_note_3.id(), output_note_3.id());
+    let (_, output_note_3) = output_notes
+        .find_by_id(expected_output_note_3.id())
+        .expect("output note 3 should be present");
     assert_eq!(expected_output_note_3.assets(), output_note_3.assets().unwrap());
+
+    // assert that the notes are grouped by their type
+    let notes_by_type = output_notes.partition_by_type();
+    assert!(notes_by_type[&NoteType::Private].contains(&expected_note_id_1));
+    assert!(notes_by_type[&NoteType::Public].contains(&expected_output_note_2.id()));
+    assert!(notes_by_type[&NoteType::Public].contains(&expected_output_note_3.id()));
 }
 
 #[test]
@@ -804,25 +1121,14 @@ fn prove_witness_and_verify() {
         .with_mock_notes_preserved()
         .build();
 
-    let account_id = tx_context.tx_inputs().account().id();
-
-    let block_ref = tx_context.tx_inputs().block_header().block_num();
-    let note_ids = tx_context
-        .tx_inputs()
-        .input_notes()
-        .iter()
-        .map(|note| note.id())
-        .collect::<Vec<_>>();
-
     let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
-    let executed_transaction = executor
-        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
-        .unwrap();
+    let (executed_transaction, tx_witness) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
     let executed_transaction_id = executed_transaction.id();
 
     let proof_options = ProvingOptions::default();
     let prover = LocalTransactionProver::new(proof_options);
-    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+    let proven_transaction = prover.prove(tx_witness).unwrap();
 
     assert_eq!(proven_transaction.id(), executed_transaction_id);
 
@@ -832,68 +1138,486 @@ fn prove_witness_and_verify() {
     assert!(verifier.verify(proven_transaction).is_ok());
 }
 
-// TEST TRANSACTION SCRIPT
-// ================================================================================================
-
-#[test]
-fn test_tx_script() {
+/// Executes and proves a transaction consuming the mock notes with the default proving options,
+/// and returns the executed transaction together with the resulting proven transaction.
+fn prove_mock_transaction() -> (ExecutedTransaction, ProvenTransaction) {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
         .with_mock_notes_preserved()
         .build();
+
     let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (executed_transaction, tx_witness) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
 
-    let account_id = tx_context.tx_inputs().account().id();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(tx_witness).unwrap();
 
-    let block_ref = tx_context.tx_inputs().block_header().block_num();
-    let note_ids = tx_context
-        .tx_inputs()
-        .input_notes()
-        .iter()
-        .map(|note| note.id())
-        .collect::<Vec<_>>();
+    (executed_transaction, proven_transaction)
+}
 
-    let tx_script_input_key = [Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)];
-    let tx_script_input_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
-    let tx_script_src = format!(
-        "
-    begin
-        # push the tx script input key onto the stack
-        push.{key}
+/// Tests the expiration, the kernel version and the serialized layout of a proven transaction.
+#[test]
+fn proven_transaction_metadata() {
+    let (executed_transaction, proven_transaction) = prove_mock_transaction();
+    let block_ref = executed_transaction.block_header().block_num();
 
-        # load the tx script input value from the map and read it onto the stack
-        adv.push_mapval adv_loadw
+    // the transaction only expires at its expiration block
+    let expiration_block_num = proven_transaction.expiration_block_num();
+    assert!(!proven_transaction.is_expired_at(block_ref));
+    assert!(proven_transaction.is_expired_at(expiration_block_num));
 
-        # assert that the value is correct
-        push.{value} assert_eqw
-    end
-",
-        key = prepare_word(&tx_script_input_key),
-        value = prepare_word(&tx_script_input_value)
-    );
+    // the transaction carries the version of the kernel build which produced it
+    let kernel_version = TransactionKernel::version().to_string();
+    assert_eq!(proven_transaction.kernel_version(), Some(kernel_version.as_str()));
 
-    let tx_script = TransactionScript::compile(
-        tx_script_src,
-        [(tx_script_input_key, tx_script_input_value.into())],
-        TransactionKernel::testing_assembler(),
-    )
-    .unwrap();
-    let tx_args = TransactionArgs::new(
-        Some(tx_script),
-        None,
-        tx_context.tx_args().advice_inputs().clone().map,
+    let serialized_transaction = proven_transaction.to_bytes();
+
+    // the proof is followed only by the optional kernel version
+    let kernel_version_size = Some(kernel_version.clone()).to_bytes().len();
+    let preceding_segments_size = proven_transaction.account_update().to_bytes().len()
+        + proven_transaction.input_notes().to_bytes().len()
+        + proven_transaction.output_notes().to_bytes().len()
+        + proven_transaction.block_ref().to_bytes().len()
+        + proven_transaction.expiration_block_num().to_bytes().len();
+    assert_eq!(
+        preceding_segments_size + proven_transaction.proof_size_bytes() + kernel_version_size,
+        serialized_transaction.len()
     );
 
-    let executed_transaction =
-        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args);
+    // transactions serialized before the kernel version was added end right after the proof and
+    // still parse
+    let proof_end = serialized_transaction.len() - kernel_version_size;
+    let legacy_transaction =
+        ProvenTransaction::read_from_bytes(&serialized_transaction[..proof_end]).unwrap();
+    assert_eq!(legacy_transaction.kernel_version(), None);
+
+    // an unknown kernel version is serialized explicitly
+    let mut unversioned_bytes = serialized_transaction[..proof_end].to_vec();
+    unversioned_bytes.extend(None::<String>.to_bytes());
+    let unversioned_transaction = ProvenTransaction::read_from_bytes(&unversioned_bytes).unwrap();
+    assert_eq!(unversioned_transaction.kernel_version(), None);
+    assert_eq!(unversioned_transaction.to_bytes(), unversioned_bytes);
+
+    // the kernel version is not part of the transaction's identity
+    assert_eq!(legacy_transaction.id(), proven_transaction.id());
+    assert_eq!(unversioned_transaction.id(), proven_transaction.id());
+    assert_eq!(unversioned_transaction, proven_transaction);
 
-    assert!(
-        executed_transaction.is_ok(),
-        "Transaction execution failed {:?}",
-        executed_transaction,
-    );
+    let proven_transaction = ProvenTransaction::read_from_bytes(&serialized_transaction).unwrap();
+    assert_eq!(proven_transaction.expiration_block_num(), expiration_block_num);
+    assert_eq!(proven_transaction.kernel_version(), Some(kernel_version.as_str()));
 }
 
-/// Tests that an account can call code in a custom library when loading that library into the
+/// Tests that the verifier reports the security level attained by a proof and rejects proofs below
+/// its minimum security level.
+#[test]
+fn prove_and_verify_with_level() {
+    let (_, proven_transaction) = prove_mock_transaction();
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+
+    // a proof created with the default options attains at least the minimum security level
+    let security_level = verifier.verify_with_level(&proven_transaction).unwrap();
+    assert!(security_level >= MIN_PROOF_SECURITY_LEVEL);
+
+    // the attained level can be used to enforce a stricter policy than the verifier's minimum
+    let strict_verifier = TransactionVerifier::new(security_level + 1);
+    assert_matches!(
+        strict_verifier.verify_with_level(&proven_transaction),
+        Err(TransactionVerifierError::InsufficientProofSecurityLevel { actual, .. })
+            if actual == security_level
+    );
+}
+
+/// Tests that the prover returns the exact witness a transaction was proven from only if witness
+/// retention is enabled, and that re-proving from the retained witness yields the same transaction.
+#[test]
+fn prove_retaining_witness() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (_, tx_witness) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
+
+    // without witness retention, only the proven transaction is returned
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven = prover.prove_retaining(tx_witness.clone()).unwrap();
+    assert!(proven.witness.is_none());
+
+    // with witness retention, the witness is returned unchanged
+    let prover = LocalTransactionProver::new(ProvingOptions::default().retain_witness(true));
+    let ProvenTransactionWithWitness { proven: proven_transaction, witness } =
+        prover.prove_retaining(tx_witness.clone()).unwrap();
+    let witness = witness.unwrap();
+    assert_eq!(proven_transaction.id(), proven.proven.id());
+    assert_eq!(witness.to_bytes(), tx_witness.to_bytes());
+    assert_eq!(witness.digest(), tx_witness.digest());
+
+    // re-proving from the retained witness reproduces the same transaction
+    let reproven_transaction = prover.prove(witness).unwrap();
+    assert_eq!(reproven_transaction.id(), proven_transaction.id());
+}
+
+/// Tests that a minimized witness omits advice data which was not accessed during the execution,
+/// and that proving from it reproduces the executed transaction.
+#[test]
+fn prove_from_minimized_witness() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    // add an advice map entry which is never accessed by the transaction
+    let unused_key = Digest::from([Felt::new(7), Felt::new(7), Felt::new(7), Felt::new(7)]);
+    let mut tx_args = tx_context.tx_args().clone();
+    tx_args.extend_advice_map([(unused_key, vec![ONE; 64])]);
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (executed_transaction, tx_witness) = execute_with_witness(&executor, &tx_context, tx_args);
+
+    let minimized_witness =
+        TransactionWitness::from_executed_minimized(executed_transaction.clone());
+    assert!(tx_witness.tx_args.advice_inputs().mapped_values(&unused_key).is_some());
+    assert!(minimized_witness.tx_args.advice_inputs().mapped_values(&unused_key).is_none());
+    assert!(minimized_witness.to_bytes().len() < tx_witness.to_bytes().len());
+
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(minimized_witness).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction.id());
+}
+
+/// Tests that the stateless validation accepts a valid proven transaction and that the stages of
+/// the validation reject different kinds of corrupted transactions.
+#[test]
+fn proven_transaction_stateless_validation() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (_, tx_witness) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(tx_witness).unwrap();
+
+    let config = StatelessValidationConfig::new([TransactionKernel::kernel_root()]);
+    validate_proven_transaction_stateless(&proven_transaction, &config).unwrap();
+
+    // returns a builder for a copy of the proven transaction with the provided final account state
+    // and proof, to which the notes are added by the caller
+    let rebuild = |final_state_hash: Digest, proof: ExecutionProof| {
+        ProvenTransactionBuilder::new(
+            proven_transaction.account_id(),
+            proven_transaction.account_update().init_state_hash(),
+            final_state_hash,
+            proven_transaction.block_ref(),
+            proven_transaction.expiration_block_num(),
+            proof,
+        )
+        .account_update_details(proven_transaction.account_update().details().clone())
+    };
+    let final_state_hash = proven_transaction.account_update().final_state_hash();
+    let input_notes = || proven_transaction.input_notes().iter().cloned();
+    let output_notes = || proven_transaction.output_notes().iter().cloned();
+
+    // a configuration which does not accept the kernel proofs are verified against
+    let err = validate_proven_transaction_stateless(
+        &proven_transaction,
+        &StatelessValidationConfig::new([Digest::default()]),
+    )
+    .unwrap_err();
+    assert_matches!(
+        err,
+        StatelessValidationError::KernelRootNotAccepted(root)
+            if root == TransactionKernel::kernel_root()
+    );
+    assert_eq!(err.stage(), StatelessValidationStage::Kernel);
+
+    // an additional public output note which exceeds the default size limit
+    let large_note_code = format!(
+        "begin {} end",
+        (0..DEFAULT_MAX_OUTPUT_NOTE_SIZE)
+            .step_by(8)
+            .map(|i| format!("push.{i} drop"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+    let large_note =
+        NoteBuilder::new(proven_transaction.account_id(), ChaCha20Rng::from_seed([7; 32]))
+            .code(large_note_code)
+            .build(&TransactionKernel::assembler())
+            .unwrap();
+    let corrupted_transaction = rebuild(final_state_hash, proven_transaction.proof().clone())
+        .add_input_notes(input_notes())
+        .add_output_notes(output_notes().chain([OutputNote::Full(large_note.clone())]))
+        .build()
+        .unwrap();
+    let err = validate_proven_transaction_stateless(&corrupted_transaction, &config).unwrap_err();
+    assert_matches!(
+        err,
+        StatelessValidationError::OutputNoteTooLarge { note_id, .. } if note_id == large_note.id()
+    );
+    assert_eq!(err.stage(), StatelessValidationStage::NoteLimits);
+
+    // an additional output note which was created by another account
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    assert_ne!(sender, proven_transaction.account_id());
+    let foreign_note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([8; 32]))
+        .build(&TransactionKernel::assembler())
+        .unwrap();
+    let corrupted_transaction = rebuild(final_state_hash, proven_transaction.proof().clone())
+        .add_input_notes(input_notes())
+        .add_output_notes(output_notes().chain([OutputNote::Full(foreign_note.clone())]))
+        .build()
+        .unwrap();
+    let err = validate_proven_transaction_stateless(&corrupted_transaction, &config).unwrap_err();
+    assert_matches!(
+        err,
+        StatelessValidationError::OutputNoteSenderMismatch { note_id, sender: note_sender }
+            if note_id == foreign_note.id() && note_sender == sender
+    );
+    assert_eq!(err.stage(), StatelessValidationStage::OutputNoteSenders);
+
+    // a proven transaction cannot hold an account update which exceeds the protocol limit or
+    // does not match the storage mode of the account, so this stage only rejects updates above
+    // a limit configured by the node
+    let limited_config = config.clone().with_max_account_update_size(0);
+    let err =
+        validate_proven_transaction_stateless(&proven_transaction, &limited_config).unwrap_err();
+    assert_matches!(err, StatelessValidationError::AccountUpdateTooLarge { .. });
+    assert_eq!(err.stage(), StatelessValidationStage::AccountUpdate);
+
+    // a corrupted proof, i.e. a proof with a different proof-of-work nonce
+    let (hash_fn, mut stark_proof) = proven_transaction.proof().clone().into_parts();
+    stark_proof.pow_nonce ^= 1;
+    let corrupted_transaction =
+        rebuild(final_state_hash, ExecutionProof::new(stark_proof, hash_fn))
+            .add_input_notes(input_notes())
+            .add_output_notes(output_notes())
+            .build()
+            .unwrap();
+    let err = validate_proven_transaction_stateless(&corrupted_transaction, &config).unwrap_err();
+    assert_matches!(err, StatelessValidationError::ProofVerificationFailed(_));
+    assert_eq!(err.stage(), StatelessValidationStage::Proof);
+
+    // the remaining corruptions pass all cheaper checks, but change the public inputs of the
+    // proof, i.e. the input notes commitment, the output notes commitment and the final account
+    // state, so they are rejected when the proof is verified
+    let mut corrupted_input_notes: Vec<InputNoteCommitment> = input_notes().collect();
+    corrupted_input_notes[0] =
+        InputNoteCommitment::from(Nullifier::from(Digest::from([ONE, ONE, ONE, ONE])));
+    let num_output_notes = proven_transaction.output_notes().num_notes();
+    assert!(num_output_notes > 0);
+    let corrupted_transactions = [
+        rebuild(final_state_hash, proven_transaction.proof().clone())
+            .add_input_notes(corrupted_input_notes)
+            .add_output_notes(output_notes()),
+        rebuild(final_state_hash, proven_transaction.proof().clone())
+            .add_input_notes(input_notes())
+            .add_output_notes(output_notes().skip(1)),
+        rebuild(Digest::from([ONE, ONE, ONE, ONE]), proven_transaction.proof().clone())
+            .add_input_notes(input_notes())
+            .add_output_notes(output_notes()),
+    ];
+    for corrupted_transaction in corrupted_transactions {
+        let corrupted_transaction = corrupted_transaction.build().unwrap();
+        let err =
+            validate_proven_transaction_stateless(&corrupted_transaction, &config).unwrap_err();
+        assert_matches!(err, StatelessValidationError::ProofVerificationFailed(_));
+        assert_eq!(err.stage(), StatelessValidationStage::Proof);
+    }
+}
+
+/// Tests that batch verification accepts valid proven transactions and names the transaction
+/// whose proof does not match its account update.
+#[test]
+fn prove_and_verify_batch_reports_failing_transaction() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (_, tx_witness) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(tx_witness).unwrap();
+
+    // a transaction whose proof was generated for a different final account state
+    let tampered_transaction = ProvenTransactionBuilder::new(
+        proven_transaction.account_id(),
+        proven_transaction.account_update().init_state_hash(),
+        Digest::default(),
+        proven_transaction.block_ref(),
+        proven_transaction.expiration_block_num(),
+        proven_transaction.proof().clone(),
+    )
+    .account_update_details(proven_transaction.account_update().details().clone())
+    .add_input_notes(proven_transaction.input_notes().iter().cloned())
+    .add_output_notes(proven_transaction.output_notes().iter().cloned())
+    .build()
+    .unwrap();
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    verifier.verify_batch(&[]).unwrap();
+    verifier
+        .verify_batch(&[proven_transaction.clone(), proven_transaction.clone()])
+        .unwrap();
+
+    let tampered_tx_id = tampered_transaction.id();
+    let err = verifier
+        .verify_batch(&[proven_transaction.clone(), tampered_transaction, proven_transaction])
+        .unwrap_err();
+    assert_matches!(
+        err,
+        TransactionVerifierError::BatchTransactionVerificationFailed { index: 1, tx_id, source }
+            if tx_id == tampered_tx_id
+                && matches!(*source, TransactionVerifierError::TransactionVerificationFailed(_))
+    );
+}
+
+/// Tests that the trace length estimate of an executed transaction is consistent with the length
+/// of the trace of its proof.
+#[test]
+fn trace_length_estimate_matches_proven_trace_length() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    // the cycle counts of the transaction are only measured if tracing is enabled
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None).with_tracing();
+    let (executed_transaction, tx_witness) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
+    let trace_length_estimate = executed_transaction.trace_length_estimate().unwrap();
+
+    let proving_options = ProvingOptions::default();
+    let prover = LocalTransactionProver::new(proving_options.clone());
+    let proven_transaction = prover.prove(tx_witness).unwrap();
+    let trace_length = proven_transaction.proof().stark_proof().trace_info().length();
+
+    // the estimate does not account for the rows required by the chiplets and the range checker,
+    // but for this transaction the executed cycles determine the padded trace length
+    assert!(trace_length_estimate.is_power_of_two());
+    assert_eq!(trace_length_estimate, trace_length);
+
+    assert!(
+        proving_options.estimated_memory_bytes(trace_length)
+            < proving_options.estimated_memory_bytes(2 * trace_length)
+    );
+}
+
+/// Tests that no trace length is estimated for a transaction executed without tracing.
+#[test]
+fn trace_length_estimate_requires_tracing() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (executed_transaction, _) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
+
+    assert_eq!(executed_transaction.measurements().total_cycles(), 0);
+    assert_eq!(executed_transaction.trace_length_estimate(), None);
+}
+
+#[test]
+fn proving_options_presets() {
+    let presets = [
+        ProvingOptions::fast(),
+        ProvingOptions::balanced(),
+        ProvingOptions::maximum_security(),
+    ];
+    let security_levels = presets.map(|options| options.security_level());
+
+    assert_eq!(security_levels, [96, 112, 128]);
+    assert!(security_levels.windows(2).all(|levels| levels[0] < levels[1]));
+    assert!(ProvingOptions::maximum_security().security_level() >= MIN_PROOF_SECURITY_LEVEL);
+    assert_eq!(
+        ProvingOptions::default().security_level(),
+        ProvingOptions::fast().security_level()
+    );
+}
+
+/// Tests that the measurements of an executed transaction report the cycles spent executing each
+/// input note.
+#[test]
+fn measurements_report_cycles_per_note() {
+    // the two input notes run different scripts, which create two and one output notes
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    assert_eq!(note_ids.len(), 2);
+
+    // the cycle counts of the transaction are only measured if tracing is enabled
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None).with_tracing();
+    let (executed_transaction, _) =
+        execute_with_witness(&executor, &tx_context, tx_context.tx_args().clone());
+    let measurements = executed_transaction.measurements();
+
+    // the notes are executed in the order of the input notes
+    let measured_note_ids: Vec<NoteId> =
+        measurements.note_execution.iter().map(|(note_id, _)| *note_id).collect();
+    assert_eq!(measured_note_ids, note_ids);
+
+    // every note execution takes some cycles, and all of them are part of the notes processing
+    assert!(measurements.note_execution.iter().all(|(_, num_cycles)| *num_cycles > 0));
+    let note_execution_cycles: usize =
+        measurements.note_execution.iter().map(|(_, num_cycles)| num_cycles).sum();
+    assert!(note_execution_cycles <= measurements.notes_processing);
+}
+
+// TEST TRANSACTION SCRIPT
+// ================================================================================================
+
+#[test]
+fn test_tx_script() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+
+    let tx_script_input_key = [Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)];
+    let tx_script_input_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let tx_script_src = format!(
+        "
+    begin
+        # push the tx script input key onto the stack
+        push.{key}
+
+        # load the tx script input value from the map and read it onto the stack
+        adv.push_mapval adv_loadw
+
+        # assert that the value is correct
+        push.{value} assert_eqw
+    end
+",
+        key = prepare_word(&tx_script_input_key),
+        value = prepare_word(&tx_script_input_value)
+    );
+
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [(tx_script_input_key, tx_script_input_value.into())],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    execute_with_witness(&executor, &tx_context, tx_args);
+}
+
+/// Tests that an account can call code in a custom library when loading that library into the
 /// executor.
 ///
 /// The call chain and dependency graph in this test is:
@@ -988,3 +1712,874 @@ fn transaction_executor_account_code_using_custom_library() {
     // Account's initial nonce of 1 should have been incremented by 4.
     assert_eq!(executed_tx.account_delta().nonce().unwrap(), Felt::new(5));
 }
+
+// NOTE CONSUMPTION CHECKER
+// ================================================================================================
+
+/// Tests that the note consumption checker reports the result for every note, instead of stopping
+/// at the first note which cannot be consumed.
+#[test]
+fn note_consumption_checker_detailed_results() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let assembler = TransactionKernel::testing_assembler();
+    let mut rng = ChaCha20Rng::from_seed([3; 32]);
+
+    let failing_note_1 = NoteBuilder::new(sender, &mut rng)
+        .code("begin push.0 assert.err=1 end")
+        .build(&assembler)
+        .unwrap();
+    let succeeding_note = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+    let failing_note_2 = NoteBuilder::new(sender, &mut rng)
+        .code("begin push.1 push.2 assert_eq.err=2 end")
+        .build(&assembler)
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![failing_note_1.clone(), succeeding_note.clone(), failing_note_2.clone()])
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let checker = NoteConsumptionChecker::new(&executor);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = [failing_note_1.id(), succeeding_note.id(), failing_note_2.id()];
+
+    // the notes cannot be consumed together
+    assert_matches!(
+        checker.check_notes_consumability(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone()
+        ),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(_))
+    );
+
+    let results = checker.check_notes_detailed(
+        account_id,
+        block_ref,
+        &note_ids,
+        tx_context.tx_args().clone(),
+    );
+
+    let result_ids: Vec<NoteId> = results.iter().map(|(note_id, _)| *note_id).collect();
+    assert_eq!(result_ids, note_ids);
+    assert_matches!(
+        &results[0].1,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code: 1, .. }
+        ))
+    );
+    assert_matches!(&results[1].1, Ok(()));
+    assert_matches!(
+        &results[2].1,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code: 2, .. }
+        ))
+    );
+}
+
+/// Tests that the transaction inputs used as a data store only serve the requests for their own
+/// account, block and input notes.
+#[test]
+fn transaction_inputs_data_store_checks_request() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let assembler = TransactionKernel::testing_assembler();
+    let mut rng = ChaCha20Rng::from_seed([4; 32]);
+
+    let note_1 = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+    let note_2 = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+    let unknown_note = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note_1.clone(), note_2.clone()])
+        .build();
+    let tx_inputs = tx_context.tx_inputs();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_inputs.block_header().block_num();
+
+    let all_notes =
+        tx_inputs.get_transaction_inputs(account_id, block_ref, &[note_2.id(), note_1.id()]);
+    assert_eq!(all_notes.unwrap(), *tx_inputs);
+
+    let subset = tx_inputs.get_transaction_inputs(account_id, block_ref, &[note_2.id()]).unwrap();
+    let subset_ids: Vec<NoteId> = subset.input_notes().iter().map(|note| note.id()).collect();
+    assert_eq!(subset_ids, [note_2.id()]);
+
+    // a request with the right number of notes must still match the note IDs
+    assert_matches!(
+        tx_inputs.get_transaction_inputs(account_id, block_ref, &[note_1.id(), unknown_note.id()]),
+        Err(DataStoreError::NoteNotFound(note_id)) if note_id == unknown_note.id()
+    );
+
+    assert_matches!(
+        tx_inputs.get_transaction_inputs(sender, block_ref, &[note_1.id()]),
+        Err(DataStoreError::AccountNotFound(id)) if id == sender
+    );
+    assert_matches!(
+        tx_inputs.get_transaction_inputs(account_id, block_ref + 1, &[note_1.id()]),
+        Err(DataStoreError::BlockNotFound(num)) if num == block_ref + 1
+    );
+}
+
+#[test]
+fn execute_transaction_with_cycle_limit() {
+    const MAX_CYCLES: u32 = 1 << 20;
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let assembler = TransactionKernel::testing_assembler();
+    let mut rng = ChaCha20Rng::from_seed([7; 32]);
+
+    let looping_note = NoteBuilder::new(sender, &mut rng)
+        .code("begin push.1 while.true push.1 end end")
+        .build(&assembler)
+        .unwrap();
+    let succeeding_note = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![looping_note.clone(), succeeding_note.clone()])
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+
+    // the note looping forever is aborted once the transaction exceeds the cycle limit
+    let err = executor
+        .execute_transaction_with_limit(
+            account_id,
+            block_ref,
+            &[looping_note.id()],
+            tx_context.tx_args().clone(),
+            MAX_CYCLES,
+        )
+        .unwrap_err();
+    assert_matches!(err, TransactionExecutorError::CycleLimitExceeded(MAX_CYCLES));
+
+    // a transaction within the cycle limit is executed as usual
+    executor
+        .execute_transaction_with_limit(
+            account_id,
+            block_ref,
+            &[succeeding_note.id()],
+            tx_context.tx_args().clone(),
+            MAX_CYCLES,
+        )
+        .unwrap();
+}
+
+#[test]
+fn note_consumption_checker_with_cycle_limit() {
+    const MAX_CYCLES: u32 = 1 << 20;
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let assembler = TransactionKernel::testing_assembler();
+    let mut rng = ChaCha20Rng::from_seed([6; 32]);
+
+    let looping_note = NoteBuilder::new(sender, &mut rng)
+        .code("begin push.1 while.true push.1 end end")
+        .build(&assembler)
+        .unwrap();
+    let failing_note = NoteBuilder::new(sender, &mut rng)
+        .code("begin push.0 assert.err=1 end")
+        .build(&assembler)
+        .unwrap();
+    let succeeding_note = NoteBuilder::new(sender, &mut rng).build(&assembler).unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![looping_note.clone(), failing_note.clone(), succeeding_note.clone()])
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let checker = NoteConsumptionChecker::new(&executor);
+
+    // the looping note is aborted and the notes after it are still checked
+    let results = checker.check_note_consumability_with_limit(
+        tx_context.account().id(),
+        tx_context.tx_inputs().block_header().block_num(),
+        &[looping_note.id(), failing_note.id(), succeeding_note.id()],
+        tx_context.tx_args().clone(),
+        MAX_CYCLES,
+    );
+
+    assert_eq!(results.len(), 3);
+    assert_matches!(
+        &results[0],
+        NoteAccountExecution::CycleLimitExceeded { note_id, cycles_consumed: MAX_CYCLES }
+            if *note_id == looping_note.id()
+    );
+    assert_matches!(
+        &results[1],
+        NoteAccountExecution::Failure {
+            note_id,
+            error: TransactionExecutorError::TransactionProgramExecutionFailed(
+                ExecutionError::FailedAssertion { err_code: 1, .. }
+            ),
+        } if *note_id == failing_note.id()
+    );
+    assert!(results[2].is_success());
+    assert_eq!(results[2].note_id(), succeeding_note.id());
+}
+
+/// Tests that P2ID notes targeting another account are reported with both account IDs before
+/// executing a transaction, while notes targeting the checked account are executed.
+#[test]
+fn note_consumption_checker_reports_target_account_mismatch() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let other_account_id = fixtures::account::basic_wallet_b().id();
+    let own_note = fixtures::note::p2id_100_to(account_id);
+    let other_note = fixtures::note::p2id_100_to(other_account_id);
+
+    let tx_script = TransactionScript::compile(
+        "
+        use.test::account
+
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .unauthenticated_input_notes(vec![other_note.clone(), own_note.clone()])
+        .tx_script(tx_script)
+        .build();
+    assert_eq!(tx_context.account().id(), account_id);
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let checker = NoteConsumptionChecker::new(&executor);
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let results = checker.check_note_consumability_with_limit(
+        account_id,
+        block_ref,
+        &[other_note.id(), own_note.id()],
+        tx_context.tx_args().clone(),
+        MAX_TX_EXECUTION_CYCLES,
+    );
+
+    assert_eq!(results.len(), 2);
+    assert_matches!(
+        &results[0],
+        NoteAccountExecution::Failure {
+            note_id,
+            error: TransactionExecutorError::TargetAccountMismatch { expected, actual },
+        } if *note_id == other_note.id() && *expected == other_account_id && *actual == account_id
+    );
+    assert!(results[1].is_success());
+    assert_eq!(results[1].note_id(), own_note.id());
+
+    // consuming both notes together fails with the same error
+    let err = checker
+        .check_notes_consumability(
+            account_id,
+            block_ref,
+            &[other_note.id(), own_note.id()],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap_err();
+    assert_matches!(
+        err,
+        TransactionExecutorError::TargetAccountMismatch { expected, actual }
+            if expected == other_account_id && actual == account_id
+    );
+}
+
+// INTERMEDIATE ACCOUNT STATES
+// ================================================================================================
+
+/// Tests that the account commitment after the execution of each of two input notes is captured.
+#[test]
+fn execute_with_trace_captures_intermediate_account_states() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let assembler = TransactionKernel::testing_assembler_with_mock_account();
+    let mut rng = ChaCha20Rng::from_seed([4; 32]);
+
+    let value_a: Word = [Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)];
+    let value_b: Word = [Felt::new(21), Felt::new(22), Felt::new(23), Felt::new(24)];
+    let set_item_note = |value: Word, rng: &mut ChaCha20Rng| {
+        NoteBuilder::new(sender, rng)
+            .code(format!(
+                "
+                use.test::account
+
+                begin
+                    push.{VALUE}
+                    push.{STORAGE_INDEX_0}
+                    call.account::set_item dropw dropw
+                end
+                ",
+                VALUE = prepare_word(&value)
+            ))
+            .build(&assembler)
+            .unwrap()
+    };
+    let note_a = set_item_note(value_a, &mut rng);
+    let note_b = set_item_note(value_b, &mut rng);
+
+    let tx_script = TransactionScript::compile(
+        "
+        use.test::account
+
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        [],
+        assembler.clone(),
+    )
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note_a.clone(), note_b.clone()])
+        .tx_script(tx_script)
+        .build();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let (executed_transaction, intermediate_commitments) = executor
+        .execute_with_trace(
+            tx_context.account().id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            &[note_a.id(), note_b.id()],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+
+    // the nonce is only incremented by the transaction script, so the intermediate states only
+    // differ from the initial state in the updated storage slot
+    let expected_commitments: Vec<Digest> = [value_a, value_b]
+        .into_iter()
+        .map(|value| {
+            let mut account = tx_context.account().clone();
+            account.storage_mut().set_item(STORAGE_INDEX_0, value).unwrap();
+            account.hash()
+        })
+        .collect();
+
+    assert_eq!(intermediate_commitments, expected_commitments);
+    assert_ne!(executed_transaction.final_account().hash(), expected_commitments[1]);
+}
+
+// TRANSACTION CHAIN EXECUTION
+// ================================================================================================
+
+/// Tests that dependent transactions are executed in a single call: a new account A consumes a
+/// note and creates a note for account B, which is then consumed by B.
+#[test]
+fn execute_chain_of_dependent_transactions() {
+    let mut mock_chain = MockChain::new();
+    let asset = FungibleAsset::mock(100);
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![asset]);
+    let account_a = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let account_b = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note_to_a = mock_chain
+        .add_p2id_note(sender.id(), account_a.id(), &[asset], NoteType::Public, None)
+        .unwrap();
+    let block_ref = mock_chain.seal_block(None).header().block_num();
+
+    let note_to_b = create_p2id_note(
+        account_a.id(),
+        account_b.id(),
+        vec![asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut RpoRandomCoin::new(Word::default()),
+    )
+    .unwrap();
+
+    let tx_script_a_src = format!(
+        "
+        begin
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{note_type}
+            push.0              # aux
+            push.{tag}
+            call.::miden::contracts::wallets::basic::create_note
+
+            push.{asset}
+            call.::miden::contracts::wallets::basic::move_asset_to_note
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            dropw dropw dropw dropw
+        end
+        ",
+        recipient = prepare_word(&note_to_b.recipient().digest()),
+        note_execution_hint = Felt::from(note_to_b.metadata().execution_hint()),
+        note_type = NoteType::Public as u8,
+        tag = Felt::from(note_to_b.metadata().tag()),
+        asset = prepare_word(&asset.into()),
+    );
+    let tx_script_a =
+        TransactionScript::compile(tx_script_a_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+    let mut tx_args_a = TransactionArgs::with_tx_script(tx_script_a);
+    tx_args_a.add_expected_output_note(&note_to_b);
+
+    let tx_script_b = TransactionScript::compile(
+        DEFAULT_AUTH_SCRIPT,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+    let tx_args_b = TransactionArgs::with_tx_script(tx_script_b);
+
+    // All accounts created with `Auth::BasicAuth` share the same key, so the authenticator of A
+    // can authenticate the transactions of both accounts.
+    let authenticator = mock_chain.account_authenticator(account_a.id()).unwrap();
+    let executor =
+        TransactionExecutor::new(Arc::new(mock_chain.clone()), Some(Arc::new(authenticator)));
+
+    let step_a = TxStep::new(account_a.id(), vec![TxStepNote::Store(note_to_a.id())], tx_args_a);
+    let step_b =
+        TxStep::new(account_b.id(), vec![TxStepNote::Output { step: 0, note_index: 0 }], tx_args_b);
+
+    let executed_transactions =
+        executor.execute_chain(block_ref, vec![step_a.clone(), step_b]).unwrap();
+    assert_eq!(executed_transactions.len(), 2);
+    let (tx_a, tx_b) = (&executed_transactions[0], &executed_transactions[1]);
+
+    // A was created in the first transaction, and sent the asset it received to B
+    assert!(tx_a.initial_account().is_new());
+    assert_eq!(tx_a.input_notes().get_note(0).id(), note_to_a.id());
+    assert_eq!(tx_a.output_notes().num_notes(), 1);
+    assert_eq!(tx_a.output_notes().get_note(0).id(), note_to_b.id());
+    let mut final_account_a = account_a.clone();
+    final_account_a.apply_delta(tx_a.account_delta()).unwrap();
+    assert_eq!(final_account_a.hash(), tx_a.final_account().hash());
+    assert_eq!(final_account_a.vault().assets().count(), 0);
+
+    // B consumed the output note of the first transaction as an unauthenticated note
+    assert_eq!(tx_b.initial_account(), &account_b);
+    assert_eq!(tx_b.input_notes().num_notes(), 1);
+    assert_eq!(tx_b.input_notes().get_note(0).id(), note_to_b.id());
+    assert!(tx_b.input_notes().get_note(0).proof().is_none());
+    let mut final_account_b = account_b.clone();
+    final_account_b.apply_delta(tx_b.account_delta()).unwrap();
+    assert_eq!(final_account_b.hash(), tx_b.final_account().hash());
+    assert_eq!(final_account_b.vault().assets().collect::<Vec<_>>(), vec![asset]);
+
+    // errors identify the step which references a non-existent output note
+    let invalid_step = TxStep::new(
+        account_b.id(),
+        vec![TxStepNote::Output { step: 0, note_index: 1 }],
+        TransactionArgs::default(),
+    );
+    let err = executor.execute_chain(block_ref, vec![step_a, invalid_step]).unwrap_err();
+    assert_matches!(
+        err,
+        ChainExecutionError::InvalidNoteReference {
+            step: 1,
+            referenced_step: 0,
+            note_index: 1
+        }
+    );
+    assert_eq!(err.step(), 1);
+}
+
+#[test]
+fn execute_transaction_against_latest_block() {
+    let mut mock_chain = MockChain::new();
+    let asset = FungibleAsset::mock(100);
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![asset]);
+    let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(sender.id(), target.id(), &[asset], NoteType::Public, None)
+        .unwrap();
+    for _ in 0..3 {
+        mock_chain.seal_block(None);
+    }
+    let latest_header = mock_chain.block_header(3);
+
+    let tx_script = TransactionScript::compile(
+        DEFAULT_AUTH_SCRIPT,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+    let authenticator = mock_chain.account_authenticator(target.id()).unwrap();
+    let executor =
+        TransactionExecutor::new(Arc::new(mock_chain.clone()), Some(Arc::new(authenticator)));
+
+    let executed_transaction = executor
+        .execute_transaction_latest(
+            target.id(),
+            &[note.id()],
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap();
+
+    assert_eq!(executed_transaction.block_header(), &latest_header);
+    assert_eq!(executed_transaction.block_header().block_num().as_u32(), 3);
+    assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
+}
+
+#[test]
+fn dry_run_tx_script() {
+    let executor = TransactionExecutor::new(Arc::new(MockChain::new()), None);
+
+    let tx_script = TransactionScript::compile(
+        "begin push.2 push.3 mul push.6 assert_eq end",
+        [],
+        TransactionKernel::assembler(),
+    )
+    .unwrap();
+    executor.dry_run_script(tx_script).unwrap();
+
+    let failing_tx_script = TransactionScript::compile(
+        "begin push.2 push.3 mul push.5 assert_eq.err=1 end",
+        [],
+        TransactionKernel::assembler(),
+    )
+    .unwrap();
+    assert_matches!(
+        executor.dry_run_script(failing_tx_script),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code: 1, .. }
+        ))
+    );
+}
+
+#[test]
+fn readonly_tx_script() {
+    let mutating_procedures = TransactionKernel::state_mutating_procedures();
+    for name in TransactionKernel::STATE_MUTATING_PROCEDURES {
+        assert!(mutating_procedures.values().any(|procedure| procedure == name));
+    }
+
+    let getter_code = "
+        use.miden::account
+        begin
+            exec.account::get_nonce drop
+            exec.account::get_vault_commitment dropw
+        end
+    ";
+    let getter_script =
+        TransactionKernel::compile_readonly_script(getter_code, [], TransactionKernel::assembler())
+            .unwrap();
+    assert!(getter_script.is_readonly());
+
+    let incr_nonce_code = "
+        use.miden::account
+        begin
+            push.1 exec.account::incr_nonce
+        end
+    ";
+    assert_matches!(
+        TransactionKernel::compile_readonly_script(
+            incr_nonce_code,
+            [],
+            TransactionKernel::assembler(),
+        ),
+        Err(TransactionScriptError::StateMutatingProcedure { name, .. })
+            if name == "miden::account::incr_nonce"
+    );
+
+    // procedures which invoke the state-mutating procedures are part of the registry as well
+    let receive_asset_code = "
+        use.miden::contracts::wallets::basic->wallet
+        begin
+            exec.wallet::receive_asset
+        end
+    ";
+    assert_matches!(
+        TransactionKernel::compile_readonly_script(
+            receive_asset_code,
+            [],
+            TransactionKernel::assembler(),
+        ),
+        Err(TransactionScriptError::StateMutatingProcedure { name, .. })
+            if name == "miden::contracts::wallets::basic::receive_asset"
+    );
+
+    // procedures of other libraries are external to the script and thus are not checked by the
+    // registry, so a wrapper around a state-mutating procedure must be rejected
+    let wrapper_module = Module::parser(ModuleKind::Library)
+        .parse_str(
+            LibraryPath::new("external_library::wrapper").unwrap(),
+            "
+            use.miden::account
+            export.incr_nonce_by_one
+                push.1 exec.account::incr_nonce
+            end
+            ",
+            &Arc::new(DefaultSourceManager::default()),
+        )
+        .unwrap();
+    let wrapper_library =
+        TransactionKernel::assembler().assemble_library([wrapper_module]).unwrap();
+    let wrapper_code = "
+        use.external_library::wrapper
+        begin
+            exec.wrapper::incr_nonce_by_one
+        end
+    ";
+    let wrapper_root = wrapper_library.mast_forest()
+        [wrapper_library.get_export_node_id(wrapper_library.exports().next().unwrap())]
+    .digest();
+    assert_matches!(
+        TransactionKernel::compile_readonly_script(
+            wrapper_code,
+            [],
+            TransactionKernel::assembler().with_library(&wrapper_library).unwrap(),
+        ),
+        Err(TransactionScriptError::UnverifiableExternalProcedure { root })
+            if root == wrapper_root
+    );
+
+    // a strict executor only dry-runs scripts compiled as read-only
+    let executor =
+        TransactionExecutor::new(Arc::new(MockChain::new()), None).with_strict_readonly_scripts();
+    executor.dry_run_script(getter_script).unwrap();
+
+    let regular_script =
+        TransactionScript::compile(getter_code, [], TransactionKernel::assembler()).unwrap();
+    assert_matches!(
+        executor.dry_run_script(regular_script.clone()),
+        Err(TransactionExecutorError::TransactionScriptNotReadonly(root))
+            if root == regular_script.hash()
+    );
+}
+
+// NOTE ASSET FORWARDING
+// ================================================================================================
+
+/// Returns the code of a note script which creates a note with the provided recipient and tag and
+/// forwards the provided assets to it.
+fn forwarding_note_code(recipient: Digest, tag: NoteTag, assets: &[Asset]) -> String {
+    let forward_assets = assets
+        .iter()
+        .map(|asset| {
+            format!(
+                "push.{asset} exec.note::forward_asset dropw",
+                asset = prepare_word(&(*asset).into())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "
+        use.miden::note
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{execution_hint}
+            push.{note_type}
+            push.0              # aux
+            push.{tag}
+            call.wallet::create_note
+            # => [note_idx, pad(23)]
+
+            {forward_assets}
+            # => [note_idx, pad(23)]
+
+            dropw dropw
+        end
+        ",
+        recipient = prepare_word(&recipient),
+        execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Public as u8,
+        tag = Felt::from(tag),
+    )
+}
+
+/// Tests that the assets of a note can be forwarded to a new note without passing through the
+/// vault of the consuming account.
+#[test]
+fn forward_note_assets_to_output_note() {
+    let mut mock_chain = MockChain::new();
+    let fungible_asset_1 = mock_chain.add_new_faucet(Auth::BasicAuth, "USDT", 100_000).mint(100);
+    let fungible_asset_2 = mock_chain.add_new_faucet(Auth::BasicAuth, "ETH", 100_000).mint(200);
+    let non_fungible_asset = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
+    let assets = [fungible_asset_1, fungible_asset_2, non_fungible_asset];
+
+    let sender = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let forwarder = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let recipient = build_p2id_recipient(sender.id(), [Felt::new(7); 4]).unwrap();
+    let tag = NoteTag::from_account_id(sender.id(), NoteExecutionMode::Local).unwrap();
+    let note = NoteBuilder::new(sender.id(), ChaCha20Rng::from_seed([7; 32]))
+        .add_assets(assets)
+        .code(forwarding_note_code(recipient.digest(), tag, &assets))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    // the details of the public output note must be known to the executor
+    let metadata =
+        NoteMetadata::new(forwarder.id(), NoteType::Public, tag, NoteExecutionHint::always(), ZERO)
+            .unwrap();
+    let expected_note = Note::new(NoteAssets::new(assets.to_vec()).unwrap(), metadata, recipient);
+
+    let executed_transaction = mock_chain
+        .build_tx_context(forwarder.id(), &[note.id()], &[])
+        .expected_notes(vec![OutputNote::Full(expected_note.clone())])
+        .build()
+        .execute()
+        .unwrap();
+
+    // the assets never entered the vault of the forwarding account
+    assert!(executed_transaction.account_delta().vault().is_empty());
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        forwarder.vault().commitment()
+    );
+
+    // all assets of the consumed note ended up in the created note
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    let output_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(output_note.metadata().sender(), forwarder.id());
+    assert_eq!(output_note.id(), expected_note.id());
+    assert_eq!(output_note.assets(), Some(note.assets()));
+
+    let forwarder = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(forwarder.vault().assets().count(), 0);
+}
+
+/// Tests that a note cannot forward an asset more than once.
+#[test]
+fn forward_note_asset_twice_fails() {
+    let mut mock_chain = MockChain::new();
+    let non_fungible_asset = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
+
+    let sender = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let forwarder = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let recipient = build_p2id_recipient(sender.id(), [Felt::new(7); 4]).unwrap();
+    let tag = NoteTag::from_account_id(sender.id(), NoteExecutionMode::Local).unwrap();
+    let note = NoteBuilder::new(sender.id(), ChaCha20Rng::from_seed([7; 32]))
+        .add_assets([non_fungible_asset])
+        .code(forwarding_note_code(
+            recipient.digest(),
+            tag,
+            &[non_fungible_asset, non_fungible_asset],
+        ))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    let result = mock_chain.build_tx_context(forwarder.id(), &[note.id()], &[]).build().execute();
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND
+    );
+}
+
+/// Tests that a note cannot forward its assets to an output note created before its execution.
+#[test]
+fn forward_note_asset_to_note_of_other_note_fails() {
+    let mut mock_chain = MockChain::new();
+    let non_fungible_asset = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
+
+    let sender = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let forwarder = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    // the first note creates an output note without forwarding any assets to it
+    let recipient = build_p2id_recipient(sender.id(), [Felt::new(7); 4]).unwrap();
+    let tag = NoteTag::from_account_id(sender.id(), NoteExecutionMode::Local).unwrap();
+    let creating_note = NoteBuilder::new(sender.id(), ChaCha20Rng::from_seed([7; 32]))
+        .code(forwarding_note_code(recipient.digest(), tag, &[]))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    // the second note tries to forward its asset to the output note created by the first note
+    let forwarding_note = NoteBuilder::new(sender.id(), ChaCha20Rng::from_seed([8; 32]))
+        .add_assets([non_fungible_asset])
+        .code(format!(
+            "
+            use.miden::note
+
+            begin
+                push.0 push.{asset}
+                exec.note::forward_asset
+                dropw drop
+            end
+            ",
+            asset = prepare_word(&non_fungible_asset.into())
+        ))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    mock_chain.add_pending_note(creating_note.clone());
+    mock_chain.add_pending_note(forwarding_note.clone());
+    mock_chain.seal_block(None);
+
+    let result = mock_chain
+        .build_tx_context(forwarder.id(), &[creating_note.id(), forwarding_note.id()], &[])
+        .build()
+        .execute();
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_NOTE_FORWARD_ASSET_TO_NOTE_NOT_CREATED_BY_CURRENT_NOTE
+    );
+}
+
+/// Tests that assets cannot be forwarded outside of the execution of a note.
+#[test]
+fn forward_asset_from_tx_script_fails() {
+    let mut mock_chain = MockChain::new();
+    let non_fungible_asset = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
+    let forwarder = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let recipient = build_p2id_recipient(forwarder.id(), [Felt::new(7); 4]).unwrap();
+    let tag = NoteTag::from_account_id(forwarder.id(), NoteExecutionMode::Local).unwrap();
+    let tx_script_code = format!(
+        "
+        use.miden::note
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{execution_hint}
+            push.{note_type}
+            push.0              # aux
+            push.{tag}
+            call.wallet::create_note
+            # => [note_idx, pad(15)]
+
+            push.{asset}
+            exec.note::forward_asset
+            # => [ASSET, note_idx, pad(15)]
+
+            dropw
+        end
+        ",
+        recipient = prepare_word(&recipient.digest()),
+        execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Public as u8,
+        tag = Felt::from(tag),
+        asset = prepare_word(&non_fungible_asset.into()),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_code, [], TransactionKernel::testing_assembler())
+            .unwrap();
+
+    let result = mock_chain
+        .build_tx_context(forwarder.id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute();
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_NOTE_ATTEMPT_TO_FORWARD_ASSET_FROM_INCORRECT_CONTEXT
+    );
+}
@@ -1,9 +1,16 @@
+use alloc::boxed::Box;
+
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
 use miden_verifier::verify;
 
 use super::TransactionVerifierError;
 
+mod stateless;
+pub use stateless::{
+    validate_proven_transaction_stateless, StatelessValidationConfig, DEFAULT_MAX_OUTPUT_NOTE_SIZE,
+};
+
 // TRANSACTION VERIFIER
 // ================================================================================================
 
@@ -31,6 +38,72 @@ impl TransactionVerifier {
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        self.verify_proof(&transaction).map(|_| ())
+    }
+
+    /// Verifies the provided [ProvenTransaction] like [Self::verify] and returns the security
+    /// level (in bits) the proof actually attains.
+    ///
+    /// The returned level is at least the minimum security level of this verifier, but may be
+    /// higher, e.g. if the transaction was proven with options which target a higher security
+    /// level. This allows callers to apply their own policy on top of the minimum.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Transaction verification fails.
+    /// - The security level of the verified proof is insufficient.
+    pub fn verify_with_level(
+        &self,
+        transaction: &ProvenTransaction,
+    ) -> Result<u32, TransactionVerifierError> {
+        self.verify_proof(transaction)
+    }
+
+    /// Verifies all of the provided [ProvenTransaction]s against the transaction kernel.
+    ///
+    /// The transaction kernel program info is shared by all transactions. With the `concurrent`
+    /// feature enabled, the proofs are verified in parallel.
+    ///
+    /// # Errors
+    /// Returns [TransactionVerifierError::BatchTransactionVerificationFailed] with the index and ID
+    /// of the first transaction in the batch which fails verification.
+    pub fn verify_batch(&self, txs: &[ProvenTransaction]) -> Result<(), TransactionVerifierError> {
+        #[cfg(feature = "concurrent")]
+        let first_failure = {
+            use rayon::prelude::*;
+
+            txs.par_iter()
+                .enumerate()
+                .find_map_first(|(index, tx)| self.verify_proof(tx).err().map(|err| (index, err)))
+        };
+
+        #[cfg(not(feature = "concurrent"))]
+        let first_failure = txs
+            .iter()
+            .enumerate()
+            .find_map(|(index, tx)| self.verify_proof(tx).err().map(|err| (index, err)));
+
+        match first_failure {
+            Some((index, err)) => {
+                Err(TransactionVerifierError::BatchTransactionVerificationFailed {
+                    index,
+                    tx_id: txs[index].id(),
+                    source: Box::new(err),
+                })
+            },
+            None => Ok(()),
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the proof of the provided [ProvenTransaction], checks its security level and
+    /// returns it.
+    fn verify_proof(
+        &self,
+        transaction: &ProvenTransaction,
+    ) -> Result<u32, TransactionVerifierError> {
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
             transaction.account_id(),
@@ -61,6 +134,6 @@ impl TransactionVerifier {
             });
         }
 
-        Ok(())
+        Ok(proof_security_level)
     }
 }
@@ -0,0 +1,212 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    transaction::ProvenTransaction, utils::serde::Serializable, Digest, ACCOUNT_UPDATE_MAX_SIZE,
+    MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX, MIN_PROOF_SECURITY_LEVEL,
+};
+
+use super::TransactionVerifier;
+use crate::errors::StatelessValidationError;
+
+// STATELESS VALIDATION CONFIG
+// ================================================================================================
+
+/// The default maximum size of a serialized output note, in bytes.
+pub const DEFAULT_MAX_OUTPUT_NOTE_SIZE: usize = 32 * 1024;
+
+/// Limits and accepted parameters used by [validate_proven_transaction_stateless].
+///
+/// The roots of the accepted transaction kernels must be provided by the caller, e.g. from the
+/// block headers of the chain. All other parameters default to the protocol limits and a minimum
+/// proof security level of [MIN_PROOF_SECURITY_LEVEL].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatelessValidationConfig {
+    proof_security_level: u32,
+    max_input_notes: usize,
+    max_output_notes: usize,
+    max_output_note_size: usize,
+    max_account_update_size: usize,
+    accepted_kernel_roots: Vec<Digest>,
+}
+
+impl StatelessValidationConfig {
+    /// Returns a new [StatelessValidationConfig] which accepts transactions proven against any of
+    /// the provided transaction kernel roots and enforces the protocol limits.
+    pub fn new(accepted_kernel_roots: impl IntoIterator<Item = Digest>) -> Self {
+        Self {
+            proof_security_level: MIN_PROOF_SECURITY_LEVEL,
+            max_input_notes: MAX_INPUT_NOTES_PER_TX,
+            max_output_notes: MAX_OUTPUT_NOTES_PER_TX,
+            max_output_note_size: DEFAULT_MAX_OUTPUT_NOTE_SIZE,
+            max_account_update_size: ACCOUNT_UPDATE_MAX_SIZE as usize,
+            accepted_kernel_roots: accepted_kernel_roots.into_iter().collect(),
+        }
+    }
+
+    /// Sets the minimum security level that the transaction proof must have.
+    pub fn with_proof_security_level(mut self, proof_security_level: u32) -> Self {
+        self.proof_security_level = proof_security_level;
+        self
+    }
+
+    /// Sets the maximum number of notes a transaction may consume.
+    ///
+    /// Limits above [MAX_INPUT_NOTES_PER_TX] have no effect since such transactions cannot be
+    /// constructed.
+    pub fn with_max_input_notes(mut self, max_input_notes: usize) -> Self {
+        self.max_input_notes = max_input_notes;
+        self
+    }
+
+    /// Sets the maximum number of notes a transaction may create.
+    ///
+    /// Limits above [MAX_OUTPUT_NOTES_PER_TX] have no effect since such transactions cannot be
+    /// constructed.
+    pub fn with_max_output_notes(mut self, max_output_notes: usize) -> Self {
+        self.max_output_notes = max_output_notes;
+        self
+    }
+
+    /// Sets the maximum size of a serialized output note, in bytes.
+    pub fn with_max_output_note_size(mut self, max_output_note_size: usize) -> Self {
+        self.max_output_note_size = max_output_note_size;
+        self
+    }
+
+    /// Sets the maximum size of the serialized account update details, in bytes.
+    ///
+    /// The limit applies to the account updates of all accounts, while [ACCOUNT_UPDATE_MAX_SIZE]
+    /// is always enforced for public accounts.
+    pub fn with_max_account_update_size(mut self, max_account_update_size: usize) -> Self {
+        self.max_account_update_size = max_account_update_size;
+        self
+    }
+
+    /// Returns the minimum security level that the transaction proof must have.
+    pub fn proof_security_level(&self) -> u32 {
+        self.proof_security_level
+    }
+
+    /// Returns the roots of the transaction kernels that are accepted.
+    pub fn accepted_kernel_roots(&self) -> &[Digest] {
+        &self.accepted_kernel_roots
+    }
+}
+
+// STATELESS VALIDATION
+// ================================================================================================
+
+/// Runs all checks on the provided [ProvenTransaction] which do not require access to the chain
+/// state.
+///
+/// The checks are executed in the order of the [StatelessValidationStage] variants, so that the
+/// proof is only verified once all cheaper checks have passed:
+/// 1. The root of the transaction kernel the proof is verified against must be accepted by the
+///    `config`.
+/// 2. The number of input and output notes and the sizes of the output notes are checked against
+///    the limits of the `config`.
+/// 3. The sender of every output note must be the account of the transaction.
+/// 4. The size of the account update is checked against the limit of the `config`.
+/// 5. The transaction proof is verified with the minimum security level of the `config`.
+///
+/// The transaction ID and the note commitments are not checked separately, since
+/// [ProvenTransaction] always derives them from its data and they are bound to the proof as its
+/// public inputs. Likewise, the account update is always valid for the storage mode of the account.
+///
+/// # Errors
+/// Returns an error describing the first failed check. The stage at which the validation failed
+/// is available through [StatelessValidationError::stage].
+///
+/// [StatelessValidationStage]: crate::StatelessValidationStage
+pub fn validate_proven_transaction_stateless(
+    tx: &ProvenTransaction,
+    config: &StatelessValidationConfig,
+) -> Result<(), StatelessValidationError> {
+    validate_kernel(config)?;
+    validate_note_limits(tx, config)?;
+    validate_output_note_senders(tx)?;
+    validate_account_update(tx, config)?;
+
+    TransactionVerifier::new(config.proof_security_level)
+        .verify_proof(tx)
+        .map(|_| ())
+        .map_err(StatelessValidationError::ProofVerificationFailed)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the root of the transaction kernel which proofs are verified against is accepted.
+fn validate_kernel(config: &StatelessValidationConfig) -> Result<(), StatelessValidationError> {
+    let kernel_root = TransactionKernel::kernel_root();
+    if !config.accepted_kernel_roots.contains(&kernel_root) {
+        return Err(StatelessValidationError::KernelRootNotAccepted(kernel_root));
+    }
+
+    Ok(())
+}
+
+/// Checks the number of notes of the transaction and the sizes of its output notes.
+fn validate_note_limits(
+    tx: &ProvenTransaction,
+    config: &StatelessValidationConfig,
+) -> Result<(), StatelessValidationError> {
+    if tx.input_notes().num_notes() > config.max_input_notes {
+        return Err(StatelessValidationError::TooManyInputNotes {
+            num_notes: tx.input_notes().num_notes(),
+            max: config.max_input_notes,
+        });
+    }
+
+    if tx.output_notes().num_notes() > config.max_output_notes {
+        return Err(StatelessValidationError::TooManyOutputNotes {
+            num_notes: tx.output_notes().num_notes(),
+            max: config.max_output_notes,
+        });
+    }
+
+    for note in tx.output_notes().iter() {
+        let size = note.to_bytes().len();
+        if size > config.max_output_note_size {
+            return Err(StatelessValidationError::OutputNoteTooLarge {
+                note_id: note.id(),
+                size,
+                max: config.max_output_note_size,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that all output notes of the transaction were created by the account of the transaction.
+fn validate_output_note_senders(tx: &ProvenTransaction) -> Result<(), StatelessValidationError> {
+    match tx
+        .output_notes()
+        .iter()
+        .find(|note| note.metadata().sender() != tx.account_id())
+    {
+        Some(note) => Err(StatelessValidationError::OutputNoteSenderMismatch {
+            note_id: note.id(),
+            sender: note.metadata().sender(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks the size of the account update of the transaction.
+fn validate_account_update(
+    tx: &ProvenTransaction,
+    config: &StatelessValidationConfig,
+) -> Result<(), StatelessValidationError> {
+    let size = tx.account_update().details().get_size_hint();
+    if size > config.max_account_update_size {
+        return Err(StatelessValidationError::AccountUpdateTooLarge {
+            size,
+            max: config.max_account_update_size,
+        });
+    }
+
+    Ok(())
+}
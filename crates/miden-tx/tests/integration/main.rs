@@ -15,7 +15,8 @@ use miden_objects::{
 };
 use miden_prover::ProvingOptions;
 use miden_tx::{
-    LocalTransactionProver, TransactionProver, TransactionVerifier, TransactionVerifierError,
+    validate_proven_transaction_stateless, LocalTransactionProver, StatelessValidationConfig,
+    StatelessValidationError, TransactionProver,
 };
 use vm_processor::utils::Deserializable;
 
@@ -44,7 +45,7 @@ macro_rules! assert_transaction_executor_error {
 #[cfg(test)]
 pub fn prove_and_verify_transaction(
     executed_transaction: ExecutedTransaction,
-) -> Result<(), TransactionVerifierError> {
+) -> Result<(), StatelessValidationError> {
     let executed_transaction_id = executed_transaction.id();
     // Prove the transaction
 
@@ -58,10 +59,11 @@ pub fn prove_and_verify_transaction(
     let serialised_transaction = proven_transaction.to_bytes();
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
 
-    // Verify that the generated proof is valid
-    let verifier = TransactionVerifier::new(miden_objects::MIN_PROOF_SECURITY_LEVEL);
-
-    verifier.verify(proven_transaction)
+    // Verify that the proven transaction passes all checks which do not require the chain state
+    validate_proven_transaction_stateless(
+        &proven_transaction,
+        &StatelessValidationConfig::new([TransactionKernel::kernel_root()]),
+    )
 }
 
 #[cfg(test)]
@@ -1,15 +1,19 @@
 extern crate alloc;
 
+use assert_matches::assert_matches;
 use miden_lib::{
     errors::tx_kernel_errors::ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
-    transaction::TransactionKernel,
+    note::create_burn_note, transaction::TransactionKernel,
 };
 use miden_objects::{
-    asset::{Asset, FungibleAsset},
-    note::{NoteAssets, NoteExecutionHint, NoteId, NoteMetadata, NoteTag, NoteType},
+    asset::{Asset, FungibleAsset, NonFungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    note::{
+        NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteId, NoteMetadata, NoteTag, NoteType,
+    },
     testing::prepare_word,
     transaction::TransactionScript,
-    Felt,
+    Felt, NoteError,
 };
 use miden_tx::testing::{Auth, MockChain};
 
@@ -214,3 +218,91 @@ fn prove_faucet_contract_burn_fungible_asset_succeeds() {
     assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(3)));
     assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
 }
+
+#[test]
+fn burn_note_decreases_faucet_issuance() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "TST", 200, Some(100));
+    let fungible_asset: Asset = FungibleAsset::new(faucet.id(), 40).unwrap().into();
+    let wallet = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![fungible_asset]);
+
+    let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    // assets issued by another faucet cannot be burned
+    let other_faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "OTH", 200, Some(100));
+    let other_asset: Asset = FungibleAsset::new(other_faucet.id(), 40).unwrap().into();
+    let result = create_burn_note(
+        wallet.id(),
+        faucet.id(),
+        vec![other_asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut rng,
+    );
+    assert_matches!(
+        result,
+        Err(NoteError::AssetNotIssuedByFaucet { faucet_id, asset })
+            if faucet_id == faucet.id() && asset == other_asset
+    );
+
+    // non-fungible assets cannot be burned
+    let non_fungible_asset = NonFungibleAsset::mock(&[1, 2, 3]);
+    let result = create_burn_note(
+        wallet.id(),
+        faucet.id(),
+        vec![non_fungible_asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut rng,
+    );
+    assert_matches!(
+        result,
+        Err(NoteError::AssetNotIssuedByFaucet { faucet_id, asset })
+            if faucet_id == faucet.id() && asset == non_fungible_asset
+    );
+
+    // non-fungible faucets cannot burn assets, even the ones they issued
+    let result = create_burn_note(
+        wallet.id(),
+        NonFungibleAsset::mock_issuer(),
+        vec![non_fungible_asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut rng,
+    );
+    assert_matches!(
+        result,
+        Err(NoteError::NotAFungibleFaucet(faucet_id)) if faucet_id == NonFungibleAsset::mock_issuer()
+    );
+
+    let note = create_burn_note(
+        wallet.id(),
+        faucet.id(),
+        vec![fungible_asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        note.metadata().tag(),
+        NoteTag::from_account_id(faucet.id(), NoteExecutionMode::Local).unwrap()
+    );
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    // the note burns its assets without creating any output notes
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+
+    mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_block(None);
+
+    assert_eq!(mock_chain.faucet_total_issued(faucet.id()), 60);
+}
@@ -1,5 +1,9 @@
 use miden_lib::{
-    errors::tx_kernel_errors::ERR_P2ID_TARGET_ACCT_MISMATCH, note::create_p2id_note,
+    errors::tx_kernel_errors::{
+        ERR_P2ID_TARGET_ACCT_MISMATCH, ERR_P2ID_TIMELOCK_TARGET_ACCT_MISMATCH,
+        ERR_P2ID_TIMELOCK_UNLOCK_TIMESTAMP_NOT_REACHED,
+    },
+    note::{create_p2id_note, create_p2id_timelock_note},
     transaction::TransactionKernel,
 };
 use miden_objects::{
@@ -16,9 +20,9 @@ use miden_objects::{
         prepare_word,
     },
     transaction::{OutputNote, TransactionScript},
-    Felt,
+    Felt, ZERO,
 };
-use miden_tx::testing::{Auth, MockChain};
+use miden_tx::testing::{fixtures, Auth, MockChain};
 
 use crate::{assert_transaction_executor_error, prove_and_verify_transaction};
 
@@ -89,6 +93,35 @@ fn p2id_script_multiple_assets() {
     assert_transaction_executor_error!(executed_transaction_2, ERR_P2ID_TARGET_ACCT_MISMATCH)
 }
 
+/// Consumes the shared P2ID note fixture with the shared wallet fixture
+#[test]
+fn prove_consume_fixture_p2id_note() {
+    let mut mock_chain = MockChain::new();
+
+    let account = fixtures::account::basic_wallet_a();
+    mock_chain.add_existing_account(account.clone(), Some(fixtures::account::authenticator()));
+
+    let note = fixtures::note::p2id_100_to(account.id());
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    let mut account_after = account.clone();
+    account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(
+        account_after.vault().assets().collect::<Vec<_>>(),
+        note.assets().iter().cloned().collect::<Vec<_>>()
+    );
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+
+    prove_and_verify_transaction(executed_transaction).unwrap();
+}
+
 /// Consumes an existing note with a new account
 #[test]
 fn prove_consume_note_with_new_account() {
@@ -296,3 +329,64 @@ fn test_create_consume_multiple_notes() {
     assert_eq!(account.vault().get_balance(input_note_faucet_id).unwrap(), 111);
     assert_eq!(account.vault().get_balance(FungibleAsset::mock_issuer()).unwrap(), 5);
 }
+
+/// Tests that a P2ID_TIMELOCK note can only be consumed by the target account in transactions
+/// whose reference block has a timestamp of at least the unlock timestamp.
+#[test]
+fn p2id_timelock_script() {
+    let mut mock_chain = MockChain::new();
+
+    let fungible_asset = FungibleAsset::mock(100);
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let malicious_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let unlock_timestamp = mock_chain.block_header(0).timestamp() + 3600;
+
+    let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let note = create_p2id_timelock_note(
+        sender_account.id(),
+        target_account.id(),
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        unlock_timestamp,
+        &mut rng,
+    )
+    .unwrap();
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block_with_timestamp(None, unlock_timestamp - 1);
+
+    // CONSUME NOTE BEFORE THE UNLOCK TIMESTAMP (Failure)
+    // --------------------------------------------------------------------------------------------
+    let early_result = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute();
+    assert_transaction_executor_error!(
+        early_result,
+        ERR_P2ID_TIMELOCK_UNLOCK_TIMESTAMP_NOT_REACHED
+    );
+
+    mock_chain.seal_block_with_timestamp(None, unlock_timestamp);
+
+    // CONSUME NOTE BY ANOTHER ACCOUNT AT THE UNLOCK TIMESTAMP (Failure)
+    // --------------------------------------------------------------------------------------------
+    let malicious_result = mock_chain
+        .build_tx_context(malicious_account.id(), &[note.id()], &[])
+        .build()
+        .execute();
+    assert_transaction_executor_error!(malicious_result, ERR_P2ID_TIMELOCK_TARGET_ACCT_MISMATCH);
+
+    // CONSUME NOTE BY TARGET AT THE UNLOCK TIMESTAMP (Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    let target_account = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(target_account.vault().get_balance(FungibleAsset::mock_issuer()).unwrap(), 100);
+}
@@ -1,15 +1,19 @@
-use miden_lib::errors::tx_kernel_errors::{
-    ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED,
+use miden_lib::{
+    errors::tx_kernel_errors::{
+        ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED,
+    },
+    note::create_p2idr_partial_note,
 };
 use miden_objects::{
     account::Account,
     asset::{Asset, AssetVault, FungibleAsset},
-    note::NoteType,
-    Felt,
+    crypto::rand::RpoRandomCoin,
+    note::{Note, NoteType},
+    Felt, ZERO,
 };
 use miden_tx::testing::{Auth, MockChain};
 
-use crate::assert_transaction_executor_error;
+use crate::{assert_transaction_executor_error, prove_and_verify_transaction};
 
 #[test]
 fn p2idr_script() {
@@ -124,3 +128,76 @@ fn p2idr_script() {
         ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER
     );
 }
+
+#[test]
+fn p2idr_partial_script() {
+    let mut mock_chain = MockChain::new();
+
+    let asset = FungibleAsset::new(FungibleAsset::mock_issuer(), 1000).unwrap();
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (note, change_note) = create_p2idr_partial_note(
+        sender_account.id(),
+        target_account.id(),
+        asset,
+        300,
+        NoteType::Public,
+        ZERO,
+        100.into(),
+        &mut rng,
+    )
+    .unwrap();
+    let change_note = change_note.unwrap();
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    // the sender cannot reclaim the note before the reclaim block height
+    let reclaim_result = mock_chain
+        .build_tx_context(sender_account.id(), &[note.id()], &[])
+        .build()
+        .execute();
+    assert_transaction_executor_error!(reclaim_result, ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED);
+
+    // CONSUME NOTE BY TARGET
+    // --------------------------------------------------------------------------------------------
+
+    let claim_tx = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    let target_account = mock_chain.apply_executed_transaction(&claim_tx);
+    assert_eq!(target_account.vault().get_balance(asset.faucet_id()).unwrap(), 300);
+
+    // the amount exceeding the claim cap is sent back to the sender in the change note
+    assert_eq!(claim_tx.output_notes().num_notes(), 1);
+    let output_change_note = claim_tx.output_notes().iter().next().unwrap().clone();
+    assert_eq!(output_change_note.id(), change_note.id());
+    assert_eq!(
+        output_change_note.assets().unwrap().iter().next().unwrap(),
+        &Asset::from(FungibleAsset::new(asset.faucet_id(), 700).unwrap())
+    );
+    assert!(prove_and_verify_transaction(claim_tx).is_ok());
+
+    // RECLAIM CHANGE NOTE BY SENDER
+    // --------------------------------------------------------------------------------------------
+
+    let full_change_note = Note::new(
+        change_note.assets().clone(),
+        *output_change_note.metadata(),
+        change_note.recipient().clone(),
+    );
+
+    let reclaim_change_tx = mock_chain
+        .build_tx_context(sender_account.id(), &[], &[full_change_note])
+        .build()
+        .execute()
+        .unwrap();
+
+    let sender_account = mock_chain.apply_executed_transaction(&reclaim_change_tx);
+    assert_eq!(sender_account.vault().get_balance(asset.faucet_id()).unwrap(), 700);
+}
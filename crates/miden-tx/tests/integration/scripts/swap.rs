@@ -1,12 +1,16 @@
-use miden_lib::{note::create_swap_note, transaction::TransactionKernel};
+use assert_matches::assert_matches;
+use miden_lib::{
+    note::{create_swap_note, utils::build_p2id_recipient, SwapNoteBuilder},
+    transaction::TransactionKernel,
+};
 use miden_objects::{
     account::AccountId,
-    asset::{Asset, NonFungibleAsset},
-    crypto::rand::RpoRandomCoin,
-    note::{Note, NoteDetails, NoteType},
-    testing::prepare_word,
+    asset::{Asset, FungibleAsset, NonFungibleAsset},
+    crypto::rand::{FeltRng, RpoRandomCoin},
+    note::{Note, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteTag, NoteType},
+    testing::{account_id::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, prepare_word},
     transaction::{OutputNote, TransactionScript},
-    Felt,
+    Felt, NoteError, Word,
 };
 use miden_tx::testing::{Auth, MockChain};
 
@@ -123,6 +127,108 @@ fn prove_consume_swap_note() {
     assert!(prove_and_verify_transaction(consume_payback_tx).is_ok());
 }
 
+// Checks that the payback note of a swap note can be derived by the note consumer: it is a P2ID
+// note to the sender whose recipient is built from the first word drawn from the RNG
+#[test]
+fn swap_note_payback_recipient_is_deterministic() {
+    let sender_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let offered_asset = FungibleAsset::mock(2000);
+    let requested_asset = NonFungibleAsset::mock(&[1, 2, 3, 4]);
+
+    let (note, payback_note) = get_swap_notes(sender_account_id, offered_asset, requested_asset);
+    let (_, other_payback_note) = get_swap_notes(sender_account_id, offered_asset, requested_asset);
+    assert_eq!(payback_note, other_payback_note);
+
+    let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let payback_recipient = build_p2id_recipient(sender_account_id, rng.draw_word()).unwrap();
+    assert_eq!(payback_note.recipient(), &payback_recipient);
+    assert_eq!(payback_note.assets().iter().collect::<Vec<_>>(), vec![&requested_asset]);
+
+    // the swap note commits to the payback recipient and the requested asset
+    let payback_recipient_word: Word = payback_recipient.digest().into();
+    let requested_asset_word: Word = requested_asset.into();
+    assert_eq!(&note.inputs().values()[..4], &payback_recipient_word);
+    assert_eq!(&note.inputs().values()[4..8], &requested_asset_word);
+}
+
+// Consumes a swap note with custom payback note metadata and checks that the payback note is
+// created with that metadata
+#[test]
+fn consume_swap_note_with_payback_metadata() {
+    let mut mock_chain = MockChain::new();
+    let offered_asset = mock_chain.add_new_faucet(Auth::BasicAuth, "USDT", 100000u64).mint(2000);
+    let requested_asset = NonFungibleAsset::mock(&[1, 2, 3, 4]);
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![offered_asset]);
+
+    let payback_tag = NoteTag::for_local_use_case(7, 0).unwrap();
+    let payback_aux = Felt::new(42);
+    let payback_execution_hint = NoteExecutionHint::on_block_slot(2, 3, 4);
+
+    let (note, payback_note) =
+        SwapNoteBuilder::new(sender_account.id(), offered_asset, requested_asset)
+            .note_type(NoteType::Public)
+            .aux(Felt::new(27))
+            .payback_tag(payback_tag)
+            .payback_aux(payback_aux)
+            .payback_execution_hint(payback_execution_hint)
+            .build(&mut RpoRandomCoin::new([
+                Felt::new(1),
+                Felt::new(2),
+                Felt::new(3),
+                Felt::new(4),
+            ]))
+            .unwrap();
+    assert_eq!(note.metadata().aux(), Felt::new(27));
+
+    // the payback recipient does not depend on the payback note metadata
+    let (_, default_payback_note) =
+        get_swap_notes(sender_account.id(), offered_asset, requested_asset);
+    assert_eq!(payback_note, default_payback_note);
+
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![requested_asset]);
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    let consume_swap_note_tx = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    let output_payback_note = consume_swap_note_tx.output_notes().iter().next().unwrap();
+    assert_eq!(output_payback_note.id(), payback_note.id());
+
+    let payback_metadata = output_payback_note.metadata();
+    assert_eq!(payback_metadata.sender(), target_account.id());
+    assert_eq!(payback_metadata.note_type(), NoteType::Private);
+    assert_eq!(payback_metadata.tag(), payback_tag);
+    assert_eq!(payback_metadata.aux(), payback_aux);
+    assert_eq!(payback_metadata.execution_hint(), payback_execution_hint);
+}
+
+#[test]
+fn swap_note_payback_tag_must_allow_private_notes() {
+    let sender_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let payback_tag = NoteTag::for_public_use_case(7, 0, NoteExecutionMode::Local).unwrap();
+
+    let result = SwapNoteBuilder::new(
+        sender_account_id,
+        FungibleAsset::mock(2000),
+        NonFungibleAsset::mock(&[1, 2, 3, 4]),
+    )
+    .payback_tag(payback_tag)
+    .build(&mut RpoRandomCoin::new([
+        Felt::new(1),
+        Felt::new(2),
+        Felt::new(3),
+        Felt::new(4),
+    ]));
+
+    assert_matches!(result, Err(NoteError::PublicUseCaseRequiresPublicNote(NoteType::Private)));
+}
+
 fn get_swap_notes(
     sender_account_id: AccountId,
     offered_asset: Asset,
@@ -0,0 +1,75 @@
+//! Smoke test of the Miden core crates without the standard library.
+//!
+//! This crate is built for `wasm32-unknown-unknown` in CI, which makes sure that the types wasm
+//! users depend on can be constructed and serialized without the standard library. The test of this
+//! crate runs the same checks on the host.
+#![no_std]
+
+use miden_air::HashFunction;
+use miden_lib::note::utils::build_p2id_recipient;
+use miden_objects::{
+    account::{AccountId, AccountStorageMode, AccountType},
+    block::BlockNumber,
+    note::{Note, NoteAssets, NoteMetadata},
+    transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder},
+    utils::serde::{Deserializable, Serializable},
+    vm::ExecutionProof,
+    Digest, Felt,
+};
+use winter_air::proof::Proof;
+
+// CONSTANTS
+// ================================================================================================
+
+/// A private regular account with updatable code, anchored to the genesis block.
+const ACCOUNT_ID: u128 = 0xaa00_0000_0000_0090_0000_00ab_0000_0000;
+
+// SMOKE TEST
+// ================================================================================================
+
+/// Constructs an [AccountId] and a [Note] created by that account, and serializes a
+/// [ProvenTransaction] which outputs the note.
+///
+/// # Panics
+/// Panics if any of the objects cannot be constructed or does not round-trip through its
+/// serialized form.
+pub fn smoke_test() {
+    let account_id = AccountId::try_from(ACCOUNT_ID).expect("account ID should be valid");
+    assert_eq!(account_id.account_type(), AccountType::RegularAccountUpdatableCode);
+    assert_eq!(account_id.storage_mode(), AccountStorageMode::Private);
+    assert_eq!(AccountId::from_hex(&account_id.to_hex()).unwrap(), account_id);
+
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let recipient =
+        build_p2id_recipient(account_id, serial_num).expect("p2id recipient should be valid");
+    let metadata = NoteMetadata::builder(account_id).build().expect("metadata should be valid");
+    let note = Note::new(NoteAssets::default(), metadata, recipient);
+    assert_eq!(Note::read_from_bytes(&note.to_bytes()).unwrap(), note);
+
+    // the transaction is only serialized, so it does not require a valid proof
+    let proof = ExecutionProof::new(Proof::new_dummy(), HashFunction::Blake3_192);
+    let proven_transaction = ProvenTransactionBuilder::new(
+        account_id,
+        Digest::default(),
+        Digest::from([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+        Digest::default(),
+        BlockNumber::from(1),
+        proof,
+    )
+    .add_output_notes([OutputNote::Header(*note.header())])
+    .build()
+    .expect("proven transaction should be valid");
+
+    let bytes = proven_transaction.to_bytes();
+    let deserialized = ProvenTransaction::read_from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized.id(), proven_transaction.id());
+    assert_eq!(deserialized.to_bytes(), bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn smoke_test() {
+        super::smoke_test();
+    }
+}